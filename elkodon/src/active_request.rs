@@ -0,0 +1,116 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .request_response()
+//! #   .open_or_create::<u64, u64>()?;
+//! # let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("received: {:?}", *request);
+//!     println!("request id {:?}, client id {:?}",
+//!         request.header().request_id(), request.header().client_id());
+//!
+//!     request.send_copy(*request + 1)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref, ptr::NonNull};
+
+use crate::port::port_identifiers::UniqueClientId;
+use crate::port::publisher::LoanError;
+use crate::port::server::{ResponseSendCopyError, Server};
+use crate::response_mut::ResponseMut;
+use crate::service::header::request_response::RequestHeader;
+use crate::{message::Message, service};
+use elkodon_bb_log::fail;
+
+/// It stores the request and is acquired by the [`Server`] whenever it receives a new request
+/// from a [`crate::port::client::Client`] via [`Server::receive()`]. Responses that are loaned
+/// via [`ActiveRequest::loan()`] are delivered only to the [`crate::port::client::Client`] that
+/// sent the request.
+#[derive(Debug)]
+pub struct ActiveRequest<
+    'a,
+    'server,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    pub(crate) server: &'server Server<'a, 'config, Service, RequestType, ResponseType>,
+    pub(crate) ptr: NonNull<Message<RequestHeader, RequestType>>,
+    pub(crate) channel_id: usize,
+    pub(crate) client_id: UniqueClientId,
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Deref
+    for ActiveRequest<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    type Target = RequestType;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &self.ptr.as_ref().data }
+    }
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Drop
+    for ActiveRequest<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        self.server
+            .release_request(self.channel_id, self.ptr.as_ptr() as *const u8);
+    }
+}
+
+impl<
+        'a,
+        'server,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > ActiveRequest<'a, 'server, 'config, Service, RequestType, ResponseType>
+{
+    /// Returns a reference to the payload of the request
+    pub fn payload(&self) -> &RequestType {
+        &unsafe { self.ptr.as_ref() }.data
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        &unsafe { self.ptr.as_ref() }.header
+    }
+
+    /// Loans/allocates a [`ResponseMut`] from the underlying data segment of the [`Server`].
+    /// When it is sent with [`ResponseMut::send()`] it is delivered to the
+    /// [`crate::port::client::Client`] that sent the request.
+    /// On failure it returns [`LoanError`] describing the failure.
+    pub fn loan(
+        &self,
+    ) -> Result<ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType>, LoanError>
+    {
+        self.server
+            .loan_response(self.channel_id, self.client_id, self.header().request_id())
+    }
+
+    /// Copies the input `value` into a [`ResponseMut`] and delivers it to the
+    /// [`crate::port::client::Client`] that sent the request.
+    /// On failure it returns [`ResponseSendCopyError`] describing the failure.
+    pub fn send_copy(&self, value: ResponseType) -> Result<(), ResponseSendCopyError> {
+        let msg = "Unable to send copy of response";
+        let mut response = fail!(from self, when self.loan(),
+                                    "{} since the loan of a response failed.", msg);
+
+        unsafe { response.as_mut_ptr().write(value) };
+        fail!(from self, when response.send(),
+            "{} since the underlying send operation failed.", msg);
+
+        Ok(())
+    }
+}
@@ -42,6 +42,27 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! use elkodon::config::Config;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//!
+//! // create a default config and override some entries
+//! let mut custom_config = Config::default();
+//! custom_config.defaults.request_response.max_active_requests = 8;
+//! custom_config.global.service.directory = "custom_service_dir".to_string();
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response_with_custom_config(&custom_config)
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Set Global Config From Custom File
 //!
 //! The [`crate::config::Config::setup_global_config_from_file()`] call must be the first
@@ -87,6 +108,7 @@ use std::time::Duration;
 use elkodon_bb_log::{fail, trace, warn};
 
 use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::port_factory::subscriber::ReceivePolicy;
 
 /// Path to the default config file
 #[cfg(target_os = "windows")]
@@ -123,6 +145,13 @@ pub struct Service {
     pub directory: String,
     /// The suffix of the publishers data segment
     pub publisher_data_segment_suffix: String,
+    /// The suffix of the subscribers data segment that receives the copies of the samples when
+    /// the payload is copied, see [`crate::service::PayloadTransfer::Copy`]
+    pub subscriber_data_segment_suffix: String,
+    /// The suffix of the clients data segment that stores the requests
+    pub client_data_segment_suffix: String,
+    /// The suffix of the servers data segment that stores the responses
+    pub server_data_segment_suffix: String,
     /// The suffix of the static config file
     pub static_config_storage_suffix: String,
     /// The suffix of the dynamic config file
@@ -132,6 +161,10 @@ pub struct Service {
     pub creation_timeout: Duration,
     /// The suffix of a one-to-one connection
     pub connection_suffix: String,
+    /// The suffix of the monitoring token every port holds to signal that its process is alive
+    pub port_monitor_suffix: String,
+    /// The suffix of the event every [`crate::service::watcher::ServiceWatcher`] waits on
+    pub service_watcher_suffix: String,
 }
 
 /// The global settings
@@ -164,6 +197,12 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern request-response
+    pub request_response: RequestResponse,
+    /// Default settings for the messaging pattern blackboard
+    pub blackboard: Blackboard,
+    /// Default settings for the messaging pattern pipeline
+    pub pipeline: Pipeline,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -190,6 +229,17 @@ pub struct PublishSubscribe {
     /// full. When safe overflow is activated, the [`crate::port::publisher::Publisher`] will
     /// replace the oldest [`crate::sample::Sample`] with the newest one.
     pub enable_safe_overflow: bool,
+    /// Defines if the [`crate::port::publisher::Publisher`] notifies the
+    /// [`crate::port::subscriber::Subscriber`]s whenever it delivers a [`crate::sample::Sample`].
+    pub enable_notifications: bool,
+    /// The maximum time a [`crate::port::subscriber::Subscriber`] sleeps in
+    /// [`crate::port::subscriber::Subscriber::timed_receive()`] or
+    /// [`crate::port::subscriber::Subscriber::blocking_receive()`] before it checks again for
+    /// new [`crate::sample::Sample`]s and if a termination was requested.
+    pub subscriber_wakeup_interval: Duration,
+    /// Defines from which [`crate::port::publisher::Publisher`] the
+    /// [`crate::port::subscriber::Subscriber`] receives the next [`crate::sample::Sample`].
+    pub subscriber_receive_policy: ReceivePolicy,
     /// If no safe overflow is activated it defines the deliver strategy of the
     /// [`crate::port::publisher::Publisher`] when the [`crate::port::subscriber::Subscriber`]s
     /// buffer is full.
@@ -207,6 +257,57 @@ pub struct Event {
     pub max_notifiers: usize,
 }
 
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RequestResponse {
+    /// The maximum amount of supported [`crate::port::client::Client`]
+    pub max_clients: usize,
+    /// The maximum amount of supported [`crate::port::server::Server`]
+    pub max_servers: usize,
+    /// The maximum amount of requests a [`crate::port::client::Client`] can have in flight, the
+    /// requests that were sent but not yet released by all [`crate::port::server::Server`]s.
+    pub max_active_requests: usize,
+    /// The maximum amount of [`crate::request_mut::RequestMut`]s a
+    /// [`crate::port::client::Client`] can loan in parallel.
+    pub client_max_loaned_requests: usize,
+    /// The maximum amount of [`crate::response_mut::ResponseMut`]s a
+    /// [`crate::port::server::Server`] can loan in parallel.
+    pub server_max_loaned_responses: usize,
+}
+
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+    /// The maximum amount of supported [`crate::port::writer::Writer`]
+    pub max_writers: usize,
+}
+
+/// Default settings for the pipeline messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Pipeline {
+    /// The maximum amount of supported [`crate::port::producer::Producer`]
+    pub max_producers: usize,
+    /// The maximum amount of supported [`crate::port::worker::Worker`]
+    pub max_workers: usize,
+    /// The maximum amount of [`crate::work_item::WorkItem`]s that can wait in the shared work
+    /// queue until a [`crate::port::worker::Worker`] takes them.
+    pub work_queue_capacity: usize,
+    /// The maximum amount of [`crate::work_item_mut::WorkItemMut`]s a
+    /// [`crate::port::producer::Producer`] can loan in parallel.
+    pub producer_max_loaned_samples: usize,
+    /// The maximum amount of [`crate::work_item::WorkItem`]s a [`crate::port::worker::Worker`]
+    /// can hold in parallel.
+    pub worker_max_borrowed_samples: usize,
+}
+
 /// Represents the configuration that Elkodon will utilize. It is divided into two sections:
 /// the [Global] settings, which must align with the Elkodon instance the application intends to
 /// join, and the [Defaults] for communication within that Elkodon instance. The user has the
@@ -233,10 +334,15 @@ impl Default for Config {
                 service: Service {
                     directory: "services".to_string(),
                     publisher_data_segment_suffix: ".publisher_data".to_string(),
+                    subscriber_data_segment_suffix: ".subscriber_data".to_string(),
+                    client_data_segment_suffix: ".client_data".to_string(),
+                    server_data_segment_suffix: ".server_data".to_string(),
                     static_config_storage_suffix: ".service".to_string(),
                     dynamic_config_storage_suffix: ".dynamic".to_string(),
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: ".connection".to_string(),
+                    port_monitor_suffix: ".port_monitor".to_string(),
+                    service_watcher_suffix: ".service_watcher".to_string(),
                 },
             },
             defaults: Defaults {
@@ -248,12 +354,33 @@ impl Default for Config {
                     subscriber_max_borrowed_samples: 2,
                     publisher_max_loaned_samples: 2,
                     enable_safe_overflow: true,
+                    enable_notifications: false,
+                    subscriber_wakeup_interval: Duration::from_millis(10),
+                    subscriber_receive_policy: ReceivePolicy::ConnectionOrder,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
                 },
                 event: Event {
                     max_listeners: 1,
                     max_notifiers: 16,
                 },
+                request_response: RequestResponse {
+                    max_clients: 8,
+                    max_servers: 2,
+                    max_active_requests: 4,
+                    client_max_loaned_requests: 2,
+                    server_max_loaned_responses: 2,
+                },
+                blackboard: Blackboard {
+                    max_readers: 8,
+                    max_writers: 1,
+                },
+                pipeline: Pipeline {
+                    max_producers: 4,
+                    max_workers: 8,
+                    work_queue_capacity: 16,
+                    producer_max_loaned_samples: 2,
+                    worker_max_borrowed_samples: 2,
+                },
             },
         }
     }
@@ -0,0 +1,423 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::introspection::{self, Introspector, PortState};
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service = zero_copy::Service::new(&ServiceName::new(b"My/Funk/IntrospectedService")?)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//! let _publisher = service.publisher().create()?;
+//!
+//! // publishes the state of all ports of all services, usually done periodically
+//! // in a background thread with [`introspection::IntrospectionThread`]
+//! let mut introspector = Introspector::<zero_copy::Service>::new();
+//! introspector.publish()?;
+//!
+//! // any tool can subscribe to the introspection service of a service
+//! let introspection_service =
+//!     zero_copy::Service::new(&introspection::service_name(service.uuid()))
+//!         .publish_subscribe()
+//!         .open::<[PortState]>()?;
+//! let subscriber = introspection_service.subscriber().create()?;
+//!
+//! // the subscriber receives the port states with the next publish
+//! introspector.publish()?;
+//! if let Some(states) = subscriber.receive()? {
+//!     for state in states.payload() {
+//!         println!("{:?} of process {}: {:?}", state.kind, state.pid, state);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_derive_macros::ZeroCopySend;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::{
+    clock::nanosleep,
+    thread::{Thread, ThreadBuilder, ThreadName},
+};
+use elkodon_cal::{dynamic_storage::DynamicStorage, zero_copy_connection::ZeroCopyConnection};
+use ouroboros::self_referencing;
+
+use crate::{
+    config,
+    port::publisher::Publisher,
+    service::{
+        self, connection_config, connection_name,
+        dynamic_config::{DynamicConfig, PortId},
+        messaging_pattern::MessagingPattern,
+        open_dynamic_config_for_inspection,
+        port_factory::publish_subscribe::PortFactory,
+        service_name::ServiceName,
+        static_config::StaticConfig,
+        Details, ServiceListError,
+    },
+};
+
+/// Every introspection service name starts with this prefix followed by the uuid of the
+/// introspected service.
+pub const SERVICE_NAME_PREFIX: &str = "elkodon://introspection/";
+
+/// The interval in which the [`IntrospectionThread`] checks whether it shall stop.
+const STOP_CHECK_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Returns the name of the introspection service of the service with the provided uuid, see
+/// for instance [`crate::service::port_factory::publish_subscribe::PortFactory::uuid()`].
+/// Panics when the provided uuid is not a valid part of a [`ServiceName`].
+pub fn service_name(service_uuid: &str) -> ServiceName {
+    let msg = "Unable to create the introspection service name";
+    let origin = "introspection::service_name()";
+    let mut name = fatal_panic!(from origin, when ServiceName::new(SERVICE_NAME_PREFIX.as_bytes()),
+                    "{} since the prefix \"{}\" is not a valid service name.", msg, SERVICE_NAME_PREFIX);
+    fatal_panic!(from origin, when name.push_bytes(service_uuid.as_bytes()),
+                    "{} since the uuid \"{}\" is either too long or contains invalid characters.", msg, service_uuid);
+    name
+}
+
+/// Returns true when the service is an introspection service.
+pub fn is_introspection_service(static_config: &StaticConfig) -> bool {
+    static_config
+        .service_name()
+        .as_bytes()
+        .starts_with(SERVICE_NAME_PREFIX.as_bytes())
+}
+
+/// The kind of a port that is described by a [`PortState`].
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+pub enum PortKind {
+    Publisher,
+    Subscriber,
+    Notifier,
+    Listener,
+    Client,
+    Server,
+    Reader,
+    Writer,
+    Producer,
+    Worker,
+}
+
+/// The state of a single port of a service as it is published on the introspection service.
+/// The sample counters of a [`PortKind::Publisher`] are the sums over all of its connections,
+/// the ones of a [`PortKind::Subscriber`] the sums over all connections it receives from. Ports
+/// that do not use connections report zero for all of them.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ZeroCopySend)]
+pub struct PortState {
+    /// The kind of the port.
+    pub kind: PortKind,
+    /// The process id of the process that owns the port.
+    pub pid: i32,
+    /// The unique id of the port.
+    pub port_id: u128,
+    /// The number of established connections.
+    pub number_of_connections: u64,
+    /// The number of samples that were sent but not yet received.
+    pub samples_in_buffer: u64,
+    /// The number of samples a subscriber has received but not yet released.
+    pub borrowed_samples: u64,
+    /// The number of samples a publisher has loaned but not yet sent or released.
+    pub loaned_samples: u64,
+    /// The number of samples that were discarded since the receive buffer was full.
+    pub dropped_samples: u64,
+}
+
+impl PortState {
+    fn new(port_id: &PortId) -> Self {
+        let kind = match port_id {
+            PortId::Publisher(_) => PortKind::Publisher,
+            PortId::Subscriber(_) => PortKind::Subscriber,
+            PortId::Notifier(_) => PortKind::Notifier,
+            PortId::Listener(_) => PortKind::Listener,
+            PortId::Client(_) => PortKind::Client,
+            PortId::Server(_) => PortKind::Server,
+            PortId::Reader(_) => PortKind::Reader,
+            PortId::Writer(_) => PortKind::Writer,
+            PortId::Producer(_) => PortKind::Producer,
+            PortId::Worker(_) => PortKind::Worker,
+        };
+
+        Self {
+            kind,
+            pid: port_id.system_id().pid().value(),
+            port_id: port_id.system_id().value(),
+            number_of_connections: 0,
+            samples_in_buffer: 0,
+            borrowed_samples: 0,
+            loaned_samples: 0,
+            dropped_samples: 0,
+        }
+    }
+}
+
+#[self_referencing]
+struct IntrospectionPublisher<'config, Service: service::Service + 'config> {
+    port_factory: PortFactory<'config, Service::Type<'config>, [PortState]>,
+    #[borrows(port_factory)]
+    #[not_covariant]
+    publisher: Publisher<'this, 'config, Service::Type<'config>, [PortState]>,
+}
+
+/// Publishes the [`PortState`]s of all ports of every service on a separate publish-subscribe
+/// service per introspected service, see [`service_name()`]. The introspection services are
+/// created on demand and removed as soon as the introspected service is gone.
+pub struct Introspector<'config, Service: service::Service + 'config> {
+    config: &'config config::Config,
+    publishers: HashMap<String, IntrospectionPublisher<'config, Service>>,
+}
+
+impl<'config, Service: service::Service + 'config> std::fmt::Debug
+    for Introspector<'config, Service>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Introspector {{ introspected_services: {:?} }}",
+            self.publishers.keys()
+        )
+    }
+}
+
+impl<'config, Service: service::Service + 'config> Introspector<'config, Service> {
+    /// Creates a new [`Introspector`] for the services of the global [`config::Config`].
+    pub fn new() -> Self {
+        Self::new_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Creates a new [`Introspector`] for the services of a given [`config::Config`].
+    pub fn new_with_custom_config(config: &'config config::Config) -> Self {
+        Self {
+            config,
+            publishers: HashMap::new(),
+        }
+    }
+
+    /// Publishes the current [`PortState`]s of every service. A service whose state cannot be
+    /// published is skipped with a warning.
+    pub fn publish(&mut self) -> Result<(), ServiceListError> {
+        let services = fail!(from self, when <Service::Type<'config> as Details<'config>>::list_with_custom_config(self.config),
+                                "Unable to publish the port states since the services could not be listed.");
+
+        let mut introspected_services = HashMap::new();
+        for static_config in services {
+            if is_introspection_service(&static_config) {
+                continue;
+            }
+
+            let uuid = static_config.uuid().to_string();
+            let dynamic_storage = match open_dynamic_config_for_inspection::<Service::Type<'config>>(
+                self.config,
+                &static_config,
+            ) {
+                Some(v) => v,
+                None => continue,
+            };
+            let dynamic_config = dynamic_storage.get();
+
+            let publisher = match self.publishers.remove(&uuid) {
+                Some(v) => v,
+                None => match self.create_publisher(&static_config, dynamic_config) {
+                    Some(v) => v,
+                    None => continue,
+                },
+            };
+
+            let states = self.collect_port_states(&static_config, dynamic_config);
+            if let Err(e) = publisher.with_publisher(|p| p.send_slice_copy(&states)) {
+                warn!(from self, "Unable to publish the port states of the service \"{}\" ({:?}).",
+                    static_config.service_name(), e);
+            }
+
+            introspected_services.insert(uuid, publisher);
+        }
+
+        // the introspection services of removed services are removed with their publishers
+        self.publishers = introspected_services;
+        Ok(())
+    }
+
+    /// Returns the current [`PortState`]s of a service without publishing them or [`None`] when
+    /// the service does not exist anymore.
+    pub fn port_states(&self, static_config: &StaticConfig) -> Option<Vec<PortState>> {
+        let dynamic_storage = open_dynamic_config_for_inspection::<Service::Type<'config>>(
+            self.config,
+            static_config,
+        )?;
+        Some(self.collect_port_states(static_config, dynamic_storage.get()))
+    }
+
+    fn create_publisher(
+        &self,
+        static_config: &StaticConfig,
+        dynamic_config: &DynamicConfig,
+    ) -> Option<IntrospectionPublisher<'config, Service>> {
+        let port_factory = match Service::new(&service_name(static_config.uuid()))
+            .publish_subscribe_with_custom_config(self.config)
+            .history_size(1)
+            .subscriber_max_buffer_size(1)
+            .enable_safe_overflow(true)
+            .max_slice_len(dynamic_config.max_number_of_ports())
+            .open_or_create::<[PortState]>()
+        {
+            Ok(v) => v,
+            Err(e) => {
+                warn!(from self, "Unable to open or create the introspection service of the service \"{}\" ({:?}).",
+                    static_config.service_name(), e);
+                return None;
+            }
+        };
+
+        IntrospectionPublisherTryBuilder {
+            port_factory,
+            publisher_builder: |port_factory| port_factory.publisher().create(),
+        }
+        .try_build()
+        .map_err(|e| {
+            warn!(from self, "Unable to create the introspection publisher of the service \"{}\" ({:?}).",
+                static_config.service_name(), e);
+        })
+        .ok()
+    }
+
+    fn collect_port_states(
+        &self,
+        static_config: &StaticConfig,
+        dynamic_config: &DynamicConfig,
+    ) -> Vec<PortState> {
+        let port_ids = dynamic_config.port_ids();
+        let mut states: Vec<PortState> = port_ids.iter().map(PortState::new).collect();
+
+        if let MessagingPattern::PublishSubscribe(_) = static_config.messaging_pattern() {
+            let pub_sub = dynamic_config.publish_subscribe();
+            for (publisher_id, loaned_samples) in pub_sub.publishers_with_loaned_samples() {
+                let publisher_index = match find(&states, publisher_id.0.value()) {
+                    Some(v) => v,
+                    None => continue,
+                };
+                states[publisher_index].loaned_samples = loaned_samples;
+
+                for subscriber_id in port_ids.iter().filter_map(|id| match id {
+                    PortId::Subscriber(v) => Some(v),
+                    _ => None,
+                }) {
+                    let connection_state =
+                        match <Service::Type<'config> as Details<'config>>::Connection::state_cfg(
+                            &connection_name(static_config.uuid(), publisher_id, *subscriber_id),
+                            &connection_config::<Service::Type<'config>>(self.config),
+                        ) {
+                            Ok(v) => v,
+                            // the connection is not yet established or already removed
+                            Err(_) => continue,
+                        };
+
+                    let samples_in_buffer = connection_state.number_of_samples_in_buffer as u64;
+                    let dropped_samples = connection_state.number_of_dropped_samples;
+
+                    let publisher = &mut states[publisher_index];
+                    publisher.number_of_connections += 1;
+                    publisher.samples_in_buffer += samples_in_buffer;
+                    publisher.dropped_samples += dropped_samples;
+
+                    if let Some(subscriber_index) = find(&states, subscriber_id.0.value()) {
+                        let subscriber = &mut states[subscriber_index];
+                        subscriber.number_of_connections += 1;
+                        subscriber.samples_in_buffer += samples_in_buffer;
+                        subscriber.borrowed_samples +=
+                            connection_state.number_of_borrowed_samples as u64;
+                        subscriber.dropped_samples += dropped_samples;
+                    }
+                }
+            }
+        }
+
+        states
+    }
+}
+
+impl<'config, Service: service::Service + 'config> Default for Introspector<'config, Service> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn find(states: &[PortState], port_id: u128) -> Option<usize> {
+    states.iter().position(|s| s.port_id == port_id)
+}
+
+/// Defines the failures that can occur when an [`IntrospectionThread`] is spawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntrospectionThreadSpawnError {
+    InternalError,
+}
+
+impl std::fmt::Display for IntrospectionThreadSpawnError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for IntrospectionThreadSpawnError {}
+
+/// Runs an [`Introspector`] for the services of the global [`config::Config`] in a background
+/// thread that publishes the [`PortState`]s periodically. The thread is stopped and joined
+/// when the [`IntrospectionThread`] goes out of scope.
+#[derive(Debug)]
+pub struct IntrospectionThread {
+    keep_running: Arc<AtomicBool>,
+    _thread: Thread<'static>,
+}
+
+impl IntrospectionThread {
+    /// Spawns the thread that publishes the [`PortState`]s of all services every `interval`.
+    pub fn spawn<Service: service::Service + 'static>(
+        interval: Duration,
+    ) -> Result<Self, IntrospectionThreadSpawnError> {
+        let keep_running = Arc::new(AtomicBool::new(true));
+        let thread_keep_running = keep_running.clone();
+
+        let thread = fail!(from "IntrospectionThread::spawn()",
+            when ThreadBuilder::new()
+                .name(&ThreadName::from(b"introspection"))
+                .spawn(move || {
+                    let mut introspector = Introspector::<Service>::new();
+                    while thread_keep_running.load(Ordering::Relaxed) {
+                        if let Err(e) = introspector.publish() {
+                            warn!(from "IntrospectionThread", "Unable to publish the port states ({:?}).", e);
+                        }
+
+                        let start = Instant::now();
+                        while thread_keep_running.load(Ordering::Relaxed) && start.elapsed() < interval {
+                            let _ = nanosleep(STOP_CHECK_INTERVAL.min(interval - start.elapsed().min(interval)));
+                        }
+                    }
+                }),
+            with IntrospectionThreadSpawnError::InternalError,
+            "Unable to spawn the introspection thread.");
+
+        Ok(Self {
+            keep_running,
+            _thread: thread,
+        })
+    }
+}
+
+impl Drop for IntrospectionThread {
+    fn drop(&mut self) {
+        // the thread is joined when the fields are dropped afterwards
+        self.keep_running.store(false, Ordering::Relaxed);
+    }
+}
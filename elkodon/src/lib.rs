@@ -8,9 +8,9 @@
 //!
 //! - Publish-Subscribe
 //! - Events
-//! - Request-Response (planned)
-//! - Pipeline (planned)
-//! - Blackboard (planned)
+//! - Request-Response
+//! - Pipeline
+//! - Blackboard
 //!
 //! For a comprehensive list of all planned features, please refer to the
 //! [GitHub Roadmap](https://github.com/elkodon/elkodon/ROADMAP.md).
@@ -157,6 +157,188 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! Explore a simple request-response setup where the client sends a request every second and
+//! the server answers every received request with a response that is delivered only to the
+//! client that sent the request.
+//!
+//! **Server (Process 1)**
+//!
+//! ```no_run
+//! use elkodon::prelude::*;
+//! use elkodon_bb_posix::signal::SignalHandler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ServiceName")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = service.server().create()?;
+//!
+//! while !SignalHandler::termination_requested() {
+//!     while let Some(request) = server.receive()? {
+//!         println!("received request: {:?}", *request);
+//!         request.send_copy(*request * 2)?;
+//!     }
+//!
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Client (Process 2)**
+//!
+//! ```no_run
+//! use elkodon::prelude::*;
+//! use elkodon_bb_posix::signal::SignalHandler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ServiceName")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let client = service.client().create()?;
+//!
+//! while !SignalHandler::termination_requested() {
+//!     let mut request = client.loan()?;
+//!     unsafe { request.as_mut_ptr().write(1234) };
+//!     client.send(request)?;
+//!
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//!
+//!     while let Some(response) = client.receive()? {
+//!         println!("received response: {:?}", *response);
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Pipeline
+//!
+//! Explore a simple pipeline setup where the producer puts a work item into the work queue every
+//! second and every work item is processed by exactly one of the running workers.
+//!
+//! **Worker (Process 1)**
+//!
+//! ```no_run
+//! use elkodon::prelude::*;
+//! use elkodon_bb_posix::signal::SignalHandler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Pipeline")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let worker = service.worker().create()?;
+//!
+//! while !SignalHandler::termination_requested() {
+//!     while let Some(work_item) = worker.receive()? {
+//!         println!("processing: {:?}", *work_item);
+//!     }
+//!
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Producer (Process 2)**
+//!
+//! ```no_run
+//! use elkodon::prelude::*;
+//! use elkodon_bb_posix::signal::SignalHandler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Pipeline")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let producer = service.producer().create()?;
+//!
+//! while !SignalHandler::termination_requested() {
+//!     let mut work_item = producer.loan()?;
+//!     unsafe { work_item.as_mut_ptr().write(1234) };
+//!     producer.send(work_item)?;
+//!
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! Explore a simple blackboard setup where the writer updates an entry every second and the
+//! reader prints the latest value of that entry every second.
+//!
+//! **Reader (Process 1)**
+//!
+//! ```no_run
+//! use elkodon::prelude::*;
+//! use elkodon_bb_posix::signal::SignalHandler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, 0)
+//!     .open_or_create()?;
+//!
+//! let reader = service.reader().create()?;
+//! let entry = reader.entry::<i32>(&0)?;
+//!
+//! while !SignalHandler::termination_requested() {
+//!     println!("current value: {}", entry.get());
+//!
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Writer (Process 2)**
+//!
+//! ```no_run
+//! use elkodon::prelude::*;
+//! use elkodon_bb_posix::signal::SignalHandler;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, 0)
+//!     .open_or_create()?;
+//!
+//! let writer = service.writer().create()?;
+//! let entry = writer.entry::<i32>(&0)?;
+//!
+//! let mut counter = 0;
+//! while !SignalHandler::termination_requested() {
+//!     counter += 1;
+//!     entry.update(counter);
+//!
+//!     std::thread::sleep(std::time::Duration::from_secs(1));
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Quality Of Services
 //!
 //! Quality of service settings, or service settings, play a crucial role in determining memory
@@ -212,6 +394,74 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"ReqResQos")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     // the maximum amount of clients of this service
+//!     .max_clients(4)
+//!     // the maximum amount of servers of this service
+//!     .max_servers(2)
+//!     // how many requests a client can have in flight in parallel
+//!     .max_active_requests(3)
+//!     .create::<u64, u64>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Pipeline
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"PipelineQos")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     // the maximum amount of producers of this service
+//!     .max_producers(2)
+//!     // the maximum amount of workers of this service
+//!     .max_workers(8)
+//!     // how many work items can wait in the work queue
+//!     .work_queue_capacity(16)
+//!     // how many work items a producer can loan in parallel
+//!     .producer_max_loaned_samples(2)
+//!     // how many work items a worker can process in parallel
+//!     .worker_max_borrowed_samples(1)
+//!     .create::<u64>()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"BlackboardQos")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     // the maximum amount of readers of this service
+//!     .max_readers(4)
+//!     // the maximum amount of writers of this service
+//!     .max_writers(2)
+//!     // the entries and their initial values, fixed for the lifetime of the service
+//!     .add::<i32>(0, -1)
+//!     .add::<f64>(1, 0.5)
+//!     .create()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Port Behavior
 //!
 //! Certain ports in elkodon provide users with the flexibility to define custom behaviors in
@@ -244,6 +494,35 @@
 //! # }
 //! ```
 //!
+//! # Async
+//!
+//! With the `async` feature, the
+//! [`Listener::wait_async()`](crate::port::listener::Listener) and
+//! [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber) futures are available.
+//! They work with any executor and wait without blocking a thread of the executor. With
+//! the additional `tokio` feature, the file descriptors of the ports are registered directly
+//! at the reactor of the tokio runtime when the futures are polled inside of it. This requires
+//! a runtime with IO enabled, see `enable_io()` of the runtime builder. In a runtime without
+//! IO the futures fall back to the background thread that is used by every other executor.
+//!
+//! # Tracing
+//!
+//! With the `tracing` feature, the creation and opening of services, the creation of ports and
+//! the core operations like `loan`, `send`, `receive`, `notify` and the waits of the
+//! [`Listener`](crate::port::listener::Listener) are instrumented with spans of the
+//! [tracing](https://docs.rs/tracing) crate. The spans of the hot path are on the `trace` level,
+//! the others on the `debug` level. Every log message of elkodon is forwarded as event to the
+//! `tracing` subscriber as well, so that tools like `tracing-chrome` or `tracing-flame` can
+//! record chrome://tracing timelines and flame graphs of the IPC latency.
+//!
+//! # Logging
+//!
+//! By default elkodon logs to the console. With the `log` feature every log message is
+//! forwarded to the [log](https://docs.rs/log) facade instead, so that it ends up in the existing
+//! logging pipeline of the application. The origin of a message becomes the target of the log
+//! record. When both, the `log` and the `tracing` feature, are enabled the messages are
+//! forwarded to `tracing`.
+//!
 //! # Custom Configuration
 //!
 //! Elkodon offers the flexibility to configure default quality of service settings, paths, and
@@ -252,14 +531,33 @@
 //! For in-depth details and examples, please visit the
 //! [GitHub config folder](https://github.com/elkodon/elkodon/tree/main/config).
 
+/// The request that is received by a [`crate::port::server::Server`] and answered with a
+/// [`crate::response_mut::ResponseMut`].
+pub mod active_request;
+
 /// Handles elkodons global configuration
 pub mod config;
 
+/// Publishes the state of all ports of every service so that tools can inspect them.
+pub mod introspection;
+
 pub(crate) mod message;
 
+/// Describes the memory representation of the payload of a [`crate::sample::Sample`].
+pub mod payload_type;
+
 /// The ports or communication endpoints of elkodon
 pub mod port;
 
+/// The request that is sent by a [`crate::port::client::Client`].
+pub mod request_mut;
+
+/// The response that is received by a [`crate::port::client::Client`].
+pub mod response;
+
+/// The response that is sent by a [`crate::port::server::Server`].
+pub mod response_mut;
+
 /// The payload that is received by a [`crate::port::subscriber::Subscriber`].
 pub mod sample;
 
@@ -270,11 +568,22 @@ pub mod sample_mut;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// Waits on multiple [`crate::port::listener::Listener`]s, deadlines and intervals at once.
+pub mod waitset;
+
+/// The payload that is received by a [`crate::port::worker::Worker`].
+pub mod work_item;
+
+/// The payload that is sent by a [`crate::port::producer::Producer`].
+pub mod work_item_mut;
+
 /// Loads a meaninful subset to cover 90% of the elkodon communication use cases.
 pub mod prelude {
     pub use crate::port::event_id::EventId;
     pub use crate::service::{
-        process_local, service_name::ServiceName, zero_copy, Details, Service,
+        copy, grouped, process_local, service_name::ServiceName, zero_copy, Details, Service,
     };
     pub use elkodon_bb_container::semantic_string::SemanticString;
+    pub use elkodon_bb_derive_macros::ZeroCopySend;
+    pub use elkodon_bb_elementary::zero_copy_send::ZeroCopySend;
 }
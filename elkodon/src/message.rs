@@ -1,5 +1,39 @@
+use std::alloc::Layout;
+
+use crate::payload_type::PayloadType;
+use elkodon_bb_log::fatal_panic;
+
 #[repr(C)]
 pub(crate) struct Message<Header, Data> {
     pub(crate) header: Header,
     pub(crate) data: Data,
 }
+
+/// The memory layout of a message whose payload is not known at compile time, like a slice. The
+/// payload is stored right after the header at `payload_offset`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct MessageLayout {
+    pub(crate) layout: Layout,
+    pub(crate) payload_offset: usize,
+}
+
+impl MessageLayout {
+    pub(crate) fn new<Header, MessageType: PayloadType + ?Sized>(
+        max_number_of_elements: usize,
+        payload_alignment: usize,
+    ) -> Self {
+        let origin = "MessageLayout::new()";
+        let payload = fatal_panic!(from origin,
+            when MessageType::layout(max_number_of_elements).and_then(|l| l.align_to(payload_alignment)),
+            "This should never happen! The payload layout of {} elements with an alignment of {} is invalid.",
+            max_number_of_elements, payload_alignment);
+
+        let (layout, payload_offset) = fatal_panic!(from origin, when Layout::new::<Header>().extend(payload),
+            "This should never happen! The message layout exceeds the maximum supported size.");
+
+        Self {
+            layout: layout.pad_to_align(),
+            payload_offset,
+        }
+    }
+}
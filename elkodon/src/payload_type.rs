@@ -0,0 +1,140 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/PointCloud")?;
+//!
+//! // a service with a sized payload type
+//! let sized = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! // a service with a slice payload type, every sample can contain up to 128 elements
+//! let service_name = ServiceName::new(b"My/Funk/PointCloudSlice")?;
+//! let slice = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .max_slice_len(128)
+//!     .open_or_create::<[u64]>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    alloc::{Layout, LayoutError},
+    fmt::Debug,
+};
+
+use elkodon_bb_elementary::zero_copy_send::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Structural description of a [`PayloadType`] that is stored in the static config of a
+/// service. Services can only be opened with a payload type that has the same fingerprint,
+/// independent of the name of the type.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TypeFingerprint {
+    pub(crate) size: usize,
+    pub(crate) alignment: usize,
+    #[serde(with = "serialize_as_i64")]
+    pub(crate) layout_hash: u64,
+}
+
+// TOML supports only signed 64-bit integers, therefore the hash is stored bitwise as i64
+mod serialize_as_i64 {
+    use super::*;
+
+    pub(super) fn serialize<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(*value as i64)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        Ok(i64::deserialize(deserializer)? as u64)
+    }
+}
+
+impl TypeFingerprint {
+    pub(crate) fn new() -> Self {
+        Self {
+            size: 0,
+            alignment: 1,
+            layout_hash: 0,
+        }
+    }
+
+    /// Returns the size of the type. For slices it is the size of a single element.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the alignment of the type.
+    pub fn alignment(&self) -> usize {
+        self.alignment
+    }
+
+    /// Returns the hash of the memory layout of the type, see [`ZeroCopySend::LAYOUT_HASH`].
+    pub fn layout_hash(&self) -> u64 {
+        self.layout_hash
+    }
+}
+
+/// Describes the memory representation of a payload that is transmitted via a
+/// [`crate::port::publisher::Publisher`]. It is implemented for every sized type `T` and for
+/// slices `[T]` when `T` is [`ZeroCopySend`], where a sized type is treated like a slice with
+/// exactly one element.
+pub trait PayloadType: Debug {
+    /// Returns the [`Layout`] of a payload that contains `number_of_elements` elements. If the
+    /// payload is not a slice `number_of_elements` is ignored.
+    fn layout(number_of_elements: usize) -> Result<Layout, LayoutError>;
+
+    /// Returns the [`TypeFingerprint`] of the payload.
+    fn type_fingerprint() -> TypeFingerprint;
+
+    /// Returns a pointer to the payload that starts at `ptr` and contains `number_of_elements`
+    /// elements.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must point to memory that is large enough to contain `number_of_elements`
+    ///    elements
+    ///  * `ptr` must be aligned to the alignment of [`PayloadType::layout()`]
+    #[doc(hidden)]
+    unsafe fn from_raw_parts(ptr: *mut u8, number_of_elements: usize) -> *mut Self;
+}
+
+impl<T: Debug + ZeroCopySend> PayloadType for T {
+    fn layout(_number_of_elements: usize) -> Result<Layout, LayoutError> {
+        Ok(Layout::new::<T>())
+    }
+
+    fn type_fingerprint() -> TypeFingerprint {
+        TypeFingerprint {
+            size: std::mem::size_of::<T>(),
+            alignment: std::mem::align_of::<T>(),
+            layout_hash: T::LAYOUT_HASH,
+        }
+    }
+
+    unsafe fn from_raw_parts(ptr: *mut u8, _number_of_elements: usize) -> *mut Self {
+        ptr as *mut T
+    }
+}
+
+impl<T: Debug + ZeroCopySend> PayloadType for [T] {
+    fn layout(number_of_elements: usize) -> Result<Layout, LayoutError> {
+        Layout::array::<T>(number_of_elements)
+    }
+
+    fn type_fingerprint() -> TypeFingerprint {
+        TypeFingerprint {
+            size: std::mem::size_of::<T>(),
+            alignment: std::mem::align_of::<T>(),
+            layout_hash: combine_layout_hash(layout_hash_of(b"slice"), T::LAYOUT_HASH),
+        }
+    }
+
+    unsafe fn from_raw_parts(ptr: *mut u8, number_of_elements: usize) -> *mut Self {
+        std::ptr::slice_from_raw_parts_mut(ptr as *mut T, number_of_elements)
+    }
+}
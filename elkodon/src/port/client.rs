@@ -0,0 +1,643 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let client = service
+//!     .client()
+//!     // defines how many requests can be loaned in parallel
+//!     .max_loaned_requests(2)
+//!     .create()?;
+//!
+//! // loan some memory and send the request
+//! let mut request = client.loan()?;
+//! unsafe {
+//!     request.as_mut_ptr().write(1337);
+//! }
+//! client.send(request)?;
+//!
+//! // send a copy of the value
+//! client.send_copy(313)?;
+//!
+//! // acquire all responses the servers have sent so far
+//! while let Some(response) = client.receive()? {
+//!     println!("response {:?} to request {}", *response, response.header().request_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+
+use super::details::publisher_connections::ConnectionFailure;
+use super::details::server_connections::*;
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::publisher::LoanError;
+use crate::message::Message;
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+use crate::request_mut::RequestMut;
+use crate::response::Response;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::port_factory::client::LocalClientConfig;
+use crate::service::static_config::request_response;
+use crate::{config, service};
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_elementary::allocator::AllocationError;
+use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_lock_free::mpmc::container::ContainerState;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
+use elkodon_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+};
+use elkodon_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError};
+use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
+use elkodon_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use elkodon_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopyReleaseError,
+    ZeroCopySendError, ZeroCopySender,
+};
+
+/// Defines a failure that can occur when a [`Client`] is created with
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    ExceedsMaxSupportedClients,
+    UnableToCreateDataSegment,
+    UnableToCreateMonitoringToken,
+}
+
+impl std::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a [`RequestMut`] is sent via [`Client::send()`].
+    RequestSendError
+  entry:
+    ExceedsMaxActiveRequests
+  mapping:
+    ConnectionFailure
+}
+
+impl std::fmt::Display for RequestSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestSendError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a request is sent via [`Client::send_copy()`].
+    RequestSendCopyError
+  mapping:
+    LoanError to LoanError,
+    RequestSendError to SendError
+}
+
+impl std::fmt::Display for RequestSendCopyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestSendCopyError {}
+
+/// Defines the failure that can occur when receiving a [`Response`] with [`Client::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ResponseReceiveError {
+    ExceedsMaxBorrowedResponses,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ResponseReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseReceiveError {}
+
+pub(crate) fn data_segment_name(client_id: UniqueClientId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the clients data segment.";
+    let origin = "data_segment_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(client_id.0.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(client_id.0.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn data_segment_config<'config, Service: service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
+    let origin = "data_segment_config()";
+
+    let f = match FileName::new(
+        global_config
+            .global
+            .service
+            .client_data_segment_suffix
+            .as_bytes(),
+    ) {
+        Err(_) => {
+            fatal_panic!(from origin, "The client_data_segment_suffix \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.service.client_data_segment_suffix);
+        }
+        Ok(v) => v,
+    };
+
+    <Service::SharedMemory as NamedConceptMgmt>::Configuration::default().suffix(f)
+}
+
+/// The requesting endpoint of a request-response based communication. It sends requests to all
+/// connected [`crate::port::server::Server`]s and receives only the responses that answer its
+/// own requests.
+#[derive(Debug)]
+pub struct Client<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    port_id: UniqueClientId,
+    pub(crate) sample_reference_counter: Vec<AtomicU64>,
+    pub(crate) data_segment: Service::SharedMemory,
+    config: LocalClientConfig,
+    max_active_requests: usize,
+
+    server_connections: ServerConnections<'config, Service>,
+    server_list_state: UnsafeCell<ContainerState<'a, UniqueServerId>>,
+    active_requests: AtomicUsize,
+    request_id_counter: AtomicU64,
+    pub(crate) loan_counter: AtomicUsize,
+    _dynamic_config_guard: DynamicConfigGuard<'a>,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Client<'a, 'config, Service, RequestType, ResponseType>
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Client::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &request_response::StaticConfig,
+        config: &LocalClientConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let port_id = UniqueClientId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with ClientCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+        let server_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests = static_config.max_active_requests + config.max_loaned_requests + 1;
+
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_requests),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let _dynamic_config_guard = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(port_id)
+        {
+            Some(unique_index) => {
+                DynamicConfigGuard::new(unique_index, service.state().global_config)
+            }
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                            "{} since it would exceed the maximum supported amount of clients of {}.",
+                            msg, static_config.max_clients);
+            }
+        };
+
+        let new_self = Self {
+            _monitoring_token,
+            port_id,
+            sample_reference_counter: {
+                let mut v = Vec::with_capacity(number_of_requests);
+                for _ in 0..number_of_requests {
+                    v.push(AtomicU64::new(0));
+                }
+                v
+            },
+            data_segment,
+            config: *config,
+            max_active_requests: static_config.max_active_requests,
+            server_connections: ServerConnections::new(
+                server_list.capacity(),
+                port_id,
+                service.state().global_config,
+                service.state().static_config.uuid(),
+                static_config,
+                number_of_requests,
+                std::mem::size_of::<Message<RequestHeader, RequestType>>(),
+            ),
+            server_list_state: unsafe { UnsafeCell::new(server_list.get_state()) },
+            active_requests: AtomicUsize::new(0),
+            request_id_counter: AtomicU64::new(0),
+            loan_counter: AtomicUsize::new(0),
+            _dynamic_config_guard,
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn create_data_segment(
+        port_id: UniqueClientId,
+        global_config: &'config config::Config,
+        number_of_requests: usize,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: Layout::new::<Message<RequestHeader, RequestType>>(),
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        Ok(fail!(from "Client::create_data_segment()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&data_segment_name(port_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(chunk_size * number_of_requests + chunk_align - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment."))
+    }
+
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.capacity(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|index, server_id| {
+                visited_indices[index as usize] = Some(*server_id);
+            })
+        };
+
+        // retrieve requests before destroying channel
+        self.retrieve_returned_requests();
+
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(server_id) => {
+                    // the slot was reused by a new server since the last update
+                    if let Some(connection) = self.server_connections.get(i) {
+                        if connection.server_id != *server_id {
+                            self.remove_connection(i);
+                        }
+                    }
+
+                    if let Err(e) = self.server_connections.create(i, *server_id) {
+                        fail!(from self, with e,
+                            "Unable to establish connection to new server {:?}.", server_id);
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.server_connections.get(i) {
+            // the server is gone and will never return the requests it still owns
+            unsafe {
+                connection
+                    .request_sender
+                    .acquire_used_offsets(|offset| self.release_returned_request(offset))
+            };
+        }
+
+        self.server_connections.remove(i);
+    }
+
+    fn sample_index(distance_to_chunk: usize) -> usize {
+        distance_to_chunk / std::mem::size_of::<Message<RequestHeader, RequestType>>()
+    }
+
+    pub(crate) fn release_request(&self, distance_to_chunk: PointerOffset) {
+        if self.sample_reference_counter[Self::sample_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment
+                .deallocate(
+                    distance_to_chunk,
+                    Layout::new::<Message<RequestHeader, RequestType>>(),
+                ), "Internal logic error. The request should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    fn release_returned_request(&self, ptr_dist: PointerOffset) {
+        let sample_index = Self::sample_index(ptr_dist.value());
+
+        if self.sample_reference_counter[sample_index].fetch_sub(1, Ordering::Relaxed) == 1 {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment
+                .deallocate(
+                    ptr_dist,
+                    Layout::new::<Message<RequestHeader, RequestType>>(),
+                ), "This should never happen! Failed to deallocate the reclaimed ptr. Either the data was corrupted or an invalid ptr was returned.")
+            };
+            self.active_requests.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.release_returned_request(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Client::send", level = "trace", skip_all, ret)
+    )]
+    fn send_impl(&self, address_to_chunk: usize) -> Result<usize, RequestSendError> {
+        let msg = "Unable to send request";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_requests();
+        if self.active_requests.load(Ordering::Relaxed) >= self.max_active_requests {
+            fail!(from self, with RequestSendError::ExceedsMaxActiveRequests,
+                "{} since already {} requests are in flight and it would exceed the maximum of {} active requests.",
+                msg, self.active_requests.load(Ordering::Relaxed), self.max_active_requests);
+        }
+
+        let mut number_of_recipients = 0;
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                match connection
+                    .request_sender
+                    .try_send(PointerOffset::new(address_to_chunk))
+                {
+                    Err(ZeroCopySendError::ReceiveBufferFull) => {
+                        warn!(from self, "Unable to send request via connection {:?} since the servers buffer is full.", connection);
+                    }
+                    Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                        warn!(from self, "Unable to send request via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection);
+                    }
+                    Err(ZeroCopySendError::OffsetOutOfRange) => {
+                        warn!(from self, "Unable to send request via connection {:?} since the request is not contained in the data segment of the connection.", connection);
+                    }
+                    Ok(_) => {
+                        self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                            .fetch_add(1, Ordering::Relaxed);
+                        number_of_recipients += 1;
+                    }
+                }
+            }
+        }
+
+        if number_of_recipients != 0 {
+            self.active_requests.fetch_add(1, Ordering::Relaxed);
+        }
+
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn release_response(&self, channel_id: usize, response: *const u8) {
+        match self.server_connections.get(channel_id) {
+            Some(c) => {
+                let distance = response as usize - c.data_segment.allocator_data_start_address();
+                match c.response_receiver.release(PointerOffset::new(distance)) {
+                    Ok(()) => (),
+                    Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                        fatal_panic!(from self, when c.response_receiver.release(PointerOffset::new(distance)),
+                                    "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release response since the connection is broken. The response will be discarded and has to be reclaimed manually by the server.");
+            }
+        }
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.port_id
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::server::Server`]s. This is
+    /// required to be called whenever a new [`crate::port::server::Server`] connected to
+    /// the service. It is done implicitly whenever [`Client::send()`], [`Client::send_copy()`]
+    /// or [`Client::receive()`] is called.
+    pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.server_list_state.get()).update() } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a Server port failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Send a previously loaned [`Client::loan()`] [`RequestMut`] to all connected
+    /// [`crate::port::server::Server`]s of the service.
+    /// On success it returns the number of [`crate::port::server::Server`]s that received
+    /// the request, otherwise a [`RequestSendError`] describing the failure.
+    pub fn send<'client>(
+        &'client self,
+        request: RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType>,
+    ) -> Result<usize, RequestSendError> {
+        Ok(
+            fail!(from self, when self.send_impl(request.offset_to_chunk().value()),
+            "Unable to send request since the underlying send failed."),
+        )
+    }
+
+    /// Copies the input `value` into a [`RequestMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::server::Server`]s that received
+    /// the request, otherwise a [`RequestSendCopyError`] describing the failure.
+    pub fn send_copy(&self, value: RequestType) -> Result<usize, RequestSendCopyError> {
+        let msg = "Unable to send copy of request";
+        let mut request = fail!(from self, when self.loan(),
+                                    "{} since the loan of a request failed.", msg);
+
+        unsafe { request.as_mut_ptr().write(value) };
+        Ok(
+            fail!(from self, when self.send_impl(request.offset_to_chunk().value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`].
+    /// Every loaned request gets a new request id assigned that is part of the
+    /// [`RequestHeader`]. On failure it returns [`LoanError`] describing the failure.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Client::loan", level = "trace", skip_all)
+    )]
+    pub fn loan<'client>(
+        &'client self,
+    ) -> Result<RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType>, LoanError>
+    {
+        self.retrieve_returned_requests();
+        let msg = "Unable to loan Request";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_requests {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} requests were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned request to loan another request.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_requests);
+        }
+
+        match self
+            .data_segment
+            .allocate(Layout::new::<Message<RequestHeader, RequestType>>())
+        {
+            Ok(chunk) => {
+                if self.sample_reference_counter[Self::sample_index(chunk.offset.value())]
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated request is already in use! This should never happen!", msg);
+                }
+
+                let request_id = self.request_id_counter.fetch_add(1, Ordering::Relaxed);
+                let mut chunk_ptr;
+                unsafe {
+                    chunk_ptr = NonNull::new_unchecked(
+                        chunk.data_ptr as *mut MaybeUninit<Message<RequestHeader, RequestType>>,
+                    );
+                    let header_ptr =
+                        std::ptr::addr_of_mut!((*chunk_ptr.as_mut().as_mut_ptr()).header);
+                    header_ptr.write(RequestHeader::new(self.port_id, request_id))
+                }
+
+                Ok(RequestMut::new(self, chunk_ptr, chunk.offset))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    /// Receives a [`Response`] from a [`crate::port::server::Server`] that answers one of the
+    /// requests of this [`Client`]. If no response could be received [`None`] is returned.
+    /// If a failure occurs [`ResponseReceiveError`] is returned.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Client::receive", level = "trace", skip_all)
+    )]
+    pub fn receive<'client>(
+        &'client self,
+    ) -> Result<
+        Option<Response<'a, 'client, 'config, Service, ResponseHeader, RequestType, ResponseType>>,
+        ResponseReceiveError,
+    > {
+        let msg = "Unable to receive another response";
+        if let Err(e) = self.update_connections() {
+            fail!(from self,
+                with ResponseReceiveError::ConnectionFailure(e),
+                "Some responses are not being received since not all connections to servers could be established.");
+        }
+
+        for id in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(id) {
+                match connection.response_receiver.receive() {
+                    Ok(None) => (),
+                    Ok(Some(relative_addr)) => {
+                        let absolute_address = relative_addr.value()
+                            + connection.data_segment.allocator_data_start_address();
+                        return Ok(Some(Response {
+                            client: self,
+                            channel_id: id,
+                            ptr: unsafe {
+                                NonNull::new_unchecked(
+                                    absolute_address as *mut Message<ResponseHeader, ResponseType>,
+                                )
+                            },
+                        }));
+                    }
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ResponseReceiveError::ExceedsMaxBorrowedResponses,
+                            "{} since it would exceed the maximum {} of borrowed responses.",
+                            msg, connection.response_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
@@ -0,0 +1,48 @@
+use elkodon_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+
+use crate::service::builder::blackboard::BlackboardKey;
+use crate::service::{dynamic_config, static_config::blackboard::StaticConfig};
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub(crate) enum EntryLookupFailure {
+    EntryDoesNotExist,
+    IncompatibleType,
+}
+
+/// The key table is stored at the beginning of the blackboard memory, followed by the
+/// entries. The key at index `n` identifies the entry `n` of the [`StaticConfig`].
+fn keys<'a, KeyType: BlackboardKey>(
+    static_config: &StaticConfig,
+    dynamic_config: &'a dynamic_config::blackboard::DynamicConfig,
+) -> &'a [KeyType] {
+    unsafe {
+        std::slice::from_raw_parts(
+            dynamic_config.entries() as *const KeyType,
+            static_config.entries.len(),
+        )
+    }
+}
+
+/// Returns the index of the entry that is identified by `key` together with its value.
+pub(crate) fn lookup<'a, KeyType: BlackboardKey, ValueType: Copy>(
+    static_config: &StaticConfig,
+    dynamic_config: &'a dynamic_config::blackboard::DynamicConfig,
+    key: &KeyType,
+) -> Result<(usize, &'a UnrestrictedAtomic<ValueType>), EntryLookupFailure> {
+    let index = match keys::<KeyType>(static_config, dynamic_config)
+        .iter()
+        .position(|k| k == key)
+    {
+        Some(index) => index,
+        None => return Err(EntryLookupFailure::EntryDoesNotExist),
+    };
+
+    let entry = &static_config.entries[index];
+    if entry.type_name != std::any::type_name::<ValueType>() {
+        return Err(EntryLookupFailure::IncompatibleType);
+    }
+
+    Ok((index, unsafe {
+        &*(dynamic_config.entries().add(entry.offset) as *const UnrestrictedAtomic<ValueType>)
+    }))
+}
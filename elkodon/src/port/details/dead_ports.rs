@@ -0,0 +1,201 @@
+use elkodon_bb_log::warn;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::*;
+use elkodon_cal::named_concept::*;
+
+use crate::{
+    port::{client, publisher, server, subscriber},
+    service::{
+        self, connection_config, connection_name, dynamic_config::PortId, event_concept_name,
+        port_monitor_config, port_monitor_name, request_connection_name, response_connection_name,
+        watcher::notify_service_watchers,
+    },
+};
+
+/// Creates the token that marks the port as alive as long as the owning process is alive.
+/// It must be created before the port is added to the dynamic config.
+pub(crate) fn create_port_token<'config, Service: service::Details<'config>>(
+    service: &Service,
+    port_id: &UniqueSystemId,
+) -> Result<<Service::Monitoring as Monitoring>::Token, MonitoringCreateTokenError> {
+    <<Service::Monitoring as Monitoring>::Builder as NamedConceptBuilder<Service::Monitoring>>::new(
+        &port_monitor_name(port_id),
+    )
+    .config(&port_monitor_config::<Service>(
+        service.state().global_config,
+    ))
+    .token()
+}
+
+/// Removes all ports of the service whose owning process died together with their stale
+/// resources, like connections and data segments.
+pub(crate) fn remove_dead_ports<'config, Service: service::Details<'config>>(service: &Service) {
+    let mut has_removed_ports = false;
+    unsafe {
+        service
+            .state()
+            .dynamic_storage
+            .get()
+            .remove_dead_ports(|port_id| {
+                if !acquire_cleaner_and_remove_resources(service, port_id) {
+                    return false;
+                }
+
+                warn!(from "remove_dead_ports()",
+                    "Removed the port {:?} since its owning process is no longer alive.", port_id);
+                has_removed_ports = true;
+                true
+            })
+    };
+
+    if has_removed_ports {
+        notify_service_watchers(service.state().global_config);
+    }
+}
+
+fn acquire_cleaner_and_remove_resources<'config, Service: service::Details<'config>>(
+    service: &Service,
+    port_id: PortId,
+) -> bool {
+    let origin = "remove_dead_ports()";
+    let global_config = service.state().global_config;
+    let name = port_monitor_name(port_id.system_id());
+    let config = port_monitor_config::<Service>(global_config);
+
+    let monitor = match <<Service::Monitoring as Monitoring>::Builder as NamedConceptBuilder<
+        Service::Monitoring,
+    >>::new(&name)
+    .config(&config)
+    .monitor()
+    {
+        Ok(monitor) => monitor,
+        Err(e) => {
+            warn!(from origin, "Unable to monitor the port {:?} ({:?}).", port_id, e);
+            return false;
+        }
+    };
+
+    match monitor.state() {
+        Ok(State::Dead) => (),
+        Ok(State::Alive) | Ok(State::DoesNotExist) => return false,
+        Err(e) => {
+            warn!(from origin, "Unable to acquire the state of the port {:?} ({:?}).", port_id, e);
+            return false;
+        }
+    }
+
+    // only the one participant that holds the cleaner removes the resources of the dead port,
+    // the token of the port is removed when the cleaner goes out of scope
+    let _cleaner = match <<Service::Monitoring as Monitoring>::Builder as NamedConceptBuilder<
+        Service::Monitoring,
+    >>::new(&name)
+    .config(&config)
+    .cleaner()
+    {
+        Ok(cleaner) => cleaner,
+        Err(_) => return false,
+    };
+
+    match port_id {
+        PortId::Publisher(id) => remove_resource::<Service::SharedMemory>(
+            &publisher::data_segment_name(id),
+            &publisher::data_segment_config::<Service>(global_config),
+        ),
+        PortId::Client(id) => remove_resource::<Service::SharedMemory>(
+            &client::data_segment_name(id),
+            &client::data_segment_config::<Service>(global_config),
+        ),
+        PortId::Server(id) => remove_resource::<Service::SharedMemory>(
+            &server::data_segment_name(id),
+            &server::data_segment_config::<Service>(global_config),
+        ),
+        PortId::Listener(_) => remove_resource::<Service::Event>(
+            &event_concept_name(port_id.system_id()),
+            &<Service::Event as NamedConceptMgmt>::Configuration::default(),
+        ),
+        // subscribers own an event concept when the service has notifications enabled and a
+        // data segment when the payload is copied
+        PortId::Subscriber(id) => {
+            remove_resource::<Service::Event>(
+                &event_concept_name(port_id.system_id()),
+                &<Service::Event as NamedConceptMgmt>::Configuration::default(),
+            );
+            remove_resource::<Service::SharedMemory>(
+                &subscriber::data_segment_name(id),
+                &subscriber::data_segment_config::<Service>(global_config),
+            );
+        }
+        _ => (),
+    }
+
+    remove_connections(service, port_id);
+
+    true
+}
+
+// removes the connections between the dead port and every counterpart that is still
+// registered in the service
+fn remove_connections<'config, Service: service::Details<'config>>(
+    service: &Service,
+    port_id: PortId,
+) {
+    let uuid = service.state().static_config.uuid();
+    let dynamic_config = service.state().dynamic_storage.get();
+    let mut connections = vec![];
+
+    match port_id {
+        PortId::Publisher(id) => unsafe {
+            dynamic_config
+                .publish_subscribe()
+                .subscribers
+                .get_state()
+                .for_each(|_, subscriber_id| {
+                    connections.push(connection_name(uuid, id, *subscriber_id))
+                })
+        },
+        PortId::Subscriber(id) => unsafe {
+            dynamic_config
+                .publish_subscribe()
+                .publishers
+                .get_state()
+                .for_each(|_, publisher_id| {
+                    connections.push(connection_name(uuid, *publisher_id, id))
+                })
+        },
+        PortId::Client(id) => unsafe {
+            dynamic_config
+                .request_response()
+                .servers
+                .get_state()
+                .for_each(|_, server_id| {
+                    connections.push(request_connection_name(uuid, id, *server_id));
+                    connections.push(response_connection_name(uuid, *server_id, id));
+                })
+        },
+        PortId::Server(id) => unsafe {
+            dynamic_config
+                .request_response()
+                .clients
+                .get_state()
+                .for_each(|_, client_id| {
+                    connections.push(request_connection_name(uuid, *client_id, id));
+                    connections.push(response_connection_name(uuid, id, *client_id));
+                })
+        },
+        _ => (),
+    }
+
+    let config = connection_config::<Service>(service.state().global_config);
+    for connection in connections {
+        remove_resource::<Service::Connection>(&connection, &config);
+    }
+}
+
+fn remove_resource<Concept: NamedConceptMgmt>(name: &FileName, config: &Concept::Configuration) {
+    if let Err(e) = unsafe { Concept::remove_cfg(name, config) } {
+        warn!(from "remove_dead_ports()",
+            "Unable to remove the stale resource \"{}\" of a dead port ({:?}).", name, e);
+    }
+}
@@ -0,0 +1,36 @@
+use std::mem::ManuallyDrop;
+
+use elkodon_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
+
+use crate::{config, service::watcher::notify_service_watchers};
+
+/// Keeps a port registered in the dynamic config of its service. The
+/// [`crate::service::watcher::ServiceWatcher`]s are notified when the port is registered and
+/// again after it was removed when the guard goes out of scope.
+#[derive(Debug)]
+pub(crate) struct DynamicConfigGuard<'a> {
+    unique_index: ManuallyDrop<UniqueIndex<'a>>,
+    global_config: &'a config::Config,
+}
+
+impl<'a> DynamicConfigGuard<'a> {
+    pub(crate) fn new(unique_index: UniqueIndex<'a>, global_config: &'a config::Config) -> Self {
+        notify_service_watchers(global_config);
+        Self {
+            unique_index: ManuallyDrop::new(unique_index),
+            global_config,
+        }
+    }
+
+    pub(crate) fn value(&self) -> u32 {
+        self.unique_index.value()
+    }
+}
+
+impl Drop for DynamicConfigGuard<'_> {
+    fn drop(&mut self) {
+        // the port must be removed before the watchers compare the ports again
+        unsafe { ManuallyDrop::drop(&mut self.unique_index) };
+        notify_service_watchers(self.global_config);
+    }
+}
@@ -1,2 +1,9 @@
+pub(crate) mod blackboard_entries;
+pub(crate) mod client_connections;
+pub(crate) mod dead_ports;
+pub(crate) mod dynamic_config_guard;
 pub(crate) mod publisher_connections;
+#[cfg(feature = "async")]
+pub(crate) mod readiness;
+pub(crate) mod server_connections;
 pub(crate) mod subscriber_connections;
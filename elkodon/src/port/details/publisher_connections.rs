@@ -6,7 +6,7 @@ use crate::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         publisher::{data_segment_config, data_segment_name},
     },
-    service::{self, connection_config},
+    service::{self, connection_config, PayloadTransfer},
     service::{connection_name, static_config::publish_subscribe::StaticConfig},
 };
 
@@ -14,7 +14,7 @@ use elkodon_bb_elementary::enum_gen;
 use elkodon_bb_log::fail;
 use elkodon_cal::named_concept::NamedConceptBuilder;
 use elkodon_cal::{
-    shared_memory::SharedMemory,
+    shared_memory::{PointerOffset, SharedMemory},
     shared_memory::{SharedMemoryBuilder, SharedMemoryOpenError},
     shm_allocator::pool_allocator::PoolAllocator,
     zero_copy_connection::*,
@@ -38,7 +38,15 @@ impl std::error::Error for ConnectionFailure {}
 pub(crate) struct Connection<'config, Service: service::Details<'config>> {
     pub(crate) receiver:
         <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: Service::SharedMemory,
+    // not available when the payload is copied into the data segment of the subscriber
+    pub(crate) data_segment: Option<Service::SharedMemory>,
+    pub(crate) publisher_id: UniquePublisherId,
+    // the sequence number of the last sample that was received via this connection
+    pub(crate) last_sequence_number: Option<u64>,
+    pub(crate) number_of_lost_samples: u64,
+    // the sample that is held back to compare it with the samples of other publishers, see
+    // ReceivePolicy::OldestFirst
+    pub(crate) pending_sample: Option<PointerOffset>,
 }
 
 impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
@@ -53,7 +61,7 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
 
         let receiver = fail!(from this,
                         when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
-                            Builder::new( &connection_name(publisher_id, this.subscriber_id))
+                            Builder::new( &connection_name(&this.service_uuid, publisher_id, this.subscriber_id))
                                     .config(&connection_config::<Service>(this.config))
                                     .buffer_size(this.static_config.subscriber_max_buffer_size)
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
@@ -61,16 +69,23 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
                                     .create_receiver(),
                         "{} since the zero copy connection could not be established.", msg);
 
-        let data_segment = fail!(from this,
+        let data_segment = match Service::PAYLOAD_TRANSFER {
+            PayloadTransfer::Copy => None,
+            PayloadTransfer::ZeroCopy => Some(fail!(from this,
                             when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
                                 Builder::new(&data_segment_name(publisher_id))
                                 .config(&data_segment_config::<Service>(this.config))
                                 .open(),
-                            "{} since the publishers data segment could not be mapped into the process.", msg);
+                            "{} since the publishers data segment could not be mapped into the process.", msg)),
+        };
 
         Ok(Self {
             receiver,
             data_segment,
+            publisher_id,
+            last_sequence_number: None,
+            number_of_lost_samples: 0,
+            pending_sample: None,
         })
     }
 }
@@ -79,6 +94,7 @@ pub(crate) struct PublisherConnections<'config, Service: service::Details<'confi
     connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
     subscriber_id: UniqueSubscriberId,
     config: &'config config::Config,
+    service_uuid: String,
     static_config: StaticConfig,
 }
 
@@ -87,12 +103,14 @@ impl<'config, Service: service::Details<'config>> PublisherConnections<'config,
         capacity: usize,
         subscriber_id: UniqueSubscriberId,
         config: &'config config::Config,
+        service_uuid: &str,
         static_config: &StaticConfig,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
             subscriber_id,
             config,
+            service_uuid: service_uuid.to_string(),
             static_config: static_config.clone(),
         }
     }
@@ -119,8 +137,13 @@ impl<'config, Service: service::Details<'config>> PublisherConnections<'config,
         index: usize,
         publisher_id: UniquePublisherId,
     ) -> Result<(), ConnectionFailure> {
-        if self.get(index).is_none() {
-            *self.get_mut(index) = Some(Connection::new(self, publisher_id)?);
+        match self.get(index) {
+            // the slot was reused by a new publisher since the last update
+            Some(connection) if connection.publisher_id != publisher_id => {
+                *self.get_mut(index) = Some(Connection::new(self, publisher_id)?)
+            }
+            Some(_) => (),
+            None => *self.get_mut(index) = Some(Connection::new(self, publisher_id)?),
         }
 
         Ok(())
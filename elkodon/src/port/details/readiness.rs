@@ -0,0 +1,395 @@
+//! Runtime agnostic readiness of the file descriptors of the ports which is used by the futures of
+//! [`crate::port::listener::Listener::wait_async()`] and
+//! [`crate::port::subscriber::Subscriber::receive_async()`].
+//!
+//! When the `tokio` feature is enabled and the future is polled inside of a tokio runtime that
+//! was built with IO enabled, the file descriptor is registered at the reactor of the runtime.
+//! Otherwise, a single background
+//! thread per process waits on the file descriptors of all pending futures and wakes them up as
+//! soon as their file descriptor becomes readable.
+
+use std::{
+    sync::{Arc, Mutex, OnceLock},
+    task::{Context, Poll, Waker},
+};
+
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::{
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    thread::{Thread, ThreadBuilder, ThreadName},
+    unique_system_id::UniqueSystemId,
+};
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_cal::{
+    event::{
+        unix_datagram_socket, Listener, ListenerBuilder, NamedConceptBuilder, NamedConceptMgmt,
+        Notifier, NotifierBuilder, NotifierNotifyError,
+    },
+    reactor::{Reactor, ReactorBuilder, ReactorWaitError},
+};
+
+#[cfg(target_os = "linux")]
+type WatcherReactor = elkodon_cal::reactor::linux_epoll::Reactor;
+#[cfg(not(target_os = "linux"))]
+type WatcherReactor = elkodon_cal::reactor::posix_select::Reactor;
+
+type WakeupEvent = unix_datagram_socket::Event<u64>;
+
+/// Defines the failures that can occur while waiting for the readiness of a file descriptor.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub(crate) enum ReadinessError {
+    WatcherUnavailable,
+    #[cfg(all(feature = "tokio", unix))]
+    RegistrationFailed,
+}
+
+#[derive(Debug)]
+struct Registration {
+    key: u64,
+    fd: i32,
+    waker: Waker,
+}
+
+#[derive(Debug, Default)]
+struct Registrations {
+    entries: Vec<Registration>,
+    key_counter: u64,
+}
+
+#[derive(Debug)]
+struct WatchedFd(FileDescriptor);
+
+impl FileDescriptorBased for WatchedFd {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+impl SynchronousMultiplexing for WatchedFd {}
+
+/// The background thread that wakes up the futures which are not polled inside of a runtime
+/// with its own reactor.
+#[derive(Debug)]
+struct Watcher {
+    registrations: Arc<Mutex<Registrations>>,
+    wakeup: <WakeupEvent as elkodon_cal::event::Event<u64>>::Notifier,
+    // the thread runs until the process terminates, the mutex only makes the watcher sync
+    _thread: Mutex<Thread<'static>>,
+}
+
+impl Watcher {
+    fn get() -> Result<&'static Watcher, ReadinessError> {
+        static WATCHER: OnceLock<Result<Watcher, ReadinessError>> = OnceLock::new();
+        match WATCHER.get_or_init(Watcher::new) {
+            Ok(watcher) => Ok(watcher),
+            Err(e) => Err(*e),
+        }
+    }
+
+    fn new() -> Result<Self, ReadinessError> {
+        let msg = "Unable to create the readiness watcher";
+        let origin = "readiness::Watcher::new()";
+
+        let unique_id = fail!(from origin, when UniqueSystemId::new(),
+                with ReadinessError::WatcherUnavailable,
+                "{} since no unique name for the wakeup event could be generated.", msg);
+        let name = fail!(from origin,
+                when FileName::new(format!("elkodon_readiness_{}", unique_id.value()).as_bytes()),
+                with ReadinessError::WatcherUnavailable,
+                "{} since the name of the wakeup event is invalid.", msg);
+
+        let listener = fail!(from origin,
+                when <WakeupEvent as elkodon_cal::event::Event<u64>>::ListenerBuilder::new(&name).create(),
+                with ReadinessError::WatcherUnavailable,
+                "{} since the wakeup event \"{}\" could not be created.", msg, name);
+        let wakeup = fail!(from origin,
+                when <WakeupEvent as elkodon_cal::event::Event<u64>>::NotifierBuilder::new(&name).open(),
+                with ReadinessError::WatcherUnavailable,
+                "{} since the wakeup event \"{}\" could not be opened.", msg, name);
+
+        // the notifier is already connected, the socket file is no longer required and would
+        // otherwise outlive the process since the watcher is never dropped
+        if let Err(e) = unsafe { WakeupEvent::remove(&name) } {
+            warn!(from origin, "Unable to remove the socket file of the wakeup event \"{}\" ({:?}).", name, e);
+        }
+
+        let registrations = Arc::new(Mutex::new(Registrations::default()));
+        let thread_registrations = registrations.clone();
+        let (reactor_result_sender, reactor_result) = std::sync::mpsc::channel();
+        let thread = fail!(from origin,
+                when ThreadBuilder::new()
+                    .name(&ThreadName::from(b"readiness"))
+                    .spawn(move || {
+                        // the reactor is not send and must be created by the watcher thread
+                        let reactor = match <WatcherReactor as Reactor>::Builder::new().create() {
+                            Ok(reactor) => {
+                                reactor_result_sender.send(Ok(())).ok();
+                                reactor
+                            }
+                            Err(e) => {
+                                reactor_result_sender.send(Err(e)).ok();
+                                return;
+                            }
+                        };
+
+                        loop {
+                            Self::watch(&thread_registrations, &reactor, &listener);
+                        }
+                    }),
+                with ReadinessError::WatcherUnavailable,
+                "{} since the watcher thread could not be spawned.", msg);
+
+        match reactor_result.recv() {
+            Ok(Ok(())) => (),
+            Ok(Err(e)) => {
+                fail!(from origin, with ReadinessError::WatcherUnavailable,
+                    "{} since the underlying reactor could not be created ({:?}).", msg, e);
+            }
+            Err(e) => {
+                fail!(from origin, with ReadinessError::WatcherUnavailable,
+                    "{} since the watcher thread terminated unexpectedly ({:?}).", msg, e);
+            }
+        }
+
+        Ok(Self {
+            registrations,
+            wakeup,
+            _thread: Mutex::new(thread),
+        })
+    }
+
+    /// Waits until at least one registered file descriptor is readable or the registrations
+    /// have changed and wakes up the futures of all readable file descriptors.
+    fn watch(
+        registrations: &Mutex<Registrations>,
+        reactor: &WatcherReactor,
+        listener: &<WakeupEvent as elkodon_cal::event::Event<u64>>::Listener,
+    ) {
+        let origin = "readiness::Watcher::watch()";
+        let mut fds: Vec<i32> = lock(registrations).entries.iter().map(|r| r.fd).collect();
+        fds.sort_unstable();
+        fds.dedup();
+        let watched_fds: Vec<WatchedFd> = fds
+            .iter()
+            .filter_map(|fd| FileDescriptor::non_owning_new(*fd))
+            .map(WatchedFd)
+            .collect();
+
+        let mut ready_fds = vec![];
+        {
+            let _wakeup_guard = match reactor.attach(listener) {
+                Ok(guard) => guard,
+                Err(e) => {
+                    fatal_panic!(from origin,
+                        "This should never happen! Unable to attach the wakeup event to the watcher ({:?}).", e);
+                }
+            };
+
+            let mut guards = vec![];
+            for fd in &watched_fds {
+                match reactor.attach(fd) {
+                    Ok(guard) => guards.push(guard),
+                    // the futures are woken up and register themselves again or fail when
+                    // they wait on their port
+                    Err(_) => ready_fds.push(unsafe { fd.0.native_handle() }),
+                }
+            }
+
+            if ready_fds.is_empty() {
+                match reactor.blocking_wait(|fd| ready_fds.push(unsafe { fd.native_handle() })) {
+                    Ok(()) | Err(ReactorWaitError::Interrupt) => (),
+                    Err(e) => {
+                        warn!(from origin, "Unable to wait on the registered file descriptors ({:?}).", e);
+                    }
+                }
+            }
+        }
+
+        while let Ok(Some(_)) = listener.try_wait() {}
+
+        let mut wakers = vec![];
+        lock(registrations).entries.retain_mut(|r| {
+            if ready_fds.contains(&r.fd) {
+                wakers.push(r.waker.clone());
+                false
+            } else {
+                true
+            }
+        });
+
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+
+    /// Registers the file descriptor with the waker and returns the key of the registration.
+    fn register(&self, fd: i32, waker: &Waker) -> u64 {
+        let mut registrations = lock(&self.registrations);
+        let key = registrations.key_counter;
+        registrations.key_counter += 1;
+        registrations.entries.push(Registration {
+            key,
+            fd,
+            waker: waker.clone(),
+        });
+        drop(registrations);
+
+        // a failed delivery means that there is already a pending wakeup
+        if let Err(NotifierNotifyError::InternalFailure) = self.wakeup.notify(key) {
+            warn!(from self, "Unable to wake up the watcher for the new registration.");
+        }
+
+        key
+    }
+
+    /// Updates the waker of the registration. Returns false when the registration does not
+    /// exist anymore since its file descriptor became readable.
+    fn update(&self, key: u64, waker: &Waker) -> bool {
+        match lock(&self.registrations)
+            .entries
+            .iter_mut()
+            .find(|r| r.key == key)
+        {
+            Some(registration) => {
+                if !registration.waker.will_wake(waker) {
+                    registration.waker = waker.clone();
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn deregister(&self, key: u64) {
+        lock(&self.registrations).entries.retain(|r| r.key != key);
+    }
+}
+
+fn lock(registrations: &Mutex<Registrations>) -> std::sync::MutexGuard<'_, Registrations> {
+    // a registration is always consistent, even when a thread panicked while holding the lock
+    registrations
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+#[cfg(all(feature = "tokio", unix))]
+use elkodon_bb_log::error;
+
+#[cfg(all(feature = "tokio", unix))]
+#[derive(Debug)]
+struct BorrowedFd(i32);
+
+#[cfg(all(feature = "tokio", unix))]
+impl std::os::unix::io::AsRawFd for BorrowedFd {
+    fn as_raw_fd(&self) -> std::os::unix::io::RawFd {
+        self.0
+    }
+}
+
+#[derive(Debug)]
+enum State {
+    Unregistered,
+    Watcher(u64),
+    #[cfg(all(feature = "tokio", unix))]
+    Tokio(tokio::io::unix::AsyncFd<BorrowedFd>),
+}
+
+/// The readiness of a file descriptor for a single future. The file descriptor must outlive
+/// the [`Readiness`].
+#[derive(Debug)]
+pub(crate) struct Readiness {
+    fd: i32,
+    state: State,
+}
+
+impl Drop for Readiness {
+    fn drop(&mut self) {
+        if let State::Watcher(key) = self.state {
+            if let Ok(watcher) = Watcher::get() {
+                watcher.deregister(key);
+            }
+        }
+    }
+}
+
+impl Readiness {
+    pub(crate) fn new(fd: &FileDescriptor) -> Self {
+        Self {
+            fd: unsafe { fd.native_handle() },
+            state: State::Unregistered,
+        }
+    }
+
+    /// Is ready when the file descriptor has become readable since the last call. Sporadic
+    /// wakeups can occur, therefore the caller has to check the port again and poll the
+    /// readiness once more when nothing was received.
+    pub(crate) fn poll_readable(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), ReadinessError>> {
+        #[cfg(all(feature = "tokio", unix))]
+        if let State::Unregistered = self.state {
+            self.register_at_tokio();
+        }
+
+        match &mut self.state {
+            #[cfg(all(feature = "tokio", unix))]
+            State::Tokio(async_fd) => match async_fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(mut guard)) => {
+                    guard.clear_ready();
+                    Poll::Ready(Ok(()))
+                }
+                Poll::Ready(Err(e)) => {
+                    error!(from self,
+                        "Unable to poll the readiness of the file descriptor {} ({:?}).", self.fd, e);
+                    Poll::Ready(Err(ReadinessError::RegistrationFailed))
+                }
+                Poll::Pending => Poll::Pending,
+            },
+            State::Unregistered => {
+                self.state = State::Watcher(Watcher::get()?.register(self.fd, cx.waker()));
+                Poll::Pending
+            }
+            State::Watcher(key) => {
+                if Watcher::get()?.update(*key, cx.waker()) {
+                    Poll::Pending
+                } else {
+                    self.state = State::Unregistered;
+                    Poll::Ready(Ok(()))
+                }
+            }
+        }
+    }
+
+    #[cfg(all(feature = "tokio", unix))]
+    fn register_at_tokio(&mut self) {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use tokio::io::{unix::AsyncFd, Interest};
+
+        // tokio panics when the file descriptor is registered at a runtime that was built
+        // without IO, this is detected once and the watcher is used from then on
+        static IS_TOKIO_IO_DISABLED: AtomicBool = AtomicBool::new(false);
+
+        if IS_TOKIO_IO_DISABLED.load(Ordering::Relaxed)
+            || tokio::runtime::Handle::try_current().is_err()
+        {
+            return;
+        }
+
+        let fd = self.fd;
+        match std::panic::catch_unwind(|| {
+            AsyncFd::with_interest(BorrowedFd(fd), Interest::READABLE)
+        }) {
+            Ok(Ok(async_fd)) => self.state = State::Tokio(async_fd),
+            // when the file descriptor is already registered by another future of the same port
+            // the watcher is used instead
+            Ok(Err(_)) => (),
+            Err(_) => {
+                warn!(from self, "The tokio runtime was built without IO, the watcher thread is used instead. Call `enable_io()` on the runtime builder to register the file descriptors at the runtime.");
+                IS_TOKIO_IO_DISABLED.store(true, Ordering::Relaxed);
+            }
+        }
+    }
+}
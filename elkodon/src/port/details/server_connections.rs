@@ -0,0 +1,148 @@
+use std::cell::UnsafeCell;
+
+use crate::{
+    config,
+    port::{
+        details::publisher_connections::ConnectionFailure,
+        port_identifiers::{UniqueClientId, UniqueServerId},
+        server::{data_segment_config, data_segment_name},
+    },
+    service::static_config::request_response::StaticConfig,
+    service::{self, connection_config, request_connection_name, response_connection_name},
+};
+
+use elkodon_bb_log::fail;
+use elkodon_cal::named_concept::NamedConceptBuilder;
+use elkodon_cal::{
+    shared_memory::{SharedMemory, SharedMemoryBuilder},
+    shm_allocator::pool_allocator::PoolAllocator,
+    zero_copy_connection::*,
+};
+
+#[derive(Debug)]
+pub(crate) struct Connection<'config, Service: service::Details<'config>> {
+    pub(crate) request_sender:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) response_receiver:
+        <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) server_id: UniqueServerId,
+}
+
+impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
+    fn new(
+        this: &ServerConnections<'config, Service>,
+        server_id: UniqueServerId,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to server {:?} from client {:?}.",
+            server_id, this.client_id
+        );
+
+        let request_sender = fail!(from this,
+                        when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                            Builder::new(&request_connection_name(&this.service_uuid, this.client_id, server_id))
+                                    .config(&connection_config::<Service>(this.config))
+                                    .buffer_size(this.static_config.max_active_requests)
+                                    .receiver_max_borrowed_samples(this.static_config.max_active_requests)
+                                    .enable_safe_overflow(false)
+                                    .number_of_samples(this.number_of_samples)
+                                    .sample_size(this.sample_size)
+                                    .create_sender(),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_receiver = fail!(from this,
+                        when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
+                            Builder::new(&response_connection_name(&this.service_uuid, server_id, this.client_id))
+                                    .config(&connection_config::<Service>(this.config))
+                                    .buffer_size(this.static_config.max_active_requests)
+                                    .receiver_max_borrowed_samples(this.static_config.max_active_requests)
+                                    .enable_safe_overflow(false)
+                                    .create_receiver(),
+                        "{} since the response connection could not be established.", msg);
+
+        let data_segment = fail!(from this,
+                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                                Builder::new(&data_segment_name(server_id))
+                                .config(&data_segment_config::<Service>(this.config))
+                                .open(),
+                            "{} since the servers data segment could not be mapped into the process.", msg);
+
+        Ok(Self {
+            request_sender,
+            response_receiver,
+            data_segment,
+            server_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ServerConnections<'config, Service: service::Details<'config>> {
+    connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    client_id: UniqueClientId,
+    config: &'config config::Config,
+    service_uuid: String,
+    static_config: StaticConfig,
+    number_of_samples: usize,
+    sample_size: usize,
+}
+
+impl<'config, Service: service::Details<'config>> ServerConnections<'config, Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        client_id: UniqueClientId,
+        config: &'config config::Config,
+        service_uuid: &str,
+        static_config: &StaticConfig,
+        number_of_samples: usize,
+        sample_size: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            client_id,
+            config,
+            service_uuid: service_uuid.to_string(),
+            static_config: static_config.clone(),
+            number_of_samples,
+            sample_size,
+        }
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Connection<'config, Service>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Connection<'config, Service>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        server_id: UniqueServerId,
+    ) -> Result<(), ConnectionFailure> {
+        if self.get(index).is_none() {
+            *self.get_mut(index) = Some(Connection::new(self, server_id)?);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -1,15 +1,20 @@
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
 
-use elkodon_bb_log::fail;
+use elkodon_bb_log::{fail, warn};
+use elkodon_cal::event::{Event, NotifierBuilder, NotifierCreateError, NotifierNotifyError};
 use elkodon_cal::named_concept::NamedConceptBuilder;
+use elkodon_cal::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
 use elkodon_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
 };
 
-use crate::service::connection_config;
+use crate::service::{connection_config, event_concept_name, PayloadTransfer};
 use crate::{
     config,
+    port::event_id::EventId,
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
+    port::subscriber::{data_segment_config, data_segment_name},
     service,
     service::{connection_name, static_config::publish_subscribe::StaticConfig},
 };
@@ -18,6 +23,12 @@ use crate::{
 pub(crate) struct Connection<'config, Service: service::Details<'config>> {
     pub(crate) sender:
         <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) subscriber_id: UniqueSubscriberId,
+    // the samples are copied into it, only available when the payload is copied
+    pub(crate) data_segment: Option<Service::SharedMemory>,
+    // set when the subscriber returned a sample that was never delivered to it
+    pub(crate) is_corrupted: Cell<bool>,
+    notifier: Option<<Service::Event as Event<EventId>>::Notifier>,
 }
 
 impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
@@ -26,39 +37,109 @@ impl<'config, Service: service::Details<'config>> Connection<'config, Service> {
         subscriber_id: UniqueSubscriberId,
     ) -> Result<Self, ZeroCopyCreationError> {
         let sender = fail!(from this, when <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::
-                        Builder::new( &connection_name(this.port_id, subscriber_id))
+                        Builder::new( &connection_name(&this.service_uuid, this.port_id, subscriber_id))
                                 .config(&connection_config::<Service>(this.config))
                                 .buffer_size(this.static_config.subscriber_max_buffer_size)
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                 .enable_safe_overflow(this.static_config.enable_safe_overflow)
+                                .number_of_samples(this.number_of_samples)
+                                .sample_size(this.sample_size)
                                 .create_sender(),
                         "Unable to establish connection to subscriber {:?} from publisher {:?}.",
                         subscriber_id, this.port_id);
 
-        Ok(Self { sender })
+        let data_segment = match Service::PAYLOAD_TRANSFER {
+            PayloadTransfer::ZeroCopy => None,
+            PayloadTransfer::Copy => Some(fail!(from this,
+                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
+                                Builder::new(&data_segment_name(subscriber_id))
+                                .config(&data_segment_config::<Service>(this.config))
+                                .open(),
+                            with ZeroCopyCreationError::InternalError,
+                            "Unable to establish connection to subscriber {:?} from publisher {:?} since the subscribers data segment could not be mapped into the process.",
+                            subscriber_id, this.port_id)),
+        };
+
+        let notifier = match this.static_config.enable_notifications {
+            false => None,
+            true => match Self::open_notifier(subscriber_id) {
+                Ok(notifier) => Some(notifier),
+                Err(e) => {
+                    warn!(from this, "The subscriber {:?} will not be notified about new samples until the connection to its notification listener can be established ({:?}).",
+                        subscriber_id, e);
+                    None
+                }
+            },
+        };
+
+        Ok(Self {
+            sender,
+            subscriber_id,
+            data_segment,
+            is_corrupted: Cell::new(false),
+            notifier,
+        })
+    }
+
+    fn open_notifier(
+        subscriber_id: UniqueSubscriberId,
+    ) -> Result<<Service::Event as Event<EventId>>::Notifier, NotifierCreateError> {
+        <Service::Event as Event<EventId>>::NotifierBuilder::new(&event_concept_name(
+            &subscriber_id.0,
+        ))
+        .open()
+    }
+
+    /// Wakes up the subscriber when the service has notifications enabled.
+    pub(crate) fn notify(&self) {
+        use elkodon_cal::event::Notifier;
+
+        if let Some(notifier) = &self.notifier {
+            match notifier.notify(EventId::default()) {
+                // the listener has still unconsumed wakeups, the subscriber wakes up anyway
+                Ok(()) | Err(NotifierNotifyError::FailedToDeliverSignal) => (),
+                Err(e) => {
+                    warn!(from self, "Unable to notify the subscriber {:?} about a new sample ({:?}).",
+                        self.subscriber_id, e);
+                }
+            }
+        }
     }
 }
 
 #[derive(Debug)]
 pub(crate) struct SubscriberConnections<'config, Service: service::Details<'config>> {
     connections: Vec<UnsafeCell<Option<Connection<'config, Service>>>>,
+    // subscribers whose connection was dropped since it was corrupted, they are not connected
+    // again until they leave the service
+    dropped_subscribers: Vec<Cell<Option<UniqueSubscriberId>>>,
     port_id: UniquePublisherId,
     config: &'config config::Config,
+    service_uuid: String,
     static_config: StaticConfig,
+    number_of_samples: usize,
+    sample_size: usize,
 }
 
 impl<'config, Service: service::Details<'config>> SubscriberConnections<'config, Service> {
     pub(crate) fn new(
         capacity: usize,
         config: &'config config::Config,
+        service_uuid: &str,
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
+        number_of_samples: usize,
+        sample_size: usize,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            dropped_subscribers: (0..capacity).map(|_| Cell::new(None)).collect(),
             config,
+            service_uuid: service_uuid.to_string(),
             port_id,
             static_config: static_config.clone(),
+            number_of_samples,
+            sample_size,
         }
     }
 
@@ -75,8 +156,34 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         }
     }
 
+    /// Opens the notifiers of all connections whose subscriber could not be reached when the
+    /// connection was established.
+    pub(crate) fn open_missing_notifiers(&self) {
+        if !self.static_config.enable_notifications {
+            return;
+        }
+
+        for i in 0..self.capacity() {
+            if let Some(connection) = self.get_mut(i) {
+                if connection.notifier.is_none() {
+                    connection.notifier =
+                        Connection::<Service>::open_notifier(connection.subscriber_id).ok();
+                }
+            }
+        }
+    }
+
     pub(crate) fn remove(&self, index: usize) {
-        *self.get_mut(index) = None
+        *self.get_mut(index) = None;
+        self.dropped_subscribers[index].set(None);
+    }
+
+    /// Drops a corrupted connection without releasing the samples the subscriber still owns,
+    /// since it may still access them.
+    pub(crate) fn drop_corrupted(&self, index: usize) {
+        if let Some(connection) = self.get_mut(index).take() {
+            self.dropped_subscribers[index].set(Some(connection.subscriber_id));
+        }
     }
 
     pub(crate) fn create(
@@ -84,8 +191,10 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         index: usize,
         subscriber_id: UniqueSubscriberId,
     ) -> Result<bool, ZeroCopyCreationError> {
-        if self.get(index).is_none() {
+        if self.get(index).is_none() && self.dropped_subscribers[index].get() != Some(subscriber_id)
+        {
             *self.get_mut(index) = Some(Connection::new(self, subscriber_id)?);
+            self.dropped_subscribers[index].set(None);
             Ok(true)
         } else {
             Ok(false)
@@ -96,6 +205,10 @@ impl<'config, Service: service::Details<'config>> SubscriberConnections<'config,
         self.connections.len()
     }
 
+    pub(crate) fn number_of_samples(&self) -> usize {
+        self.number_of_samples
+    }
+
     pub(crate) fn capacity(&self) -> usize {
         self.connections.capacity()
     }
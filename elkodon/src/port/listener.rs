@@ -18,14 +18,22 @@
 //! # }
 //! ```
 
-use elkodon_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use elkodon_bb_log::fail;
+use elkodon_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use elkodon_bb_posix::file_descriptor_set::SynchronousMultiplexing;
 use elkodon_cal::dynamic_storage::DynamicStorage;
 use elkodon_cal::event::{ListenerBuilder, ListenerWaitError};
+use elkodon_cal::monitoring::Monitoring;
 use elkodon_cal::named_concept::NamedConceptBuilder;
 
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+#[cfg(feature = "async")]
+use crate::port::details::readiness::Readiness;
 use crate::service::event_concept_name;
 use crate::{port::port_identifiers::UniqueListenerId, service};
+#[cfg(feature = "async")]
+use std::future::poll_fn;
 use std::{marker::PhantomData, time::Duration};
 
 use super::event_id::EventId;
@@ -36,6 +44,7 @@ use super::event_id::EventId;
 pub enum ListenerCreateError {
     ExceedsMaxSupportedListeners,
     ResourceCreationFailed,
+    UnableToCreateMonitoringToken,
 }
 
 impl std::fmt::Display for ListenerCreateError {
@@ -49,26 +58,43 @@ impl std::error::Error for ListenerCreateError {}
 /// Represents the receiving endpoint of an event based communication.
 #[derive(Debug)]
 pub struct Listener<'a, 'config: 'a, Service: service::Details<'config>> {
-    _dynamic_config_guard: Option<UniqueIndex<'a>>,
+    _dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
     listener: <Service::Event as elkodon_cal::event::Event<EventId>>::Listener,
     cache: Vec<EventId>,
     _phantom_a: PhantomData<&'a Service>,
     _phantom_b: PhantomData<&'config ()>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
 }
 
 impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config, Service> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Listener::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
     pub(crate) fn new(service: &'a Service) -> Result<Self, ListenerCreateError> {
         let msg = "Failed to create listener";
         let origin = "Listener::new()";
         let port_id = UniqueListenerId::new();
 
-        let event_name = event_concept_name(&port_id);
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with ListenerCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+
+        let event_name = event_concept_name(&port_id.0);
         let listener = fail!(from origin,
                              when <Service::Event as elkodon_cal::event::Event<EventId>>::ListenerBuilder::new(&event_name).create(),
                              with ListenerCreateError::ResourceCreationFailed,
                              "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
 
         let mut new_self = Self {
+            _monitoring_token,
             _dynamic_config_guard: None,
             listener,
             cache: vec![],
@@ -86,7 +112,9 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
                 .event()
                 .add_listener_id(port_id)
             {
-                Some(unique_index) => unique_index,
+                Some(unique_index) => {
+                    DynamicConfigGuard::new(unique_index, service.state().global_config)
+                }
                 None => {
                     fail!(from origin, with ListenerCreateError::ExceedsMaxSupportedListeners,
                                  "{} since it would exceed the maximum supported amount of listeners of {}.",
@@ -121,6 +149,10 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
     /// Non-blocking wait for new [`EventId`]s. If no [`EventId`]s were notified the returned slice
     /// is empty. On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Listener::try_wait", level = "trace", skip_all)
+    )]
     pub fn try_wait(&mut self) -> Result<&[EventId], ListenerWaitError> {
         self.cache.clear();
         self.fill_cache()?;
@@ -132,6 +164,10 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
     /// has passed. If no [`EventId`]s were notified the returned slice
     /// is empty. On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Listener::timed_wait", level = "trace", skip_all)
+    )]
     pub fn timed_wait(&mut self, timeout: Duration) -> Result<&[EventId], ListenerWaitError> {
         use elkodon_cal::event::Listener;
         self.cache.clear();
@@ -151,6 +187,10 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
     /// Sporadic wakeups can occur and if no [`EventId`]s were notified the returned slice
     /// is empty. On error it returns [`ListenerWaitError`] is returned which describes the error
     /// in detail.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Listener::blocking_wait", level = "trace", skip_all)
+    )]
     pub fn blocking_wait(&mut self) -> Result<&[EventId], ListenerWaitError> {
         use elkodon_cal::event::Listener;
         self.cache.clear();
@@ -166,3 +206,53 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config,
         Ok(self.cache())
     }
 }
+
+#[cfg(feature = "async")]
+impl<'a, 'config: 'a, Service: service::Details<'config>> Listener<'a, 'config, Service>
+where
+    <Service::Event as elkodon_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    /// Asynchronous wait for new [`EventId`]s. The returned future is ready as soon as at
+    /// least one [`EventId`] was received. It does not block a thread of the runtime and can be
+    /// used with any executor. On error it returns [`ListenerWaitError`] is returned which
+    /// describes the error in detail.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Listener::wait_async", level = "trace", skip_all)
+    )]
+    pub async fn wait_async(&mut self) -> Result<&[EventId], ListenerWaitError> {
+        let mut readiness = Readiness::new(self.listener.file_descriptor());
+
+        loop {
+            self.cache.clear();
+            self.fill_cache()?;
+            if !self.cache.is_empty() {
+                return Ok(self.cache());
+            }
+
+            if let Err(e) = poll_fn(|cx| readiness.poll_readable(cx)).await {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "Failed to wait_async on Listener port since the readiness of the underlying Listener concept could not be awaited ({:?}).", e);
+            }
+        }
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>> FileDescriptorBased
+    for Listener<'a, 'config, Service>
+where
+    <Service::Event as elkodon_cal::event::Event<EventId>>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+/// A [`Listener`] can be attached to a [`crate::waitset::WaitSet`] when its underlying event
+/// concept is based on a file descriptor.
+impl<'a, 'config: 'a, Service: service::Details<'config>> SynchronousMultiplexing
+    for Listener<'a, 'config, Service>
+where
+    <Service::Event as elkodon_cal::event::Event<EventId>>::Listener: SynchronousMultiplexing,
+{
+}
@@ -4,6 +4,8 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Requesting endpoint (port) for request-response based communication
+pub mod client;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
@@ -12,10 +14,20 @@ pub mod listener;
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
+/// Producing endpoint (port) for pipeline based communication
+pub mod producer;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Reading endpoint (port) for blackboard based communication
+pub mod reader;
+/// Responding endpoint (port) for request-response based communication
+pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
+/// Processing endpoint (port) for pipeline based communication
+pub mod worker;
+/// Writing endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
 use crate::service;
@@ -38,9 +50,25 @@ pub enum DegrationAction {
     Fail,
 }
 
+/// Describes the degration a port detected. Is provided to the [`DegrationCallback`] so that
+/// the custom behavior can depend on it.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum DegrationCause {
+    /// The connection between the [`publisher::Publisher`] and the [`subscriber::Subscriber`]
+    /// could not be established.
+    FailedToEstablishConnection,
+    /// The [`publisher::Publisher`] did not send a sample within the deadline of the service,
+    /// see [`service::static_config::publish_subscribe::StaticConfig::deadline()`].
+    DeadlineMissed,
+    /// The [`publisher::Publisher`] did not assert its liveliness within the lease duration of
+    /// the service, see
+    /// [`service::static_config::publish_subscribe::StaticConfig::liveliness_lease_duration()`].
+    LivelinessLost,
+}
+
 tiny_fn! {
     /// Defines a custom behavior whenever a port detects a degregation.
-    pub struct DegrationCallback = Fn(service: service::static_config::StaticConfig, publisher_id: UniquePublisherId, subscriber_id: UniqueSubscriberId) -> DegrationAction;
+    pub struct DegrationCallback = Fn(service: service::static_config::StaticConfig, publisher_id: UniquePublisherId, subscriber_id: UniqueSubscriberId, cause: DegrationCause) -> DegrationAction;
 }
 
 impl<'a> Debug for DegrationCallback<'a> {
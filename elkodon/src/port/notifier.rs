@@ -23,12 +23,15 @@
 //! # }
 //! ```
 
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
 use crate::{
     port::port_identifiers::UniqueNotifierId,
     service::{self, event_concept_name},
 };
-use elkodon_bb_lock_free::mpmc::{container::ContainerState, unique_index_set::UniqueIndex};
+use elkodon_bb_lock_free::mpmc::container::ContainerState;
 use elkodon_bb_log::{fail, warn};
+use elkodon_cal::monitoring::Monitoring;
 use elkodon_cal::named_concept::NamedConceptBuilder;
 use elkodon_cal::{dynamic_storage::DynamicStorage, event::NotifierBuilder};
 use std::{cell::UnsafeCell, marker::PhantomData};
@@ -40,6 +43,7 @@ use super::{event_id::EventId, port_identifiers::UniqueListenerId};
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum NotifierCreateError {
     ExceedsMaxSupportedNotifiers,
+    UnableToCreateMonitoringToken,
 }
 
 impl std::fmt::Display for NotifierCreateError {
@@ -86,7 +90,7 @@ impl<'config, Service: service::Details<'config>> ListenerConnections<'config, S
     }
 
     fn create(&self, index: usize, listener_id: UniqueListenerId) -> Result<(), ()> {
-        let event_name = event_concept_name(&listener_id);
+        let event_name = event_concept_name(&listener_id.0);
         if self.get(index).is_none() {
             let notifier = fail!(from self, when <Service::Event as elkodon_cal::event::Event<EventId>>::NotifierBuilder::new(&event_name).open(),
                                     with (),
@@ -127,12 +131,23 @@ pub struct Notifier<'a, 'config: 'a, Service: service::Details<'config>> {
     listener_connections: ListenerConnections<'config, Service>,
     listener_list_state: UnsafeCell<ContainerState<'a, UniqueListenerId>>,
     default_event_id: EventId,
-    _dynamic_config_guard: Option<UniqueIndex<'a>>,
+    _dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
     _phantom_a: PhantomData<&'a Service>,
     _phantom_b: PhantomData<&'config ()>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
 }
 
 impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config, Service> {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Notifier::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
     pub(crate) fn new(
         service: &'a Service,
         default_event_id: EventId,
@@ -141,9 +156,15 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
         let origin = "Notifier::new()";
         let port_id = UniqueNotifierId::new();
 
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with NotifierCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+
         let listener_list = &service.state().dynamic_storage.get().event().listeners;
 
         let mut new_self = Self {
+            _monitoring_token,
             listener_connections: ListenerConnections::new(listener_list.capacity()),
             default_event_id,
             listener_list_state: unsafe { UnsafeCell::new(listener_list.get_state()) },
@@ -161,7 +182,9 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
             .event()
             .add_notifier_id(port_id)
         {
-            Some(unique_index) => unique_index,
+            Some(unique_index) => {
+                DynamicConfigGuard::new(unique_index, service.state().global_config)
+            }
             None => {
                 fail!(from origin, with NotifierCreateError::ExceedsMaxSupportedNotifiers,
                             "{} since it would exceed the maximum supported amount of notifiers of {}.",
@@ -228,6 +251,16 @@ impl<'a, 'config: 'a, Service: service::Details<'config>> Notifier<'a, 'config,
     /// On success the number of
     /// [`crate::port::listener::Listener`]s that were notified otherwise it returns
     /// [`NotifierConnectionUpdateFailure`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Notifier::notify",
+            level = "trace",
+            skip_all,
+            fields(event_id = value.as_u64()),
+            ret
+        )
+    )]
     pub fn notify_with_custom_event_id(
         &self,
         value: EventId,
@@ -27,3 +27,9 @@ generate_id! { UniquePublisherId }
 generate_id! { UniqueSubscriberId }
 generate_id! { UniqueNotifierId }
 generate_id! { UniqueListenerId }
+generate_id! { UniqueClientId }
+generate_id! { UniqueServerId }
+generate_id! { UniqueReaderId }
+generate_id! { UniqueWriterId }
+generate_id! { UniqueProducerId }
+generate_id! { UniqueWorkerId }
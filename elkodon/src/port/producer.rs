@@ -0,0 +1,254 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let producer = service.producer().create()?;
+//!
+//! // loan some memory and put it into the work queue
+//! let mut work_item = producer.loan()?;
+//! unsafe {
+//!     work_item.as_mut_ptr().write(1337);
+//! }
+//! producer.send(work_item)?;
+//!
+//! // put a copy of the value into the work queue
+//! producer.send_copy(313)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_log::fail;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
+
+use super::port_identifiers::UniqueProducerId;
+use super::publisher::LoanError;
+use crate::message::Message;
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+use crate::service;
+use crate::service::dynamic_config::pipeline::DynamicConfig;
+use crate::service::header::pipeline::Header;
+use crate::work_item_mut::WorkItemMut;
+
+/// Defines the failures that can occur when a [`Producer`] is created with the
+/// [`crate::service::port_factory::producer::PortFactoryProducer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ProducerCreateError {
+    ExceedsMaxSupportedProducers,
+    UnableToCreateMonitoringToken,
+}
+
+impl std::fmt::Display for ProducerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ProducerCreateError {}
+
+/// Failure that can be emitted when a [`WorkItemMut`] is sent via [`Producer::send()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum WorkItemSendError {
+    WorkQueueFull,
+}
+
+impl std::fmt::Display for WorkItemSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WorkItemSendError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a work item is sent via [`Producer::send_copy()`].
+    WorkItemSendCopyError
+  mapping:
+    LoanError to LoanError,
+    WorkItemSendError to SendError
+}
+
+impl std::fmt::Display for WorkItemSendCopyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WorkItemSendCopyError {}
+
+/// The producing endpoint of a pipeline based communication. Every sample it sends is put into
+/// the shared work queue and is processed by exactly one [`crate::port::worker::Worker`].
+#[derive(Debug)]
+pub struct Producer<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+    _dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
+    service: &'a Service,
+    port_id: UniqueProducerId,
+    max_loaned_samples: usize,
+    pub(crate) loan_counter: AtomicUsize,
+    _phantom_config: PhantomData<&'config ()>,
+    _phantom_message_type: PhantomData<MessageType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+    Producer<'a, 'config, Service, MessageType>
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Producer::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
+    pub(crate) fn new(service: &'a Service) -> Result<Self, ProducerCreateError> {
+        let msg = "Failed to create producer";
+        let origin = "Producer::new()";
+        let port_id = UniqueProducerId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with ProducerCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+        let static_config = service.state().static_config.pipeline();
+
+        let mut new_self = Self {
+            _monitoring_token,
+            _dynamic_config_guard: None,
+            service,
+            port_id,
+            max_loaned_samples: static_config.producer_max_loaned_samples,
+            loan_counter: AtomicUsize::new(0),
+            _phantom_config: PhantomData,
+            _phantom_message_type: PhantomData,
+        };
+
+        // !MUST! be the last task otherwise a producer is added to the dynamic config without
+        // being fully initialized
+        new_self._dynamic_config_guard = Some(
+            match service
+                .state()
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .add_producer_id(port_id)
+            {
+                Some(unique_index) => {
+                    DynamicConfigGuard::new(unique_index, service.state().global_config)
+                }
+                None => {
+                    fail!(from origin, with ProducerCreateError::ExceedsMaxSupportedProducers,
+                        "{} since it would exceed the maximum supported amount of producers of {}.",
+                        msg, static_config.max_producers);
+                }
+            },
+        );
+
+        Ok(new_self)
+    }
+
+    fn dynamic_config(&self) -> &DynamicConfig {
+        self.service.state().dynamic_storage.get().pipeline()
+    }
+
+    pub(crate) fn release_sample(&self, sample_index: usize) {
+        self.dynamic_config().release_sample(sample_index);
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`]
+    pub fn id(&self) -> UniqueProducerId {
+        self.port_id
+    }
+
+    /// Puts a previously loaned [`Producer::loan()`] [`WorkItemMut`] into the work queue of the
+    /// pipeline. If the work queue is full it returns [`WorkItemSendError::WorkQueueFull`] and
+    /// the [`WorkItemMut`] is discarded.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Producer::send", level = "trace", skip_all, ret)
+    )]
+    pub fn send<'producer>(
+        &'producer self,
+        mut work_item: WorkItemMut<'a, 'producer, 'config, Service, MessageType>,
+    ) -> Result<(), WorkItemSendError> {
+        if !self
+            .dynamic_config()
+            .enqueue_sample(work_item.sample_index())
+        {
+            fail!(from self, with WorkItemSendError::WorkQueueFull,
+                "Unable to send work item since the work queue is full. The work item is discarded.");
+        }
+
+        work_item.mark_as_sent();
+        Ok(())
+    }
+
+    /// Copies the input `value` into a [`WorkItemMut`] and puts it into the work queue.
+    /// On failure it returns [`WorkItemSendCopyError`] describing the failure.
+    pub fn send_copy(&self, value: MessageType) -> Result<(), WorkItemSendCopyError> {
+        let msg = "Unable to send copy of work item";
+        let mut work_item = fail!(from self, when self.loan(),
+                                    "{} since the loan of a work item failed.", msg);
+
+        unsafe { work_item.as_mut_ptr().write(value) };
+        fail!(from self, when self.send(work_item),
+            "{} since the underlying send operation failed.", msg);
+        Ok(())
+    }
+
+    /// Loans/allocates a [`WorkItemMut`] from the shared memory of the pipeline. On failure it
+    /// returns [`LoanError`] describing the failure.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Producer::loan", level = "trace", skip_all)
+    )]
+    pub fn loan<'producer>(
+        &'producer self,
+    ) -> Result<WorkItemMut<'a, 'producer, 'config, Service, MessageType>, LoanError> {
+        let msg = "Unable to loan work item";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.max_loaned_samples {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} work items were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned work item to loan another work item.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.max_loaned_samples);
+        }
+
+        let sample_index = match self.dynamic_config().acquire_sample() {
+            Some(index) => index,
+            None => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since all samples of the pipeline are in use.", msg);
+            }
+        };
+
+        let mut ptr = unsafe {
+            NonNull::new_unchecked(self.dynamic_config().sample(sample_index)
+                as *mut MaybeUninit<Message<Header, MessageType>>)
+        };
+
+        unsafe {
+            let header_ptr = std::ptr::addr_of_mut!((*ptr.as_mut().as_mut_ptr()).header);
+            header_ptr.write(Header::new(self.port_id));
+        }
+
+        Ok(WorkItemMut::new(self, ptr, sample_index))
+    }
+}
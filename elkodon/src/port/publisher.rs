@@ -30,30 +30,75 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Slices
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/PointCloud")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     // defines how many elements a slice can contain at most
+//!     .max_slice_len(128)
+//!     .open_or_create::<[f32]>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//!
+//! // loan a slice with 3 default initialized elements and send it
+//! let mut sample = publisher.loan_slice(3)?;
+//! unsafe {
+//!     (*sample.as_mut_ptr())[0] = 1.5;
+//! }
+//! publisher.send(sample)?;
+//!
+//! // loan an uninitialized slice, initialize every element and send it
+//! let mut sample = publisher.loan_uninit_slice(2)?;
+//! unsafe {
+//!     let elements = sample.as_mut_ptr() as *mut f32;
+//!     elements.write(0.5);
+//!     elements.add(1).write(2.5);
+//! }
+//! publisher.send(sample)?;
+//!
+//! // send a copy of a slice
+//! publisher.send_slice_copy(&[1.0, 2.0, 3.0, 4.0])?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use std::cell::UnsafeCell;
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
-use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+use std::{marker::PhantomData, ptr::NonNull};
 
 use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
-use crate::message::Message;
+use crate::message::MessageLayout;
+use crate::payload_type::PayloadType;
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
 use crate::port::details::subscriber_connections::*;
-use crate::port::{DegrationAction, DegrationCallback};
+use crate::port::{DegrationAction, DegrationCallback, DegrationCause};
 use crate::service;
+use crate::service::dynamic_config::publish_subscribe::monotonic_time;
 use crate::service::header::publish_subscribe::Header;
 use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
 use crate::service::static_config::publish_subscribe;
+use crate::service::PayloadTransfer;
 use crate::{config, sample_mut::SampleMut};
 use elkodon_bb_container::queue::Queue;
 use elkodon_bb_container::semantic_string::SemanticString;
 use elkodon_bb_elementary::allocator::AllocationError;
 use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_elementary::math::align;
+use elkodon_bb_elementary::zero_copy_send::ZeroCopySend;
 use elkodon_bb_lock_free::mpmc::container::ContainerState;
-use elkodon_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use elkodon_bb_log::{fail, fatal_panic, warn};
 use elkodon_bb_system_types::file_name::FileName;
 use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
 use elkodon_cal::named_concept::{
     NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
 };
@@ -64,12 +109,19 @@ use elkodon_cal::zero_copy_connection::{
     ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
 };
 
+type Sender<'config, Service> =
+    <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender;
+type DeliverCall<Sender> =
+    fn(&Sender, PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
 /// Defines a failure that can occur when a [`Publisher`] is created with
 /// [`crate::service::port_factory::publisher::PortFactoryPublisher`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum PublisherCreateError {
     ExceedsMaxSupportedPublishers,
     UnableToCreateDataSegment,
+    UnableToCreateMonitoringToken,
+    UntypedService,
 }
 
 impl std::fmt::Display for PublisherCreateError {
@@ -86,6 +138,8 @@ impl std::error::Error for PublisherCreateError {}
 pub enum LoanError {
     OutOfMemory,
     ExceedsMaxLoanedChunks,
+    ExceedsMaxSliceLength,
+    UnsupportedAlignment,
     InternalFailure,
 }
 
@@ -147,25 +201,45 @@ pub(crate) fn data_segment_config<'config, Service: service::Details<'config>>(
 
 /// Sending endpoint of a publish-subscriber based communication.
 #[derive(Debug)]
-pub struct Publisher<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+pub struct Publisher<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+> {
     port_id: UniquePublisherId,
     pub(crate) sample_reference_counter: Vec<AtomicU64>,
     pub(crate) data_segment: Service::SharedMemory,
     config: LocalPublisherConfig,
+    message_layout: MessageLayout,
+    max_slice_len: usize,
+    payload_alignment: usize,
 
     subscriber_connections: SubscriberConnections<'config, Service>,
     subscriber_list_state: UnsafeCell<ContainerState<'a, UniqueSubscriberId>>,
     history: Option<UnsafeCell<Queue<usize>>>,
     service: &'a Service,
     degration_callback: Option<DegrationCallback<'a>>,
-    pub(crate) loan_counter: AtomicUsize,
-    _dynamic_config_guard: UniqueIndex<'a>,
+    loan_counter: AtomicUsize,
+    sequence_number: AtomicU64,
+    _dynamic_config_guard: DynamicConfigGuard<'a>,
     _phantom_message_type: PhantomData<MessageType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     Publisher<'a, 'config, Service, MessageType>
 {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Publisher::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
     pub(crate) fn new(
         service: &'a Service,
         static_config: &publish_subscribe::StaticConfig,
@@ -173,7 +247,18 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
     ) -> Result<Self, PublisherCreateError> {
         let msg = "Unable to create Publisher port";
         let origin = "Publisher::new()";
+
+        if static_config.type_fingerprint != MessageType::type_fingerprint() {
+            fail!(from origin, with PublisherCreateError::UntypedService,
+                "{} since the service was opened without its payload type \"{}\".", msg, static_config.type_name);
+        }
+
         let port_id = UniquePublisherId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with PublisherCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
         let subscriber_list = &service
             .state()
             .dynamic_storage
@@ -187,7 +272,23 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .messaging_pattern
             .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
 
-        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_samples),
+        // the offsets a subscriber receives point into the data segment of the publisher or, when
+        // the payload is copied, into the data segment of the subscriber
+        let number_of_samples_per_connection = match Service::PAYLOAD_TRANSFER {
+            PayloadTransfer::ZeroCopy => number_of_samples,
+            PayloadTransfer::Copy => service
+                .state()
+                .static_config
+                .messaging_pattern
+                .required_amount_of_samples_per_receive_segment(),
+        };
+
+        let message_layout = MessageLayout::new::<Header, MessageType>(
+            static_config.max_slice_len,
+            static_config.payload_alignment,
+        );
+
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_samples, message_layout),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
@@ -200,7 +301,9 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .publish_subscribe()
             .add_publisher_id(port_id)
         {
-            Some(unique_index) => unique_index,
+            Some(unique_index) => {
+                DynamicConfigGuard::new(unique_index, service.state().global_config)
+            }
             None => {
                 fail!(from origin, with PublisherCreateError::ExceedsMaxSupportedPublishers,
                             "{} since it would exceed the maximum supported amount of publishers of {}.",
@@ -208,16 +311,30 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             }
         };
 
+        service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .reset_publisher_details(_dynamic_config_guard.value());
+
         let new_self = Self {
+            _monitoring_token,
             port_id,
             subscriber_connections: SubscriberConnections::new(
                 subscriber_list.capacity(),
                 service.state().global_config,
+                service.state().static_config.uuid(),
                 port_id,
                 static_config,
+                number_of_samples_per_connection,
+                message_layout.layout.size(),
             ),
             data_segment,
             config: *config,
+            message_layout,
+            max_slice_len: static_config.max_slice_len,
+            payload_alignment: static_config.payload_alignment,
             sample_reference_counter: {
                 let mut v = Vec::with_capacity(number_of_samples);
                 for _ in 0..number_of_samples {
@@ -233,6 +350,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             service,
             degration_callback: None,
             loan_counter: AtomicUsize::new(0),
+            sequence_number: AtomicU64::new(0),
             _dynamic_config_guard,
             _phantom_message_type: PhantomData,
         };
@@ -260,6 +378,13 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         for (i, index) in visited_indices.iter().enumerate() {
             match index {
                 Some(subscriber_id) => {
+                    // the slot was reused by a new subscriber since the last update
+                    if let Some(connection) = self.subscriber_connections.get(i) {
+                        if connection.subscriber_id != *subscriber_id {
+                            self.remove_connection(i);
+                        }
+                    }
+
                     match self.subscriber_connections.create(i, *subscriber_id) {
                         Ok(false) => (),
                         Ok(true) => match &self.subscriber_connections.get(i) {
@@ -273,6 +398,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                                 self.service.state().static_config.clone(),
                                 self.port_id,
                                 *subscriber_id,
+                                DegrationCause::FailedToEstablishConnection,
                             ) {
                                 DegrationAction::Ignore => (),
                                 DegrationAction::Warn => {
@@ -289,13 +415,26 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                         },
                     }
                 }
-                None => self.subscriber_connections.remove(i),
+                None => self.remove_connection(i),
             }
         }
 
         Ok(())
     }
 
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.subscriber_connections.get(i) {
+            // the subscriber is gone and will never return the samples it still owns
+            unsafe {
+                connection.sender.acquire_used_offsets(|offset| {
+                    self.release_delivered_sample(connection, offset)
+                })
+            };
+        }
+
+        self.subscriber_connections.remove(i);
+    }
+
     fn deliver_history(&self, connection: &Connection<'config, Service>) {
         match &self.history {
             None => (),
@@ -304,31 +443,30 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 for i in 0..history.len() {
                     let ptr_distance = unsafe { history.get_unchecked(i) };
 
-                    match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => {
-                            self.sample_reference_counter[Self::sample_index(ptr_distance)]
-                                .fetch_add(1, Ordering::Relaxed);
-                        }
-                        Err(e) => {
-                            warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
-                        }
+                    if let Err(e) = self.send_to(
+                        connection,
+                        ptr_distance,
+                        Sender::<'config, Service>::try_send,
+                    ) {
+                        warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                     }
                 }
             }
         }
     }
 
-    fn sample_index(distance_to_chunk: usize) -> usize {
-        distance_to_chunk / std::mem::size_of::<Message<Header, MessageType>>()
+    fn sample_index(&self, distance_to_chunk: usize) -> usize {
+        distance_to_chunk / self.message_layout.layout.size()
     }
 
     fn create_data_segment(
         port_id: UniquePublisherId,
         global_config: &'config config::Config,
         number_of_samples: usize,
+        message_layout: MessageLayout,
     ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
         let allocator_config = shm_allocator::pool_allocator::Config {
-            bucket_layout: Layout::new::<Message<Header, MessageType>>(),
+            bucket_layout: message_layout.layout,
         };
         let chunk_size = allocator_config.bucket_layout.size();
         let chunk_align = allocator_config.bucket_layout.align();
@@ -343,12 +481,35 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             "Unable to create the data segment."))
     }
 
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Publisher::send", level = "trace", skip_all, ret)
+    )]
     fn send_impl(&self, address_to_chunk: usize) -> Result<usize, ZeroCopyCreationError> {
         fail!(from self, when self.update_connections(),
             "Unable to send sample since the connections could not be updated.");
 
+        let header_ptr =
+            (self.data_segment.allocator_data_start_address() + address_to_chunk) as *mut Header;
+        unsafe {
+            (*header_ptr).prepare_delivery(self.sequence_number.fetch_add(1, Ordering::Relaxed))
+        };
+
         self.add_to_history(address_to_chunk);
-        Ok(self.deliver_sample(address_to_chunk))
+        let number_of_recipients = self.deliver_sample(address_to_chunk);
+
+        // every sample asserts the liveliness implicitly
+        let now = monotonic_time();
+        let dynamic_config = self
+            .service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+        dynamic_config.set_last_send(self._dynamic_config_guard.value(), now);
+        dynamic_config.set_last_liveliness_assertion(self._dynamic_config_guard.value(), now);
+
+        Ok(number_of_recipients)
     }
 
     fn add_to_history(&self, address_to_chunk: usize) {
@@ -356,7 +517,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                self.sample_reference_counter[self.sample_index(address_to_chunk)]
                     .fetch_add(1, Ordering::Relaxed);
                 match unsafe { history.push_with_overflow(address_to_chunk) } {
                     None => (),
@@ -367,16 +528,22 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
     }
 
     fn deliver_sample(&self, address_to_chunk: usize) -> usize {
-        let deliver_call = match self.config.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender::blocking_send,
-            UnableToDeliverStrategy::DiscardSample => <<Service as service::Details<'config>>::Connection as ZeroCopyConnection>::Sender::try_send,
+        let deliver_call: DeliverCall<Sender<'config, Service>> = match (
+            Service::PAYLOAD_TRANSFER,
+            self.config.unable_to_deliver_strategy,
+        ) {
+            // a subscriber must never be able to block the publisher when the payload is copied
+            (PayloadTransfer::ZeroCopy, UnableToDeliverStrategy::Block) => {
+                Sender::<'config, Service>::blocking_send
+            }
+            _ => Sender::<'config, Service>::try_send,
         };
 
         let mut number_of_recipients = 0;
         for i in 0..self.subscriber_connections.len() {
             match self.subscriber_connections.get(i) {
                 Some(ref connection) => {
-                    match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
+                    match self.send_to(connection, address_to_chunk, deliver_call) {
                         Err(ZeroCopySendError::ReceiveBufferFull) => {
                             /* causes no problem
                              *   blocking_send => can never happen
@@ -386,16 +553,12 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                         Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
                             warn!(from self, "Unable to send sample via connection {:?} since the retrieve buffer is full. This can be caused by a corrupted retrieve channel.", connection);
                         }
-                        Ok(overflow) => {
-                            self.sample_reference_counter[Self::sample_index(address_to_chunk)]
-                                .fetch_add(1, Ordering::Relaxed);
-                            number_of_recipients += 1;
-
-                            if let Some(old) = overflow {
-                                self.release_sample(old)
-                            }
+                        Err(ZeroCopySendError::OffsetOutOfRange) => {
+                            warn!(from self, "Unable to send sample via connection {:?} since the sample is not contained in the data segment of the connection.", connection);
                         }
+                        Ok(()) => number_of_recipients += 1,
                     }
+                    self.drop_corrupted_connection(i);
                 }
                 None => (),
             }
@@ -403,8 +566,148 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         number_of_recipients
     }
 
+    fn send_to(
+        &self,
+        connection: &Connection<'config, Service>,
+        address_to_chunk: usize,
+        deliver_call: DeliverCall<Sender<'config, Service>>,
+    ) -> Result<(), ZeroCopySendError> {
+        let offset = match &connection.data_segment {
+            None => PointerOffset::new(address_to_chunk),
+            Some(data_segment) => self.copy_sample(data_segment, address_to_chunk)?,
+        };
+
+        match deliver_call(&connection.sender, offset) {
+            Ok(overflow) => {
+                if connection.data_segment.is_none() {
+                    self.sample_reference_counter[self.sample_index(address_to_chunk)]
+                        .fetch_add(1, Ordering::Relaxed);
+                }
+
+                if let Some(old) = overflow {
+                    self.release_delivered_sample(connection, old)
+                }
+
+                connection.notify();
+                Ok(())
+            }
+            Err(e) => {
+                if connection.data_segment.is_some() {
+                    self.release_delivered_sample(connection, offset);
+                }
+                Err(e)
+            }
+        }
+    }
+
+    // copies the sample into the data segment of a subscriber, a full data segment is handled
+    // like a full receive buffer
+    fn copy_sample(
+        &self,
+        data_segment: &Service::SharedMemory,
+        address_to_chunk: usize,
+    ) -> Result<PointerOffset, ZeroCopySendError> {
+        let chunk = match data_segment.allocate(self.message_layout.layout) {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
+                    "Unable to copy the sample into the data segment of the subscriber ({:?}).", e);
+            }
+        };
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                (self.data_segment.allocator_data_start_address() + address_to_chunk) as *const u8,
+                chunk.data_ptr,
+                self.message_layout.layout.size(),
+            )
+        };
+
+        Ok(chunk.offset)
+    }
+
+    // a sample that was copied is located in the data segment of the subscriber, a sample that
+    // cannot be released there marks the connection as corrupted
+    fn release_delivered_sample(
+        &self,
+        connection: &Connection<'config, Service>,
+        distance_to_chunk: PointerOffset,
+    ) {
+        let data_segment = match &connection.data_segment {
+            None => {
+                self.release_sample(distance_to_chunk);
+                return;
+            }
+            Some(data_segment) => data_segment,
+        };
+
+        if !self.is_sample_of_subscriber(data_segment, distance_to_chunk) {
+            warn!(from self, "The subscriber {:?} returned the offset {:?} which does not point to a sample of its data segment. The connection will be dropped.",
+                connection.subscriber_id, distance_to_chunk);
+            connection.is_corrupted.set(true);
+            return;
+        }
+
+        if let Err(e) =
+            unsafe { data_segment.deallocate(distance_to_chunk, self.message_layout.layout) }
+        {
+            warn!(from self, "Unable to release the sample {:?} in the data segment of the subscriber {:?} ({:?}). The connection will be dropped.",
+                distance_to_chunk, connection.subscriber_id, e);
+            connection.is_corrupted.set(true);
+        }
+    }
+
+    // the copies are located at the aligned samples of the data segment of the subscriber
+    fn is_sample_of_subscriber(
+        &self,
+        data_segment: &Service::SharedMemory,
+        distance_to_chunk: PointerOffset,
+    ) -> bool {
+        let layout = self.message_layout.layout;
+        let start = data_segment.allocator_data_start_address();
+        let first_sample = align(start, layout.align()) - start;
+        let sample_size = align(layout.size(), layout.align());
+
+        match distance_to_chunk.value().checked_sub(first_sample) {
+            Some(distance) => {
+                distance % sample_size == 0
+                    && distance / sample_size < self.subscriber_connections.number_of_samples()
+            }
+            None => false,
+        }
+    }
+
+    fn drop_corrupted_connection(&self, i: usize) {
+        if let Some(connection) = self.subscriber_connections.get(i) {
+            if connection.is_corrupted.get() {
+                warn!(from self, "Dropping the corrupted connection to the subscriber {:?}.", connection.subscriber_id);
+                self.subscriber_connections.drop_corrupted(i);
+            }
+        }
+    }
+
+    pub(crate) fn increment_loan_counter(&self) {
+        let loan_counter = self.loan_counter.fetch_add(1, Ordering::Relaxed) + 1;
+        self.publish_loan_counter(loan_counter);
+    }
+
+    pub(crate) fn decrement_loan_counter(&self) {
+        let loan_counter = self.loan_counter.fetch_sub(1, Ordering::Relaxed) - 1;
+        self.publish_loan_counter(loan_counter);
+    }
+
+    // makes the number of loaned samples visible for the introspection
+    fn publish_loan_counter(&self, value: usize) {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .set_loaned_samples(self._dynamic_config_guard.value(), value);
+    }
+
     pub(crate) fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[Self::sample_index(distance_to_chunk.value())]
+        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
             .fetch_sub(1, Ordering::Relaxed)
             == 1
         {
@@ -412,7 +715,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 fatal_panic!(from self, when self.data_segment
                 .deallocate(
                     distance_to_chunk,
-                    Layout::new::<MessageType>(),
+                    self.message_layout.layout,
                 ), "Internal logic error. The sample should always contain a valid memory chunk from the provided allocator.");
             };
         }
@@ -423,30 +726,18 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             match self.subscriber_connections.get(i) {
                 Some(ref connection) => loop {
                     match connection.sender.reclaim() {
-                        Ok(Some(ptr_dist)) => {
-                            let sample_index = Self::sample_index(ptr_dist.value());
-
-                            if self.sample_reference_counter[sample_index]
-                                .fetch_sub(1, Ordering::Relaxed)
-                                == 1
-                            {
-                                unsafe {
-                                    fatal_panic!(from self, when self.data_segment
-                                    .deallocate(
-                                        ptr_dist,
-                                        Layout::new::<Message<Header, MessageType>>(),
-                                    ), "This should never happen! Failed to deallocate the reclaimed ptr. Either the data was corrupted or an invalid ptr was returned.")
-                                };
-                            }
-                        }
+                        Ok(Some(ptr_dist)) => self.release_delivered_sample(connection, ptr_dist),
                         Ok(None) => break,
                         Err(e) => {
-                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}. This may lead to a situation where no more samples will be delivered to this connection.", connection, e)
+                            warn!(from self, "Unable to reclaim samples from connection {:?} due to {:?}.", connection, e);
+                            connection.is_corrupted.set(true);
+                            break;
                         }
                     }
                 },
                 None => (),
             }
+            self.drop_corrupted_connection(i);
         }
     }
 
@@ -458,6 +749,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 service::static_config::StaticConfig,
                 UniquePublisherId,
                 UniqueSubscriberId,
+                DegrationCause,
             ) -> DegrationAction
             + 'a,
     >(
@@ -470,6 +762,25 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
+    /// Returns the [`UniquePublisherId`] of the [`Publisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.port_id
+    }
+
+    /// Asserts the liveliness of the [`Publisher`] without sending a [`crate::sample::Sample`].
+    /// It has to be called periodically, within the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::liveliness_lease_duration()`],
+    /// when the [`Publisher`] sends samples less frequently. [`Publisher::send()`] and
+    /// [`Publisher::send_copy()`] assert the liveliness implicitly.
+    pub fn assert_liveliness(&self) {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .set_last_liveliness_assertion(self._dynamic_config_guard.value(), monotonic_time());
+    }
+
     /// Explicitly updates all connections to the [`crate::port::subscriber::Subscriber`]s. This is
     /// required to be called whenever a new [`crate::port::subscriber::Subscriber`] connected to
     /// the service. It is done implicitly whenever [`Publisher::send()`] or [`Publisher::send_copy()`]
@@ -480,6 +791,7 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 "Connections were updated only partially since at least one connection to a Subscriber port failed.");
         }
 
+        self.subscriber_connections.open_missing_notifiers();
         Ok(())
     }
 
@@ -497,25 +809,13 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         )
     }
 
-    /// Copies the input `value` into a [`SampleMut`] and delivers it.
-    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
-    /// the data, otherwise a [`SendCopyError`] describing the failure.
-    pub fn send_copy(&self, value: MessageType) -> Result<usize, SendCopyError> {
-        let msg = "Unable to send copy of message";
-        let mut sample = fail!(from self, when self.loan(),
-                                    "{} since the loan of a sample failed.", msg);
-
-        unsafe { sample.as_mut_ptr().write(value) };
-        Ok(
-            fail!(from self, when self.send_impl(sample.offset_to_chunk().value()),
-            "{} since the underlying send operation failed.", msg),
-        )
-    }
-
-    /// Loans/allocates a [`SampleMut`] from the underlying data segment of the [`Publisher`].
-    /// On failure it returns [`LoanError`] describing the failure.
-    pub fn loan<'publisher>(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Publisher::loan", level = "trace", skip_all)
+    )]
+    fn loan_impl<'publisher>(
         &'publisher self,
+        number_of_elements: usize,
     ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, MessageType>, LoanError> {
         self.retrieve_returned_samples();
         let msg = "Unable to loan Sample";
@@ -526,12 +826,9 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_samples);
         }
 
-        match self
-            .data_segment
-            .allocate(Layout::new::<Message<Header, MessageType>>())
-        {
+        match self.data_segment.allocate(self.message_layout.layout) {
             Ok(chunk) => {
-                if self.sample_reference_counter[Self::sample_index(chunk.offset.value())]
+                if self.sample_reference_counter[self.sample_index(chunk.offset.value())]
                     .fetch_add(1, Ordering::Relaxed)
                     != 0
                 {
@@ -539,17 +836,22 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                                 "{} since the allocated sample is already in use! This should never happen!", msg);
                 }
 
-                let mut chunk_ptr;
-                unsafe {
-                    chunk_ptr = NonNull::new_unchecked(
-                        chunk.data_ptr as *mut MaybeUninit<Message<Header, MessageType>>,
-                    );
-                    let header_ptr =
-                        std::ptr::addr_of_mut!((*chunk_ptr.as_mut().as_mut_ptr()).header);
-                    header_ptr.write(Header::new(self.port_id))
-                }
-
-                Ok(SampleMut::new(self, chunk_ptr, chunk.offset))
+                let header_ptr = chunk.data_ptr as *mut Header;
+                unsafe { header_ptr.write(Header::new(self.port_id, number_of_elements as u64)) };
+
+                let payload_ptr = unsafe {
+                    MessageType::from_raw_parts(
+                        chunk.data_ptr.add(self.message_layout.payload_offset),
+                        number_of_elements,
+                    )
+                };
+
+                Ok(SampleMut::new(
+                    self,
+                    unsafe { NonNull::new_unchecked(header_ptr) },
+                    unsafe { NonNull::new_unchecked(payload_ptr) },
+                    chunk.offset,
+                ))
             }
             Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
                 fail!(from self, with LoanError::OutOfMemory,
@@ -566,3 +868,125 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 }
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: PayloadType + ?Sized> Drop
+    for Publisher<'a, 'config, Service, MessageType>
+{
+    fn drop(&mut self) {
+        // the copies the subscribers already returned are released in their data segments, the
+        // subscribers release the remaining copies themselves when the publisher is gone
+        if Service::PAYLOAD_TRANSFER == PayloadTransfer::Copy {
+            self.retrieve_returned_samples();
+        }
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug + ZeroCopySend>
+    Publisher<'a, 'config, Service, MessageType>
+{
+    /// Copies the input `value` into a [`SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`SendCopyError`] describing the failure.
+    pub fn send_copy(&self, value: MessageType) -> Result<usize, SendCopyError> {
+        let msg = "Unable to send copy of message";
+        let mut sample = fail!(from self, when self.loan(),
+                                    "{} since the loan of a sample failed.", msg);
+
+        unsafe { sample.as_mut_ptr().write(value) };
+        Ok(
+            fail!(from self, when self.send_impl(sample.offset_to_chunk().value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Loans/allocates a [`SampleMut`] from the underlying data segment of the [`Publisher`].
+    /// On failure it returns [`LoanError`] describing the failure.
+    pub fn loan<'publisher>(
+        &'publisher self,
+    ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, MessageType>, LoanError> {
+        self.loan_impl(1)
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, T: Debug + ZeroCopySend>
+    Publisher<'a, 'config, Service, [T]>
+{
+    /// Copies the input `values` into a [`SampleMut`] and delivers it.
+    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
+    /// the data, otherwise a [`SendCopyError`] describing the failure.
+    pub fn send_slice_copy(&self, values: &[T]) -> Result<usize, SendCopyError>
+    where
+        T: Copy,
+    {
+        let msg = "Unable to send copy of slice";
+        let mut sample = fail!(from self, when self.loan_uninit_slice(values.len()),
+                                    "{} since the loan of a sample failed.", msg);
+
+        unsafe {
+            std::ptr::copy_nonoverlapping(
+                values.as_ptr(),
+                sample.as_mut_ptr() as *mut T,
+                values.len(),
+            )
+        };
+        Ok(
+            fail!(from self, when self.send_impl(sample.offset_to_chunk().value()),
+            "{} since the underlying send operation failed.", msg),
+        )
+    }
+
+    /// Loans/allocates a [`SampleMut`] that contains a slice of `slice_len` elements which are
+    /// initialized with [`Default::default()`].
+    /// On failure it returns [`LoanError`] describing the failure.
+    pub fn loan_slice<'publisher>(
+        &'publisher self,
+        slice_len: usize,
+    ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, [T]>, LoanError>
+    where
+        T: Default,
+    {
+        let mut sample = self.loan_uninit_slice(slice_len)?;
+        let elements = sample.as_mut_ptr() as *mut T;
+        for i in 0..slice_len {
+            unsafe { elements.add(i).write(T::default()) };
+        }
+
+        Ok(sample)
+    }
+
+    /// Loans/allocates a [`SampleMut`] that contains a slice of `slice_len` uninitialized
+    /// elements. Every element must be written via [`SampleMut::as_mut_ptr()`] before the
+    /// [`SampleMut`] is sent.
+    /// On failure it returns [`LoanError`] describing the failure.
+    pub fn loan_uninit_slice<'publisher>(
+        &'publisher self,
+        slice_len: usize,
+    ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, [T]>, LoanError> {
+        if self.max_slice_len < slice_len {
+            fail!(from self, with LoanError::ExceedsMaxSliceLength,
+                "Unable to loan slice with {} elements since it exceeds the maximum slice length of {}.",
+                slice_len, self.max_slice_len);
+        }
+
+        self.loan_impl(slice_len)
+    }
+
+    /// Loans/allocates a [`SampleMut`] that contains a slice of `slice_len` uninitialized
+    /// elements and whose payload is aligned to at least `alignment`. The `alignment` must be a
+    /// power of two and cannot exceed
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::payload_alignment()`].
+    /// On failure it returns [`LoanError`] describing the failure.
+    pub fn loan_uninit_slice_with_alignment<'publisher>(
+        &'publisher self,
+        slice_len: usize,
+        alignment: usize,
+    ) -> Result<SampleMut<'a, 'publisher, 'config, Service, Header, [T]>, LoanError> {
+        if !alignment.is_power_of_two() || self.payload_alignment < alignment {
+            fail!(from self, with LoanError::UnsupportedAlignment,
+                "Unable to loan slice with an alignment of {} since the service provides a payload alignment of {} and the alignment must be a power of two.",
+                alignment, self.payload_alignment);
+        }
+
+        self.loan_uninit_slice(slice_len)
+    }
+}
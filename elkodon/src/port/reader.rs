@@ -0,0 +1,179 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .open_or_create()?;
+//!
+//! let reader = blackboard.reader().create()?;
+//! let entry = reader.entry::<i32>(&0)?;
+//!
+//! println!("current value: {}", entry.get());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use elkodon_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use elkodon_bb_log::fail;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
+
+use crate::port::details::blackboard_entries::{lookup, EntryLookupFailure};
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+use crate::port::port_identifiers::UniqueReaderId;
+use crate::service;
+use crate::service::builder::blackboard::BlackboardKey;
+
+/// Defines the failures that can occur when a [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderCreateError {
+    ExceedsMaxSupportedReaders,
+    UnableToCreateMonitoringToken,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// Defines the failures that can occur when an [`EntryHandle`] is acquired with
+/// [`Reader::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EntryHandleError {
+    EntryDoesNotExist,
+    IncompatibleType,
+}
+
+impl std::fmt::Display for EntryHandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for EntryHandleError {}
+
+/// Provides read access to a single entry of the blackboard. Every read is lock-free and
+/// returns a consistent copy of the latest value that was written by the
+/// [`crate::port::writer::Writer`].
+#[derive(Debug)]
+pub struct EntryHandle<'a, ValueType: Copy + Debug> {
+    atomic: &'a UnrestrictedAtomic<ValueType>,
+}
+
+impl<'a, ValueType: Copy + Debug> EntryHandle<'a, ValueType> {
+    /// Returns a copy of the current value of the entry.
+    pub fn get(&self) -> ValueType {
+        self.atomic.load()
+    }
+}
+
+/// Represents the reading endpoint of a blackboard based communication.
+#[derive(Debug)]
+pub struct Reader<'a, 'config: 'a, Service: service::Details<'config>, KeyType: BlackboardKey> {
+    _dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
+    service: &'a Service,
+    port_id: UniqueReaderId,
+    _phantom_config: PhantomData<&'config ()>,
+    _phantom_key: PhantomData<KeyType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, KeyType: BlackboardKey>
+    Reader<'a, 'config, Service, KeyType>
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Reader::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
+    pub(crate) fn new(service: &'a Service) -> Result<Self, ReaderCreateError> {
+        let msg = "Failed to create reader";
+        let origin = "Reader::new()";
+        let port_id = UniqueReaderId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with ReaderCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+
+        let mut new_self = Self {
+            _monitoring_token,
+            _dynamic_config_guard: None,
+            service,
+            port_id,
+            _phantom_config: PhantomData,
+            _phantom_key: PhantomData,
+        };
+
+        // !MUST! be the last task otherwise a reader is added to the dynamic config without
+        // being fully initialized
+        new_self._dynamic_config_guard = Some(
+            match service
+                .state()
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .add_reader_id(port_id)
+            {
+                Some(unique_index) => {
+                    DynamicConfigGuard::new(unique_index, service.state().global_config)
+                }
+                None => {
+                    fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                        "{} since it would exceed the maximum supported amount of readers of {}.",
+                        msg, service.state().static_config.blackboard().max_readers);
+                }
+            },
+        );
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.port_id
+    }
+
+    /// Returns an [`EntryHandle`] to the entry that is identified by `key`. On failure it
+    /// returns [`EntryHandleError`] describing the failure.
+    pub fn entry<ValueType: Copy + Debug>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandle<'_, ValueType>, EntryHandleError> {
+        let msg = "Unable to acquire entry handle";
+        match lookup::<KeyType, ValueType>(
+            self.service.state().static_config.blackboard(),
+            self.service.state().dynamic_storage.get().blackboard(),
+            key,
+        ) {
+            Ok((_, atomic)) => Ok(EntryHandle { atomic }),
+            Err(EntryLookupFailure::EntryDoesNotExist) => {
+                fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} exists.", msg, key);
+            }
+            Err(EntryLookupFailure::IncompatibleType) => {
+                fail!(from self, with EntryHandleError::IncompatibleType,
+                    "{} since the entry with the key {:?} does not have the type \"{}\".",
+                    msg, key, std::any::type_name::<ValueType>());
+            }
+        }
+    }
+}
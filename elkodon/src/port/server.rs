@@ -0,0 +1,601 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = service
+//!     .server()
+//!     // defines how many responses can be loaned in parallel
+//!     .max_loaned_responses(2)
+//!     .create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     println!("received request: {:?}", *request);
+//!
+//!     // loan some memory and send the response
+//!     let mut response = request.loan()?;
+//!     unsafe {
+//!         response.as_mut_ptr().write(*request * 2);
+//!     }
+//!     response.send()?;
+//!
+//!     // send a copy of the value
+//!     request.send_copy(*request * 3)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::{alloc::Layout, marker::PhantomData, mem::MaybeUninit, ptr::NonNull};
+
+use super::details::client_connections::*;
+use super::details::publisher_connections::ConnectionFailure;
+use super::port_identifiers::{UniqueClientId, UniqueServerId};
+use super::publisher::LoanError;
+use crate::active_request::ActiveRequest;
+use crate::message::Message;
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+use crate::response_mut::ResponseMut;
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::static_config::request_response;
+use crate::{config, service};
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_elementary::allocator::AllocationError;
+use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_lock_free::mpmc::container::ContainerState;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
+use elkodon_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+};
+use elkodon_cal::shared_memory::{SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError};
+use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
+use elkodon_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use elkodon_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopyReleaseError,
+    ZeroCopySendError, ZeroCopySender,
+};
+
+/// Defines a failure that can occur when a [`Server`] is created with
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    ExceedsMaxSupportedServers,
+    UnableToCreateDataSegment,
+    UnableToCreateMonitoringToken,
+}
+
+impl std::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+/// Defines the failure that can occur when receiving an [`ActiveRequest`] with
+/// [`Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum RequestReceiveError {
+    ExceedsMaxBorrowedRequests,
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for RequestReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestReceiveError {}
+
+/// Defines the failure that can occur when a [`ResponseMut`] is sent with
+/// [`ResponseMut::send()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ResponseSendError {
+    ClientDisconnected,
+    ResponseBufferFull,
+    ConnectionCorrupted,
+}
+
+impl std::fmt::Display for ResponseSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseSendError {}
+
+enum_gen! {
+    /// Failure that can be emitted when a response is sent via [`ActiveRequest::send_copy()`].
+    ResponseSendCopyError
+  mapping:
+    LoanError to LoanError,
+    ResponseSendError to SendError
+}
+
+impl std::fmt::Display for ResponseSendCopyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ResponseSendCopyError {}
+
+pub(crate) fn data_segment_name(server_id: UniqueServerId) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the servers data segment.";
+    let origin = "data_segment_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(server_id.0.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(server_id.0.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn data_segment_config<'config, Service: service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
+    let origin = "data_segment_config()";
+
+    let f = match FileName::new(
+        global_config
+            .global
+            .service
+            .server_data_segment_suffix
+            .as_bytes(),
+    ) {
+        Err(_) => {
+            fatal_panic!(from origin, "The server_data_segment_suffix \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.service.server_data_segment_suffix);
+        }
+        Ok(v) => v,
+    };
+
+    <Service::SharedMemory as NamedConceptMgmt>::Configuration::default().suffix(f)
+}
+
+/// The responding endpoint of a request-response based communication. It receives the requests
+/// of all connected [`crate::port::client::Client`]s and delivers every response only to the
+/// [`crate::port::client::Client`] that sent the corresponding request.
+#[derive(Debug)]
+pub struct Server<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    port_id: UniqueServerId,
+    pub(crate) sample_reference_counter: Vec<AtomicU64>,
+    pub(crate) data_segment: Service::SharedMemory,
+    config: LocalServerConfig,
+
+    client_connections: ClientConnections<'config, Service>,
+    client_list_state: UnsafeCell<ContainerState<'a, UniqueClientId>>,
+    pub(crate) loan_counter: AtomicUsize,
+    _dynamic_config_guard: DynamicConfigGuard<'a>,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
+}
+
+impl<
+        'a,
+        'config: 'a,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Server<'a, 'config, Service, RequestType, ResponseType>
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Server::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
+    pub(crate) fn new(
+        service: &'a Service,
+        static_config: &request_response::StaticConfig,
+        config: &LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let port_id = UniqueServerId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with ServerCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+        let client_list = &service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        // every client connection can hold up to max_active_requests responses in its buffer
+        // and the client can borrow up to max_active_requests responses
+        let number_of_responses = static_config.max_clients * 2 * static_config.max_active_requests
+            + config.max_loaned_responses
+            + 1;
+
+        let data_segment = fail!(from origin, when Self::create_data_segment(port_id, service.state().global_config, number_of_responses),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let _dynamic_config_guard = match service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(port_id)
+        {
+            Some(unique_index) => {
+                DynamicConfigGuard::new(unique_index, service.state().global_config)
+            }
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                            "{} since it would exceed the maximum supported amount of servers of {}.",
+                            msg, static_config.max_servers);
+            }
+        };
+
+        let new_self = Self {
+            _monitoring_token,
+            port_id,
+            sample_reference_counter: {
+                let mut v = Vec::with_capacity(number_of_responses);
+                for _ in 0..number_of_responses {
+                    v.push(AtomicU64::new(0));
+                }
+                v
+            },
+            data_segment,
+            config: *config,
+            client_connections: ClientConnections::new(
+                client_list.capacity(),
+                port_id,
+                service.state().global_config,
+                service.state().static_config.uuid(),
+                static_config,
+                number_of_responses,
+                std::mem::size_of::<Message<ResponseHeader, ResponseType>>(),
+            ),
+            client_list_state: unsafe { UnsafeCell::new(client_list.get_state()) },
+            loan_counter: AtomicUsize::new(0),
+            _dynamic_config_guard,
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        Ok(new_self)
+    }
+
+    fn create_data_segment(
+        port_id: UniqueServerId,
+        global_config: &'config config::Config,
+        number_of_responses: usize,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: Layout::new::<Message<ResponseHeader, ResponseType>>(),
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        Ok(fail!(from "Server::create_data_segment()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&data_segment_name(port_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(chunk_size * number_of_responses + chunk_align - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment."))
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.capacity(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|index, client_id| {
+                visited_indices[index as usize] = Some(*client_id);
+            })
+        };
+
+        // retrieve responses before destroying channel
+        self.retrieve_returned_responses();
+
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(client_id) => {
+                    // the slot was reused by a new client since the last update
+                    if let Some(connection) = self.client_connections.get(i) {
+                        if connection.client_id != *client_id {
+                            self.remove_connection(i);
+                        }
+                    }
+
+                    if let Err(e) = self.client_connections.create(i, *client_id) {
+                        fail!(from self, with e,
+                            "Unable to establish connection to new client {:?}.", client_id);
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.client_connections.get(i) {
+            // the client is gone and will never return the responses it still owns
+            unsafe {
+                connection
+                    .response_sender
+                    .acquire_used_offsets(|offset| self.release_response(offset))
+            };
+        }
+
+        self.client_connections.remove(i);
+    }
+
+    fn sample_index(distance_to_chunk: usize) -> usize {
+        distance_to_chunk / std::mem::size_of::<Message<ResponseHeader, ResponseType>>()
+    }
+
+    pub(crate) fn release_response(&self, distance_to_chunk: PointerOffset) {
+        if self.sample_reference_counter[Self::sample_index(distance_to_chunk.value())]
+            .fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                fatal_panic!(from self, when self.data_segment
+                .deallocate(
+                    distance_to_chunk,
+                    Layout::new::<Message<ResponseHeader, ResponseType>>(),
+                ), "Internal logic error. The response should always contain a valid memory chunk from the provided allocator.");
+            };
+        }
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(ptr_dist)) => self.release_response(ptr_dist),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) fn release_request(&self, channel_id: usize, request: *const u8) {
+        match self.client_connections.get(channel_id) {
+            Some(c) => {
+                let distance = request as usize - c.data_segment.allocator_data_start_address();
+                match c.request_receiver.release(PointerOffset::new(distance)) {
+                    Ok(()) => (),
+                    Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                        fatal_panic!(from self, when c.request_receiver.release(PointerOffset::new(distance)),
+                                    "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+                    }
+                }
+            }
+            None => {
+                warn!(from self, "Unable to release request since the connection is broken. The request will be discarded and has to be reclaimed manually by the client.");
+            }
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Server::loan", level = "trace", skip_all)
+    )]
+    pub(crate) fn loan_response<'server>(
+        &'server self,
+        channel_id: usize,
+        client_id: UniqueClientId,
+        request_id: u64,
+    ) -> Result<ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType>, LoanError>
+    {
+        self.retrieve_returned_responses();
+        let msg = "Unable to loan Response";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_responses {
+            fail!(from self, with LoanError::ExceedsMaxLoanedChunks,
+                "{} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_responses);
+        }
+
+        match self
+            .data_segment
+            .allocate(Layout::new::<Message<ResponseHeader, ResponseType>>())
+        {
+            Ok(chunk) => {
+                if self.sample_reference_counter[Self::sample_index(chunk.offset.value())]
+                    .fetch_add(1, Ordering::Relaxed)
+                    != 0
+                {
+                    fatal_panic!(from self,
+                                "{} since the allocated response is already in use! This should never happen!", msg);
+                }
+
+                let mut chunk_ptr;
+                unsafe {
+                    chunk_ptr = NonNull::new_unchecked(
+                        chunk.data_ptr as *mut MaybeUninit<Message<ResponseHeader, ResponseType>>,
+                    );
+                    let header_ptr =
+                        std::ptr::addr_of_mut!((*chunk_ptr.as_mut().as_mut_ptr()).header);
+                    header_ptr.write(ResponseHeader::new(self.port_id, request_id))
+                }
+
+                Ok(ResponseMut::new(
+                    self,
+                    chunk_ptr,
+                    chunk.offset,
+                    channel_id,
+                    client_id,
+                ))
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with LoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with LoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Server::send", level = "trace", skip_all, ret)
+    )]
+    pub(crate) fn send_response(
+        &self,
+        channel_id: usize,
+        client_id: UniqueClientId,
+        address_to_chunk: usize,
+    ) -> Result<(), ResponseSendError> {
+        let msg = "Unable to send response";
+        self.retrieve_returned_responses();
+
+        let connection = match self.client_connections.get(channel_id) {
+            Some(ref connection) if connection.client_id == client_id => connection,
+            _ => {
+                fail!(from self, with ResponseSendError::ClientDisconnected,
+                    "{} since the client {:?} is no longer connected.", msg, client_id);
+            }
+        };
+
+        match connection
+            .response_sender
+            .try_send(PointerOffset::new(address_to_chunk))
+        {
+            Ok(_) => {
+                self.sample_reference_counter[Self::sample_index(address_to_chunk)]
+                    .fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull) => {
+                fail!(from self, with ResponseSendError::ResponseBufferFull,
+                    "{} since the response buffer of the client {:?} is full.", msg, client_id);
+            }
+            Err(ZeroCopySendError::ClearRetrieveChannelBeforeSend) => {
+                fail!(from self, with ResponseSendError::ConnectionCorrupted,
+                    "{} since the retrieve buffer of the connection {:?} is full. This can be caused by a corrupted retrieve channel.",
+                    msg, connection);
+            }
+            Err(ZeroCopySendError::OffsetOutOfRange) => {
+                fail!(from self, with ResponseSendError::ConnectionCorrupted,
+                    "{} since the response is not contained in the data segment of the connection {:?}.",
+                    msg, connection);
+            }
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.port_id
+    }
+
+    /// Explicitly updates all connections to the [`crate::port::client::Client`]s. This is
+    /// required to be called whenever a new [`crate::port::client::Client`] connected to
+    /// the service. It is done implicitly whenever [`Server::receive()`] is called.
+    pub fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe { (*self.client_list_state.get()).update() } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a Client port failed.");
+        }
+
+        Ok(())
+    }
+
+    /// Receives an [`ActiveRequest`] from a [`crate::port::client::Client`]. The response to
+    /// the request can be loaned with [`ActiveRequest::loan()`]. If no request could be received
+    /// [`None`] is returned. If a failure occurs [`RequestReceiveError`] is returned.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Server::receive", level = "trace", skip_all)
+    )]
+    pub fn receive<'server>(
+        &'server self,
+    ) -> Result<
+        Option<ActiveRequest<'a, 'server, 'config, Service, RequestType, ResponseType>>,
+        RequestReceiveError,
+    > {
+        let msg = "Unable to receive another request";
+        if let Err(e) = self.update_connections() {
+            fail!(from self,
+                with RequestReceiveError::ConnectionFailure(e),
+                "Some requests are not being received since not all connections to clients could be established.");
+        }
+
+        for id in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(id) {
+                match connection.request_receiver.receive() {
+                    Ok(None) => (),
+                    Ok(Some(relative_addr)) => {
+                        let absolute_address = relative_addr.value()
+                            + connection.data_segment.allocator_data_start_address();
+                        return Ok(Some(ActiveRequest {
+                            server: self,
+                            channel_id: id,
+                            client_id: connection.client_id,
+                            ptr: unsafe {
+                                NonNull::new_unchecked(
+                                    absolute_address as *mut Message<RequestHeader, RequestType>,
+                                )
+                            },
+                        }));
+                    }
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with RequestReceiveError::ExceedsMaxBorrowedRequests,
+                            "{} since it would exceed the maximum {} of borrowed requests.",
+                            msg, connection.request_receiver.max_borrowed_samples());
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
@@ -18,32 +18,127 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Timed And Blocking Receive
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/WaitingServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     // timed and blocking receive wait for the notifications of the publishers
+//!     .enable_notifications(true)
+//!     .open_or_create::<u64>()?;
+//!
+//! let subscriber = service.subscriber().create()?;
+//!
+//! // sleeps until a sample was received or the timeout has passed
+//! if let Some(sample) = subscriber.timed_receive(Duration::from_millis(10))? {
+//!     println!("received: {:?}", *sample);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Notifications
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! use elkodon::waitset::WaitSetBuilder;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/NotifiedServiceName")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     // every publisher wakes up the subscribers it delivered a sample to
+//!     .enable_notifications(true)
+//!     .open_or_create::<u64>()?;
+//!
+//! let subscriber = service.subscriber().create()?;
+//! let waitset = WaitSetBuilder::new().create::<zero_copy::Service>()?;
+//! let _guard = waitset.attach_notification(subscriber.notification_handle().unwrap())?;
+//!
+//! waitset.timed_wait(|_| {
+//!     while let Ok(Some(sample)) = subscriber.receive() {
+//!         println!("received: {:?}", *sample);
+//!     }
+//! }, Duration::from_millis(10))?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
 use std::fmt::Debug;
+#[cfg(feature = "async")]
+use std::future::poll_fn;
+use std::time::{Duration, Instant};
 use std::{marker::PhantomData, ptr::NonNull};
 
+use elkodon_bb_container::semantic_string::SemanticString;
 use elkodon_bb_lock_free::mpmc::container::ContainerState;
-use elkodon_bb_lock_free::mpmc::unique_index_set::UniqueIndex;
 use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use elkodon_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use elkodon_bb_posix::signal::SignalHandler;
+use elkodon_bb_system_types::file_name::FileName;
 use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::event::{Event, Listener, ListenerBuilder};
+use elkodon_cal::monitoring::Monitoring;
+use elkodon_cal::named_concept::{
+    NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt,
+};
+use elkodon_cal::shm_allocator::{self, pool_allocator::PoolAllocator};
 use elkodon_cal::{shared_memory::*, zero_copy_connection::*};
+use tiny_fn::tiny_fn;
 
-use crate::port::DegrationAction;
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+#[cfg(feature = "async")]
+use crate::port::details::readiness::Readiness;
+use crate::port::event_id::EventId;
+use crate::port::{DegrationAction, DegrationCause};
+use crate::service::dynamic_config::publish_subscribe::DynamicConfig;
+use crate::service::event_concept_name;
+use crate::service::port_factory::subscriber::{LocalSubscriberConfig, ReceivePolicy};
 use crate::service::static_config::publish_subscribe::StaticConfig;
+use crate::service::PayloadTransfer;
 use crate::{
-    message::Message, sample::Sample, service, service::header::publish_subscribe::Header,
+    config, message::MessageLayout, payload_type::PayloadType, sample::Sample, service,
+    service::header::publish_subscribe::Header,
 };
 
 use super::details::publisher_connections::{Connection, ConnectionFailure, PublisherConnections};
 use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::DegrationCallback;
 
-/// Defines the failure that can occur when receiving data with [`Subscriber::receive()`].
+/// Defines the failure that can occur when receiving data with [`Subscriber::receive()`],
+/// [`Subscriber::timed_receive()`] or [`Subscriber::blocking_receive()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum ReceiveError {
     ExceedsMaxBorrowedSamples,
     ConnectionFailure(ConnectionFailure),
+    /// Only returned by [`Subscriber::timed_receive()`] and [`Subscriber::blocking_receive()`]
+    /// when [`SignalHandler::termination_requested()`] was true while waiting.
+    TerminationRequested,
+    /// Returned by [`Subscriber::timed_receive()`] and [`Subscriber::blocking_receive()`]
+    /// when the service was created without notifications, see
+    /// [`crate::service::builder::publish_subscribe::Builder::enable_notifications()`].
+    NotificationsNotEnabled,
+    /// Returned by [`Subscriber::timed_receive()`] and [`Subscriber::blocking_receive()`]
+    /// when the wait for the next notification failed.
+    UnableToWaitForNotifications,
+    /// A [`crate::port::publisher::Publisher`] missed its deadline and the
+    /// [`DegrationCallback`] returned [`DegrationAction::Fail`].
+    PublisherMissedDeadline,
+    /// A [`crate::port::publisher::Publisher`] lost its liveliness and the
+    /// [`DegrationCallback`] returned [`DegrationAction::Fail`].
+    PublisherLostLiveliness,
 }
 
 impl std::fmt::Display for ReceiveError {
@@ -59,6 +154,9 @@ impl std::error::Error for ReceiveError {}
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum SubscriberCreateError {
     ExceedsMaxSupportedSubscribers,
+    UnableToCreateMonitoringToken,
+    UnableToCreateNotificationHandle,
+    UnableToCreateDataSegment,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
@@ -69,29 +167,217 @@ impl std::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+pub(crate) fn data_segment_name(subscriber_id: UniqueSubscriberId) -> FileName {
+    let msg = "The system does not support the required file name length for the subscribers data segment.";
+    let origin = "data_segment_name()";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(subscriber_id.0.pid().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(subscriber_id.0.value().to_string().as_bytes()), "{}", msg);
+    file
+}
+
+pub(crate) fn data_segment_config<'config, Service: service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::SharedMemory as NamedConceptMgmt>::Configuration {
+    let origin = "data_segment_config()";
+
+    let f = match FileName::new(
+        global_config
+            .global
+            .service
+            .subscriber_data_segment_suffix
+            .as_bytes(),
+    ) {
+        Err(_) => {
+            fatal_panic!(from origin, "The subscriber_data_segment_suffix \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.service.subscriber_data_segment_suffix);
+        }
+        Ok(v) => v,
+    };
+
+    <Service::SharedMemory as NamedConceptMgmt>::Configuration::default().suffix(f)
+}
+
+/// The waitable handle of a [`Subscriber`]. It is signaled whenever a
+/// [`crate::port::publisher::Publisher`] delivers a [`Sample`] to the [`Subscriber`] and is only
+/// available when the service has notifications enabled, see
+/// [`crate::service::static_config::publish_subscribe::StaticConfig::has_notifications()`].
+#[derive(Debug)]
+pub struct NotificationHandle<'config, Service: service::Details<'config>> {
+    listener: <Service::Event as Event<EventId>>::Listener,
+    _phantom: PhantomData<&'config ()>,
+}
+
+impl<'config, Service: service::Details<'config>> NotificationHandle<'config, Service> {
+    /// Consumes all pending notifications and returns true if there was at least one.
+    fn consume_notifications(&self) -> bool {
+        let mut has_notifications = false;
+        loop {
+            match self.listener.try_wait() {
+                Ok(Some(_)) => has_notifications = true,
+                Ok(None) => return has_notifications,
+                Err(e) => {
+                    warn!(from self, "Unable to consume the pending notifications ({:?}).", e);
+                    return has_notifications;
+                }
+            }
+        }
+    }
+}
+
+impl<'config, Service: service::Details<'config>> FileDescriptorBased
+    for NotificationHandle<'config, Service>
+where
+    <Service::Event as Event<EventId>>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+/// A [`NotificationHandle`] can be attached to a [`crate::waitset::WaitSet`] when its underlying
+/// event concept is based on a file descriptor.
+impl<'config, Service: service::Details<'config>> SynchronousMultiplexing
+    for NotificationHandle<'config, Service>
+where
+    <Service::Event as Event<EventId>>::Listener: SynchronousMultiplexing,
+{
+}
+
+tiny_fn! {
+    /// Is called whenever the [`Subscriber`] detects a gap in the sequence numbers of the
+    /// [`Sample`]s of a [`crate::port::publisher::Publisher`], for instance when samples were
+    /// overwritten in a safely overflowing service. Provides the number of lost samples.
+    pub struct SampleLossCallback = Fn(publisher_id: UniquePublisherId, number_of_lost_samples: u64);
+}
+
+impl<'a> Debug for SampleLossCallback<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
+
+// the degrations of a publisher that were already reported, they are reported again only after
+// the publisher recovered from them
+#[derive(Debug, Default, Clone, Copy)]
+struct ReportedDegrations {
+    publisher_id: Option<UniquePublisherId>,
+    deadline_missed: bool,
+    liveliness_lost: bool,
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
-pub struct Subscriber<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
-    dynamic_config_guard: Option<UniqueIndex<'a>>,
+pub struct Subscriber<
+    'a,
+    'config: 'a,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+> {
+    dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
     publisher_connections: PublisherConnections<'config, Service>,
     service: &'a Service,
     degration_callback: Option<DegrationCallback<'a>>,
+    sample_loss_callback: Option<SampleLossCallback<'a>>,
+    message_layout: MessageLayout,
+    max_slice_len: usize,
+    // how many elements of the MessageType form one element of the payload type of the service,
+    // it is only not 1 for services that were opened without their payload type
+    elements_per_payload_element: usize,
+    notification_handle: Option<NotificationHandle<'config, Service>>,
+    // the publishers copy their samples into it, only available when the payload is copied
+    data_segment: Option<Service::SharedMemory>,
+    config: LocalSubscriberConfig,
+    deadline: Option<Duration>,
+    liveliness_lease_duration: Option<Duration>,
+    lifespan: Option<Duration>,
+    number_of_expired_samples: Cell<u64>,
+    // the connection that is considered first by the ReceivePolicy::RoundRobin
+    next_connection: Cell<usize>,
+    // indexed like the publishers in the dynamic config
+    reported_degrations: Vec<Cell<ReportedDegrations>>,
 
     publisher_list_state: UnsafeCell<ContainerState<'a, UniquePublisherId>>,
     _phantom_message_type: PhantomData<MessageType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
 }
 
-impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     Subscriber<'a, 'config, Service, MessageType>
 {
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Subscriber::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
     pub(crate) fn new(
         service: &'a Service,
         static_config: &StaticConfig,
+        config: &LocalSubscriberConfig,
     ) -> Result<Self, SubscriberCreateError> {
         let msg = "Failed to create Subscriber port";
         let origin = "Subscriber::new()";
         let port_id = UniqueSubscriberId::new();
 
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with SubscriberCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+
+        // created before the subscriber is added to the dynamic config so that every publisher
+        // can connect to it
+        let notification_handle = match static_config.enable_notifications {
+            false => None,
+            true => {
+                let event_name = event_concept_name(&port_id.0);
+                let listener = fail!(from origin,
+                    when <Service::Event as Event<EventId>>::ListenerBuilder::new(&event_name).create(),
+                    with SubscriberCreateError::UnableToCreateNotificationHandle,
+                    "{} since the underlying event concept \"{}\" could not be created.", msg, event_name);
+                Some(NotificationHandle {
+                    listener,
+                    _phantom: PhantomData,
+                })
+            }
+        };
+
+        // a service that was opened without its payload type provides the payload as bytes,
+        // see publish_subscribe::Builder::open_untyped()
+        let elements_per_payload_element =
+            match static_config.type_fingerprint == MessageType::type_fingerprint() {
+                true => 1,
+                false => static_config.type_fingerprint.size(),
+            };
+        let max_slice_len = static_config.max_slice_len * elements_per_payload_element;
+        let message_layout = MessageLayout::new::<Header, MessageType>(
+            max_slice_len,
+            static_config.payload_alignment,
+        );
+
+        // created before the subscriber is added to the dynamic config so that every publisher
+        // can copy its samples into it
+        let data_segment = match Service::PAYLOAD_TRANSFER {
+            PayloadTransfer::ZeroCopy => None,
+            PayloadTransfer::Copy => Some(fail!(from origin,
+                when Self::create_data_segment(
+                    port_id,
+                    service.state().global_config,
+                    service
+                        .state()
+                        .static_config
+                        .messaging_pattern
+                        .required_amount_of_samples_per_receive_segment(),
+                    message_layout),
+                with SubscriberCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be created.", msg)),
+        };
+
         let publisher_list = &service
             .state()
             .dynamic_storage
@@ -100,16 +386,31 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
             .publishers;
 
         let mut new_self = Self {
+            _monitoring_token,
             publisher_connections: PublisherConnections::new(
                 publisher_list.capacity(),
                 port_id,
                 service.state().global_config,
+                service.state().static_config.uuid(),
                 static_config,
             ),
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_config_guard: None,
             service,
             degration_callback: None,
+            sample_loss_callback: None,
+            message_layout,
+            max_slice_len,
+            elements_per_payload_element,
+            notification_handle,
+            data_segment,
+            config: *config,
+            deadline: static_config.deadline,
+            liveliness_lease_duration: static_config.liveliness_lease_duration,
+            lifespan: static_config.lifespan,
+            number_of_expired_samples: Cell::new(0),
+            next_connection: Cell::new(0),
+            reported_degrations: vec![Cell::default(); publisher_list.capacity()],
             _phantom_message_type: PhantomData,
         };
 
@@ -127,7 +428,9 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 .publish_subscribe()
                 .add_subscriber_id(port_id)
             {
-                Some(unique_index) => unique_index,
+                Some(unique_index) => {
+                    DynamicConfigGuard::new(unique_index, service.state().global_config)
+                }
                 None => {
                     fail!(from origin, with SubscriberCreateError::ExceedsMaxSupportedSubscribers,
                                 "{} since it would exceed the maximum supported amount of subscribers of {}.",
@@ -139,10 +442,45 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         Ok(new_self)
     }
 
-    pub(crate) fn release_sample(&self, channel_id: usize, message: *const MessageType) {
+    fn create_data_segment(
+        port_id: UniqueSubscriberId,
+        global_config: &'config config::Config,
+        number_of_samples: usize,
+        message_layout: MessageLayout,
+    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
+        let allocator_config = shm_allocator::pool_allocator::Config {
+            bucket_layout: message_layout.layout,
+        };
+        let chunk_size = allocator_config.bucket_layout.size();
+        let chunk_align = allocator_config.bucket_layout.align();
+
+        Ok(fail!(from "Subscriber::create_data_segment()",
+            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(&data_segment_name(port_id))
+                .config(&data_segment_config::<Service>(global_config))
+                .size(chunk_size * number_of_samples + chunk_align - 1)
+                .create(&allocator_config),
+            "Unable to create the data segment."))
+    }
+
+    pub(crate) fn release_sample(&self, channel_id: usize, chunk: *const u8) {
         match self.publisher_connections.get(channel_id) {
             Some(c) => {
-                let distance = message as usize - c.data_segment.allocator_data_start_address();
+                let distance = chunk as usize - self.data_segment_start_address(c);
+
+                // the publisher is gone and will never reclaim the copy, returning it only keeps
+                // the number of borrowed samples of the connection consistent
+                if c.data_segment.is_none() && !c.receiver.is_connected() {
+                    c.receiver.release(PointerOffset::new(distance)).ok();
+                    unsafe {
+                        c.receiver.acquire_released_offsets(|relative_addr| {
+                            self.release_copy(relative_addr)
+                        })
+                    };
+                    return;
+                }
+
                 match c.receiver.release(PointerOffset::new(distance)) {
                     Ok(()) => (),
                     Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
@@ -151,12 +489,67 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                     }
                 }
             }
-            None => {
-                warn!(from self, "Unable to release sample since the connection is broken. The sample will be discarded and has to be reclaimed manually by the publisher.");
+            None => match &self.data_segment {
+                Some(data_segment) => self.release_copy(PointerOffset::new(
+                    chunk as usize - data_segment.allocator_data_start_address(),
+                )),
+                None => {
+                    warn!(from self, "Unable to release sample since the connection is broken. The sample will be discarded and has to be reclaimed manually by the publisher.");
+                }
+            },
+        }
+    }
+
+    // when the payload is copied the subscriber owns the memory of the sample and releases the
+    // samples the publisher cannot reclaim anymore
+    fn release_copy(&self, relative_addr: PointerOffset) {
+        if let Some(data_segment) = &self.data_segment {
+            unsafe {
+                fatal_panic!(from self, when data_segment.deallocate(relative_addr, self.message_layout.layout),
+                    "This should never happen! The copied sample is not part of the data segment of the subscriber.");
             }
         }
     }
 
+    // the samples are located in the data segment of the publisher or, when the payload is
+    // copied, in the data segment of the subscriber
+    fn data_segment_start_address(&self, connection: &Connection<'config, Service>) -> usize {
+        match (&connection.data_segment, &self.data_segment) {
+            (Some(data_segment), _) | (None, Some(data_segment)) => {
+                data_segment.allocator_data_start_address()
+            }
+            (None, None) => {
+                fatal_panic!(from self, "This should never happen! The connection {:?} has no data segment.", connection)
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let (Some(connection), Some(_)) =
+            (self.publisher_connections.get_mut(i), &self.data_segment)
+        {
+            // the publisher is gone and will never reclaim the copies that were already released
+            unsafe {
+                connection
+                    .receiver
+                    .acquire_released_offsets(|relative_addr| self.release_copy(relative_addr))
+            };
+
+            // nor the copies that were not received yet
+            if let Some(relative_addr) = connection.pending_sample.take() {
+                self.release_copy(relative_addr);
+                connection.receiver.release(relative_addr).ok();
+            }
+
+            while let Ok(Some(relative_addr)) = connection.receiver.receive() {
+                self.release_copy(relative_addr);
+                connection.receiver.release(relative_addr).ok();
+            }
+        }
+
+        self.publisher_connections.remove(i);
+    }
+
     fn populate_publisher_channels(&self) -> Result<(), ConnectionFailure> {
         let mut visited_indices = vec![];
         visited_indices.resize(self.publisher_connections.capacity(), None);
@@ -170,30 +563,40 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         // update all connections
         for (i, index) in visited_indices.iter().enumerate() {
             match index {
-                Some(publisher_id) => match self.publisher_connections.create(i, *publisher_id) {
-                    Ok(()) => (),
-                    Err(e) => match &self.degration_callback {
-                        None => {
-                            warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
+                Some(publisher_id) => {
+                    // the slot was reused by a new publisher since the last update
+                    if let Some(connection) = self.publisher_connections.get(i) {
+                        if connection.publisher_id != *publisher_id {
+                            self.remove_connection(i);
                         }
-                        Some(c) => {
-                            match c.call(
-                                self.service.state().static_config.clone(),
-                                *publisher_id,
-                                self.publisher_connections.subscriber_id(),
-                            ) {
-                                DegrationAction::Ignore => (),
-                                DegrationAction::Warn => {
-                                    warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
-                                }
-                                DegrationAction::Fail => {
-                                    fail!(from self, with e, "Unable to establish connection to new publisher {:?}.", publisher_id);
+                    }
+
+                    match self.publisher_connections.create(i, *publisher_id) {
+                        Ok(()) => (),
+                        Err(e) => match &self.degration_callback {
+                            None => {
+                                warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
+                            }
+                            Some(c) => {
+                                match c.call(
+                                    self.service.state().static_config.clone(),
+                                    *publisher_id,
+                                    self.publisher_connections.subscriber_id(),
+                                    DegrationCause::FailedToEstablishConnection,
+                                ) {
+                                    DegrationAction::Ignore => (),
+                                    DegrationAction::Warn => {
+                                        warn!(from self, "Unable to establish connection to new publisher {:?}.", publisher_id)
+                                    }
+                                    DegrationAction::Fail => {
+                                        fail!(from self, with e, "Unable to establish connection to new publisher {:?}.", publisher_id);
+                                    }
                                 }
                             }
-                        }
-                    },
-                },
-                None => self.publisher_connections.remove(i),
+                        },
+                    }
+                }
+                None => self.remove_connection(i),
             }
         }
 
@@ -206,40 +609,172 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         connection: &mut Connection<'config, Service>,
     ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
     {
+        Ok(self
+            .receive_offset(connection)?
+            .map(|relative_addr| self.create_sample(channel_id, connection, relative_addr)))
+    }
+
+    // receives the offset of the next sample that did not exceed its lifespan
+    fn receive_offset(
+        &self,
+        connection: &mut Connection<'config, Service>,
+    ) -> Result<Option<PointerOffset>, ReceiveError> {
         let msg = "Unable to receive another sample";
-        match connection.receiver.receive() {
-            Ok(data) => match data {
-                None => Ok(None),
-                Some(relative_addr) => {
-                    let absolute_address = relative_addr.value()
-                        + connection.data_segment.allocator_data_start_address();
-                    Ok(Some(Sample {
-                        subscriber: self,
-                        channel_id,
-                        ptr: unsafe {
-                            NonNull::new_unchecked(
-                                absolute_address as *mut Message<Header, MessageType>,
-                            )
-                        },
-                    }))
+        loop {
+            match connection.receiver.receive() {
+                Ok(data) => match data {
+                    None => return Ok(None),
+                    Some(relative_addr) => {
+                        let header_ptr = self.header_ptr(connection, relative_addr);
+                        self.detect_lost_samples(connection, unsafe {
+                            (*header_ptr).sequence_number()
+                        });
+
+                        if self.is_expired(unsafe { &*header_ptr }) {
+                            self.release_expired_sample(connection, relative_addr);
+                            continue;
+                        }
+
+                        return Ok(Some(relative_addr));
+                    }
+                },
+                Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                    fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
+                        "{} since it would exceed the maximum {} of borrowed samples.",
+                        msg, connection.receiver.max_borrowed_samples());
                 }
-            },
-            Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
-                fail!(from self, with ReceiveError::ExceedsMaxBorrowedSamples,
-                    "{} since it would exceed the maximum {} of borrowed samples.",
-                    msg, connection.receiver.max_borrowed_samples());
             }
         }
     }
 
+    fn header_ptr(
+        &self,
+        connection: &Connection<'config, Service>,
+        relative_addr: PointerOffset,
+    ) -> *mut Header {
+        (relative_addr.value() + self.data_segment_start_address(connection)) as *mut Header
+    }
+
+    fn create_sample<'subscriber>(
+        &'subscriber self,
+        channel_id: usize,
+        connection: &Connection<'config, Service>,
+        relative_addr: PointerOffset,
+    ) -> Sample<'a, 'subscriber, 'config, Service, Header, MessageType> {
+        let header_ptr = self.header_ptr(connection, relative_addr);
+        // never trust the header to stay within the bounds of the sample
+        let number_of_elements = std::cmp::min(
+            (unsafe { (*header_ptr).number_of_elements() } as usize)
+                .saturating_mul(self.elements_per_payload_element),
+            self.max_slice_len,
+        );
+        let payload_ptr = unsafe {
+            MessageType::from_raw_parts(
+                (header_ptr as usize + self.message_layout.payload_offset) as *mut u8,
+                number_of_elements,
+            )
+        };
+
+        Sample {
+            subscriber: self,
+            channel_id,
+            header_ptr: unsafe { NonNull::new_unchecked(header_ptr) },
+            payload_ptr: unsafe { NonNull::new_unchecked(payload_ptr) },
+        }
+    }
+
+    fn detect_lost_samples(
+        &self,
+        connection: &mut Connection<'config, Service>,
+        sequence_number: u64,
+    ) {
+        let last_sequence_number = match connection.last_sequence_number {
+            // the first sample of a publisher cannot reveal a loss
+            None => sequence_number,
+            Some(last_sequence_number) => {
+                let number_of_lost_samples = sequence_number
+                    .saturating_sub(last_sequence_number)
+                    .saturating_sub(1);
+                if number_of_lost_samples != 0 {
+                    connection.number_of_lost_samples += number_of_lost_samples;
+                    if let Some(callback) = &self.sample_loss_callback {
+                        callback.call(connection.publisher_id, number_of_lost_samples);
+                    }
+                }
+                last_sequence_number.max(sequence_number)
+            }
+        };
+
+        connection.last_sequence_number = Some(last_sequence_number);
+    }
+
+    /// Returns for every connected [`crate::port::publisher::Publisher`] how many of its
+    /// [`Sample`]s were lost, for instance since they were overwritten in a safely overflowing
+    /// service. The loss is detected by a gap in the sequence numbers of the received samples.
+    pub fn lost_samples(&self) -> Vec<(UniquePublisherId, u64)> {
+        let mut lost_samples = vec![];
+        for id in 0..self.publisher_connections.len() {
+            if let Some(connection) = self.publisher_connections.get(id) {
+                lost_samples.push((connection.publisher_id, connection.number_of_lost_samples));
+            }
+        }
+        lost_samples
+    }
+
+    /// Sets the [`SampleLossCallback`] of the [`Subscriber`]. It is called whenever a gap in
+    /// the sequence numbers of a [`crate::port::publisher::Publisher`] is detected.
+    pub fn set_sample_loss_callback<F: Fn(UniquePublisherId, u64) + 'a>(
+        &mut self,
+        callback: Option<F>,
+    ) {
+        match callback {
+            Some(c) => self.sample_loss_callback = Some(SampleLossCallback::new(c)),
+            None => self.sample_loss_callback = None,
+        }
+    }
+
+    fn is_expired(&self, header: &Header) -> bool {
+        match self.lifespan {
+            None => false,
+            Some(lifespan) => header.age() > lifespan,
+        }
+    }
+
+    fn release_expired_sample(
+        &self,
+        connection: &Connection<'config, Service>,
+        relative_addr: PointerOffset,
+    ) {
+        self.number_of_expired_samples
+            .set(self.number_of_expired_samples.get() + 1);
+
+        match connection.receiver.release(relative_addr) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, when connection.receiver.release(relative_addr),
+                            "This should never happen! The publishers retrieve channel is full and the expired sample cannot be returned.");
+            }
+        }
+    }
+
+    /// Returns how many [`Sample`]s were discarded since they exceeded the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::lifespan()`] before
+    /// they were received.
+    pub fn number_of_expired_samples(&self) -> u64 {
+        self.number_of_expired_samples.get()
+    }
+
     /// Sets the [`DegrationCallback`] of the [`Subscriber`]. Whenever a connection to a
     /// [`crate::port::publisher::Publisher`] is corrupted or a seems to be dead, this callback
     /// is called and depending on the returned [`DegrationAction`] measures will be taken.
+    /// It is also called once whenever a [`crate::port::publisher::Publisher`] misses its
+    /// deadline or loses its liveliness, see [`DegrationCause`].
     pub fn set_degration_callback<
         F: Fn(
                 service::static_config::StaticConfig,
                 UniquePublisherId,
                 UniqueSubscriberId,
+                DegrationCause,
             ) -> DegrationAction
             + 'a,
     >(
@@ -252,11 +787,120 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         }
     }
 
+    /// Returns the [`NotificationHandle`] which is signaled whenever a
+    /// [`crate::port::publisher::Publisher`] delivers a [`Sample`]. If the service has no
+    /// notifications enabled it returns [`None`].
+    pub fn notification_handle(&self) -> Option<&NotificationHandle<'config, Service>> {
+        self.notification_handle.as_ref()
+    }
+
     /// Receives a [`Sample`] from [`crate::port::publisher::Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`ReceiveError`] is returned.
+    /// When no [`Sample`] is left all pending notifications of the [`NotificationHandle`] are
+    /// consumed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Subscriber::receive", level = "trace", skip_all)
+    )]
     pub fn receive<'subscriber>(
         &'subscriber self,
     ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        if let Some(sample) = self.receive_impl()? {
+            return Ok(Some(sample));
+        }
+
+        match &self.notification_handle {
+            // a sample that arrived after the last receive attempt may have had its
+            // notification consumed, so it has to be received now
+            Some(handle) if handle.consume_notifications() => self.receive_impl(),
+            _ => Ok(None),
+        }
+    }
+
+    /// Receives a [`Sample`] from [`crate::port::publisher::Publisher`]. If no sample is
+    /// available it sleeps until either a [`Sample`] was received or the timeout has passed, then
+    /// [`None`] is returned. When a termination was requested, see
+    /// [`SignalHandler::termination_requested()`], it returns
+    /// [`ReceiveError::TerminationRequested`]. Requires a service with notifications enabled,
+    /// otherwise [`ReceiveError::NotificationsNotEnabled`] is returned.
+    pub fn timed_receive<'subscriber>(
+        &'subscriber self,
+        timeout: Duration,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        self.wait_and_receive(Some(timeout))
+    }
+
+    /// Receives a [`Sample`] from [`crate::port::publisher::Publisher`]. If no sample is
+    /// available it sleeps until a [`Sample`] was received. When a termination was requested,
+    /// see [`SignalHandler::termination_requested()`], it returns
+    /// [`ReceiveError::TerminationRequested`]. Requires a service with notifications enabled,
+    /// otherwise [`ReceiveError::NotificationsNotEnabled`] is returned.
+    pub fn blocking_receive<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>, ReceiveError> {
+        match self.wait_and_receive(None)? {
+            Some(sample) => Ok(sample),
+            None => fatal_panic!(from self,
+                "This should never happen! The blocking receive returned without a sample."),
+        }
+    }
+
+    fn wait_and_receive<'subscriber>(
+        &'subscriber self,
+        timeout: Option<Duration>,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        let handle = match &self.notification_handle {
+            Some(handle) => handle,
+            None => {
+                fail!(from self, with ReceiveError::NotificationsNotEnabled,
+                    "Unable to wait for samples since the service has no notifications enabled.");
+            }
+        };
+
+        let start = Instant::now();
+
+        loop {
+            if let Some(sample) = self.receive()? {
+                return Ok(Some(sample));
+            }
+
+            if SignalHandler::termination_requested() {
+                fail!(from self, with ReceiveError::TerminationRequested,
+                    "Stop waiting for samples since a termination was requested.");
+            }
+
+            // the wait is split into slices of the wakeup interval so that a termination
+            // request is handled even when no publisher delivers anything
+            let wait_time = match timeout {
+                None => self.config.wakeup_interval,
+                Some(timeout) => {
+                    let elapsed = start.elapsed();
+                    if elapsed >= timeout {
+                        return Ok(None);
+                    }
+                    (timeout - elapsed).min(self.config.wakeup_interval)
+                }
+            };
+
+            if let Err(e) = handle.listener.timed_wait(wait_time) {
+                // a termination request may interrupt the wait
+                if SignalHandler::termination_requested() {
+                    fail!(from self, with ReceiveError::TerminationRequested,
+                        "Stop waiting for samples since a termination was requested.");
+                }
+
+                fail!(from self, with ReceiveError::UnableToWaitForNotifications,
+                    "Unable to wait for samples since the wait for notifications failed ({:?}).", e);
+            }
+        }
+    }
+
+    fn receive_impl<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
     {
         if let Err(e) = self.update_connections() {
             fail!(from self,
@@ -264,20 +908,214 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
                 "Some samples are not being received since not all connections to publishers could be established.");
         }
 
-        for id in 0..self.publisher_connections.len() {
-            match &mut self.publisher_connections.get_mut(id) {
-                Some(ref mut connection) => {
-                    if let Some(sample) = self.receive_from_connection(id, connection)? {
-                        return Ok(Some(sample));
-                    }
+        self.verify_publisher_qos()?;
+
+        match self.config.receive_policy {
+            ReceivePolicy::ConnectionOrder => self.receive_in_connection_order(0),
+            ReceivePolicy::RoundRobin => {
+                self.receive_in_connection_order(self.next_connection.get())
+            }
+            ReceivePolicy::OldestFirst => self.receive_oldest_sample(),
+        }
+    }
+
+    fn receive_in_connection_order<'subscriber>(
+        &'subscriber self,
+        start: usize,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        let number_of_connections = self.publisher_connections.len();
+        for id in (start..number_of_connections).chain(0..start) {
+            if let Some(ref mut connection) = self.publisher_connections.get_mut(id) {
+                if let Some(sample) = self.receive_from_connection(id, connection)? {
+                    self.next_connection.set((id + 1) % number_of_connections);
+                    return Ok(Some(sample));
                 }
-                None => (),
             }
         }
 
         Ok(None)
     }
 
+    fn receive_oldest_sample<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Option<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>>, ReceiveError>
+    {
+        loop {
+            let mut oldest: Option<(usize, Duration)> = None;
+            for id in 0..self.publisher_connections.len() {
+                if let Some(ref mut connection) = self.publisher_connections.get_mut(id) {
+                    if connection.pending_sample.is_none() {
+                        connection.pending_sample = self.receive_offset(connection)?;
+                    }
+
+                    if let Some(relative_addr) = connection.pending_sample {
+                        let time_stamp = unsafe {
+                            (*self.header_ptr(connection, relative_addr))
+                                .time_stamp()
+                                .as_duration()
+                        };
+                        if oldest.map_or(true, |(_, oldest)| time_stamp < oldest) {
+                            oldest = Some((id, time_stamp));
+                        }
+                    }
+                }
+            }
+
+            let id = match oldest {
+                None => return Ok(None),
+                Some((id, _)) => id,
+            };
+
+            if let Some(ref mut connection) = self.publisher_connections.get_mut(id) {
+                if let Some(relative_addr) = connection.pending_sample.take() {
+                    // the sample may have expired while it was held back
+                    if self.is_expired(unsafe { &*self.header_ptr(connection, relative_addr) }) {
+                        self.release_expired_sample(connection, relative_addr);
+                        continue;
+                    }
+
+                    return Ok(Some(self.create_sample(id, connection, relative_addr)));
+                }
+            }
+        }
+    }
+
+    /// Returns the ids of all [`crate::port::publisher::Publisher`]s that did not send a
+    /// [`Sample`] within the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::deadline()`]. If the
+    /// service has no deadline it is always empty.
+    pub fn publishers_with_missed_deadline(&self) -> Vec<UniquePublisherId> {
+        match self.deadline {
+            None => vec![],
+            Some(deadline) => self.publishers_where(|dynamic_config, index| {
+                dynamic_config.time_since_last_send(index) > deadline
+            }),
+        }
+    }
+
+    /// Returns the ids of all [`crate::port::publisher::Publisher`]s that did not assert their
+    /// liveliness within the
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::liveliness_lease_duration()`].
+    /// If the service has no liveliness lease duration it is always empty.
+    pub fn publishers_with_lost_liveliness(&self) -> Vec<UniquePublisherId> {
+        match self.liveliness_lease_duration {
+            None => vec![],
+            Some(lease_duration) => self.publishers_where(|dynamic_config, index| {
+                dynamic_config.time_since_last_liveliness_assertion(index) > lease_duration
+            }),
+        }
+    }
+
+    fn publishers_where<F: Fn(&DynamicConfig, u32) -> bool>(
+        &self,
+        predicate: F,
+    ) -> Vec<UniquePublisherId> {
+        let dynamic_config = self
+            .service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+        let mut publishers = vec![];
+        unsafe {
+            dynamic_config.publishers.get_state().for_each(|index, id| {
+                if predicate(dynamic_config, index) {
+                    publishers.push(*id)
+                }
+            })
+        };
+        publishers
+    }
+
+    fn verify_publisher_qos(&self) -> Result<(), ReceiveError> {
+        if self.deadline.is_none() && self.liveliness_lease_duration.is_none() {
+            return Ok(());
+        }
+
+        let dynamic_config = self
+            .service
+            .state()
+            .dynamic_storage
+            .get()
+            .publish_subscribe();
+        let mut result = Ok(());
+        unsafe {
+            (*self.publisher_list_state.get()).for_each(|index, publisher_id| {
+                let reported = &self.reported_degrations[index as usize];
+                let mut state = reported.get();
+                if state.publisher_id != Some(*publisher_id) {
+                    state = ReportedDegrations {
+                        publisher_id: Some(*publisher_id),
+                        ..Default::default()
+                    };
+                }
+
+                let deadline_missed = self
+                    .deadline
+                    .is_some_and(|deadline| dynamic_config.time_since_last_send(index) > deadline);
+                if deadline_missed && !state.deadline_missed {
+                    let r = self.report_degration(
+                        *publisher_id,
+                        DegrationCause::DeadlineMissed,
+                        ReceiveError::PublisherMissedDeadline,
+                    );
+                    result = result.and(r);
+                }
+
+                let liveliness_lost =
+                    self.liveliness_lease_duration
+                        .is_some_and(|lease_duration| {
+                            dynamic_config.time_since_last_liveliness_assertion(index)
+                                > lease_duration
+                        });
+                if liveliness_lost && !state.liveliness_lost {
+                    let r = self.report_degration(
+                        *publisher_id,
+                        DegrationCause::LivelinessLost,
+                        ReceiveError::PublisherLostLiveliness,
+                    );
+                    result = result.and(r);
+                }
+
+                state.deadline_missed = deadline_missed;
+                state.liveliness_lost = liveliness_lost;
+                reported.set(state);
+            })
+        };
+
+        result
+    }
+
+    fn report_degration(
+        &self,
+        publisher_id: UniquePublisherId,
+        cause: DegrationCause,
+        error: ReceiveError,
+    ) -> Result<(), ReceiveError> {
+        let action = match &self.degration_callback {
+            None => DegrationAction::Warn,
+            Some(c) => c.call(
+                self.service.state().static_config.clone(),
+                publisher_id,
+                self.publisher_connections.subscriber_id(),
+                cause,
+            ),
+        };
+
+        match action {
+            DegrationAction::Ignore => (),
+            DegrationAction::Warn => {
+                warn!(from self, "The publisher {:?} is degraded ({:?}).", publisher_id, cause)
+            }
+            DegrationAction::Fail => {
+                fail!(from self, with error, "The publisher {:?} is degraded ({:?}).", publisher_id, cause);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Explicitly updates all connections to the [`crate::port::publisher::Publisher`]s. This is
     /// required to be called whenever a new [`crate::port::publisher::Publisher`] connected to
     /// the service. It is done implicitly whenever [`Subscriber::receive()`]
@@ -291,3 +1129,43 @@ impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
         Ok(())
     }
 }
+
+#[cfg(feature = "async")]
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
+    Subscriber<'a, 'config, Service, MessageType>
+where
+    <Service::Event as Event<EventId>>::Listener: FileDescriptorBased,
+{
+    /// Receives a [`Sample`] from [`crate::port::publisher::Publisher`]. The returned future is
+    /// ready as soon as a [`Sample`] was received. It does not block a thread of the runtime and
+    /// can be used with any executor. Requires a service with notifications enabled, otherwise
+    /// [`ReceiveError::NotificationsNotEnabled`] is returned.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Subscriber::receive_async", level = "trace", skip_all)
+    )]
+    pub async fn receive_async<'subscriber>(
+        &'subscriber self,
+    ) -> Result<Sample<'a, 'subscriber, 'config, Service, Header, MessageType>, ReceiveError> {
+        let handle = match &self.notification_handle {
+            Some(handle) => handle,
+            None => {
+                fail!(from self, with ReceiveError::NotificationsNotEnabled,
+                    "Unable to wait asynchronously for samples since the service has no notifications enabled.");
+            }
+        };
+
+        let mut readiness = Readiness::new(handle.file_descriptor());
+
+        loop {
+            if let Some(sample) = self.receive()? {
+                return Ok(sample);
+            }
+
+            if let Err(e) = poll_fn(|cx| readiness.poll_readable(cx)).await {
+                fail!(from self, with ReceiveError::UnableToWaitForNotifications,
+                    "Unable to wait asynchronously for samples since the readiness of the notifications could not be awaited ({:?}).", e);
+            }
+        }
+    }
+}
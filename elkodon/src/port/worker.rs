@@ -0,0 +1,206 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let worker = service.worker().create()?;
+//!
+//! // take all work items from the work queue, every work item is processed by exactly one
+//! // worker
+//! while let Some(work_item) = worker.receive()? {
+//!     println!("processing: {:?}", *work_item);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use elkodon_bb_log::fail;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
+
+use super::port_identifiers::UniqueWorkerId;
+use crate::message::Message;
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+use crate::service;
+use crate::service::dynamic_config::pipeline::DynamicConfig;
+use crate::service::header::pipeline::Header;
+use crate::work_item::WorkItem;
+
+/// Defines the failures that can occur when a [`Worker`] is created with the
+/// [`crate::service::port_factory::worker::PortFactoryWorker`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WorkerCreateError {
+    ExceedsMaxSupportedWorkers,
+    UnableToCreateMonitoringToken,
+}
+
+impl std::fmt::Display for WorkerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WorkerCreateError {}
+
+/// Defines the failure that can occur when receiving a [`WorkItem`] with [`Worker::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WorkItemReceiveError {
+    ExceedsMaxBorrowedSamples,
+}
+
+impl std::fmt::Display for WorkItemReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WorkItemReceiveError {}
+
+/// The processing endpoint of a pipeline based communication. It takes the samples from the
+/// shared work queue. Samples that are still held by the [`Worker`] when it goes out of scope
+/// or when its process crashes are put back into the work queue.
+#[derive(Debug)]
+pub struct Worker<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug> {
+    _dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
+    service: &'a Service,
+    port_id: UniqueWorkerId,
+    worker_index: usize,
+    max_borrowed_samples: usize,
+    borrow_counter: AtomicUsize,
+    _phantom_config: PhantomData<&'config ()>,
+    _phantom_message_type: PhantomData<MessageType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug> Drop
+    for Worker<'_, 'config, Service, MessageType>
+{
+    fn drop(&mut self) {
+        if self._dynamic_config_guard.is_some() {
+            self.dynamic_config().remove_worker(self.worker_index);
+        }
+    }
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, MessageType: Debug>
+    Worker<'a, 'config, Service, MessageType>
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Worker::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
+    pub(crate) fn new(service: &'a Service) -> Result<Self, WorkerCreateError> {
+        let msg = "Failed to create worker";
+        let origin = "Worker::new()";
+        let port_id = UniqueWorkerId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with WorkerCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+        let static_config = service.state().static_config.pipeline();
+        let dynamic_config = service.state().dynamic_storage.get().pipeline();
+
+        let mut new_self = Self {
+            _monitoring_token,
+            _dynamic_config_guard: None,
+            service,
+            port_id,
+            worker_index: 0,
+            max_borrowed_samples: static_config.worker_max_borrowed_samples,
+            borrow_counter: AtomicUsize::new(0),
+            _phantom_config: PhantomData,
+            _phantom_message_type: PhantomData,
+        };
+
+        // !MUST! be the last task otherwise a worker is added to the dynamic config without
+        // being fully initialized
+        let unique_index = match dynamic_config.add_worker_id(port_id) {
+            Some(unique_index) => unique_index,
+            None => {
+                fail!(from origin, with WorkerCreateError::ExceedsMaxSupportedWorkers,
+                    "{} since it would exceed the maximum supported amount of workers of {}.",
+                    msg, static_config.max_workers);
+            }
+        };
+        new_self.worker_index = unique_index.value() as usize;
+        new_self._dynamic_config_guard = Some(DynamicConfigGuard::new(
+            unique_index,
+            service.state().global_config,
+        ));
+
+        Ok(new_self)
+    }
+
+    fn dynamic_config(&self) -> &DynamicConfig {
+        self.service.state().dynamic_storage.get().pipeline()
+    }
+
+    pub(crate) fn release_sample(&self, sample_index: usize) {
+        self.dynamic_config().release_borrowed_sample(sample_index);
+        self.borrow_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Returns the [`UniqueWorkerId`] of the [`Worker`]
+    pub fn id(&self) -> UniqueWorkerId {
+        self.port_id
+    }
+
+    /// Takes the oldest [`WorkItem`] from the work queue. If the work queue is empty it checks
+    /// if any [`Worker`] has crashed and puts its samples back into the work queue before
+    /// it returns [`None`]. On failure it returns [`WorkItemReceiveError`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "Worker::receive", level = "trace", skip_all)
+    )]
+    pub fn receive<'worker>(
+        &'worker self,
+    ) -> Result<Option<WorkItem<'a, 'worker, 'config, Service, MessageType>>, WorkItemReceiveError>
+    {
+        if self.borrow_counter.load(Ordering::Relaxed) >= self.max_borrowed_samples {
+            fail!(from self, with WorkItemReceiveError::ExceedsMaxBorrowedSamples,
+                "Unable to receive another work item since already {} work items were borrowed and this exceeds the max number of borrowed work items of {}. Release a work item to receive another one.",
+                self.borrow_counter.load(Ordering::Relaxed), self.max_borrowed_samples);
+        }
+
+        let dynamic_config = self.dynamic_config();
+        let sample_index = match dynamic_config.dequeue_sample(self.worker_index) {
+            Some(index) => index,
+            None => {
+                remove_dead_ports(self.service);
+                match dynamic_config.dequeue_sample(self.worker_index) {
+                    Some(index) => index,
+                    None => return Ok(None),
+                }
+            }
+        };
+
+        self.borrow_counter.fetch_add(1, Ordering::Relaxed);
+        let ptr = unsafe {
+            NonNull::new_unchecked(
+                dynamic_config.sample(sample_index) as *mut Message<Header, MessageType>
+            )
+        };
+
+        Ok(Some(WorkItem::new(self, ptr, sample_index)))
+    }
+}
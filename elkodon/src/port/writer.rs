@@ -0,0 +1,246 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer().create()?;
+//! let entry = writer.entry::<i32>(&0)?;
+//!
+//! entry.update(1234);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+
+use elkodon_bb_lock_free::spmc::unrestricted_atomic::{Producer, UnrestrictedAtomic};
+use elkodon_bb_log::{fail, fatal_panic};
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::monitoring::Monitoring;
+
+use crate::port::details::blackboard_entries::{lookup, EntryLookupFailure};
+use crate::port::details::dead_ports::*;
+use crate::port::details::dynamic_config_guard::DynamicConfigGuard;
+use crate::port::port_identifiers::UniqueWriterId;
+use crate::service;
+use crate::service::builder::blackboard::BlackboardKey;
+use crate::service::dynamic_config::blackboard::DynamicConfig;
+
+/// Defines the failures that can occur when a [`Writer`] is created with the
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterCreateError {
+    ExceedsMaxSupportedWriters,
+    UnableToCreateMonitoringToken,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Defines the failures that can occur when an [`EntryHandleMut`] is acquired with
+/// [`Writer::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EntryHandleMutError {
+    EntryDoesNotExist,
+    IncompatibleType,
+    HandleAlreadyExists,
+}
+
+impl std::fmt::Display for EntryHandleMutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for EntryHandleMutError {}
+
+/// Provides write access to a single entry of the blackboard. There can be only one
+/// [`EntryHandleMut`] per entry at a time, shared by all [`Writer`]s of the blackboard.
+pub struct EntryHandleMut<'a, ValueType: Copy + Debug> {
+    atomic: &'a UnrestrictedAtomic<ValueType>,
+    producer: ManuallyDrop<Producer<'a, ValueType>>,
+    dynamic_config: &'a DynamicConfig,
+    index: usize,
+}
+
+impl<'a, ValueType: Copy + Debug> Drop for EntryHandleMut<'a, ValueType> {
+    fn drop(&mut self) {
+        // the producer must be released before the entry can be acquired by another handle
+        unsafe { ManuallyDrop::drop(&mut self.producer) };
+        self.dynamic_config.release_entry(self.index);
+    }
+}
+
+impl<'a, ValueType: Copy + Debug> Debug for EntryHandleMut<'a, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandleMut<{}> {{ atomic: {:?} }}",
+            std::any::type_name::<ValueType>(),
+            self.atomic
+        )
+    }
+}
+
+impl<'a, ValueType: Copy + Debug> EntryHandleMut<'a, ValueType> {
+    /// Stores `value` in the entry. Every [`crate::port::reader::Reader`] reads it from
+    /// now on.
+    pub fn update(&self, value: ValueType) {
+        self.producer.store(value);
+    }
+
+    /// Returns a copy of the current value of the entry.
+    pub fn get(&self) -> ValueType {
+        self.atomic.load()
+    }
+}
+
+/// Represents the writing endpoint of a blackboard based communication.
+#[derive(Debug)]
+pub struct Writer<'a, 'config: 'a, Service: service::Details<'config>, KeyType: BlackboardKey> {
+    _dynamic_config_guard: Option<DynamicConfigGuard<'a>>,
+    service: &'a Service,
+    port_id: UniqueWriterId,
+    _phantom_config: PhantomData<&'config ()>,
+    _phantom_key: PhantomData<KeyType>,
+    // dropped after the dynamic config guard so that the port is never seen without a token
+    _monitoring_token: <Service::Monitoring as Monitoring>::Token,
+}
+
+impl<'a, 'config: 'a, Service: service::Details<'config>, KeyType: BlackboardKey>
+    Writer<'a, 'config, Service, KeyType>
+{
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "Writer::new",
+            level = "debug",
+            skip_all,
+            fields(service = %service.state().static_config.service_name())
+        )
+    )]
+    pub(crate) fn new(service: &'a Service) -> Result<Self, WriterCreateError> {
+        let msg = "Failed to create writer";
+        let origin = "Writer::new()";
+        let port_id = UniqueWriterId::new();
+
+        remove_dead_ports(service);
+        let _monitoring_token = fail!(from origin, when create_port_token(service, &port_id.0),
+                with WriterCreateError::UnableToCreateMonitoringToken,
+                "{} since the monitoring token could not be created.", msg);
+
+        let mut new_self = Self {
+            _monitoring_token,
+            _dynamic_config_guard: None,
+            service,
+            port_id,
+            _phantom_config: PhantomData,
+            _phantom_key: PhantomData,
+        };
+
+        // !MUST! be the last task otherwise a writer is added to the dynamic config without
+        // being fully initialized
+        new_self._dynamic_config_guard = Some(
+            match service
+                .state()
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .add_writer_id(port_id)
+            {
+                Some(unique_index) => {
+                    DynamicConfigGuard::new(unique_index, service.state().global_config)
+                }
+                None => {
+                    fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                        "{} since it would exceed the maximum supported amount of writers of {}.",
+                        msg, service.state().static_config.blackboard().max_writers);
+                }
+            },
+        );
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.port_id
+    }
+
+    /// Returns an [`EntryHandleMut`] to the entry that is identified by `key`. On failure it
+    /// returns [`EntryHandleMutError`] describing the failure.
+    pub fn entry<ValueType: Copy + Debug>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandleMut<'_, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to acquire mutable entry handle";
+        let dynamic_config = self.service.state().dynamic_storage.get().blackboard();
+        let (index, atomic) = match lookup::<KeyType, ValueType>(
+            self.service.state().static_config.blackboard(),
+            dynamic_config,
+            key,
+        ) {
+            Ok(entry) => entry,
+            Err(EntryLookupFailure::EntryDoesNotExist) => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} exists.", msg, key);
+            }
+            Err(EntryLookupFailure::IncompatibleType) => {
+                fail!(from self, with EntryHandleMutError::IncompatibleType,
+                    "{} since the entry with the key {:?} does not have the type \"{}\".",
+                    msg, key, std::any::type_name::<ValueType>());
+            }
+        };
+
+        if !dynamic_config.acquire_entry(index, self.dynamic_config_index()) {
+            fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                "{} since a handle for the entry with the key {:?} already exists.", msg, key);
+        }
+
+        let producer = match atomic.acquire_producer() {
+            Some(producer) => producer,
+            None => {
+                // the entry is owned by no one else, the producer was acquired by a writer that
+                // died without releasing it
+                unsafe { atomic.release_producer() };
+                match atomic.acquire_producer() {
+                    Some(producer) => producer,
+                    None => {
+                        fatal_panic!(from self,
+                            "This should never happen! Unable to acquire the producer of the entry with the key {:?} which has no owner.", key);
+                    }
+                }
+            }
+        };
+
+        Ok(EntryHandleMut {
+            atomic,
+            producer: ManuallyDrop::new(producer),
+            dynamic_config,
+            index,
+        })
+    }
+
+    fn dynamic_config_index(&self) -> u32 {
+        match &self._dynamic_config_guard {
+            Some(guard) => guard.value(),
+            None => {
+                fatal_panic!(from self, "This should never happen! The writer is not registered in the dynamic config.");
+            }
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/Calculator").unwrap();
+//! #
+//! # let service = zero_copy::Service::new(&service_name)
+//! #     .request_response()
+//! #     .open_or_create::<u64, u64>()?;
+//! #
+//! # let client = service.client().create()?;
+//!
+//! let mut request = client.loan()?;
+//!
+//! unsafe { request.as_mut_ptr().write(1234) };
+//! println!("request id: {:?}, client port id: {:?}",
+//!     request.header().request_id(), request.header().client_id());
+//! client.send(request)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    message::Message, port::client::Client, service,
+    service::header::request_response::RequestHeader,
+};
+use elkodon_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, ptr::NonNull, sync::atomic::Ordering};
+
+/// Acquired by a [`Client`] via [`Client::loan()`]. It stores the request that will be sent
+/// to all connected [`crate::port::server::Server`]s. If the [`RequestMut`] is not sent
+/// it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent requests in the [`Client`] and the
+/// [`Client`] is not thread-safe!
+#[derive(Debug)]
+pub struct RequestMut<
+    'a,
+    'client,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    client: &'client Client<'a, 'config, Service, RequestType, ResponseType>,
+    ptr: NonNull<MaybeUninit<Message<RequestHeader, RequestType>>>,
+    offset_to_chunk: PointerOffset,
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Drop
+    for RequestMut<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        self.client.release_request(self.offset_to_chunk);
+        self.client.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<
+        'a,
+        'client,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > RequestMut<'a, 'client, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        client: &'client Client<'a, 'config, Service, RequestType, ResponseType>,
+        ptr: NonNull<MaybeUninit<Message<RequestHeader, RequestType>>>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        client.loan_counter.fetch_add(1, Ordering::Relaxed);
+        Self {
+            client,
+            ptr,
+            offset_to_chunk,
+        }
+    }
+
+    pub(crate) fn offset_to_chunk(&self) -> PointerOffset {
+        self.offset_to_chunk
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request. It contains the request id
+    /// that is also part of every [`crate::response::Response`] that answers the request.
+    pub fn header(&self) -> &RequestHeader {
+        &unsafe { &*self.ptr.as_ref().as_ptr() }.header
+    }
+
+    /// Returns a pointer to the underlying memory.
+    pub fn as_ptr(&self) -> *const RequestType {
+        &unsafe { &*self.ptr.as_ref().as_ptr() }.data
+    }
+
+    /// Returns a mutable pointer to the underlying memory.
+    pub fn as_mut_ptr(&mut self) -> *mut RequestType {
+        &mut unsafe { &mut *self.ptr.as_mut().as_mut_ptr() }.data
+    }
+}
@@ -0,0 +1,91 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .request_response()
+//! #   .open_or_create::<u64, u64>()?;
+//! # let client = service.client().create()?;
+//!
+//! while let Some(response) = client.receive()? {
+//!     println!("received: {:?}", *response);
+//!     println!("answers request {:?}, server id {:?}",
+//!         response.header().request_id(), response.header().server_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref, ptr::NonNull};
+
+use crate::{message::Message, port::client::Client, service};
+
+/// It stores the response and is acquired by the [`Client`] whenever it receives new data from a
+/// [`crate::port::server::Server`] via [`Client::receive()`].
+#[derive(Debug)]
+pub struct Response<
+    'a,
+    'client,
+    'config,
+    Service: service::Details<'config>,
+    Header: Debug,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    pub(crate) client: &'client Client<'a, 'config, Service, RequestType, ResponseType>,
+    pub(crate) ptr: NonNull<Message<Header, ResponseType>>,
+    pub(crate) channel_id: usize,
+}
+
+impl<
+        'config,
+        Service: service::Details<'config>,
+        Header: Debug,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Deref for Response<'_, '_, 'config, Service, Header, RequestType, ResponseType>
+{
+    type Target = ResponseType;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &self.ptr.as_ref().data }
+    }
+}
+
+impl<
+        'config,
+        Service: service::Details<'config>,
+        Header: Debug,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Drop for Response<'_, '_, 'config, Service, Header, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        self.client
+            .release_response(self.channel_id, self.ptr.as_ptr() as *const u8);
+    }
+}
+
+impl<
+        'a,
+        'client,
+        'config,
+        Service: service::Details<'config>,
+        Header: Debug,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > Response<'a, 'client, 'config, Service, Header, RequestType, ResponseType>
+{
+    /// Returns a reference to the payload of the response
+    pub fn payload(&self) -> &ResponseType {
+        &unsafe { self.ptr.as_ref() }.data
+    }
+
+    /// Returns a reference to the header of the response. In request response communication the
+    /// default header is [`crate::service::header::request_response::ResponseHeader`].
+    pub fn header(&self) -> &Header {
+        &unsafe { self.ptr.as_ref() }.header
+    }
+}
@@ -0,0 +1,124 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/Calculator").unwrap();
+//! #
+//! # let service = zero_copy::Service::new(&service_name)
+//! #     .request_response()
+//! #     .open_or_create::<u64, u64>()?;
+//! #
+//! # let server = service.server().create()?;
+//!
+//! while let Some(request) = server.receive()? {
+//!     let mut response = request.loan()?;
+//!
+//!     unsafe { response.as_mut_ptr().write(*request * 2) };
+//!     println!("answers request: {:?}, server port id: {:?}",
+//!         response.header().request_id(), response.header().server_id());
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    message::Message,
+    port::{
+        port_identifiers::UniqueClientId,
+        server::{ResponseSendError, Server},
+    },
+    service,
+    service::header::request_response::ResponseHeader,
+};
+use elkodon_bb_log::fail;
+use elkodon_cal::shared_memory::*;
+use std::{fmt::Debug, mem::MaybeUninit, ptr::NonNull, sync::atomic::Ordering};
+
+/// Acquired by an [`crate::active_request::ActiveRequest`] via
+/// [`crate::active_request::ActiveRequest::loan()`]. It stores the response that will be
+/// delivered to the [`crate::port::client::Client`] that sent the request. If the [`ResponseMut`]
+/// is not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent responses in the [`Server`] and the
+/// [`Server`] is not thread-safe!
+#[derive(Debug)]
+pub struct ResponseMut<
+    'a,
+    'server,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    server: &'server Server<'a, 'config, Service, RequestType, ResponseType>,
+    ptr: NonNull<MaybeUninit<Message<ResponseHeader, ResponseType>>>,
+    offset_to_chunk: PointerOffset,
+    channel_id: usize,
+    client_id: UniqueClientId,
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug> Drop
+    for ResponseMut<'_, '_, 'config, Service, RequestType, ResponseType>
+{
+    fn drop(&mut self) {
+        self.server.release_response(self.offset_to_chunk);
+        self.server.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<
+        'a,
+        'server,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > ResponseMut<'a, 'server, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        server: &'server Server<'a, 'config, Service, RequestType, ResponseType>,
+        ptr: NonNull<MaybeUninit<Message<ResponseHeader, ResponseType>>>,
+        offset_to_chunk: PointerOffset,
+        channel_id: usize,
+        client_id: UniqueClientId,
+    ) -> Self {
+        server.loan_counter.fetch_add(1, Ordering::Relaxed);
+        Self {
+            server,
+            ptr,
+            offset_to_chunk,
+            channel_id,
+            client_id,
+        }
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        &unsafe { &*self.ptr.as_ref().as_ptr() }.header
+    }
+
+    /// Returns a pointer to the underlying memory.
+    pub fn as_ptr(&self) -> *const ResponseType {
+        &unsafe { &*self.ptr.as_ref().as_ptr() }.data
+    }
+
+    /// Returns a mutable pointer to the underlying memory.
+    pub fn as_mut_ptr(&mut self) -> *mut ResponseType {
+        &mut unsafe { &mut *self.ptr.as_mut().as_mut_ptr() }.data
+    }
+
+    /// Delivers the response to the [`crate::port::client::Client`] that sent the
+    /// corresponding request. On failure it returns a [`ResponseSendError`] describing the
+    /// failure.
+    pub fn send(self) -> Result<(), ResponseSendError> {
+        fail!(from self, when self.server.send_response(self.channel_id, self.client_id, self.offset_to_chunk.value()),
+            "Unable to send response since the underlying send failed.");
+
+        Ok(())
+    }
+}
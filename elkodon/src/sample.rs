@@ -21,7 +21,7 @@
 
 use std::{fmt::Debug, ops::Deref, ptr::NonNull};
 
-use crate::{message::Message, port::subscriber::Subscriber, service};
+use crate::{payload_type::PayloadType, port::subscriber::Subscriber, service};
 
 /// It stores the payload and is acquired by the [`Subscriber`] whenever it receives new data from a
 /// [`crate::port::publisher::Publisher`] via [`Subscriber::receive()`].
@@ -32,19 +32,24 @@ pub struct Sample<
     'config,
     Service: service::Details<'config>,
     Header: Debug,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
     pub(crate) subscriber: &'subscriber Subscriber<'a, 'config, Service, MessageType>,
-    pub(crate) ptr: NonNull<Message<Header, MessageType>>,
+    pub(crate) header_ptr: NonNull<Header>,
+    pub(crate) payload_ptr: NonNull<MessageType>,
     pub(crate) channel_id: usize,
 }
 
-impl<'config, Service: service::Details<'config>, Header: Debug, MessageType: Debug> Deref
-    for Sample<'_, '_, 'config, Service, Header, MessageType>
+impl<
+        'config,
+        Service: service::Details<'config>,
+        Header: Debug,
+        MessageType: PayloadType + ?Sized,
+    > Deref for Sample<'_, '_, 'config, Service, Header, MessageType>
 {
     type Target = MessageType;
     fn deref(&self) -> &Self::Target {
-        unsafe { &self.ptr.as_ref().data }
+        self.payload()
     }
 }
 
@@ -54,12 +59,12 @@ impl<
         'config,
         Service: service::Details<'config>,
         Header: Debug,
-        MessageType: Debug,
+        MessageType: PayloadType + ?Sized,
     > Drop for Sample<'a, 'subscriber, 'config, Service, Header, MessageType>
 {
     fn drop(&mut self) {
         self.subscriber
-            .release_sample(self.channel_id, self.payload());
+            .release_sample(self.channel_id, self.header_ptr.as_ptr() as *const u8);
     }
 }
 
@@ -69,17 +74,17 @@ impl<
         'config,
         Service: service::Details<'config>,
         Header: Debug,
-        MessageType: Debug,
+        MessageType: PayloadType + ?Sized,
     > Sample<'a, 'subscriber, 'config, Service, Header, MessageType>
 {
     /// Returns a reference to the payload of the sample
     pub fn payload(&self) -> &MessageType {
-        &unsafe { self.ptr.as_ref() }.data
+        unsafe { self.payload_ptr.as_ref() }
     }
 
     /// Returns a reference to the header of the sample. In publish subscribe communication the
     /// default header is [`crate::service::header::publish_subscribe::Header`].
     pub fn header(&self) -> &Header {
-        &unsafe { self.ptr.as_ref() }.header
+        unsafe { self.header_ptr.as_ref() }
     }
 }
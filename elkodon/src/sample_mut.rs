@@ -22,13 +22,13 @@
 //! # }
 //! ```
 
-use crate::{message::Message, port::publisher::Publisher, service};
+use crate::{payload_type::PayloadType, port::publisher::Publisher, service};
 use elkodon_cal::shared_memory::*;
-use std::{fmt::Debug, mem::MaybeUninit, ptr::NonNull, sync::atomic::Ordering};
+use std::{fmt::Debug, ptr::NonNull};
 
-/// Acquired by a [`Publisher`] via [`Publisher::loan()`]. It stores the payload that will be sent
-/// to all connected [`crate::port::subscriber::Subscriber`]s. If the [`SampleMut`] is not sent
-/// it will release the loaned memory when going out of scope.
+/// Acquired by a [`Publisher`] via [`Publisher::loan()`] or [`Publisher::loan_slice()`]. It stores
+/// the payload that will be sent to all connected [`crate::port::subscriber::Subscriber`]s. If the
+/// [`SampleMut`] is not sent it will release the loaned memory when going out of scope.
 ///
 /// # Notes
 ///
@@ -41,19 +41,24 @@ pub struct SampleMut<
     'config,
     Service: service::Details<'config>,
     Header: Debug,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
     publisher: &'publisher Publisher<'a, 'config, Service, MessageType>,
-    ptr: NonNull<MaybeUninit<Message<Header, MessageType>>>,
+    header_ptr: NonNull<Header>,
+    payload_ptr: NonNull<MessageType>,
     offset_to_chunk: PointerOffset,
 }
 
-impl<'config, Service: service::Details<'config>, Header: Debug, MessageType: Debug> Drop
-    for SampleMut<'_, '_, 'config, Service, Header, MessageType>
+impl<
+        'config,
+        Service: service::Details<'config>,
+        Header: Debug,
+        MessageType: PayloadType + ?Sized,
+    > Drop for SampleMut<'_, '_, 'config, Service, Header, MessageType>
 {
     fn drop(&mut self) {
         self.publisher.release_sample(self.offset_to_chunk);
-        self.publisher.loan_counter.fetch_sub(1, Ordering::Relaxed);
+        self.publisher.decrement_loan_counter();
     }
 }
 
@@ -63,18 +68,20 @@ impl<
         'config,
         Service: service::Details<'config>,
         Header: Debug,
-        MessageType: Debug,
+        MessageType: PayloadType + ?Sized,
     > SampleMut<'a, 'publisher, 'config, Service, Header, MessageType>
 {
     pub(crate) fn new(
         publisher: &'publisher Publisher<'a, 'config, Service, MessageType>,
-        ptr: NonNull<MaybeUninit<Message<Header, MessageType>>>,
+        header_ptr: NonNull<Header>,
+        payload_ptr: NonNull<MessageType>,
         offset_to_chunk: PointerOffset,
     ) -> Self {
-        publisher.loan_counter.fetch_add(1, Ordering::Relaxed);
+        publisher.increment_loan_counter();
         Self {
             publisher,
-            ptr,
+            header_ptr,
+            payload_ptr,
             offset_to_chunk,
         }
     }
@@ -86,16 +93,16 @@ impl<
     /// Returns a reference to the header of the sample. In publish subscribe communication the
     /// default header is [`crate::service::header::publish_subscribe::Header`].
     pub fn header(&self) -> &Header {
-        &unsafe { &*self.ptr.as_ref().as_ptr() }.header
+        unsafe { self.header_ptr.as_ref() }
     }
 
     /// Returns a pointer to the underlying memory.
     pub fn as_ptr(&self) -> *const MessageType {
-        &unsafe { &*self.ptr.as_ref().as_ptr() }.data
+        self.payload_ptr.as_ptr()
     }
 
     /// Returns a mutable pointer to the underlying memory.
     pub fn as_mut_ptr(&mut self) -> *mut MessageType {
-        &mut unsafe { &mut *self.ptr.as_mut().as_mut_ptr() }.data
+        self.payload_ptr.as_ptr()
     }
 }
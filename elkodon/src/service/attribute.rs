@@ -0,0 +1,107 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/AttributeService")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // stored in the static config when the service is created
+//!     .attribute("owner", "hypnotoad")
+//!     .attribute("frequency", "100Hz")
+//!     .publish_subscribe()
+//!     .create::<u64>()?;
+//!
+//! let opened_service = zero_copy::Service::new(&service_name)
+//!     // opening fails when the service does not have the attribute with this value
+//!     .require_attribute("owner", "hypnotoad")
+//!     .publish_subscribe()
+//!     .open::<u64>()?;
+//!
+//! println!("owner: {:?}", opened_service.attributes().get("owner"));
+//!
+//! // lists only the services of the owner
+//! let services = zero_copy::Service::list_filtered(|attributes| {
+//!     attributes.contains("owner", "hypnotoad")
+//! })?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// A user-defined key/value pair that is stored in the static config of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Attribute {
+    key: String,
+    value: String,
+}
+
+impl Attribute {
+    /// Returns the key of the attribute.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Returns the value of the attribute.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+/// The set of [`Attribute`]s of a [`crate::service::Service`]. Every key is contained at most
+/// once.
+#[derive(Debug, Clone, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AttributeSet(Vec<Attribute>);
+
+impl AttributeSet {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the attribute. If an attribute with the same key exists already, its value is
+    /// replaced.
+    pub(crate) fn insert(&mut self, key: &str, value: &str) {
+        match self.0.iter_mut().find(|a| a.key == key) {
+            Some(attribute) => attribute.value = value.to_string(),
+            None => self.0.push(Attribute {
+                key: key.to_string(),
+                value: value.to_string(),
+            }),
+        }
+    }
+
+    /// Returns the value of the attribute with the given key or [`None`] if it does not exist.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.iter().find(|a| a.key == key).map(|a| a.value())
+    }
+
+    /// Returns true if the set contains an attribute with the given key and value, otherwise
+    /// false.
+    pub fn contains(&self, key: &str, value: &str) -> bool {
+        self.get(key) == Some(value)
+    }
+
+    /// Returns the first [`Attribute`] of `required` that is not contained in the set.
+    pub(crate) fn first_missing<'a>(&self, required: &'a AttributeSet) -> Option<&'a Attribute> {
+        required.iter().find(|a| !self.contains(a.key(), a.value()))
+    }
+
+    /// Returns an iterator over all [`Attribute`]s.
+    pub fn iter(&self) -> impl Iterator<Item = &Attribute> {
+        self.0.iter()
+    }
+
+    /// Returns the number of [`Attribute`]s.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true if the set does not contain any [`Attribute`], otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
@@ -0,0 +1,541 @@
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use std::alloc::Layout;
+
+use crate::service;
+use crate::service::dynamic_config::blackboard::DynamicConfigSettings;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::blackboard;
+use crate::service::static_config::blackboard::EntryConfig;
+use crate::service::*;
+use elkodon_bb_container::byte_string::FixedSizeByteString;
+use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_elementary::math::align;
+use elkodon_bb_lock_free::spmc::unrestricted_atomic::UnrestrictedAtomic;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::serialize::Serialize;
+use elkodon_cal::static_storage::StaticStorageLocked;
+
+use super::ServiceState;
+
+/// Errors that can occur when an existing [`MessagingPattern::Blackboard`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum BlackboardOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleKeyType,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    DoesNotSupportRequestedAmountOfReaders,
+    DoesNotSupportRequestedAmountOfWriters,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for BlackboardOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::Blackboard`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum BlackboardCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+    NoEntriesProvided,
+    DuplicateEntryKey,
+}
+
+impl std::fmt::Display for BlackboardCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleKeyType,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::Blackboard`] [`Service`] shall be
+    /// created or opened.
+    BlackboardOpenOrCreateError
+  mapping:
+    BlackboardOpenError,
+    BlackboardCreateError
+}
+
+impl std::fmt::Display for BlackboardOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for BlackboardOpenOrCreateError {}
+
+/// Marks the types that can be used as key of a [`MessagingPattern::Blackboard`] based
+/// [`Service`]. The keys are stored in the shared memory of the blackboard and compared in
+/// every process that opens it, therefore they must be plain values that do not refer to
+/// any process local memory.
+///
+/// # Safety
+///
+///  * the type must not contain pointers, references or handles to process local resources
+///
+pub unsafe trait BlackboardKey: Copy + Eq + Debug {}
+
+macro_rules! impl_blackboard_key {
+    ($($t:ty),*) => {
+        $(unsafe impl BlackboardKey for $t {})*
+    };
+}
+
+impl_blackboard_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+unsafe impl<T: BlackboardKey, const N: usize> BlackboardKey for [T; N] {}
+unsafe impl<const CAPACITY: usize> BlackboardKey for FixedSizeByteString<CAPACITY> {}
+
+struct Entry<KeyType> {
+    key: KeyType,
+    type_name: String,
+    layout: Layout,
+    initializer: Box<dyn Fn(*mut u8)>,
+}
+
+impl<KeyType: Debug> Debug for Entry<KeyType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Entry {{ key: {:?}, type_name: {}, layout: {:?} }}",
+            self.key, self.type_name, self.layout
+        )
+    }
+}
+
+/// Builder to create new [`MessagingPattern::Blackboard`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<'config, ServiceType: service::Details<'config>, KeyType: BlackboardKey> {
+    base: builder::BuilderWithServiceType<'config, ServiceType>,
+    verify_number_of_readers: bool,
+    verify_number_of_writers: bool,
+    entries: Vec<Entry<KeyType>>,
+}
+
+impl<'config, ServiceType: service::Details<'config>, KeyType: BlackboardKey>
+    Builder<'config, ServiceType, KeyType>
+{
+    pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_readers: false,
+            verify_number_of_writers: false,
+            entries: vec![],
+        };
+
+        let mut static_config =
+            static_config::blackboard::StaticConfig::new(new_self.base.global_config);
+        static_config.key_type_name = std::any::type_name::<KeyType>().to_string();
+        new_self.base.service_config.messaging_pattern =
+            MessagingPattern::Blackboard(static_config);
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::blackboard::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Blackboard builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::reader::Reader`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::reader::Reader`] must be at least supported.
+    pub fn max_readers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_readers = value;
+        self.verify_number_of_readers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::writer::Writer`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::writer::Writer`] must be at least supported.
+    pub fn max_writers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_writers = value;
+        self.verify_number_of_writers = true;
+        self
+    }
+
+    /// Adds an entry identified by `key` that stores values of type `ValueType` and is
+    /// initialized with `value`. The entries are only used when the [`Service`] is created,
+    /// they are fixed for the whole lifetime of the [`Service`].
+    pub fn add<ValueType: Copy + Debug + 'static>(
+        mut self,
+        key: KeyType,
+        value: ValueType,
+    ) -> Self {
+        self.entries.push(Entry {
+            key,
+            type_name: std::any::type_name::<ValueType>().to_string(),
+            layout: Layout::new::<UnrestrictedAtomic<ValueType>>(),
+            initializer: Box::new(move |ptr: *mut u8| unsafe {
+                (ptr as *mut UnrestrictedAtomic<ValueType>).write(UnrestrictedAtomic::new(value))
+            }),
+        });
+        self
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                if config.blackboard().key_type_name != std::any::type_name::<KeyType>() {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleKeyType,
+                        "{} since the service uses the key type \"{}\" but the key type \"{}\" is required.",
+                        error_msg, config.blackboard().key_type_name, std::any::type_name::<KeyType>());
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<'config, ServiceType, KeyType>, BlackboardOpenOrCreateError>
+    {
+        let msg = "Unable to open or create blackboard service";
+
+        match self.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open()?),
+            Ok(None) => Ok(self.create()?),
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open()?),
+            Err(ServiceAvailabilityState::IncompatibleKeyType) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::IncompatibleKeyType),
+                    "{} since the service uses an incompatible key type.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with BlackboardOpenOrCreateError::BlackboardOpenError(BlackboardOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`]. The entries that were added with [`Builder::add()`] are
+    /// ignored, the entries of the existing [`Service`] are used.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "blackboard::open",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn open(
+        mut self,
+    ) -> Result<blackboard::PortFactory<'config, ServiceType, KeyType>, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with BlackboardOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
+                    let static_config = self.verify_service_properties(&static_config)?;
+
+                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
+                            with BlackboardOpenError::UnableToOpenDynamicServiceInformation,
+                            "{} since the dynamic service information could not be opened.", msg);
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Blackboard(static_config);
+
+                    return Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.base.service_config,
+                            self.base.global_config,
+                            dynamic_config,
+                            static_storage,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with BlackboardOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout > self.base.global_config.global.service.creation_timeout {
+                        fail!(from self, with BlackboardOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleKeyType) => {
+                    fail!(from self, with BlackboardOpenError::IncompatibleKeyType,
+                        "{} since the service uses an incompatible key type.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                        "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with BlackboardOpenError::ServiceInCorruptedState,
+                        "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with BlackboardOpenError::PermissionDenied,
+                        "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`] that contains all entries that were added with
+    /// [`Builder::add()`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "blackboard::create",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn create(
+        mut self,
+    ) -> Result<blackboard::PortFactory<'config, ServiceType, KeyType>, BlackboardCreateError> {
+        self.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create blackboard service";
+
+        if self.entries.is_empty() {
+            fail!(from self, with BlackboardCreateError::NoEntriesProvided,
+                "{} since no entries were provided.", msg);
+        }
+
+        for (n, entry) in self.entries.iter().enumerate() {
+            if self.entries[n + 1..].iter().any(|e| e.key == entry.key) {
+                fail!(from self, with BlackboardCreateError::DuplicateEntryKey,
+                    "{} since the key {:?} was provided more than once.", msg, entry.key);
+            }
+        }
+
+        self.calculate_entry_layout();
+
+        match self.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = fail!(from self, when self.base.create_static_config_storage(),
+                    with BlackboardCreateError::UnableToCreateStaticServiceInformation,
+                    "{} since the static service information could not be created.", msg);
+
+                let blackboard_config = self.base.service_config.blackboard();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_readers: blackboard_config.max_readers,
+                    number_of_writers: blackboard_config.max_writers,
+                    number_of_entries: blackboard_config.entries.len(),
+                    entries_size: blackboard_config.entries_size,
+                    entries_alignment: blackboard_config.entries_alignment,
+                };
+
+                let dynamic_config = self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::Blackboard(
+                        dynamic_config::blackboard::DynamicConfig::new(&dynamic_config_setting),
+                    ),
+                    dynamic_config::blackboard::DynamicConfig::memory_size(&dynamic_config_setting),
+                );
+                let dynamic_config = fail!(from self, when dynamic_config,
+                    with BlackboardCreateError::InternalFailure,
+                    "{} since the dynamic service segment could not be created.", msg);
+
+                // the service is not yet visible to others, the entries can be initialized
+                // without synchronization
+                self.initialize_entries(dynamic_config.get().blackboard().entries() as *mut u8);
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with BlackboardCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with BlackboardCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+                watcher::notify_service_watchers(self.base.global_config);
+
+                Ok(blackboard::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.global_config,
+                        dynamic_config,
+                        unlocked_static_details,
+                    ),
+                )))
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleKeyType)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with BlackboardCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with BlackboardCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with BlackboardCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    /// The memory of the blackboard starts with the key table, followed by the entries.
+    fn calculate_entry_layout(&mut self) {
+        let mut offset = std::mem::size_of::<KeyType>() * self.entries.len();
+        let mut alignment = std::mem::align_of::<KeyType>();
+        let mut entries = vec![];
+
+        for entry in &self.entries {
+            offset = align(offset, entry.layout.align());
+            alignment = alignment.max(entry.layout.align());
+            entries.push(EntryConfig {
+                type_name: entry.type_name.clone(),
+                offset,
+            });
+            offset += entry.layout.size();
+        }
+
+        let details = self.config_details_mut();
+        details.entries = entries;
+        details.entries_size = offset;
+        details.entries_alignment = alignment;
+    }
+
+    fn initialize_entries(&self, memory: *mut u8) {
+        let entry_configs = &self.base.service_config.blackboard().entries;
+        for (n, entry) in self.entries.iter().enumerate() {
+            unsafe {
+                (memory as *mut KeyType).add(n).write(entry.key);
+            }
+            (entry.initializer)(unsafe { memory.add(entry_configs[n].offset) });
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.blackboard_mut();
+
+        if settings.max_readers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of readers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_readers = 1;
+        }
+
+        if settings.max_writers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of writers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_writers = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::blackboard::StaticConfig, BlackboardOpenError> {
+        let msg = "Unable to open blackboard service";
+
+        if let Some(attribute) = self.base.missing_required_attribute(existing_settings) {
+            fail!(from self, with BlackboardOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attribute {:?}.", msg, attribute);
+        }
+
+        let required_settings = self.base.service_config.blackboard();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            p => {
+                fail!(from self, with BlackboardOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Blackboard is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_readers
+            && existing_settings.max_readers < required_settings.max_readers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders,
+                                "{} since the service supports only {} readers but a support of {} readers was requested.",
+                                msg, existing_settings.max_readers, required_settings.max_readers);
+        }
+
+        if self.verify_number_of_writers
+            && existing_settings.max_writers < required_settings.max_writers
+        {
+            fail!(from self, with BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters,
+                                "{} since the service supports only {} writers but a support of {} writers was requested.",
+                                msg, existing_settings.max_writers, required_settings.max_writers);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
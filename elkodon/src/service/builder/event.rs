@@ -19,6 +19,7 @@ pub enum EventOpenError {
     PermissionDenied,
     EventInCorruptedState,
     IncompatibleMessagingPattern,
+    IncompatibleAttributes,
     InternalFailure,
     HangsInCreation,
     DoesNotSupportRequestedAmountOfNotifiers,
@@ -151,6 +152,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// Opens an existing [`Service`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "event::open",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
     pub fn open(mut self) -> Result<event::PortFactory<'config, ServiceType>, EventOpenError> {
         let msg = "Unable to open event service";
 
@@ -165,6 +175,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         "{} since the event does not exist.", msg);
                 }
                 Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
                     let static_config = self.verify_service_properties(&static_config)?;
 
                     let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
@@ -211,6 +222,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// Creates a new [`Service`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "event::create",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
     pub fn create(mut self) -> Result<event::PortFactory<'config, ServiceType>, EventCreateError> {
         self.adjust_properties_to_meaningful_values();
 
@@ -246,6 +266,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
                             with EventCreateError::Corrupted,
                             "{} since the configuration could not be written to the static storage.", msg);
+                watcher::notify_service_watchers(self.base.global_config);
 
                 return Ok(event::PortFactory::new(ServiceType::from_state(
                     service::ServiceState::new(
@@ -296,6 +317,11 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     ) -> Result<static_config::event::StaticConfig, EventOpenError> {
         let msg = "Unable to open event";
 
+        if let Some(attribute) = self.base.missing_required_attribute(existing_settings) {
+            fail!(from self, with EventOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attribute {:?}.", msg, attribute);
+        }
+
         let required_settings = self.base.service_config.event();
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::Event(ref v) => v,
@@ -2,14 +2,25 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
+/// Builder for [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
+
 use crate::config;
 use crate::service;
+use crate::service::attribute::{Attribute, AttributeSet};
+use crate::service::builder::blackboard::BlackboardKey;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use elkodon_bb_container::semantic_string::SemanticString;
@@ -25,6 +36,7 @@ use elkodon_cal::named_concept::NamedConceptDoesExistError;
 use elkodon_cal::named_concept::NamedConceptMgmt;
 use elkodon_cal::serialize::Serialize;
 use elkodon_cal::static_storage::*;
+use std::fmt::Debug;
 use std::marker::PhantomData;
 
 use super::dynamic_config_storage_config;
@@ -83,6 +95,8 @@ impl std::error::Error for ReadStaticStorageFailure {}
 #[derive(Debug)]
 pub struct Builder<S: Service> {
     name: ServiceName,
+    attributes: AttributeSet,
+    required_attributes: AttributeSet,
     _phantom_s: PhantomData<S>,
 }
 
@@ -90,10 +104,29 @@ impl<S: Service> Builder<S> {
     pub(crate) fn new(name: &ServiceName) -> Self {
         Self {
             name: *name,
+            attributes: AttributeSet::new(),
+            required_attributes: AttributeSet::new(),
             _phantom_s: PhantomData,
         }
     }
 
+    /// Defines an [`Attribute`] that is stored in the static config when the [`Service`] is
+    /// created. If an attribute with the same key was already defined, its value is replaced.
+    /// It is ignored when an existing [`Service`] is opened.
+    pub fn attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.insert(key, value);
+        self
+    }
+
+    /// Requires that an existing [`Service`] has the [`Attribute`] with the given key and
+    /// value, otherwise opening it fails. When the [`Service`] is created the attribute is
+    /// defined like with [`Builder::attribute()`].
+    pub fn require_attribute(mut self, key: &str, value: &str) -> Self {
+        self.attributes.insert(key, value);
+        self.required_attributes.insert(key, value);
+        self
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
     pub fn publish_subscribe<'config>(
@@ -115,6 +148,7 @@ impl<S: Service> Builder<S> {
             >(&self.name, config),
             config,
         )
+        .with_attributes(self.attributes, self.required_attributes)
         .publish_subscribe()
     }
 
@@ -137,8 +171,81 @@ impl<S: Service> Builder<S> {
             >(&self.name, config),
             config,
         )
+        .with_attributes(self.attributes, self.required_attributes)
         .event()
     }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    pub fn request_response<'config>(self) -> request_response::Builder<'config, S::Type<'config>> {
+        self.request_response_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn request_response_with_custom_config(
+        self,
+        config: &config::Config,
+    ) -> request_response::Builder<'_, S::Type<'_>> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_request_response::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+        )
+        .with_attributes(self.attributes, self.required_attributes)
+        .request_response()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    /// The entries of the blackboard are identified by a key of type `KeyType`.
+    pub fn blackboard<'config, KeyType: BlackboardKey>(
+        self,
+    ) -> blackboard::Builder<'config, S::Type<'config>, KeyType> {
+        self.blackboard_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn blackboard_with_custom_config<KeyType: BlackboardKey>(
+        self,
+        config: &config::Config,
+    ) -> blackboard::Builder<'_, S::Type<'_>, KeyType> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+        )
+        .with_attributes(self.attributes, self.required_attributes)
+        .blackboard()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    pub fn pipeline<'config>(self) -> pipeline::Builder<'config, S::Type<'config>> {
+        self.pipeline_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    /// with a custom [`config::Config`]
+    pub fn pipeline_with_custom_config(
+        self,
+        config: &config::Config,
+    ) -> pipeline::Builder<'_, S::Type<'_>> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_pipeline::<
+                <<S as service::Service>::Type<'_> as service::Details<'_>>::ServiceNameHasher,
+            >(&self.name, config),
+            config,
+        )
+        .with_attributes(self.attributes, self.required_attributes)
+        .pipeline()
+    }
 }
 
 #[doc(hidden)]
@@ -146,6 +253,7 @@ impl<S: Service> Builder<S> {
 pub struct BuilderWithServiceType<'config, ServiceType: service::Details<'config>> {
     service_config: StaticConfig,
     global_config: &'config config::Config,
+    required_attributes: AttributeSet,
     _phantom_data: PhantomData<ServiceType>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
@@ -155,11 +263,28 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         Self {
             service_config,
             global_config,
+            required_attributes: AttributeSet::new(),
             _phantom_data: PhantomData,
             _phantom_lifetime_b: PhantomData,
         }
     }
 
+    fn with_attributes(
+        mut self,
+        attributes: AttributeSet,
+        required_attributes: AttributeSet,
+    ) -> Self {
+        self.service_config.attributes = attributes;
+        self.required_attributes = required_attributes;
+        self
+    }
+
+    fn missing_required_attribute<'a>(&'a self, existing: &StaticConfig) -> Option<&'a Attribute> {
+        existing
+            .attributes()
+            .first_missing(&self.required_attributes)
+    }
+
     fn publish_subscribe(self) -> publish_subscribe::Builder<'config, ServiceType> {
         publish_subscribe::Builder::new(self)
     }
@@ -168,6 +293,20 @@ impl<'config, ServiceType: service::Details<'config>> BuilderWithServiceType<'co
         event::Builder::new(self)
     }
 
+    fn request_response(self) -> request_response::Builder<'config, ServiceType> {
+        request_response::Builder::new(self)
+    }
+
+    fn blackboard<KeyType: BlackboardKey>(
+        self,
+    ) -> blackboard::Builder<'config, ServiceType, KeyType> {
+        blackboard::Builder::new(self)
+    }
+
+    fn pipeline(self) -> pipeline::Builder<'config, ServiceType> {
+        pipeline::Builder::new(self)
+    }
+
     fn is_service_available(
         &self,
     ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceState> {
@@ -0,0 +1,513 @@
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use std::alloc::Layout;
+
+use crate::message::Message;
+use crate::service;
+use crate::service::dynamic_config::pipeline::DynamicConfigSettings;
+use crate::service::header::pipeline::Header;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::pipeline;
+use crate::service::*;
+use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use elkodon_cal::serialize::Serialize;
+use elkodon_cal::static_storage::StaticStorageLocked;
+
+use super::ServiceState;
+
+/// Errors that can occur when an existing [`MessagingPattern::Pipeline`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipelineOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleTypes,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    DoesNotSupportRequestedAmountOfProducers,
+    DoesNotSupportRequestedAmountOfWorkers,
+    DoesNotSupportRequestedWorkQueueCapacity,
+    DoesNotSupportRequestedAmountOfLoanedSamples,
+    DoesNotSupportRequestedAmountOfBorrowedSamples,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for PipelineOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PipelineOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::Pipeline`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum PipelineCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+}
+
+impl std::fmt::Display for PipelineCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PipelineCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::Pipeline`] [`Service`] shall be
+    /// created or opened.
+    PipelineOpenOrCreateError
+  mapping:
+    PipelineOpenError,
+    PipelineCreateError
+}
+
+impl std::fmt::Display for PipelineOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for PipelineOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::Pipeline`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<'config, ServiceType: service::Details<'config>> {
+    base: builder::BuilderWithServiceType<'config, ServiceType>,
+    verify_number_of_producers: bool,
+    verify_number_of_workers: bool,
+    verify_work_queue_capacity: bool,
+    verify_producer_max_loaned_samples: bool,
+    verify_worker_max_borrowed_samples: bool,
+}
+
+impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_producers: false,
+            verify_number_of_workers: false,
+            verify_work_queue_capacity: false,
+            verify_producer_max_loaned_samples: false,
+            verify_worker_max_borrowed_samples: false,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::Pipeline(
+            static_config::pipeline::StaticConfig::new(new_self.base.global_config),
+        );
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::pipeline::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Pipeline(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Pipeline builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::pipeline::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in Pipeline builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::producer::Producer`]
+    /// shall be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::producer::Producer`] must be at least supported.
+    pub fn max_producers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_producers = value;
+        self.verify_number_of_producers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::worker::Worker`]
+    /// shall be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::worker::Worker`] must be at least supported.
+    pub fn max_workers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_workers = value;
+        self.verify_number_of_workers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many samples can wait in the shared work
+    /// queue at most. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn work_queue_capacity(mut self, value: usize) -> Self {
+        self.config_details_mut().work_queue_capacity = value;
+        self.verify_work_queue_capacity = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many samples a
+    /// [`crate::port::producer::Producer`] can loan in parallel. If an existing [`Service`] is
+    /// opened it defines the minimum required.
+    pub fn producer_max_loaned_samples(mut self, value: usize) -> Self {
+        self.config_details_mut().producer_max_loaned_samples = value;
+        self.verify_producer_max_loaned_samples = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many samples a
+    /// [`crate::port::worker::Worker`] can hold in parallel. If an existing [`Service`] is
+    /// opened it defines the minimum required.
+    pub fn worker_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.config_details_mut().worker_max_borrowed_samples = value;
+        self.verify_worker_max_borrowed_samples = true;
+        self
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                if config.pipeline().type_name != self.config_details().type_name {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
+                        error_msg, config.pipeline().type_name, self.config_details().type_name);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create<MessageType: Debug>(
+        mut self,
+    ) -> Result<pipeline::PortFactory<'config, ServiceType, MessageType>, PipelineOpenOrCreateError>
+    {
+        let msg = "Unable to open or create pipeline service";
+        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+
+        match self.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open::<MessageType>()?),
+            Ok(None) => Ok(self.create::<MessageType>()?),
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open::<MessageType>()?),
+            Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::IncompatibleTypes),
+                    "{} since the service is not type compatible.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with PipelineOpenOrCreateError::PipelineOpenError(PipelineOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "pipeline::open",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn open<MessageType: Debug>(
+        mut self,
+    ) -> Result<pipeline::PortFactory<'config, ServiceType, MessageType>, PipelineOpenError> {
+        let msg = "Unable to open pipeline service";
+        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with PipelineOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with PipelineOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
+                    let static_config = self.verify_service_properties(&static_config)?;
+
+                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
+                            with PipelineOpenError::UnableToOpenDynamicServiceInformation,
+                            "{} since the dynamic service information could not be opened.", msg);
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::Pipeline(static_config);
+
+                    return Ok(pipeline::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.base.service_config,
+                            self.base.global_config,
+                            dynamic_config,
+                            static_storage,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with PipelineOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout > self.base.global_config.global.service.creation_timeout {
+                        fail!(from self, with PipelineOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with PipelineOpenError::IncompatibleTypes,
+                    "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with PipelineOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with PipelineOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with PipelineOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "pipeline::create",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn create<MessageType: Debug>(
+        mut self,
+    ) -> Result<pipeline::PortFactory<'config, ServiceType, MessageType>, PipelineCreateError> {
+        self.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create pipeline service";
+        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+
+        match self.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = fail!(from self, when self.base.create_static_config_storage(),
+                    with PipelineCreateError::UnableToCreateStaticServiceInformation,
+                    "{} since the static service information could not be created.", msg);
+
+                let pipeline_config = self.base.service_config.pipeline();
+                let sample_layout = Layout::new::<Message<Header, MessageType>>().pad_to_align();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_producers: pipeline_config.max_producers,
+                    number_of_workers: pipeline_config.max_workers,
+                    number_of_samples: pipeline_config.number_of_samples(),
+                    work_queue_capacity: pipeline_config.work_queue_capacity,
+                    sample_size: sample_layout.size(),
+                    sample_alignment: sample_layout.align(),
+                };
+
+                let dynamic_config = self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::Pipeline(
+                        dynamic_config::pipeline::DynamicConfig::new(&dynamic_config_setting),
+                    ),
+                    dynamic_config::pipeline::DynamicConfig::memory_size(&dynamic_config_setting),
+                );
+                let dynamic_config = fail!(from self, when dynamic_config,
+                    with PipelineCreateError::InternalFailure,
+                    "{} since the dynamic service segment could not be created.", msg);
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with PipelineCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with PipelineCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+                watcher::notify_service_watchers(self.base.global_config);
+
+                Ok(pipeline::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.global_config,
+                        dynamic_config,
+                        unlocked_static_details,
+                    ),
+                )))
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with PipelineCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with PipelineCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with PipelineCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with PipelineCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.pipeline_mut();
+
+        if settings.max_producers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of producers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_producers = 1;
+        }
+
+        if settings.max_workers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of workers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_workers = 1;
+        }
+
+        if settings.work_queue_capacity == 0 {
+            warn!(from origin,
+                "Setting the work queue capacity to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.work_queue_capacity = 1;
+        }
+
+        if settings.producer_max_loaned_samples == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of loaned samples per producer to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.producer_max_loaned_samples = 1;
+        }
+
+        if settings.worker_max_borrowed_samples == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of borrowed samples per worker to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.worker_max_borrowed_samples = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::pipeline::StaticConfig, PipelineOpenError> {
+        let msg = "Unable to open pipeline service";
+
+        if let Some(attribute) = self.base.missing_required_attribute(existing_settings) {
+            fail!(from self, with PipelineOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attribute {:?}.", msg, attribute);
+        }
+
+        let required_settings = self.base.service_config.pipeline();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            p => {
+                fail!(from self, with PipelineOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::Pipeline is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_producers
+            && existing_settings.max_producers < required_settings.max_producers
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfProducers,
+                                "{} since the service supports only {} producers but a support of {} producers was requested.",
+                                msg, existing_settings.max_producers, required_settings.max_producers);
+        }
+
+        if self.verify_number_of_workers
+            && existing_settings.max_workers < required_settings.max_workers
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfWorkers,
+                                "{} since the service supports only {} workers but a support of {} workers was requested.",
+                                msg, existing_settings.max_workers, required_settings.max_workers);
+        }
+
+        if self.verify_work_queue_capacity
+            && existing_settings.work_queue_capacity < required_settings.work_queue_capacity
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedWorkQueueCapacity,
+                                "{} since the service supports only a work queue capacity of {} but a capacity of {} was requested.",
+                                msg, existing_settings.work_queue_capacity, required_settings.work_queue_capacity);
+        }
+
+        if self.verify_producer_max_loaned_samples
+            && existing_settings.producer_max_loaned_samples
+                < required_settings.producer_max_loaned_samples
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfLoanedSamples,
+                                "{} since the service supports only {} loaned samples per producer but a support of {} loaned samples was requested.",
+                                msg, existing_settings.producer_max_loaned_samples, required_settings.producer_max_loaned_samples);
+        }
+
+        if self.verify_worker_max_borrowed_samples
+            && existing_settings.worker_max_borrowed_samples
+                < required_settings.worker_max_borrowed_samples
+        {
+            fail!(from self, with PipelineOpenError::DoesNotSupportRequestedAmountOfBorrowedSamples,
+                                "{} since the service supports only {} borrowed samples per worker but a support of {} borrowed samples was requested.",
+                                msg, existing_settings.worker_max_borrowed_samples, required_settings.worker_max_borrowed_samples);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
@@ -2,6 +2,7 @@
 //!
 //! See [`crate::service`]
 //!
+use crate::payload_type::PayloadType;
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::messaging_pattern::MessagingPattern;
@@ -12,6 +13,7 @@ use elkodon_bb_log::{fail, fatal_panic, warn};
 use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use elkodon_cal::serialize::Serialize;
 use elkodon_cal::static_storage::StaticStorageLocked;
+use std::time::Duration;
 
 use super::ServiceState;
 
@@ -20,14 +22,22 @@ use super::ServiceState;
 pub enum PublishSubscribeOpenError {
     DoesNotExist,
     InternalFailure,
-    IncompatibleTypes,
+    IncompatibleTypeLayout,
     IncompatibleMessagingPattern,
+    IncompatibleAttributes,
     DoesNotSupportRequestedMinBufferSize,
     DoesNotSupportRequestedMinHistorySize,
     DoesNotSupportRequestedMinSubscriberBorrowedSamples,
     DoesNotSupportRequestedAmountOfPublishers,
     DoesNotSupportRequestedAmountOfSubscribers,
+    DoesNotSupportRequestedMaxSliceLength,
+    DoesNotSupportRequestedPayloadAlignment,
     IncompatibleOverflowBehavior,
+    IncompatibleNotificationBehavior,
+    IncompatiblePayloadTransfer,
+    DoesNotSupportRequestedDeadline,
+    DoesNotSupportRequestedLivelinessLeaseDuration,
+    DoesNotSupportRequestedLifespan,
     Inaccessible,
     PermissionDenied,
     ServiceInCorruptedState,
@@ -63,12 +73,6 @@ impl std::fmt::Display for PublishSubscribeCreateError {
 
 impl std::error::Error for PublishSubscribeCreateError {}
 
-#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
-enum ServiceAvailabilityState {
-    ServiceState(ServiceState),
-    IncompatibleTypes,
-}
-
 enum_gen! {
     /// Errors that can occur when a [`MessagingPattern::PublishSubscribe`] [`Service`] shall be
     /// created or opened.
@@ -100,6 +104,13 @@ pub struct Builder<'config, ServiceType: service::Details<'config>> {
     verify_subscriber_max_borrowed_samples: bool,
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
+    verify_enable_notifications: bool,
+    verify_max_slice_len: bool,
+    verify_payload_alignment: bool,
+    verify_type_fingerprint: bool,
+    verify_deadline: bool,
+    verify_liveliness_lease_duration: bool,
+    verify_lifespan: bool,
 }
 
 impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
@@ -112,11 +123,23 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             verify_publisher_history_size: false,
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
+            verify_enable_notifications: false,
+            verify_max_slice_len: false,
+            verify_payload_alignment: false,
+            verify_type_fingerprint: true,
+            verify_deadline: false,
+            verify_liveliness_lease_duration: false,
+            verify_lifespan: false,
         };
 
         new_self.base.service_config.messaging_pattern = MessagingPattern::PublishSubscribe(
             static_config::publish_subscribe::StaticConfig::new(new_self.base.global_config),
         );
+        new_self
+            .base
+            .service_config
+            .publish_subscribe_mut()
+            .payload_transfer = ServiceType::PAYLOAD_TRANSFER;
 
         new_self
     }
@@ -147,6 +170,16 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         self
     }
 
+    /// If the [`Service`] is created, defines if the [`crate::port::publisher::Publisher`] notifies
+    /// the [`crate::port::subscriber::Subscriber`]s whenever it delivers a
+    /// [`crate::sample::Sample`] so that they can wait for new data. If an existing [`Service`] is
+    /// opened it requires the service to have the defined notification behavior.
+    pub fn enable_notifications(mut self, value: bool) -> Self {
+        self.config_details_mut().enable_notifications = value;
+        self.verify_enable_notifications = true;
+        self
+    }
+
     /// If the [`Service`] is created it defines how many [`crate::sample::Sample`] a
     /// [`crate::port::subscriber::Subscriber`] can borrow at most in parallel. If an existing
     /// [`Service`] is opened it defines the minimum required.
@@ -192,57 +225,81 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         self
     }
 
-    fn is_service_available(
-        &mut self,
-        error_msg: &str,
-    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
-        match self.base.is_service_available() {
-            Ok(Some((config, storage))) => {
-                if config.publish_subscribe().type_name != self.config_details().type_name {
-                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
-                        "{} since the service offers the type \"{}\" but the requested type is \"{}\".",
-                        error_msg, &config.publish_subscribe().type_name , self.config_details().type_name);
-                }
+    /// If the [`Service`] is created it defines how many elements a slice payload, like `[u64]`,
+    /// can contain at most. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn max_slice_len(mut self, value: usize) -> Self {
+        self.config_details_mut().max_slice_len = value;
+        self.verify_max_slice_len = true;
+        self
+    }
 
-                Ok(Some((config, storage)))
-            }
-            Ok(None) => Ok(None),
-            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
-        }
+    /// If the [`Service`] is created it defines the alignment of the payload inside every
+    /// [`crate::sample::Sample`]. The payload is always at least aligned to the alignment of its
+    /// type. If an existing [`Service`] is opened it defines the minimum required.
+    pub fn payload_alignment(mut self, value: usize) -> Self {
+        self.config_details_mut().payload_alignment = value;
+        self.verify_payload_alignment = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the maximum time that is allowed to pass between
+    /// two [`crate::sample::Sample`]s of a [`crate::port::publisher::Publisher`]. A
+    /// [`crate::port::subscriber::Subscriber`] reports every
+    /// [`crate::port::publisher::Publisher`] that missed its deadline. If an existing
+    /// [`Service`] is opened it defines the maximum deadline the service must guarantee.
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.config_details_mut().deadline = Some(value);
+        self.verify_deadline = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how long a [`crate::port::publisher::Publisher`]
+    /// is considered alive after it asserted its liveliness, either implicitly by sending a
+    /// [`crate::sample::Sample`] or explicitly with
+    /// [`crate::port::publisher::Publisher::assert_liveliness()`]. A
+    /// [`crate::port::subscriber::Subscriber`] reports every
+    /// [`crate::port::publisher::Publisher`] that lost its liveliness. If an existing
+    /// [`Service`] is opened it defines the maximum lease duration the service must guarantee.
+    pub fn liveliness_lease_duration(mut self, value: Duration) -> Self {
+        self.config_details_mut().liveliness_lease_duration = Some(value);
+        self.verify_liveliness_lease_duration = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how long a [`crate::sample::Sample`] is valid
+    /// after it was sent. A [`crate::port::subscriber::Subscriber`] discards older samples
+    /// instead of receiving them. If an existing [`Service`] is opened it defines the maximum
+    /// lifespan the service must guarantee.
+    pub fn lifespan(mut self, value: Duration) -> Self {
+        self.config_details_mut().lifespan = Some(value);
+        self.verify_lifespan = true;
+        self
     }
 
     /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
     /// created.
-    pub fn open_or_create<MessageType: Debug>(
+    pub fn open_or_create<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
         PublishSubscribeOpenOrCreateError,
     > {
         let msg = "Unable to open or create publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        self.set_payload_type::<MessageType>();
 
-        match self.is_service_available(msg) {
+        match self.base.is_service_available() {
             Ok(Some(_)) => Ok(self.open::<MessageType>()?),
             Ok(None) => Ok(self.create::<MessageType>()?),
-            Err(ServiceAvailabilityState::ServiceState(
-                ServiceState::IsBeingCreatedByAnotherInstance,
-            )) => Ok(self.open::<MessageType>()?),
-            Err(ServiceAvailabilityState::IncompatibleTypes) => {
-                fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleTypes),
-                    "{} since the service is not type compatible.", msg);
-            }
-            Err(ServiceAvailabilityState::ServiceState(
-                ServiceState::IncompatibleMessagingPattern,
-            )) => {
+            Err(ServiceState::IsBeingCreatedByAnotherInstance) => Ok(self.open::<MessageType>()?),
+            Err(ServiceState::IncompatibleMessagingPattern) => {
                 fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::IncompatibleMessagingPattern),
                     "{} since the services messaging pattern does not match.", msg);
             }
-            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+            Err(ServiceState::Corrupted) => {
                 fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::ServiceInCorruptedState),
                     "{} since the service is in a corrupted state.", msg);
             }
-            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+            Err(ServiceState::PermissionDenied) => {
                 fail!(from self, with PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(PublishSubscribeOpenError::PermissionDenied),
                     "{} due to insufficient permissions to access the service.", msg);
             }
@@ -250,26 +307,36 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     }
 
     /// Opens an existing [`Service`].
-    pub fn open<MessageType: Debug>(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "publish_subscribe::open",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn open<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
         PublishSubscribeOpenError,
     > {
         let msg = "Unable to open publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        self.set_payload_type::<MessageType>();
 
         let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                         with PublishSubscribeOpenError::InternalFailure,
                                         "{} since the adaptive wait could not be created.", msg);
 
         loop {
-            match self.is_service_available(msg) {
+            match self.base.is_service_available() {
                 Ok(None) => {
                     fail!(from self, with PublishSubscribeOpenError::DoesNotExist,
                         "{} since the service does not exist.", msg);
                 }
                 Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
                     let static_config = self.verify_service_properties(&static_config)?;
 
                     let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
@@ -288,9 +355,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                         )),
                     ));
                 }
-                Err(ServiceAvailabilityState::ServiceState(
-                    ServiceState::IsBeingCreatedByAnotherInstance,
-                )) => {
+                Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
                     let timeout = fail!(from self, when adaptive_wait.wait(),
                                         with PublishSubscribeOpenError::InternalFailure,
                                         "{} since the adaptive wait failed.", msg);
@@ -301,21 +366,15 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                             msg, self.base.global_config.global.service.creation_timeout, timeout);
                     }
                 }
-                Err(ServiceAvailabilityState::IncompatibleTypes) => {
-                    fail!(from self, with PublishSubscribeOpenError::IncompatibleTypes,
-                    "{} since the service is not type compatible.", msg);
-                }
-                Err(ServiceAvailabilityState::ServiceState(
-                    ServiceState::IncompatibleMessagingPattern,
-                )) => {
+                Err(ServiceState::IncompatibleMessagingPattern) => {
                     fail!(from self, with PublishSubscribeOpenError::IncompatibleMessagingPattern,
                     "{} since the services messaging pattern does not match.", msg);
                 }
-                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                Err(ServiceState::Corrupted) => {
                     fail!(from self, with PublishSubscribeOpenError::ServiceInCorruptedState,
                     "{} since the service is in a corrupted state.", msg);
                 }
-                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                Err(ServiceState::PermissionDenied) => {
                     fail!(from self, with PublishSubscribeOpenError::PermissionDenied,
                     "{} due to insufficient permissions to access the service.", msg);
                 }
@@ -323,8 +382,31 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         }
     }
 
+    /// Opens an existing [`Service`] independent of its payload type. Every
+    /// [`crate::sample::Sample`] provides the raw bytes of its payload, the type can be inspected
+    /// with [`static_config::publish_subscribe::StaticConfig::type_name()`] and
+    /// [`static_config::publish_subscribe::StaticConfig::type_fingerprint()`]. Since the payload
+    /// type is unknown, the [`Service`] can only be used to create
+    /// [`crate::port::subscriber::Subscriber`]s.
+    pub fn open_untyped(
+        mut self,
+    ) -> Result<publish_subscribe::PortFactory<'config, ServiceType, [u8]>, PublishSubscribeOpenError>
+    {
+        self.verify_type_fingerprint = false;
+        self.open::<[u8]>()
+    }
+
     /// Creates a new [`Service`].
-    pub fn create<MessageType: Debug>(
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "publish_subscribe::create",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn create<MessageType: PayloadType + ?Sized>(
         mut self,
     ) -> Result<
         publish_subscribe::PortFactory<'config, ServiceType, MessageType>,
@@ -333,7 +415,8 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
         self.adjust_properties_to_meaningful_values();
 
         let msg = "Unable to create publish subscribe service";
-        self.config_details_mut().type_name = std::any::type_name::<MessageType>().to_string();
+        self.set_payload_type::<MessageType>();
+        self.adjust_payload_alignment_to_type::<MessageType>();
 
         if !self.config_details().enable_safe_overflow
             && (self.config_details().subscriber_max_buffer_size
@@ -343,7 +426,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 "{} since the history size is greater than the subscriber buffer size. The subscriber buffer size must be always greater or equal to the history size in the non-overflowing setup.", msg);
         }
 
-        match self.is_service_available(msg) {
+        match self.base.is_service_available() {
             Ok(None) => {
                 // create static config
                 let static_config = fail!(from self, when self.base.create_static_config_storage(),
@@ -380,6 +463,7 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
                             with PublishSubscribeCreateError::Corrupted,
                             "{} since the configuration could not be written to the static storage.", msg);
+                watcher::notify_service_watchers(self.base.global_config);
 
                 return Ok(publish_subscribe::PortFactory::new(
                     ServiceType::from_state(service::ServiceState::new(
@@ -390,31 +474,39 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                     )),
                 ));
             }
-            Ok(Some(_))
-            | Err(ServiceAvailabilityState::IncompatibleTypes)
-            | Err(ServiceAvailabilityState::ServiceState(
-                ServiceState::IncompatibleMessagingPattern,
-            )) => {
+            Ok(Some(_)) | Err(ServiceState::IncompatibleMessagingPattern) => {
                 fail!(from self, with PublishSubscribeCreateError::AlreadyExists,
                     "{} since the service already exists.", msg);
             }
-            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+            Err(ServiceState::PermissionDenied) => {
                 fail!(from self, with PublishSubscribeCreateError::PermissionDenied,
                     "{} due to possible insufficient permissions to access the underlying service details.", msg);
             }
-            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+            Err(ServiceState::Corrupted) => {
                 fail!(from self, with PublishSubscribeCreateError::Corrupted,
                     "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
             }
-            Err(ServiceAvailabilityState::ServiceState(
-                ServiceState::IsBeingCreatedByAnotherInstance,
-            )) => {
+            Err(ServiceState::IsBeingCreatedByAnotherInstance) => {
                 fail!(from self, with PublishSubscribeCreateError::IsBeingCreatedByAnotherInstance,
                     "{} since the service is being created by another instance.", msg);
             }
         }
     }
 
+    fn set_payload_type<MessageType: PayloadType + ?Sized>(&mut self) {
+        let settings = self.config_details_mut();
+        settings.type_name = std::any::type_name::<MessageType>().to_string();
+        settings.type_fingerprint = MessageType::type_fingerprint();
+    }
+
+    fn adjust_payload_alignment_to_type<MessageType: PayloadType + ?Sized>(&mut self) {
+        let type_alignment = fatal_panic!(from self, when MessageType::layout(1),
+            "This should never happen! Unable to acquire the layout of the payload type.")
+        .align();
+        let settings = self.config_details_mut();
+        settings.payload_alignment = std::cmp::max(settings.payload_alignment, type_alignment);
+    }
+
     fn adjust_properties_to_meaningful_values(&mut self) {
         let origin = format!("{:?}", self);
         let settings = self.base.service_config.publish_subscribe_mut();
@@ -442,6 +534,20 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                 "Setting the maximum amount of publishers to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_publishers = 1;
         }
+
+        if settings.max_slice_len == 0 {
+            warn!(from origin,
+                "Setting the maximum slice length to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_slice_len = 1;
+        }
+
+        if !settings.payload_alignment.is_power_of_two() {
+            let adjusted_alignment = settings.payload_alignment.next_power_of_two();
+            warn!(from origin,
+                "The payload alignment must be a power of two but {} was provided. Adjust it to {}, the next power of two.",
+                settings.payload_alignment, adjusted_alignment);
+            settings.payload_alignment = adjusted_alignment;
+        }
     }
 
     fn verify_service_properties(
@@ -450,6 +556,11 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
     ) -> Result<static_config::publish_subscribe::StaticConfig, PublishSubscribeOpenError> {
         let msg = "Unable to open publish subscribe service";
 
+        if let Some(attribute) = self.base.missing_required_attribute(existing_settings) {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attribute {:?}.", msg, attribute);
+        }
+
         let required_settings = self.base.service_config.publish_subscribe();
         let existing_settings = match &existing_settings.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -459,6 +570,21 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
             }
         };
 
+        if self.verify_type_fingerprint
+            && existing_settings.type_fingerprint != required_settings.type_fingerprint
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleTypeLayout,
+                                "{} since the service offers the type \"{}\" with the layout {:?} but the requested type \"{}\" has the layout {:?}.",
+                                msg, existing_settings.type_name, existing_settings.type_fingerprint,
+                                required_settings.type_name, required_settings.type_fingerprint);
+        }
+
+        if existing_settings.payload_transfer != required_settings.payload_transfer {
+            fail!(from self, with PublishSubscribeOpenError::IncompatiblePayloadTransfer,
+                                "{} since the service transfers the payload with {:?} but {:?} is required by the service variant.",
+                                msg, existing_settings.payload_transfer, required_settings.payload_transfer);
+        }
+
         if self.verify_number_of_publishers
             && existing_settings.max_publishers < required_settings.max_publishers
         {
@@ -501,6 +627,22 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                                 msg, existing_settings.subscriber_max_borrowed_samples, required_settings.subscriber_max_borrowed_samples);
         }
 
+        if self.verify_max_slice_len
+            && existing_settings.max_slice_len < required_settings.max_slice_len
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedMaxSliceLength,
+                                "{} since the service supports only a maximum slice length of {} but a maximum slice length of {} was requested.",
+                                msg, existing_settings.max_slice_len, required_settings.max_slice_len);
+        }
+
+        if self.verify_payload_alignment
+            && existing_settings.payload_alignment < required_settings.payload_alignment
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedPayloadAlignment,
+                                "{} since the service supports only a payload alignment of {} but a payload alignment of {} was requested.",
+                                msg, existing_settings.payload_alignment, required_settings.payload_alignment);
+        }
+
         if self.verify_enable_safe_overflow
             && existing_settings.enable_safe_overflow != required_settings.enable_safe_overflow
         {
@@ -509,6 +651,50 @@ impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceTy
                                 msg);
         }
 
+        if self.verify_enable_notifications
+            && existing_settings.enable_notifications != required_settings.enable_notifications
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleNotificationBehavior,
+                                "{} since the service has an incompatible notification behavior.",
+                                msg);
+        }
+
+        if self.verify_deadline
+            && !Self::is_within(existing_settings.deadline, required_settings.deadline)
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedDeadline,
+                                "{} since the service has a deadline of {:?} but a deadline of {:?} was requested.",
+                                msg, existing_settings.deadline, required_settings.deadline);
+        }
+
+        if self.verify_liveliness_lease_duration
+            && !Self::is_within(
+                existing_settings.liveliness_lease_duration,
+                required_settings.liveliness_lease_duration,
+            )
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedLivelinessLeaseDuration,
+                                "{} since the service has a liveliness lease duration of {:?} but a liveliness lease duration of {:?} was requested.",
+                                msg, existing_settings.liveliness_lease_duration, required_settings.liveliness_lease_duration);
+        }
+
+        if self.verify_lifespan
+            && !Self::is_within(existing_settings.lifespan, required_settings.lifespan)
+        {
+            fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedLifespan,
+                                "{} since the service has a sample lifespan of {:?} but a lifespan of {:?} was requested.",
+                                msg, existing_settings.lifespan, required_settings.lifespan);
+        }
+
         Ok(existing_settings.clone())
     }
+
+    // a service without a time limit never satisfies a requested time limit
+    fn is_within(existing: Option<Duration>, required: Option<Duration>) -> bool {
+        match (existing, required) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(existing), Some(required)) => existing <= required,
+        }
+    }
 }
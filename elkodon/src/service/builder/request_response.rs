@@ -0,0 +1,474 @@
+//! # Example
+//!
+//! See [`crate::service`]
+//!
+use crate::service;
+use crate::service::dynamic_config::request_response::DynamicConfigSettings;
+use crate::service::messaging_pattern::MessagingPattern;
+use crate::service::port_factory::request_response;
+use crate::service::*;
+use elkodon_bb_elementary::enum_gen;
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use elkodon_cal::serialize::Serialize;
+use elkodon_cal::static_storage::StaticStorageLocked;
+
+use super::ServiceState;
+
+/// Errors that can occur when an existing [`MessagingPattern::RequestResponse`] [`Service`] shall be opened.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseOpenError {
+    DoesNotExist,
+    InternalFailure,
+    IncompatibleTypes,
+    IncompatibleMessagingPattern,
+    IncompatibleAttributes,
+    DoesNotSupportRequestedAmountOfClients,
+    DoesNotSupportRequestedAmountOfServers,
+    DoesNotSupportRequestedAmountOfActiveRequests,
+    PermissionDenied,
+    ServiceInCorruptedState,
+    HangsInCreation,
+    UnableToOpenDynamicServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenError {}
+
+/// Errors that can occur when a new [`MessagingPattern::RequestResponse`] [`Service`] shall be created.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum RequestResponseCreateError {
+    Corrupted,
+    AlreadyExists,
+    PermissionDenied,
+    InternalFailure,
+    IsBeingCreatedByAnotherInstance,
+    UnableToCreateStaticServiceInformation,
+}
+
+impl std::fmt::Display for RequestResponseCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseCreateError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+enum ServiceAvailabilityState {
+    ServiceState(ServiceState),
+    IncompatibleTypes,
+}
+
+enum_gen! {
+    /// Errors that can occur when a [`MessagingPattern::RequestResponse`] [`Service`] shall be
+    /// created or opened.
+    RequestResponseOpenOrCreateError
+  mapping:
+    RequestResponseOpenError,
+    RequestResponseCreateError
+}
+
+impl std::fmt::Display for RequestResponseOpenOrCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for RequestResponseOpenOrCreateError {}
+
+/// Builder to create new [`MessagingPattern::RequestResponse`] based [`Service`]s
+///
+/// # Example
+///
+/// See [`crate::service`]
+#[derive(Debug)]
+pub struct Builder<'config, ServiceType: service::Details<'config>> {
+    base: builder::BuilderWithServiceType<'config, ServiceType>,
+    verify_number_of_clients: bool,
+    verify_number_of_servers: bool,
+    verify_max_active_requests: bool,
+}
+
+impl<'config, ServiceType: service::Details<'config>> Builder<'config, ServiceType> {
+    pub(crate) fn new(base: builder::BuilderWithServiceType<'config, ServiceType>) -> Self {
+        let mut new_self = Self {
+            base,
+            verify_number_of_clients: false,
+            verify_number_of_servers: false,
+            verify_max_active_requests: false,
+        };
+
+        new_self.base.service_config.messaging_pattern = MessagingPattern::RequestResponse(
+            static_config::request_response::StaticConfig::new(new_self.base.global_config),
+        );
+
+        new_self
+    }
+
+    fn config_details_mut(&mut self) -> &mut static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    fn config_details(&self) -> &static_config::request_response::StaticConfig {
+        match self.base.service_config.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            _ => {
+                fatal_panic!(from self, "This should never happen! Accessing wrong messaging pattern in RequestResponse builder!");
+            }
+        }
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::client::Client`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::client::Client`] must be at least supported.
+    pub fn max_clients(mut self, value: usize) -> Self {
+        self.config_details_mut().max_clients = value;
+        self.verify_number_of_clients = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many [`crate::port::server::Server`] shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many
+    /// [`crate::port::server::Server`] must be at least supported.
+    pub fn max_servers(mut self, value: usize) -> Self {
+        self.config_details_mut().max_servers = value;
+        self.verify_number_of_servers = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines how many requests a
+    /// [`crate::port::client::Client`] can have in flight at most. If an existing [`Service`]
+    /// is opened it defines the minimum required.
+    pub fn max_active_requests(mut self, value: usize) -> Self {
+        self.config_details_mut().max_active_requests = value;
+        self.verify_max_active_requests = true;
+        self
+    }
+
+    fn set_type_names<RequestType: Debug, ResponseType: Debug>(&mut self) {
+        let details = self.config_details_mut();
+        details.request_type_name = std::any::type_name::<RequestType>().to_string();
+        details.response_type_name = std::any::type_name::<ResponseType>().to_string();
+    }
+
+    fn is_service_available(
+        &mut self,
+        error_msg: &str,
+    ) -> Result<Option<(StaticConfig, ServiceType::StaticStorage)>, ServiceAvailabilityState> {
+        match self.base.is_service_available() {
+            Ok(Some((config, storage))) => {
+                let existing_settings = config.request_response();
+                let required_settings = self.config_details();
+                if existing_settings.request_type_name != required_settings.request_type_name
+                    || existing_settings.response_type_name != required_settings.response_type_name
+                {
+                    fail!(from self, with ServiceAvailabilityState::IncompatibleTypes,
+                        "{} since the service offers the types request: \"{}\", response: \"{}\" but the requested types are request: \"{}\", response: \"{}\".",
+                        error_msg, existing_settings.request_type_name, existing_settings.response_type_name,
+                        required_settings.request_type_name, required_settings.response_type_name);
+                }
+
+                Ok(Some((config, storage)))
+            }
+            Ok(None) => Ok(None),
+            Err(e) => Err(ServiceAvailabilityState::ServiceState(e)),
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create<RequestType: Debug, ResponseType: Debug>(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestType, ResponseType>,
+        RequestResponseOpenOrCreateError,
+    > {
+        let msg = "Unable to open or create request response service";
+        self.set_type_names::<RequestType, ResponseType>();
+
+        match self.is_service_available(msg) {
+            Ok(Some(_)) => Ok(self.open::<RequestType, ResponseType>()?),
+            Ok(None) => Ok(self.create::<RequestType, ResponseType>()?),
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => Ok(self.open::<RequestType, ResponseType>()?),
+            Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleTypes),
+                    "{} since the service is not type compatible.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::IncompatibleMessagingPattern),
+                    "{} since the services messaging pattern does not match.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::ServiceInCorruptedState),
+                    "{} since the service is in a corrupted state.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseOpenOrCreateError::RequestResponseOpenError(RequestResponseOpenError::PermissionDenied),
+                    "{} due to insufficient permissions to access the service.", msg);
+            }
+        }
+    }
+
+    /// Opens an existing [`Service`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "request_response::open",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn open<RequestType: Debug, ResponseType: Debug>(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestType, ResponseType>,
+        RequestResponseOpenError,
+    > {
+        let msg = "Unable to open request response service";
+        self.set_type_names::<RequestType, ResponseType>();
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            match self.is_service_available(msg) {
+                Ok(None) => {
+                    fail!(from self, with RequestResponseOpenError::DoesNotExist,
+                        "{} since the service does not exist.", msg);
+                }
+                Ok(Some((static_config, static_storage))) => {
+                    self.base.service_config.attributes = static_config.attributes().clone();
+                    let static_config = self.verify_service_properties(&static_config)?;
+
+                    let dynamic_config = fail!(from self, when self.base.open_dynamic_config_storage(),
+                            with RequestResponseOpenError::UnableToOpenDynamicServiceInformation,
+                            "{} since the dynamic service information could not be opened.", msg);
+
+                    self.base.service_config.messaging_pattern =
+                        MessagingPattern::RequestResponse(static_config);
+
+                    return Ok(request_response::PortFactory::new(ServiceType::from_state(
+                        service::ServiceState::new(
+                            self.base.service_config,
+                            self.base.global_config,
+                            dynamic_config,
+                            static_storage,
+                        ),
+                    )));
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IsBeingCreatedByAnotherInstance,
+                )) => {
+                    let timeout = fail!(from self, when adaptive_wait.wait(),
+                                        with RequestResponseOpenError::InternalFailure,
+                                        "{} since the adaptive wait failed.", msg);
+
+                    if timeout > self.base.global_config.global.service.creation_timeout {
+                        fail!(from self, with RequestResponseOpenError::HangsInCreation,
+                            "{} since the service hangs while being created, max timeout for service creation of {:?} exceeded. Waited for {:?} but the state did not change.",
+                            msg, self.base.global_config.global.service.creation_timeout, timeout);
+                    }
+                }
+                Err(ServiceAvailabilityState::IncompatibleTypes) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleTypes,
+                    "{} since the service is not type compatible.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(
+                    ServiceState::IncompatibleMessagingPattern,
+                )) => {
+                    fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                    "{} since the services messaging pattern does not match.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                    fail!(from self, with RequestResponseOpenError::ServiceInCorruptedState,
+                    "{} since the service is in a corrupted state.", msg);
+                }
+                Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                    fail!(from self, with RequestResponseOpenError::PermissionDenied,
+                    "{} due to insufficient permissions to access the service.", msg);
+                }
+            }
+        }
+    }
+
+    /// Creates a new [`Service`].
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(
+            name = "request_response::create",
+            level = "debug",
+            skip_all,
+            fields(service = %self.base.service_config.service_name())
+        )
+    )]
+    pub fn create<RequestType: Debug, ResponseType: Debug>(
+        mut self,
+    ) -> Result<
+        request_response::PortFactory<'config, ServiceType, RequestType, ResponseType>,
+        RequestResponseCreateError,
+    > {
+        self.adjust_properties_to_meaningful_values();
+
+        let msg = "Unable to create request response service";
+        self.set_type_names::<RequestType, ResponseType>();
+
+        match self.is_service_available(msg) {
+            Ok(None) => {
+                // create static config
+                let static_config = fail!(from self, when self.base.create_static_config_storage(),
+                    with RequestResponseCreateError::UnableToCreateStaticServiceInformation,
+                    "{} since the static service information could not be created.", msg);
+
+                let reqres_config = self.base.service_config.request_response();
+
+                // create dynamic config
+                let dynamic_config_setting = DynamicConfigSettings {
+                    number_of_clients: reqres_config.max_clients,
+                    number_of_servers: reqres_config.max_servers,
+                };
+
+                let dynamic_config = self.base.create_dynamic_config_storage(
+                    dynamic_config::MessagingPattern::RequestResponse(
+                        dynamic_config::request_response::DynamicConfig::new(
+                            &dynamic_config_setting,
+                        ),
+                    ),
+                    dynamic_config::request_response::DynamicConfig::memory_size(
+                        &dynamic_config_setting,
+                    ),
+                );
+                let dynamic_config = fail!(from self, when dynamic_config,
+                    with RequestResponseCreateError::InternalFailure,
+                    "{} since the dynamic service segment could not be created.", msg);
+
+                let service_config = fail!(from self, when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be serialized.", msg);
+
+                // only unlock the static details when the service is successfully created
+                let unlocked_static_details = fail!(from self, when static_config.unlock(service_config.as_slice()),
+                            with RequestResponseCreateError::Corrupted,
+                            "{} since the configuration could not be written to the static storage.", msg);
+                watcher::notify_service_watchers(self.base.global_config);
+
+                Ok(request_response::PortFactory::new(ServiceType::from_state(
+                    service::ServiceState::new(
+                        self.base.service_config.clone(),
+                        self.base.global_config,
+                        dynamic_config,
+                        unlocked_static_details,
+                    ),
+                )))
+            }
+            Ok(Some(_))
+            | Err(ServiceAvailabilityState::IncompatibleTypes)
+            | Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IncompatibleMessagingPattern,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::AlreadyExists,
+                    "{} since the service already exists.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::PermissionDenied)) => {
+                fail!(from self, with RequestResponseCreateError::PermissionDenied,
+                    "{} due to possible insufficient permissions to access the underlying service details.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(ServiceState::Corrupted)) => {
+                fail!(from self, with RequestResponseCreateError::Corrupted,
+                    "{} since a service in a corrupted state already exists. A cleanup of the service constructs may help.", msg);
+            }
+            Err(ServiceAvailabilityState::ServiceState(
+                ServiceState::IsBeingCreatedByAnotherInstance,
+            )) => {
+                fail!(from self, with RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                    "{} since the service is being created by another instance.", msg);
+            }
+        }
+    }
+
+    fn adjust_properties_to_meaningful_values(&mut self) {
+        let origin = format!("{:?}", self);
+        let settings = self.base.service_config.request_response_mut();
+
+        if settings.max_clients == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of clients to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_clients = 1;
+        }
+
+        if settings.max_servers == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of servers to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_servers = 1;
+        }
+
+        if settings.max_active_requests == 0 {
+            warn!(from origin,
+                "Setting the maximum amount of active requests to 0 is not supported. Adjust it to 1, the smallest supported value.");
+            settings.max_active_requests = 1;
+        }
+    }
+
+    fn verify_service_properties(
+        &self,
+        existing_settings: &static_config::StaticConfig,
+    ) -> Result<static_config::request_response::StaticConfig, RequestResponseOpenError> {
+        let msg = "Unable to open request response service";
+
+        if let Some(attribute) = self.base.missing_required_attribute(existing_settings) {
+            fail!(from self, with RequestResponseOpenError::IncompatibleAttributes,
+                "{} since the service does not have the required attribute {:?}.", msg, attribute);
+        }
+
+        let required_settings = self.base.service_config.request_response();
+        let existing_settings = match &existing_settings.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            p => {
+                fail!(from self, with RequestResponseOpenError::IncompatibleMessagingPattern,
+                "{} since a service with the messaging pattern {:?} exists but MessagingPattern::RequestResponse is required.", msg, p);
+            }
+        };
+
+        if self.verify_number_of_clients
+            && existing_settings.max_clients < required_settings.max_clients
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients,
+                                "{} since the service supports only {} clients but a support of {} clients was requested.",
+                                msg, existing_settings.max_clients, required_settings.max_clients);
+        }
+
+        if self.verify_number_of_servers
+            && existing_settings.max_servers < required_settings.max_servers
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers,
+                                "{} since the service supports only {} servers but a support of {} servers was requested.",
+                                msg, existing_settings.max_servers, required_settings.max_servers);
+        }
+
+        if self.verify_max_active_requests
+            && existing_settings.max_active_requests < required_settings.max_active_requests
+        {
+            fail!(from self, with RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests,
+                                "{} since the service supports only {} active requests per client but a support of {} active requests was requested.",
+                                msg, existing_settings.max_active_requests, required_settings.max_active_requests);
+        }
+
+        Ok(existing_settings.clone())
+    }
+}
@@ -0,0 +1,82 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ServiceName")?;
+//!
+//! // use `copy` as communication variant, the subscribers receive a copy of every sample
+//! let service = copy::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Every [`crate::port::subscriber::Subscriber`] owns a data segment and the
+//! [`crate::port::publisher::Publisher`] copies every sample into the data segments of its
+//! subscribers. A subscriber never maps the memory of a publisher and cannot exhaust it by
+//! holding on to samples. When the data segment or the buffer of a subscriber is full, the
+//! sample is discarded for this subscriber instead of blocking the publisher, independent of the
+//! [`crate::service::port_factory::publisher::UnableToDeliverStrategy`].
+//!
+//! Only the payload of the publish-subscribe messaging pattern is copied, all other messaging
+//! patterns behave exactly like in [`crate::service::zero_copy`].
+
+use crate::port::event_id::EventId;
+use crate::service::dynamic_config::DynamicConfig;
+use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
+use elkodon_cal::*;
+
+use super::{PayloadTransfer, ServiceState};
+
+/// Defines an inter-process communication setup based on posix mechanisms where every
+/// subscriber receives a copy of the payload.
+#[derive(Debug)]
+pub struct Service<'config> {
+    state: ServiceState<
+        'config,
+        static_storage::file::Storage,
+        dynamic_storage::posix_shared_memory::Storage<DynamicConfig>,
+    >,
+}
+
+impl<'config> crate::service::Service for Service<'config> {
+    type Type<'b> = Service<'b>;
+}
+
+impl<'config> crate::service::Details<'config> for Service<'config> {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::unix_datagram_socket::Event<EventId>;
+    type Monitoring = monitoring::file_lock::Monitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::linux_epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
+    type Reactor = reactor::posix_select::Reactor;
+
+    const PAYLOAD_TRANSFER: PayloadTransfer = PayloadTransfer::Copy;
+
+    fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
+        Self { state }
+    }
+
+    fn state(&self) -> &ServiceState<'config, Self::StaticStorage, Self::DynamicStorage> {
+        &self.state
+    }
+
+    fn state_mut(
+        &mut self,
+    ) -> &mut ServiceState<'config, Self::StaticStorage, Self::DynamicStorage> {
+        &mut self.state
+    }
+}
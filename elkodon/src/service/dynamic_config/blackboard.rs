@@ -0,0 +1,161 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .open_or_create()?;
+//!
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use elkodon_bb_elementary::allocator::BaseAllocator;
+use elkodon_bb_elementary::relocatable_container::RelocatableContainer;
+use elkodon_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
+use elkodon_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
+use elkodon_bb_log::fatal_panic;
+use elkodon_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::port::port_identifiers::{UniqueReaderId, UniqueWriterId};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_writers: usize,
+    pub number_of_entries: usize,
+    pub entries_size: usize,
+    pub entries_alignment: usize,
+}
+
+const NO_OWNER: u32 = 0;
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints and the memory
+/// of the blackboard entries.
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<UniqueReaderId>,
+    pub(crate) writers: Container<UniqueWriterId>,
+    // the writer that owns the mutable handle of an entry, identified by its index in the
+    // writers container plus one
+    entry_owners: RelocatablePointer<AtomicU32>,
+    number_of_entries: usize,
+    entries: RelocatablePointer<u8>,
+    entries_size: usize,
+    entries_alignment: usize,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+            entry_owners: unsafe { RelocatablePointer::new_uninit() },
+            number_of_entries: config.number_of_entries,
+            entries: unsafe { RelocatablePointer::new_uninit() },
+            entries_size: config.entries_size,
+            entries_alignment: config.entries_alignment,
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+
+        // zeroed memory corresponds to entries without an owner
+        self.entry_owners
+            .init(fatal_panic!(from "blackboard::DynamicConfig::init",
+            when allocator.allocate_zeroed(Layout::from_size_align_unchecked(
+                    std::mem::size_of::<AtomicU32>() * self.number_of_entries.max(1),
+                    std::mem::align_of::<AtomicU32>())),
+            "This should never happen! Unable to allocate the memory for the owners of the blackboard entries."));
+        self.entries
+            .init(fatal_panic!(from "blackboard::DynamicConfig::init",
+            when allocator.allocate_zeroed(Layout::from_size_align_unchecked(
+                    self.entries_size.max(1), self.entries_alignment)),
+            "This should never happen! Unable to allocate the memory for the blackboard entries."));
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<UniqueReaderId>::memory_size(config.number_of_readers)
+            + Container::<UniqueWriterId>::memory_size(config.number_of_writers)
+            + std::mem::size_of::<AtomicU32>() * config.number_of_entries.max(1)
+            + std::mem::align_of::<AtomicU32>()
+            - 1
+            + config.entries_size.max(1)
+            + config.entries_alignment
+            - 1
+    }
+
+    /// Returns how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    /// Returns the start address of the memory that contains the key table and all entries
+    /// of the blackboard.
+    pub(crate) fn entries(&self) -> *const u8 {
+        unsafe { self.entries.as_ptr() }
+    }
+
+    fn entry_owner(&self, index: usize) -> &AtomicU32 {
+        unsafe { &*self.entry_owners.as_ptr().add(index) }
+    }
+
+    /// Makes the writer with the index `writer_index` in the writers container the owner of
+    /// the entry `index`. Returns false when the entry is already owned.
+    pub(crate) fn acquire_entry(&self, index: usize, writer_index: u32) -> bool {
+        self.entry_owner(index)
+            .compare_exchange(
+                NO_OWNER,
+                writer_index + 1,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
+    pub(crate) fn release_entry(&self, index: usize) {
+        self.entry_owner(index).store(NO_OWNER, Ordering::Relaxed);
+    }
+
+    /// Releases all entries that are owned by the writer with the index `writer_index` in the
+    /// writers container.
+    pub(crate) fn release_entries_of_writer(&self, writer_index: u32) {
+        for index in 0..self.number_of_entries {
+            let _ = self.entry_owner(index).compare_exchange(
+                writer_index + 1,
+                NO_OWNER,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
+
+    pub(crate) fn add_reader_id(&self, id: UniqueReaderId) -> Option<UniqueIndex<'_>> {
+        unsafe { self.readers.add(id) }
+    }
+
+    pub(crate) fn add_writer_id(&self, id: UniqueWriterId) -> Option<UniqueIndex<'_>> {
+        unsafe { self.writers.add(id) }
+    }
+}
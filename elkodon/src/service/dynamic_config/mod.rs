@@ -1,20 +1,39 @@
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 /// based service.
 pub mod event;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service.
+pub mod pipeline;
+
 /// The dynamic service configuration of an
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 /// based service.
 pub mod publish_subscribe;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service.
+pub mod request_response;
+
 use std::{
-    fmt::Display,
+    fmt::{Debug, Display},
     sync::atomic::{AtomicU64, Ordering},
 };
 
+use elkodon_bb_lock_free::mpmc::container::Container;
 use elkodon_bb_log::{fail, fatal_panic};
 use elkodon_bb_memory::bump_allocator::BumpAllocator;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+
+use crate::port::port_identifiers::*;
 
 const MARKED_FOR_DESTRUCTION: u64 = u64::MAX - 1;
 
@@ -24,10 +43,45 @@ pub(crate) enum DecrementReferenceCounterResult {
     NoMoreOwners,
 }
 
+/// The id of a port that is registered in the [`DynamicConfig`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub(crate) enum PortId {
+    Publisher(UniquePublisherId),
+    Subscriber(UniqueSubscriberId),
+    Notifier(UniqueNotifierId),
+    Listener(UniqueListenerId),
+    Client(UniqueClientId),
+    Server(UniqueServerId),
+    Reader(UniqueReaderId),
+    Writer(UniqueWriterId),
+    Producer(UniqueProducerId),
+    Worker(UniqueWorkerId),
+}
+
+impl PortId {
+    pub(crate) fn system_id(&self) -> &UniqueSystemId {
+        match self {
+            PortId::Publisher(ref v) => &v.0,
+            PortId::Subscriber(ref v) => &v.0,
+            PortId::Notifier(ref v) => &v.0,
+            PortId::Listener(ref v) => &v.0,
+            PortId::Client(ref v) => &v.0,
+            PortId::Server(ref v) => &v.0,
+            PortId::Reader(ref v) => &v.0,
+            PortId::Writer(ref v) => &v.0,
+            PortId::Producer(ref v) => &v.0,
+            PortId::Worker(ref v) => &v.0,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    RequestResponse(request_response::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
+    Pipeline(pipeline::DynamicConfig),
 }
 
 #[doc(hidden)]
@@ -59,7 +113,105 @@ impl DynamicConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::RequestResponse(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
+            MessagingPattern::Pipeline(ref v) => v.init(allocator),
+        }
+    }
+
+    /// Calls the callback for every registered port. When the callback returns true the owner of
+    /// the port is dead, the callback has removed all of its resources and the port is removed
+    /// from the dynamic config.
+    ///
+    /// # Safety
+    ///
+    ///  * the callback must return true only when the caller is the only participant that
+    ///    cleans up the dead port
+    ///
+    pub(crate) unsafe fn remove_dead_ports<F: FnMut(PortId) -> bool>(&self, mut callback: F) {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => {
+                remove_dead_entries(&v.publishers, |id| callback(PortId::Publisher(*id)));
+                remove_dead_entries(&v.subscribers, |id| callback(PortId::Subscriber(*id)));
+            }
+            MessagingPattern::Event(ref v) => {
+                remove_dead_entries(&v.notifiers, |id| callback(PortId::Notifier(*id)));
+                remove_dead_entries(&v.listeners, |id| callback(PortId::Listener(*id)));
+            }
+            MessagingPattern::RequestResponse(ref v) => {
+                remove_dead_entries(&v.clients, |id| callback(PortId::Client(*id)));
+                remove_dead_entries(&v.servers, |id| callback(PortId::Server(*id)));
+            }
+            MessagingPattern::Blackboard(ref v) => {
+                remove_dead_entries(&v.readers, |id| callback(PortId::Reader(*id)));
+                // the entries owned by a dead writer must be released so that a new writer
+                // is able to acquire them
+                v.writers.get_state().for_each(|index, id| {
+                    if callback(PortId::Writer(*id)) {
+                        v.release_entries_of_writer(index);
+                        v.writers.remove_raw_index(index);
+                    }
+                });
+            }
+            MessagingPattern::Pipeline(ref v) => {
+                remove_dead_entries(&v.producers, |id| callback(PortId::Producer(*id)));
+                // the samples borrowed by a dead worker are put back into the work queue
+                v.workers.get_state().for_each(|index, id| {
+                    if callback(PortId::Worker(*id)) {
+                        v.remove_worker(index as usize);
+                        v.workers.remove_raw_index(index);
+                    }
+                });
+            }
+        }
+    }
+
+    /// Returns the ids of all registered ports. The list can be out of date as soon as it is
+    /// returned.
+    pub(crate) fn port_ids(&self) -> Vec<PortId> {
+        let mut ids = vec![];
+        unsafe {
+            match &self.messaging_pattern {
+                MessagingPattern::PublishSubscribe(ref v) => {
+                    collect_entries(&v.publishers, &mut ids, |id| PortId::Publisher(*id));
+                    collect_entries(&v.subscribers, &mut ids, |id| PortId::Subscriber(*id));
+                }
+                MessagingPattern::Event(ref v) => {
+                    collect_entries(&v.notifiers, &mut ids, |id| PortId::Notifier(*id));
+                    collect_entries(&v.listeners, &mut ids, |id| PortId::Listener(*id));
+                }
+                MessagingPattern::RequestResponse(ref v) => {
+                    collect_entries(&v.clients, &mut ids, |id| PortId::Client(*id));
+                    collect_entries(&v.servers, &mut ids, |id| PortId::Server(*id));
+                }
+                MessagingPattern::Blackboard(ref v) => {
+                    collect_entries(&v.writers, &mut ids, |id| PortId::Writer(*id));
+                    collect_entries(&v.readers, &mut ids, |id| PortId::Reader(*id));
+                }
+                MessagingPattern::Pipeline(ref v) => {
+                    collect_entries(&v.producers, &mut ids, |id| PortId::Producer(*id));
+                    collect_entries(&v.workers, &mut ids, |id| PortId::Worker(*id));
+                }
+            }
         }
+        ids
+    }
+
+    /// Returns the maximum number of ports that can be registered at the same time.
+    pub(crate) fn max_number_of_ports(&self) -> usize {
+        match &self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref v) => {
+                v.publishers.capacity() + v.subscribers.capacity()
+            }
+            MessagingPattern::Event(ref v) => v.notifiers.capacity() + v.listeners.capacity(),
+            MessagingPattern::RequestResponse(ref v) => v.clients.capacity() + v.servers.capacity(),
+            MessagingPattern::Blackboard(ref v) => v.writers.capacity() + v.readers.capacity(),
+            MessagingPattern::Pipeline(ref v) => v.producers.capacity() + v.workers.capacity(),
+        }
+    }
+
+    pub(crate) fn is_marked_for_destruction(&self) -> bool {
+        self.reference_counter.load(Ordering::Relaxed) == MARKED_FOR_DESTRUCTION
     }
 
     pub(crate) fn increment_reference_counter(&self) -> Result<(), ()> {
@@ -126,4 +278,52 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access request_response::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access pipeline::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+}
+
+unsafe fn collect_entries<T: Copy + Debug, F: Fn(&T) -> PortId>(
+    container: &Container<T>,
+    ids: &mut Vec<PortId>,
+    to_port_id: F,
+) {
+    container
+        .get_state()
+        .for_each(|_, id| ids.push(to_port_id(id)));
+}
+
+unsafe fn remove_dead_entries<T: Copy + Debug, F: FnMut(&T) -> bool>(
+    container: &Container<T>,
+    mut is_dead: F,
+) {
+    container.get_state().for_each(|index, id| {
+        if is_dead(id) {
+            container.remove_raw_index(index);
+        }
+    });
 }
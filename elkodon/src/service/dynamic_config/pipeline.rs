@@ -0,0 +1,277 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! println!("number of active producers:   {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active workers:     {:?}", pipeline.dynamic_config().number_of_workers());
+//! println!("sent samples:                 {:?}", pipeline.dynamic_config().number_of_sent_samples());
+//! println!("queued samples:               {:?}", pipeline.dynamic_config().number_of_queued_samples());
+//! println!("samples in process:           {:?}", pipeline.dynamic_config().number_of_samples_in_process());
+//! println!("processed samples:            {:?}", pipeline.dynamic_config().number_of_processed_samples());
+//! println!("reclaimed samples:            {:?}", pipeline.dynamic_config().number_of_reclaimed_samples());
+//! # Ok(())
+//! # }
+//! ```
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use elkodon_bb_elementary::allocator::BaseAllocator;
+use elkodon_bb_elementary::relocatable_container::RelocatableContainer;
+use elkodon_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
+use elkodon_bb_lock_free::mpmc::index_queue::RelocatableIndexQueue;
+use elkodon_bb_lock_free::mpmc::unique_index_set::UniqueIndexSet;
+use elkodon_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
+use elkodon_bb_log::fatal_panic;
+use elkodon_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::port::port_identifiers::{UniqueProducerId, UniqueWorkerId};
+
+// The state of a sample consists of its generation, which is increased whenever the sample is
+// put into the work queue, a flag that marks it as queued and the index + 1 of the worker that
+// borrowed it. An entry of the work queue contains the sample index and its generation so that
+// an entry which was left behind by a crashed worker is never taken.
+const INDEX_BITS: u32 = usize::BITS / 2;
+const INDEX_MASK: usize = (1 << INDEX_BITS) - 1;
+const GENERATION_MASK: u64 = INDEX_MASK as u64;
+const IS_QUEUED: u64 = 1 << 32;
+const OWNER_SHIFT: u32 = 33;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_producers: usize,
+    pub number_of_workers: usize,
+    pub number_of_samples: usize,
+    pub work_queue_capacity: usize,
+    pub sample_size: usize,
+    pub sample_alignment: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Pipeline`]
+/// based service. Contains dynamic parameters like the connected endpoints, the shared work
+/// queue and the statistics of every pipeline stage.
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) producers: Container<UniqueProducerId>,
+    pub(crate) workers: Container<UniqueWorkerId>,
+    free_samples: UniqueIndexSet,
+    work_queue: RelocatableIndexQueue,
+    sample_states: RelocatablePointer<AtomicU64>,
+    samples: RelocatablePointer<u8>,
+    number_of_samples: usize,
+    work_queue_capacity: usize,
+    sample_size: usize,
+    sample_alignment: usize,
+    sent_samples: AtomicU64,
+    processed_samples: AtomicU64,
+    reclaimed_samples: AtomicU64,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            producers: unsafe { Container::new_uninit(config.number_of_producers) },
+            workers: unsafe { Container::new_uninit(config.number_of_workers) },
+            free_samples: unsafe { UniqueIndexSet::new_uninit(config.number_of_samples) },
+            work_queue: unsafe { RelocatableIndexQueue::new_uninit(Self::work_queue_size(config)) },
+            sample_states: unsafe { RelocatablePointer::new_uninit() },
+            samples: unsafe { RelocatablePointer::new_uninit() },
+            number_of_samples: config.number_of_samples,
+            work_queue_capacity: config.work_queue_capacity,
+            sample_size: config.sample_size,
+            sample_alignment: config.sample_alignment,
+            sent_samples: AtomicU64::new(0),
+            processed_samples: AtomicU64::new(0),
+            reclaimed_samples: AtomicU64::new(0),
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.producers.init(allocator),
+            "This should never happen! Unable to initialize producer port id container.");
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.workers.init(allocator),
+            "This should never happen! Unable to initialize worker port id container.");
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.free_samples.init(allocator),
+            "This should never happen! Unable to initialize the free sample index set.");
+        fatal_panic!(from "pipeline::DynamicConfig::init",
+            when self.work_queue.init(allocator),
+            "This should never happen! Unable to initialize the work queue.");
+        self.sample_states
+            .init(fatal_panic!(from "pipeline::DynamicConfig::init",
+            when allocator.allocate_zeroed(Layout::from_size_align_unchecked(
+                    std::mem::size_of::<AtomicU64>() * self.number_of_samples,
+                    std::mem::align_of::<AtomicU64>())),
+            "This should never happen! Unable to allocate the memory for the sample states."));
+        self.samples
+            .init(fatal_panic!(from "pipeline::DynamicConfig::init",
+            when allocator.allocate(Layout::from_size_align_unchecked(
+                    self.sample_size * self.number_of_samples, self.sample_alignment)),
+            "This should never happen! Unable to allocate the memory for the samples."));
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<UniqueProducerId>::memory_size(config.number_of_producers)
+            + Container::<UniqueWorkerId>::memory_size(config.number_of_workers)
+            + UniqueIndexSet::memory_size(config.number_of_samples)
+            + RelocatableIndexQueue::memory_size(Self::work_queue_size(config))
+            + std::mem::size_of::<AtomicU64>() * config.number_of_samples
+            + std::mem::align_of::<AtomicU64>()
+            - 1
+            + config.sample_size * config.number_of_samples
+            + config.sample_alignment
+            - 1
+    }
+
+    // every sample is at most once in the work queue, additionally every worker that crashed
+    // while it took a sample may leave one entry behind
+    fn work_queue_size(config: &DynamicConfigSettings) -> usize {
+        config.number_of_samples + config.number_of_workers
+    }
+
+    /// Returns how many [`crate::port::producer::Producer`] ports are currently connected.
+    pub fn number_of_producers(&self) -> usize {
+        self.producers.len()
+    }
+
+    /// Returns how many [`crate::port::worker::Worker`] ports are currently connected.
+    pub fn number_of_workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Returns how many samples were put into the work queue by all
+    /// [`crate::port::producer::Producer`]s.
+    pub fn number_of_sent_samples(&self) -> u64 {
+        self.sent_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns how many samples are currently waiting in the work queue. It may contain
+    /// entries left behind by crashed workers until they are removed by the next worker.
+    pub fn number_of_queued_samples(&self) -> usize {
+        self.work_queue.len()
+    }
+
+    /// Returns how many samples are currently held by [`crate::port::worker::Worker`]s.
+    pub fn number_of_samples_in_process(&self) -> usize {
+        (0..self.number_of_samples)
+            .filter(|i| self.sample_state(*i).load(Ordering::Relaxed) >> OWNER_SHIFT != 0)
+            .count()
+    }
+
+    /// Returns how many samples were processed and released by all
+    /// [`crate::port::worker::Worker`]s.
+    pub fn number_of_processed_samples(&self) -> u64 {
+        self.processed_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns how many samples were put back into the work queue since the
+    /// [`crate::port::worker::Worker`] holding them was dropped or has crashed.
+    pub fn number_of_reclaimed_samples(&self) -> u64 {
+        self.reclaimed_samples.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn add_producer_id(&self, id: UniqueProducerId) -> Option<UniqueIndex<'_>> {
+        unsafe { self.producers.add(id) }
+    }
+
+    pub(crate) fn add_worker_id(&self, id: UniqueWorkerId) -> Option<UniqueIndex<'_>> {
+        unsafe { self.workers.add(id) }
+    }
+
+    /// Returns all samples the worker still holds to the work queue. Must be called before the
+    /// [`UniqueIndex`] of the worker is released, either by the worker itself or by the one
+    /// participant that removes the worker after its process died.
+    pub(crate) fn remove_worker(&self, worker_index: usize) {
+        let owner = Self::owner(worker_index);
+        for index in 0..self.number_of_samples {
+            if self.sample_state(index).load(Ordering::Relaxed) >> OWNER_SHIFT == owner {
+                self.push_to_work_queue(index);
+                self.reclaimed_samples.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Returns the address of the sample with the given index.
+    pub(crate) fn sample(&self, index: usize) -> *mut u8 {
+        unsafe { (self.samples.as_ptr() as *mut u8).add(index * self.sample_size) }
+    }
+
+    /// Acquires the index of an unused sample. If all samples are in use it returns [`None`].
+    pub(crate) fn acquire_sample(&self) -> Option<usize> {
+        unsafe { self.free_samples.acquire_raw_index() }.map(|index| index as usize)
+    }
+
+    /// Returns an unused or processed sample so that it can be loaned again.
+    pub(crate) fn release_sample(&self, index: usize) {
+        unsafe { self.free_samples.release_raw_index(index as u32) }
+    }
+
+    /// Puts a sample into the work queue. Returns false when the work queue is full.
+    pub(crate) fn enqueue_sample(&self, index: usize) -> bool {
+        if self.work_queue.len() >= self.work_queue_capacity {
+            return false;
+        }
+
+        self.push_to_work_queue(index);
+        self.sent_samples.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
+    /// Takes the oldest sample from the work queue and marks it as borrowed by the worker.
+    /// Returns [`None`] when the work queue is empty.
+    pub(crate) fn dequeue_sample(&self, worker_index: usize) -> Option<usize> {
+        let borrowed = Self::owner(worker_index) << OWNER_SHIFT;
+
+        // the sample is marked as borrowed before its entry is removed from the work queue,
+        // when the worker crashes in between the sample is reclaimed and the entry is skipped
+        self.work_queue
+            .pop_with(|entry| {
+                let generation = (entry >> INDEX_BITS) as u64;
+                self.sample_state(entry & INDEX_MASK)
+                    .compare_exchange(
+                        IS_QUEUED | generation,
+                        borrowed | generation,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    )
+                    .is_ok()
+            })
+            .map(|entry| entry & INDEX_MASK)
+    }
+
+    /// Releases a sample that was processed by a worker.
+    pub(crate) fn release_borrowed_sample(&self, index: usize) {
+        self.sample_state(index)
+            .fetch_and(GENERATION_MASK, Ordering::Relaxed);
+        self.release_sample(index);
+        self.processed_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn push_to_work_queue(&self, index: usize) {
+        let state = self.sample_state(index);
+        let generation = state.load(Ordering::Relaxed).wrapping_add(1) & GENERATION_MASK;
+        state.store(IS_QUEUED | generation, Ordering::Relaxed);
+
+        // the work queue has room for every sample and the entries of crashed workers, a push
+        // can never fail
+        self.work_queue
+            .push(index | (generation as usize) << INDEX_BITS);
+    }
+
+    fn owner(worker_index: usize) -> u64 {
+        worker_index as u64 + 1
+    }
+
+    fn sample_state(&self, index: usize) -> &AtomicU64 {
+        unsafe { &*self.sample_states.as_ptr().add(index) }
+    }
+}
@@ -14,13 +14,37 @@
 //! # Ok(())
 //! # }
 //! ```
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use elkodon_bb_elementary::allocator::BaseAllocator;
 use elkodon_bb_elementary::relocatable_container::RelocatableContainer;
+use elkodon_bb_elementary::relocatable_ptr::{PointerTrait, RelocatablePointer};
 use elkodon_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
 use elkodon_bb_log::fatal_panic;
 use elkodon_bb_memory::bump_allocator::BumpAllocator;
+use elkodon_bb_posix::clock::{ClockType, Time};
 
 use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 
+// the monotonic time in nanoseconds, it is comparable between processes on the same host
+pub(crate) fn monotonic_time() -> u64 {
+    fatal_panic!(from "publish_subscribe::monotonic_time()",
+        when Time::now_with_clock(ClockType::Monotonic),
+        "This should never happen! Unable to acquire the monotonic time.")
+    .as_duration()
+    .as_nanos() as u64
+}
+
+// the state of a publisher that is shared with all other ports of the service
+#[derive(Debug)]
+struct PublisherDetails {
+    loaned_samples: AtomicU64,
+    last_send: AtomicU64,
+    last_liveliness_assertion: AtomicU64,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct DynamicConfigSettings {
     pub number_of_subscribers: usize,
@@ -33,6 +57,9 @@ pub(crate) struct DynamicConfigSettings {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<UniqueSubscriberId>,
     pub(crate) publishers: Container<UniquePublisherId>,
+    // the details of every publisher, identified by its index in the publishers container
+    publisher_details: RelocatablePointer<PublisherDetails>,
+    number_of_publishers: usize,
 }
 
 impl DynamicConfig {
@@ -40,6 +67,8 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            publisher_details: unsafe { RelocatablePointer::new_uninit() },
+            number_of_publishers: config.number_of_publishers,
         }
     }
 
@@ -50,11 +79,20 @@ impl DynamicConfig {
         fatal_panic!(from "publish_subscribe::DynamicConfig::init",
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        self.publisher_details
+            .init(fatal_panic!(from "publish_subscribe::DynamicConfig::init",
+            when allocator.allocate_zeroed(Layout::from_size_align_unchecked(
+                    std::mem::size_of::<PublisherDetails>() * self.number_of_publishers.max(1),
+                    std::mem::align_of::<PublisherDetails>())),
+            "This should never happen! Unable to allocate the memory for the publisher details."));
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<UniqueSubscriberId>::memory_size(config.number_of_subscribers)
             + Container::<UniquePublisherId>::memory_size(config.number_of_publishers)
+            + std::mem::size_of::<PublisherDetails>() * config.number_of_publishers.max(1)
+            + std::mem::align_of::<PublisherDetails>()
+            - 1
     }
 
     /// Returns how many [`crate::port::publisher::Publisher`] ports are currently connected.
@@ -67,6 +105,86 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Returns the ids of all currently connected [`crate::port::publisher::Publisher`] ports
+    /// together with the number of samples they have currently loaned.
+    pub(crate) fn publishers_with_loaned_samples(&self) -> Vec<(UniquePublisherId, u64)> {
+        let mut publishers = vec![];
+        unsafe {
+            self.publishers
+                .get_state()
+                .for_each(|index, id| publishers.push((*id, self.loaned_samples(index))))
+        };
+        publishers
+    }
+
+    fn loaned_samples(&self, publisher_index: u32) -> u64 {
+        self.publisher_details(publisher_index)
+            .loaned_samples
+            .load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn set_loaned_samples(&self, publisher_index: u32, value: usize) {
+        self.publisher_details(publisher_index)
+            .loaned_samples
+            .store(value as u64, Ordering::Relaxed);
+    }
+
+    // the details of a previous publisher with the same index may not be reset when it died
+    pub(crate) fn reset_publisher_details(&self, publisher_index: u32) {
+        let details = self.publisher_details(publisher_index);
+        let now = monotonic_time();
+        details.loaned_samples.store(0, Ordering::Relaxed);
+        details.last_send.store(now, Ordering::Relaxed);
+        details
+            .last_liveliness_assertion
+            .store(now, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_last_send(&self, publisher_index: u32, time: u64) {
+        self.publisher_details(publisher_index)
+            .last_send
+            .store(time, Ordering::Relaxed);
+    }
+
+    pub(crate) fn set_last_liveliness_assertion(&self, publisher_index: u32, time: u64) {
+        self.publisher_details(publisher_index)
+            .last_liveliness_assertion
+            .store(time, Ordering::Relaxed);
+    }
+
+    /// Returns how much time has passed since the publisher with the provided index sent its
+    /// last sample.
+    pub(crate) fn time_since_last_send(&self, publisher_index: u32) -> Duration {
+        Self::time_since(
+            self.publisher_details(publisher_index)
+                .last_send
+                .load(Ordering::Relaxed),
+        )
+    }
+
+    /// Returns how much time has passed since the publisher with the provided index asserted
+    /// its liveliness for the last time.
+    pub(crate) fn time_since_last_liveliness_assertion(&self, publisher_index: u32) -> Duration {
+        Self::time_since(
+            self.publisher_details(publisher_index)
+                .last_liveliness_assertion
+                .load(Ordering::Relaxed),
+        )
+    }
+
+    fn time_since(time: u64) -> Duration {
+        Duration::from_nanos(monotonic_time().saturating_sub(time))
+    }
+
+    fn publisher_details(&self, publisher_index: u32) -> &PublisherDetails {
+        unsafe {
+            &*self
+                .publisher_details
+                .as_ptr()
+                .add(publisher_index as usize)
+        }
+    }
+
     pub(crate) fn add_subscriber_id(&self, id: UniqueSubscriberId) -> Option<UniqueIndex> {
         unsafe { self.subscribers.add(id) }
     }
@@ -0,0 +1,78 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! println!("number of active clients:         {:?}", reqres.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", reqres.dynamic_config().number_of_servers());
+//! # Ok(())
+//! # }
+//! ```
+use elkodon_bb_elementary::relocatable_container::RelocatableContainer;
+use elkodon_bb_lock_free::mpmc::{container::*, unique_index_set::UniqueIndex};
+use elkodon_bb_log::fatal_panic;
+use elkodon_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_clients: usize,
+    pub number_of_servers: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::RequestResponse`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) clients: Container<UniqueClientId>,
+    pub(crate) servers: Container<UniqueServerId>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            clients: unsafe { Container::new_uninit(config.number_of_clients) },
+            servers: unsafe { Container::new_uninit(config.number_of_servers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "request_response::DynamicConfig::init",
+            when self.clients.init(allocator),
+            "This should never happen! Unable to initialize client port id container.");
+        fatal_panic!(from "request_response::DynamicConfig::init",
+            when self.servers.init(allocator),
+            "This should never happen! Unable to initialize server port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<UniqueClientId>::memory_size(config.number_of_clients)
+            + Container::<UniqueServerId>::memory_size(config.number_of_servers)
+    }
+
+    /// Returns how many [`crate::port::client::Client`] ports are currently connected.
+    pub fn number_of_clients(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns how many [`crate::port::server::Server`] ports are currently connected.
+    pub fn number_of_servers(&self) -> usize {
+        self.servers.len()
+    }
+
+    pub(crate) fn add_client_id(&self, id: UniqueClientId) -> Option<UniqueIndex<'_>> {
+        unsafe { self.clients.add(id) }
+    }
+
+    pub(crate) fn add_server_id(&self, id: UniqueServerId) -> Option<UniqueIndex<'_>> {
+        unsafe { self.servers.add(id) }
+    }
+}
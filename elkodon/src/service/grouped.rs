@@ -0,0 +1,84 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ServiceName")?;
+//!
+//! // use `grouped` as communication variant
+//! let service = grouped::Service::new(&service_name)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! let publisher = service.publisher().create()?;
+//! let subscriber = service.subscriber().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Behaves like [`crate::service::zero_copy`] but places the connections between all ports and
+//! the dynamic configurations of all services into a few
+//! [`elkodon_cal::shared_memory_directory::SharedMemoryDirectory`]s instead of creating one
+//! shared memory object for each of them. It is intended for huge setups with thousands of
+//! connections that would otherwise exceed the operating system limits of shared memory objects
+//! and file descriptors. The data segments of the publishers are still separate shared memory
+//! objects.
+//!
+//! The directories are created on first use and are never removed automatically. The memory of
+//! a connection or dynamic configuration that was held by a crashed process is not released
+//! until the directory is removed.
+
+use crate::port::event_id::EventId;
+use crate::service::dynamic_config::DynamicConfig;
+use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
+use elkodon_cal::*;
+
+use super::{PayloadTransfer, ServiceState};
+
+/// Defines a zero copy inter-process communication setup for huge systems where the connections
+/// and dynamic configurations are grouped into a few shared memory objects.
+#[derive(Debug)]
+pub struct Service<'config> {
+    state: ServiceState<
+        'config,
+        static_storage::file::Storage,
+        dynamic_storage::shared_memory_directory::Storage<DynamicConfig>,
+    >,
+}
+
+impl<'config> crate::service::Service for Service<'config> {
+    type Type<'b> = Service<'b>;
+}
+
+impl<'config> crate::service::Details<'config> for Service<'config> {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::shared_memory_directory::Storage<DynamicConfig>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
+    type Connection = zero_copy_connection::shared_memory_directory::Connection;
+    type Event = event::unix_datagram_socket::Event<EventId>;
+    type Monitoring = monitoring::file_lock::Monitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::linux_epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
+    type Reactor = reactor::posix_select::Reactor;
+
+    const PAYLOAD_TRANSFER: PayloadTransfer = PayloadTransfer::ZeroCopy;
+
+    fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
+        Self { state }
+    }
+
+    fn state(&self) -> &ServiceState<'config, Self::StaticStorage, Self::DynamicStorage> {
+        &self.state
+    }
+
+    fn state_mut(
+        &mut self,
+    ) -> &mut ServiceState<'config, Self::StaticStorage, Self::DynamicStorage> {
+        &mut self.state
+    }
+}
@@ -0,0 +1,39 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let worker = service.worker().create()?;
+//!
+//! while let Some(work_item) = worker.receive()? {
+//!     println!("header: {:?}", work_item.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::port::port_identifiers::UniqueProducerId;
+
+/// Message header used by
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+#[derive(Debug)]
+#[repr(C)]
+pub struct Header {
+    producer_port_id: UniqueProducerId,
+}
+
+impl Header {
+    pub(crate) fn new(producer_port_id: UniqueProducerId) -> Self {
+        Self { producer_port_id }
+    }
+
+    /// Returns the [`UniqueProducerId`] of the source [`crate::port::producer::Producer`].
+    pub fn producer_id(&self) -> UniqueProducerId {
+        self.producer_port_id
+    }
+}
@@ -18,7 +18,10 @@
 //! # Ok(())
 //! # }
 //! ```
-use elkodon_bb_posix::clock::{Time, TimeBuilder};
+use std::time::Duration;
+
+use elkodon_bb_log::fatal_panic;
+use elkodon_bb_posix::clock::{ClockType, Time, TimeBuilder};
 
 use crate::port::port_identifiers::UniquePublisherId;
 
@@ -29,6 +32,26 @@ struct TimeStamp {
     nanoseconds: u32,
 }
 
+impl TimeStamp {
+    // the monotonic clock is used so that the age of a sample is not affected when the system
+    // time is adjusted, it is comparable between processes on the same host
+    fn now() -> Self {
+        let now = fatal_panic!(from "TimeStamp::now()",
+            when Time::now_with_clock(ClockType::Monotonic),
+            "This should never happen! Unable to acquire the monotonic time.");
+        Self {
+            seconds: now.seconds(),
+            nanoseconds: now.nanoseconds(),
+        }
+    }
+
+    fn as_duration(&self) -> Duration {
+        // the header is shared with other processes and must never cause an overflow
+        Duration::from_secs(self.seconds)
+            .saturating_add(Duration::from_nanos(self.nanoseconds as u64))
+    }
+}
+
 /// Message header used by
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 #[derive(Debug)]
@@ -36,30 +59,59 @@ struct TimeStamp {
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     time_stamp: TimeStamp,
+    number_of_elements: u64,
+    sequence_number: u64,
 }
 
 impl Header {
-    pub(crate) fn new(publisher_port_id: UniquePublisherId) -> Self {
-        let now = Time::now().unwrap();
+    pub(crate) fn new(publisher_port_id: UniquePublisherId, number_of_elements: u64) -> Self {
         Self {
             publisher_port_id,
-            time_stamp: TimeStamp {
-                seconds: now.seconds(),
-                nanoseconds: now.nanoseconds(),
-            },
+            time_stamp: TimeStamp::now(),
+            number_of_elements,
+            sequence_number: 0,
         }
     }
 
+    // called on send so that the time a sample was loaned does not count into its age and
+    // samples that were loaned but never sent do not leave a gap in the sequence
+    pub(crate) fn prepare_delivery(&mut self, sequence_number: u64) {
+        self.time_stamp = TimeStamp::now();
+        self.sequence_number = sequence_number;
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
     }
 
-    /// Returns the [`Time`] when the [`crate::sample::Sample`] was delivered.
+    /// Returns the [`Time`] when the [`crate::sample::Sample`] was delivered. It is based on
+    /// [`ClockType::Monotonic`].
     pub fn time_stamp(&self) -> Time {
         TimeBuilder::new()
+            .clock_type(ClockType::Monotonic)
             .nanoseconds(self.time_stamp.nanoseconds)
             .seconds(self.time_stamp.seconds)
             .create()
     }
+
+    /// Returns how much time has passed since the [`crate::sample::Sample`] was delivered.
+    pub fn age(&self) -> Duration {
+        TimeStamp::now()
+            .as_duration()
+            .saturating_sub(self.time_stamp.as_duration())
+    }
+
+    /// Returns the sequence number of the [`crate::sample::Sample`]. Every
+    /// [`crate::port::publisher::Publisher`] numbers the samples it sends consecutively,
+    /// starting with 0.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns how many elements are stored in the payload. If the payload is not a slice it is
+    /// always 1.
+    pub fn number_of_elements(&self) -> u64 {
+        self.number_of_elements
+    }
 }
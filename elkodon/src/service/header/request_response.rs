@@ -0,0 +1,83 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let service = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let client = service.client().create()?;
+//!
+//! let request = client.loan()?;
+//! println!("request header: {:?}", request.header());
+//!
+//! while let Some(response) = client.receive()? {
+//!     println!("response header: {:?}", response.header());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+
+/// Request header used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+#[derive(Debug)]
+#[repr(C)]
+pub struct RequestHeader {
+    client_port_id: UniqueClientId,
+    request_id: u64,
+}
+
+impl RequestHeader {
+    pub(crate) fn new(client_port_id: UniqueClientId, request_id: u64) -> Self {
+        Self {
+            client_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueClientId`] of the source [`crate::port::client::Client`].
+    pub fn client_id(&self) -> UniqueClientId {
+        self.client_port_id
+    }
+
+    /// Returns the id of the request. It is unique for every request of a
+    /// [`crate::port::client::Client`] and is part of every
+    /// [`crate::response::Response`] that answers it.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
+
+/// Response header used by
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+#[derive(Debug)]
+#[repr(C)]
+pub struct ResponseHeader {
+    server_port_id: UniqueServerId,
+    request_id: u64,
+}
+
+impl ResponseHeader {
+    pub(crate) fn new(server_port_id: UniqueServerId, request_id: u64) -> Self {
+        Self {
+            server_port_id,
+            request_id,
+        }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`crate::port::server::Server`] that sent the
+    /// response.
+    pub fn server_id(&self) -> UniqueServerId {
+        self.server_port_id
+    }
+
+    /// Returns the id of the request this response answers, see
+    /// [`RequestHeader::request_id()`].
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+}
@@ -19,8 +19,34 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Request-Response
+//!
+//! See the
+//! [Wikipedia Article: Request-response](https://en.wikipedia.org/wiki/Request%E2%80%93response).
+//! `n` [`Client`](crate::port::client::Client)s send requests to `m`
+//! [`Server`](crate::port::server::Server)s. Every response is delivered only to the
+//! [`Client`](crate::port::client::Client) that sent the corresponding request.
+//!
+//! ### Blackboard
+//!
+//! A shared key-value store where every entry holds the latest value of a specific type. The
+//! entries are fixed when the service is created. One [`Writer`](crate::port::writer::Writer)
+//! updates the entries while `m` [`Reader`](crate::port::reader::Reader)s read them
+//! lock-free and always get a consistent value.
+//!
+//! ### Pipeline
+//!
+//! Distributes work load between processes. `n` [`Producer`](crate::port::producer::Producer)s
+//! put samples into a shared work queue and every sample is taken by exactly one of the `m`
+//! [`Worker`](crate::port::worker::Worker)s. When a [`Worker`](crate::port::worker::Worker)
+//! crashes while it holds samples, those samples are put back into the work queue so that
+//! another [`Worker`](crate::port::worker::Worker) can process them.
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
+use crate::service::static_config::pipeline;
 use crate::service::static_config::publish_subscribe;
+use crate::service::static_config::request_response;
 use serde::{Deserialize, Serialize};
 
 /// Contains the static config of the corresponding messaging pattern.
@@ -30,6 +56,9 @@ use serde::{Deserialize, Serialize};
 pub enum MessagingPattern {
     PublishSubscribe(publish_subscribe::StaticConfig),
     Event(event::StaticConfig),
+    RequestResponse(request_response::StaticConfig),
+    Blackboard(blackboard::StaticConfig),
+    Pipeline(pipeline::StaticConfig),
 }
 
 impl MessagingPattern {
@@ -41,6 +70,15 @@ impl MessagingPattern {
             MessagingPattern::Event(_) => {
                 matches!(rhs, MessagingPattern::Event(_))
             }
+            MessagingPattern::RequestResponse(_) => {
+                matches!(rhs, MessagingPattern::RequestResponse(_))
+            }
+            MessagingPattern::Blackboard(_) => {
+                matches!(rhs, MessagingPattern::Blackboard(_))
+            }
+            MessagingPattern::Pipeline(_) => {
+                matches!(rhs, MessagingPattern::Pipeline(_))
+            }
         }
     }
 
@@ -59,4 +97,16 @@ impl MessagingPattern {
             _ => 0,
         }
     }
+
+    // every publisher can have at most as many samples in flight to a subscriber as the
+    // retrieve channel of their connection can hold
+    pub(crate) fn required_amount_of_samples_per_receive_segment(&self) -> usize {
+        match self {
+            MessagingPattern::PublishSubscribe(v) => {
+                v.max_publishers
+                    * (v.subscriber_max_buffer_size + v.subscriber_max_borrowed_samples + 1)
+            }
+            _ => 0,
+        }
+    }
 }
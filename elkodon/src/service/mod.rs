@@ -46,6 +46,73 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/RequestResponse")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // define the messaging pattern
+//!     .request_response()
+//!     // various QoS
+//!     .max_clients(4)
+//!     .max_servers(2)
+//!     .max_active_requests(3)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Pipeline
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Pipeline")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // define the messaging pattern
+//!     .pipeline()
+//!     // various QoS
+//!     .max_producers(2)
+//!     .max_workers(4)
+//!     .work_queue_capacity(8)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create::<u64>()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//!
+//! let service = zero_copy::Service::new(&service_name)
+//!     // define the messaging pattern and the type of the keys
+//!     .blackboard::<u64>()
+//!     // various QoS
+//!     .max_readers(4)
+//!     // the entries with their initial values
+//!     .add::<u32>(0, 0)
+//!     .add::<f32>(1, 1.5)
+//!     // if the service already exists, open it, otherwise create it
+//!     .open_or_create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! ## Publish-Subscribe With Custom Configuration
 //!
 //! ```
@@ -88,6 +155,9 @@
 //! # }
 //! ```
 
+/// User-defined key/value attributes of a [`Service`]
+pub mod attribute;
+
 /// The builder to create or open [`Service`]s
 pub mod builder;
 
@@ -123,22 +193,39 @@ pub mod process_local;
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod zero_copy;
 
+/// A configuration when communicating between different processes using posix mechanisms where
+/// every subscriber receives a copy of the payload.
+pub mod copy;
+
+/// A configuration when communicating between different processes in huge setups where the
+/// connections and dynamic configurations are grouped into a few shared memory objects.
+pub mod grouped;
+
+/// Reports when [`Service`]s are created or removed or when their ports change
+pub mod watcher;
+
 use std::fmt::Debug;
 
 use crate::config;
 use crate::port::event_id::EventId;
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::port::port_identifiers::{
+    UniqueClientId, UniquePublisherId, UniqueServerId, UniqueSubscriberId,
+};
+use crate::service::attribute::AttributeSet;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::static_config::*;
 use elkodon_bb_container::semantic_string::SemanticString;
 use elkodon_bb_log::{fail, fatal_panic, trace, warn};
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
 use elkodon_bb_system_types::file_name::FileName;
 use elkodon_bb_system_types::path::Path;
-use elkodon_cal::dynamic_storage::DynamicStorage;
+use elkodon_cal::dynamic_storage::{DynamicStorage, DynamicStorageBuilder};
 use elkodon_cal::event::Event;
 use elkodon_cal::hash::Hash;
+use elkodon_cal::monitoring::Monitoring;
 use elkodon_cal::named_concept::NamedConceptListError;
 use elkodon_cal::named_concept::*;
+use elkodon_cal::reactor::Reactor;
 use elkodon_cal::serialize::Serialize;
 use elkodon_cal::shared_memory::SharedMemory;
 use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
@@ -149,6 +236,20 @@ use self::builder::Builder;
 use self::dynamic_config::DecrementReferenceCounterResult;
 use self::service_name::ServiceName;
 
+/// Defines how the payload of a [`crate::port::publisher::Publisher`] reaches the
+/// [`crate::port::subscriber::Subscriber`]s of a [`Service`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum PayloadTransfer {
+    /// Every [`crate::port::subscriber::Subscriber`] maps the data segment of the
+    /// [`crate::port::publisher::Publisher`] and reads the payload in place.
+    ZeroCopy,
+    /// Every [`crate::port::subscriber::Subscriber`] owns a data segment and the
+    /// [`crate::port::publisher::Publisher`] copies the payload into it. A
+    /// [`crate::port::subscriber::Subscriber`] can neither access the memory of the
+    /// [`crate::port::publisher::Publisher`] nor block it.
+    Copy,
+}
+
 /// Failure that can be reported by [`Details::does_exist()`] or
 /// [`Details::does_exist_with_custom_config()`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -181,12 +282,12 @@ impl std::fmt::Display for ServiceListError {
 
 impl std::error::Error for ServiceListError {}
 
-pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
+pub(crate) fn event_concept_name(port_id: &UniqueSystemId) -> FileName {
     let msg = "The system does not support the required file name length for the listeners event concept name.";
     let origin = "event_concept_name()";
-    let mut file = fatal_panic!(from origin, when FileName::new(listener_id.0.pid().to_string().as_bytes()), "{}", msg);
+    let mut file = fatal_panic!(from origin, when FileName::new(port_id.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(listener_id.0.value().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.value().to_string().as_bytes()), "{}", msg);
     file
 }
 
@@ -216,6 +317,29 @@ pub(crate) fn dynamic_config_storage_config<'config, Service: crate::service::De
     <Service::DynamicStorage as NamedConceptMgmt>::Configuration::default().suffix(f)
 }
 
+// opens the dynamic config of a service without becoming one of its owners, services whose
+// dynamic config is not yet initialized or already marked for destruction are not opened
+pub(crate) fn open_dynamic_config_for_inspection<
+    'config,
+    Service: crate::service::Details<'config>,
+>(
+    global_config: &config::Config,
+    static_config: &StaticConfig,
+) -> Option<Service::DynamicStorage> {
+    let storage = <<Service::DynamicStorage as DynamicStorage<DynamicConfig>>::Builder as NamedConceptBuilder<
+        Service::DynamicStorage,
+    >>::new(&dynamic_config_storage_name(static_config))
+    .config(&dynamic_config_storage_config::<Service>(global_config))
+    .has_ownership(false)
+    .open()
+    .ok()?;
+
+    match storage.get().is_marked_for_destruction() {
+        true => None,
+        false => Some(storage),
+    }
+}
+
 pub(crate) fn static_config_storage_name(uuid: &str) -> FileName {
     FileName::new(uuid.as_bytes()).unwrap()
 }
@@ -260,17 +384,47 @@ pub(crate) fn static_config_storage_config<'config, Service: crate::service::Det
         .path_hint(path_hint)
 }
 
-pub(crate) fn connection_name(
-    publisher_id: UniquePublisherId,
-    subscriber_id: UniqueSubscriberId,
+// a connection is named after the service and both of its participants,
+// "<service uuid>_<sender id>_<receiver id>"
+fn connection_name_of(
+    service_uuid: &str,
+    sender_id: &UniqueSystemId,
+    receiver_id: &UniqueSystemId,
 ) -> FileName {
-    let mut file = FileName::new(publisher_id.0.value().to_string().as_bytes()).unwrap();
+    let mut file = FileName::new(service_uuid.as_bytes()).unwrap();
     file.push(b'_').unwrap();
-    file.push_bytes(subscriber_id.0.value().to_string().as_bytes())
+    file.push_bytes(sender_id.value().to_string().as_bytes())
+        .unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(receiver_id.value().to_string().as_bytes())
         .unwrap();
     file
 }
 
+pub(crate) fn connection_name(
+    service_uuid: &str,
+    publisher_id: UniquePublisherId,
+    subscriber_id: UniqueSubscriberId,
+) -> FileName {
+    connection_name_of(service_uuid, &publisher_id.0, &subscriber_id.0)
+}
+
+pub(crate) fn request_connection_name(
+    service_uuid: &str,
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    connection_name_of(service_uuid, &client_id.0, &server_id.0)
+}
+
+pub(crate) fn response_connection_name(
+    service_uuid: &str,
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    connection_name_of(service_uuid, &server_id.0, &client_id.0)
+}
+
 pub(crate) fn connection_config<'config, Service: crate::service::Details<'config>>(
     global_config: &config::Config,
 ) -> <Service::Connection as NamedConceptMgmt>::Configuration {
@@ -287,6 +441,26 @@ pub(crate) fn connection_config<'config, Service: crate::service::Details<'confi
     <Service::Connection as NamedConceptMgmt>::Configuration::default().suffix(f)
 }
 
+pub(crate) fn port_monitor_name(port_id: &UniqueSystemId) -> FileName {
+    FileName::new(port_id.value().to_string().as_bytes()).unwrap()
+}
+
+pub(crate) fn port_monitor_config<'config, Service: crate::service::Details<'config>>(
+    global_config: &config::Config,
+) -> <Service::Monitoring as NamedConceptMgmt>::Configuration {
+    let origin = "port_monitor_config()";
+
+    let f = match FileName::new(global_config.global.service.port_monitor_suffix.as_bytes()) {
+        Err(_) => {
+            fatal_panic!(from origin, "The port_monitor_suffix \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.service.port_monitor_suffix);
+        }
+        Ok(v) => v,
+    };
+
+    <Service::Monitoring as NamedConceptMgmt>::Configuration::default().suffix(f)
+}
+
 /// Represents the [`Service`]s state.
 #[derive(Debug)]
 pub struct ServiceState<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> {
@@ -327,6 +501,9 @@ impl<'config, Static: StaticStorage, Dynamic: DynamicStorage<DynamicConfig>> Dro
             DecrementReferenceCounterResult::NoMoreOwners => {
                 self.static_storage.acquire_ownership();
                 self.dynamic_storage.acquire_ownership();
+                // the dynamic config is already marked for destruction, therefore the watchers
+                // no longer see the service
+                watcher::notify_service_watchers(self.global_config);
                 trace!(from self, "close and remove service");
             }
         }
@@ -369,6 +546,15 @@ pub trait Details<'config>: Debug + Sized {
     /// The mechanism used to signal events between endpoints.
     type Event: Event<EventId>;
 
+    /// Detects if the process that owns a port is still alive.
+    type Monitoring: Monitoring;
+
+    /// The mechanism used by the [`crate::waitset::WaitSet`] to wait on multiple attachments.
+    type Reactor: Reactor;
+
+    /// Defines how the payload of a publish-subscribe [`Service`] is transferred.
+    const PAYLOAD_TRANSFER: PayloadTransfer;
+
     #[doc(hidden)]
     fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self;
 
@@ -572,4 +758,58 @@ pub trait Details<'config>: Debug + Sized {
 
         Ok(service_vec)
     }
+
+    /// Returns a list of all created services in the system whose [`AttributeSet`] satisfies
+    /// the predicate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let services = zero_copy::Service::list_filtered(|attributes| {
+    ///     attributes.contains("unit", "m/s") && attributes.get("owner").is_some()
+    /// })?;
+    ///
+    /// for service in services {
+    ///     println!("\n{:#?}", &service);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_filtered<F: FnMut(&AttributeSet) -> bool>(
+        predicate: F,
+    ) -> Result<Vec<StaticConfig>, ServiceListError> {
+        Self::list_filtered_with_custom_config(config::Config::get_global_config(), predicate)
+    }
+
+    /// Returns a list of all services created under a given [`config::Config`] whose
+    /// [`AttributeSet`] satisfies the predicate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    /// use elkodon::config::Config;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut custom_config = Config::default();
+    /// let services = zero_copy::Service::list_filtered_with_custom_config(&custom_config,
+    ///     |attributes| attributes.contains("owner", "hypnotoad"))?;
+    ///
+    /// for service in services {
+    ///     println!("\n{:#?}", &service);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn list_filtered_with_custom_config<F: FnMut(&AttributeSet) -> bool>(
+        config: &'config config::Config,
+        mut predicate: F,
+    ) -> Result<Vec<StaticConfig>, ServiceListError> {
+        let mut services = Self::list_with_custom_config(config)?;
+        services.retain(|service| predicate(service.attributes()));
+        Ok(services)
+    }
 }
@@ -0,0 +1,142 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .add::<f64>(1, 0.0)
+//!     .open_or_create()?;
+//!
+//! println!("name:                         {:?}", blackboard.name());
+//! println!("uuid:                         {:?}", blackboard.uuid());
+//! println!("key type name:                {:?}", blackboard.static_config().key_type_name());
+//! println!("number of entries:            {:?}", blackboard.static_config().number_of_entries());
+//! println!("max readers:                  {:?}", blackboard.static_config().max_supported_readers());
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//!
+//! let writer = blackboard.writer().create()?;
+//! let reader = blackboard.reader().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use elkodon_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::builder::blackboard::BlackboardKey;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::{reader::PortFactoryReader, writer::PortFactoryWriter};
+
+/// The factory for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::reader::Reader`]
+/// or [`crate::port::writer::Writer`] ports.
+#[derive(Debug)]
+pub struct PortFactory<'config, Service: service::Details<'config>, KeyType: BlackboardKey> {
+    pub(crate) service: Service,
+    _phantom_key_type: PhantomData<KeyType>,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<'config, Service: service::Details<'config>, KeyType: BlackboardKey> Send
+    for PortFactory<'config, Service, KeyType>
+{
+}
+unsafe impl<'config, Service: service::Details<'config>, KeyType: BlackboardKey> Sync
+    for PortFactory<'config, Service, KeyType>
+{
+}
+
+impl<'config, Service: service::Details<'config>, KeyType: BlackboardKey>
+    PortFactory<'config, Service, KeyType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_key_type: PhantomData,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the [`crate::service::Service`]
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::blackboard::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::blackboard::StaticConfig {
+        self.service.state().static_config.blackboard()
+    }
+
+    /// Returns the [`dynamic_config::blackboard::DynamicConfig`] of the [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::blackboard::DynamicConfig {
+        self.service.state().dynamic_storage.get().blackboard()
+    }
+
+    /// Returns a [`PortFactoryWriter`] to create a new [`crate::port::writer::Writer`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+    /// let blackboard = zero_copy::Service::new(&service_name)
+    ///     .blackboard::<u64>()
+    ///     .add::<i32>(0, -1)
+    ///     .open_or_create()?;
+    ///
+    /// let writer = blackboard.writer().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn writer<'a>(&'a self) -> PortFactoryWriter<'a, 'config, Service, KeyType> {
+        PortFactoryWriter { factory: self }
+    }
+
+    /// Returns a [`PortFactoryReader`] to create a new [`crate::port::reader::Reader`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+    /// let blackboard = zero_copy::Service::new(&service_name)
+    ///     .blackboard::<u64>()
+    ///     .add::<i32>(0, -1)
+    ///     .open_or_create()?;
+    ///
+    /// let reader = blackboard.reader().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reader<'a>(&'a self) -> PortFactoryReader<'a, 'config, Service, KeyType> {
+        PortFactoryReader { factory: self }
+    }
+}
@@ -0,0 +1,92 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let client = reqres.client()
+//!                 .max_loaned_requests(3)
+//!                 .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use elkodon_bb_log::fail;
+
+use crate::{
+    port::client::{Client, ClientCreateError},
+    service,
+};
+
+use super::request_response::PortFactory;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalClientConfig {
+    pub(crate) max_loaned_requests: usize,
+}
+
+/// Factory to create a new [`Client`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryClient<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    config: LocalClientConfig,
+    pub(crate) factory: &'factory PortFactory<'config, Service, RequestType, ResponseType>,
+}
+
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > PortFactoryClient<'factory, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<'config, Service, RequestType, ResponseType>,
+    ) -> Self {
+        Self {
+            config: LocalClientConfig {
+                max_loaned_requests: factory
+                    .service
+                    .state()
+                    .global_config
+                    .defaults
+                    .request_response
+                    .client_max_loaned_requests,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::request_mut::RequestMut`] the [`Client`] can loan with
+    /// [`Client::loan()`] in parallel.
+    pub fn max_loaned_requests(mut self, value: usize) -> Self {
+        self.config.max_loaned_requests = value;
+        self
+    }
+
+    /// Creates a new [`Client`] or returns a [`ClientCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Client<'factory, 'config, Service, RequestType, ResponseType>, ClientCreateError>
+    {
+        Ok(
+            fail!(from self, when Client::new(&self.factory.service, self.factory.service.state().static_config.request_response(), &self.config),
+                "Failed to create new Client port."),
+        )
+    }
+}
@@ -23,6 +23,7 @@
 //! ```
 use elkodon_cal::dynamic_storage::DynamicStorage;
 
+use crate::service::attribute::AttributeSet;
 use crate::service::{self, static_config};
 use crate::service::{dynamic_config, ServiceName};
 use std::marker::PhantomData;
@@ -61,6 +62,11 @@ impl<'config, Service: service::Details<'config>> PortFactory<'config, Service>
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::event::StaticConfig {
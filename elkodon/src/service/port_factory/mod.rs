@@ -1,3 +1,11 @@
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication and to acquire static and dynamic service information
+pub mod blackboard;
+
+/// Factory to create a [`crate::port::client::Client`]
+pub mod client;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
@@ -9,6 +17,14 @@ pub mod listener;
 /// Factory to create a [`crate::port::notifier::Notifier`]
 pub mod notifier;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication and to acquire static and dynamic service information
+pub mod pipeline;
+
+/// Factory to create a [`crate::port::producer::Producer`]
+pub mod producer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication and to acquire static and dynamic service information
@@ -17,5 +33,22 @@ pub mod publish_subscribe;
 /// Factory to create a [`crate::port::publisher::Publisher`]
 pub mod publisher;
 
+/// Factory to create a [`crate::port::reader::Reader`]
+pub mod reader;
+
+/// Factory to create the endpoints of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication and to acquire static and dynamic service information
+pub mod request_response;
+
+/// Factory to create a [`crate::port::server::Server`]
+pub mod server;
+
 /// Factory to create a [`crate::port::subscriber::Subscriber`]
 pub mod subscriber;
+
+/// Factory to create a [`crate::port::worker::Worker`]
+pub mod worker;
+
+/// Factory to create a [`crate::port::writer::Writer`]
+pub mod writer;
@@ -0,0 +1,140 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .max_workers(4)
+//!     .open_or_create::<u64>()?;
+//!
+//! println!("name:                         {:?}", pipeline.name());
+//! println!("uuid:                         {:?}", pipeline.uuid());
+//! println!("type name:                    {:?}", pipeline.static_config().type_name());
+//! println!("max producers:                {:?}", pipeline.static_config().max_supported_producers());
+//! println!("max workers:                  {:?}", pipeline.static_config().max_supported_workers());
+//! println!("work queue capacity:          {:?}", pipeline.static_config().work_queue_capacity());
+//! println!("number of active producers:   {:?}", pipeline.dynamic_config().number_of_producers());
+//! println!("number of active workers:     {:?}", pipeline.dynamic_config().number_of_workers());
+//!
+//! let producer = pipeline.producer().create()?;
+//! let worker = pipeline.worker().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use elkodon_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::{producer::PortFactoryProducer, worker::PortFactoryWorker};
+
+/// The factory for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::producer::Producer`]
+/// or [`crate::port::worker::Worker`] ports.
+#[derive(Debug)]
+pub struct PortFactory<'config, Service: service::Details<'config>, MessageType: Debug> {
+    pub(crate) service: Service,
+    _phantom_message_type: PhantomData<MessageType>,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Send
+    for PortFactory<'config, Service, MessageType>
+{
+}
+unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Sync
+    for PortFactory<'config, Service, MessageType>
+{
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug>
+    PortFactory<'config, Service, MessageType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_message_type: PhantomData,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the [`crate::service::Service`]
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::pipeline::StaticConfig`] of the [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::pipeline::StaticConfig {
+        self.service.state().static_config.pipeline()
+    }
+
+    /// Returns the [`dynamic_config::pipeline::DynamicConfig`] of the [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants and the statistics of every
+    /// pipeline stage.
+    pub fn dynamic_config(&self) -> &dynamic_config::pipeline::DynamicConfig {
+        self.service.state().dynamic_storage.get().pipeline()
+    }
+
+    /// Returns a [`PortFactoryProducer`] to create a new [`crate::port::producer::Producer`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+    /// let pipeline = zero_copy::Service::new(&service_name)
+    ///     .pipeline()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let producer = pipeline.producer().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn producer<'a>(&'a self) -> PortFactoryProducer<'a, 'config, Service, MessageType> {
+        PortFactoryProducer { factory: self }
+    }
+
+    /// Returns a [`PortFactoryWorker`] to create a new [`crate::port::worker::Worker`] port
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+    /// let pipeline = zero_copy::Service::new(&service_name)
+    ///     .pipeline()
+    ///     .open_or_create::<u64>()?;
+    ///
+    /// let worker = pipeline.worker().create()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn worker<'a>(&'a self) -> PortFactoryWorker<'a, 'config, Service, MessageType> {
+        PortFactoryWorker { factory: self }
+    }
+}
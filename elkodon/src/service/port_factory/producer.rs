@@ -0,0 +1,48 @@
+//! # Examples
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let producer = pipeline.producer().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use elkodon_bb_log::fail;
+
+use crate::port::producer::{Producer, ProducerCreateError};
+use crate::service;
+
+use super::pipeline::PortFactory;
+
+/// Factory to create a new [`Producer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryProducer<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    MessageType: Debug,
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+    PortFactoryProducer<'factory, 'config, Service, MessageType>
+{
+    /// Creates the [`Producer`] port or returns a [`ProducerCreateError`] on failure.
+    pub fn create(
+        &self,
+    ) -> Result<Producer<'factory, 'config, Service, MessageType>, ProducerCreateError> {
+        Ok(fail!(from self, when Producer::new(&self.factory.service),
+                    "Failed to create new Producer port."))
+    }
+}
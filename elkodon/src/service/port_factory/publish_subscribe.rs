@@ -28,10 +28,12 @@
 //! # }
 //! ```
 
-use std::{fmt::Debug, marker::PhantomData};
+use std::marker::PhantomData;
 
 use elkodon_cal::dynamic_storage::DynamicStorage;
 
+use crate::payload_type::PayloadType;
+use crate::service::attribute::AttributeSet;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
@@ -43,22 +45,26 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 /// [`crate::port::publisher::Publisher`]
 /// or [`crate::port::subscriber::Subscriber`] ports.
 #[derive(Debug)]
-pub struct PortFactory<'config, Service: service::Details<'config>, MessageType: Debug> {
+pub struct PortFactory<
+    'config,
+    Service: service::Details<'config>,
+    MessageType: PayloadType + ?Sized,
+> {
     pub(crate) service: Service,
     _phantom_message_type: PhantomData<MessageType>,
     _phantom_lifetime_b: PhantomData<&'config ()>,
 }
 
-unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Send
+unsafe impl<'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized> Send
     for PortFactory<'config, Service, MessageType>
 {
 }
-unsafe impl<'config, Service: service::Details<'config>, MessageType: Debug> Sync
+unsafe impl<'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized> Sync
     for PortFactory<'config, Service, MessageType>
 {
 }
 
-impl<'config, Service: service::Details<'config>, MessageType: Debug>
+impl<'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     PortFactory<'config, Service, MessageType>
 {
     pub(crate) fn new(service: Service) -> Self {
@@ -79,6 +85,11 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
         self.service.state().static_config.uuid()
     }
 
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
     /// Returns the [`static_config::event::StaticConfig`] of the [`crate::service::Service`].
     /// Contains all settings that never change during the lifetime of the service.
     pub fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
@@ -115,7 +126,7 @@ impl<'config, Service: service::Details<'config>, MessageType: Debug>
     /// # }
     /// ```
     pub fn subscriber<'a>(&'a self) -> PortFactorySubscriber<'a, 'config, Service, MessageType> {
-        PortFactorySubscriber { factory: self }
+        PortFactorySubscriber::new(self)
     }
 
     /// Returns a [`PortFactoryPublisher`] to create a new
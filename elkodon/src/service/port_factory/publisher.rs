@@ -18,12 +18,12 @@
 //! # Ok(())
 //! # }
 //! ```
-use std::fmt::Debug;
 
 use elkodon_bb_log::fail;
 use serde::{de::Visitor, Deserialize, Serialize};
 
 use crate::{
+    payload_type::PayloadType,
     port::publisher::{Publisher, PublisherCreateError},
     service,
 };
@@ -36,7 +36,8 @@ use super::publish_subscribe::PortFactory;
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub enum UnableToDeliverStrategy {
     /// Blocks until the [`crate::port::subscriber::Subscriber`] has consumed the
-    /// [`crate::sample::Sample`] from the buffer and there is space again
+    /// [`crate::sample::Sample`] from the buffer and there is space again. Services with
+    /// [`crate::service::PayloadTransfer::Copy`] never block and discard the sample instead.
     Block,
     /// Do not deliver the [`crate::sample::Sample`].
     DiscardSample,
@@ -98,13 +99,13 @@ pub struct PortFactoryPublisher<
     'factory,
     'config,
     Service: service::Details<'config>,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
     config: LocalPublisherConfig,
     pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     PortFactoryPublisher<'factory, 'config, Service, MessageType>
 {
     pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
@@ -0,0 +1,164 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! println!("name:                             {:?}", reqres.name());
+//! println!("uuid:                             {:?}", reqres.uuid());
+//! println!("request type name:                {:?}", reqres.static_config().request_type_name());
+//! println!("response type name:               {:?}", reqres.static_config().response_type_name());
+//! println!("max clients:                      {:?}", reqres.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", reqres.static_config().max_supported_servers());
+//! println!("max active requests:              {:?}", reqres.static_config().max_active_requests());
+//! println!("number of active clients:         {:?}", reqres.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", reqres.dynamic_config().number_of_servers());
+//!
+//! let client = reqres.client().create()?;
+//! let server = reqres.server().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use elkodon_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::attribute::AttributeSet;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::{client::PortFactoryClient, server::PortFactoryServer};
+
+/// The factory for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::client::Client`]
+/// or [`crate::port::server::Server`] ports.
+#[derive(Debug)]
+pub struct PortFactory<
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    pub(crate) service: Service,
+    _phantom_request_type: PhantomData<RequestType>,
+    _phantom_response_type: PhantomData<ResponseType>,
+    _phantom_lifetime_b: PhantomData<&'config ()>,
+}
+
+unsafe impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug>
+    Send for PortFactory<'config, Service, RequestType, ResponseType>
+{
+}
+unsafe impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug>
+    Sync for PortFactory<'config, Service, RequestType, ResponseType>
+{
+}
+
+impl<'config, Service: service::Details<'config>, RequestType: Debug, ResponseType: Debug>
+    PortFactory<'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _phantom_request_type: PhantomData,
+            _phantom_response_type: PhantomData,
+            _phantom_lifetime_b: PhantomData,
+        }
+    }
+
+    /// Returns the [`ServiceName`] of the service
+    pub fn name(&self) -> &ServiceName {
+        self.service.state().static_config.service_name()
+    }
+
+    /// Returns the uuid of the [`crate::service::Service`]
+    pub fn uuid(&self) -> &str {
+        self.service.state().static_config.uuid()
+    }
+
+    /// Returns the [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        self.service.state().static_config.attributes()
+    }
+
+    /// Returns the [`static_config::request_response::StaticConfig`] of the
+    /// [`crate::service::Service`].
+    /// Contains all settings that never change during the lifetime of the service.
+    pub fn static_config(&self) -> &static_config::request_response::StaticConfig {
+        self.service.state().static_config.request_response()
+    }
+
+    /// Returns the [`dynamic_config::request_response::DynamicConfig`] of the
+    /// [`crate::service::Service`].
+    /// Contains all dynamic settings, like the current participants etc..
+    pub fn dynamic_config(&self) -> &dynamic_config::request_response::DynamicConfig {
+        self.service
+            .state()
+            .dynamic_storage
+            .get()
+            .request_response()
+    }
+
+    /// Returns a [`PortFactoryClient`] to create a new
+    /// [`crate::port::client::Client`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+    /// let reqres = zero_copy::Service::new(&service_name)
+    ///     .request_response()
+    ///     .open_or_create::<u64, u64>()?;
+    ///
+    /// let client = reqres.client()
+    ///                 .max_loaned_requests(3)
+    ///                 .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client<'a>(
+        &'a self,
+    ) -> PortFactoryClient<'a, 'config, Service, RequestType, ResponseType> {
+        PortFactoryClient::new(self)
+    }
+
+    /// Returns a [`PortFactoryServer`] to create a new
+    /// [`crate::port::server::Server`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use elkodon::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+    /// let reqres = zero_copy::Service::new(&service_name)
+    ///     .request_response()
+    ///     .open_or_create::<u64, u64>()?;
+    ///
+    /// let server = reqres.server()
+    ///                 .max_loaned_responses(3)
+    ///                 .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server<'a>(
+        &'a self,
+    ) -> PortFactoryServer<'a, 'config, Service, RequestType, ResponseType> {
+        PortFactoryServer::new(self)
+    }
+}
@@ -0,0 +1,92 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! let server = reqres.server()
+//!                 .max_loaned_responses(3)
+//!                 .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use elkodon_bb_log::fail;
+
+use crate::{
+    port::server::{Server, ServerCreateError},
+    service,
+};
+
+use super::request_response::PortFactory;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalServerConfig {
+    pub(crate) max_loaned_responses: usize,
+}
+
+/// Factory to create a new [`Server`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryServer<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    RequestType: Debug,
+    ResponseType: Debug,
+> {
+    config: LocalServerConfig,
+    pub(crate) factory: &'factory PortFactory<'config, Service, RequestType, ResponseType>,
+}
+
+impl<
+        'factory,
+        'config,
+        Service: service::Details<'config>,
+        RequestType: Debug,
+        ResponseType: Debug,
+    > PortFactoryServer<'factory, 'config, Service, RequestType, ResponseType>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<'config, Service, RequestType, ResponseType>,
+    ) -> Self {
+        Self {
+            config: LocalServerConfig {
+                max_loaned_responses: factory
+                    .service
+                    .state()
+                    .global_config
+                    .defaults
+                    .request_response
+                    .server_max_loaned_responses,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::response_mut::ResponseMut`] the [`Server`] can loan with
+    /// [`crate::active_request::ActiveRequest::loan()`] in parallel.
+    pub fn max_loaned_responses(mut self, value: usize) -> Self {
+        self.config.max_loaned_responses = value;
+        self
+    }
+
+    /// Creates a new [`Server`] or returns a [`ServerCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Server<'factory, 'config, Service, RequestType, ResponseType>, ServerCreateError>
+    {
+        Ok(
+            fail!(from self, when Server::new(&self.factory.service, self.factory.service.state().static_config.request_response(), &self.config),
+                "Failed to create new Server port."),
+        )
+    }
+}
@@ -2,7 +2,8 @@
 //!
 //! ```
 //! use elkodon::prelude::*;
-//! use elkodon::service::port_factory::publisher::UnableToDeliverStrategy;
+//! use elkodon::service::port_factory::subscriber::ReceivePolicy;
+//! use std::time::Duration;
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let service_name = ServiceName::new(b"My/Funk/ServiceName")?;
@@ -11,23 +12,106 @@
 //!     .open_or_create::<u64>()?;
 //!
 //! let subscriber = pubsub.subscriber()
+//!                     // check at least every 50ms for new samples and termination requests
+//!                     .wakeup_interval(Duration::from_millis(50))
+//!                     // receive the samples of all publishers in the order they were sent
+//!                     .receive_policy(ReceivePolicy::OldestFirst)
 //!                     .create()?;
 //!
 //! # Ok(())
 //! # }
 //! ```
 
-use std::fmt::Debug;
+use std::time::Duration;
 
-use elkodon_bb_log::fail;
+use elkodon_bb_log::{fail, warn};
+use serde::{de::Visitor, Deserialize, Serialize};
 
 use crate::{
+    payload_type::PayloadType,
     port::subscriber::{Subscriber, SubscriberCreateError},
     service,
 };
 
 use super::publish_subscribe::PortFactory;
 
+/// The smallest supported [`PortFactorySubscriber::wakeup_interval()`].
+pub const MIN_WAKEUP_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Defines from which [`crate::port::publisher::Publisher`] the [`Subscriber`] receives the
+/// next [`crate::sample::Sample`] when multiple publishers delivered samples.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ReceivePolicy {
+    /// Receives from the [`crate::port::publisher::Publisher`]s in the order they are
+    /// connected. A busy publisher that connected first can starve all others.
+    ConnectionOrder,
+    /// Receives from every [`crate::port::publisher::Publisher`] in turn, one
+    /// [`crate::sample::Sample`] at a time.
+    RoundRobin,
+    /// Receives the oldest [`crate::sample::Sample`] of all
+    /// [`crate::port::publisher::Publisher`]s first, based on
+    /// [`crate::service::header::publish_subscribe::Header::time_stamp()`]. To compare the
+    /// samples, the [`Subscriber`] holds back one sample of every publisher which counts as
+    /// borrowed, see
+    /// [`crate::service::static_config::publish_subscribe::StaticConfig::subscriber_max_borrowed_samples()`].
+    OldestFirst,
+}
+
+impl Serialize for ReceivePolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(match self {
+            ReceivePolicy::ConnectionOrder => "connection_order",
+            ReceivePolicy::RoundRobin => "round_robin",
+            ReceivePolicy::OldestFirst => "oldest_first",
+        })
+    }
+}
+
+struct ReceivePolicyVisitor;
+
+impl<'de> Visitor<'de> for ReceivePolicyVisitor {
+    type Value = ReceivePolicy;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str(
+            "a string containing either 'connection_order', 'round_robin' or 'oldest_first'",
+        )
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "connection_order" => Ok(ReceivePolicy::ConnectionOrder),
+            "round_robin" => Ok(ReceivePolicy::RoundRobin),
+            "oldest_first" => Ok(ReceivePolicy::OldestFirst),
+            v => Err(E::custom(format!(
+                "Invalid ReceivePolicy provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ReceivePolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(ReceivePolicyVisitor)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LocalSubscriberConfig {
+    pub(crate) wakeup_interval: Duration,
+    pub(crate) receive_policy: ReceivePolicy,
+}
+
 /// Factory to create a new [`Subscriber`] port/endpoint for
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication.
@@ -36,20 +120,62 @@ pub struct PortFactorySubscriber<
     'factory,
     'config,
     Service: service::Details<'config>,
-    MessageType: Debug,
+    MessageType: PayloadType + ?Sized,
 > {
+    config: LocalSubscriberConfig,
     pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
 }
 
-impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: PayloadType + ?Sized>
     PortFactorySubscriber<'factory, 'config, Service, MessageType>
 {
+    pub(crate) fn new(factory: &'factory PortFactory<'config, Service, MessageType>) -> Self {
+        let defaults = &factory
+            .service
+            .state()
+            .global_config
+            .defaults
+            .publish_subscribe;
+
+        Self {
+            config: LocalSubscriberConfig {
+                wakeup_interval: defaults.subscriber_wakeup_interval,
+                receive_policy: defaults.subscriber_receive_policy,
+            },
+            factory,
+        }
+    }
+
+    /// Defines the maximum time the [`Subscriber`] sleeps in [`Subscriber::timed_receive()`]
+    /// or [`Subscriber::blocking_receive()`] before it checks again for new
+    /// [`crate::sample::Sample`]s and if a termination was requested. When the service has
+    /// notifications enabled the [`Subscriber`] is woken up earlier by every delivered
+    /// [`crate::sample::Sample`]. Intervals below [`MIN_WAKEUP_INTERVAL`] are adjusted to
+    /// [`MIN_WAKEUP_INTERVAL`].
+    pub fn wakeup_interval(mut self, value: Duration) -> Self {
+        self.config.wakeup_interval = value;
+        self
+    }
+
+    /// Sets the [`ReceivePolicy`].
+    pub fn receive_policy(mut self, value: ReceivePolicy) -> Self {
+        self.config.receive_policy = value;
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         &self,
     ) -> Result<Subscriber<'factory, 'config, Service, MessageType>, SubscriberCreateError> {
+        let mut config = self.config;
+        if config.wakeup_interval < MIN_WAKEUP_INTERVAL {
+            warn!(from self, "Setting the wakeup interval to {:?} is not supported. Adjust it to {:?}, the smallest supported value.",
+                config.wakeup_interval, MIN_WAKEUP_INTERVAL);
+            config.wakeup_interval = MIN_WAKEUP_INTERVAL;
+        }
+
         Ok(
-            fail!(from self, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe()),
+            fail!(from self, when Subscriber::new(&self.factory.service, self.factory.service.state().static_config.publish_subscribe(), &config),
                 "Failed to create new Subscriber port."),
         )
     }
@@ -0,0 +1,48 @@
+//! # Examples
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! let worker = pipeline.worker().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use elkodon_bb_log::fail;
+
+use crate::port::worker::{Worker, WorkerCreateError};
+use crate::service;
+
+use super::pipeline::PortFactory;
+
+/// Factory to create a new [`Worker`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryWorker<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    MessageType: Debug,
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, MessageType>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>, MessageType: Debug>
+    PortFactoryWorker<'factory, 'config, Service, MessageType>
+{
+    /// Creates the [`Worker`] port or returns a [`WorkerCreateError`] on failure.
+    pub fn create(
+        &self,
+    ) -> Result<Worker<'factory, 'config, Service, MessageType>, WorkerCreateError> {
+        Ok(fail!(from self, when Worker::new(&self.factory.service),
+                    "Failed to create new Worker port."))
+    }
+}
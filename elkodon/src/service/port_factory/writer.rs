@@ -0,0 +1,48 @@
+//! # Examples
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .open_or_create()?;
+//!
+//! let writer = blackboard.writer().create()?;
+//! # Ok(())
+//! # }
+//! ```
+use std::fmt::Debug;
+
+use elkodon_bb_log::fail;
+
+use crate::port::writer::{Writer, WriterCreateError};
+use crate::service;
+use crate::service::builder::blackboard::BlackboardKey;
+
+use super::blackboard::PortFactory;
+
+/// Factory to create a new [`Writer`] port/endpoint for
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryWriter<
+    'factory,
+    'config,
+    Service: service::Details<'config>,
+    KeyType: BlackboardKey,
+> {
+    pub(crate) factory: &'factory PortFactory<'config, Service, KeyType>,
+}
+
+impl<'factory, 'config, Service: service::Details<'config>, KeyType: BlackboardKey>
+    PortFactoryWriter<'factory, 'config, Service, KeyType>
+{
+    /// Creates the [`Writer`] port or returns a [`WriterCreateError`] on failure.
+    pub fn create(&self) -> Result<Writer<'factory, 'config, Service, KeyType>, WriterCreateError> {
+        Ok(fail!(from self, when Writer::new(&self.factory.service),
+                    "Failed to create new Writer port."))
+    }
+}
@@ -23,7 +23,7 @@ use crate::service::dynamic_config::DynamicConfig;
 use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
 use elkodon_cal::*;
 
-use super::ServiceState;
+use super::{PayloadTransfer, ServiceState};
 
 /// Defines a process local or single address space communication setup.
 #[derive(Debug)]
@@ -47,6 +47,10 @@ impl<'config> crate::service::Details<'config> for Service<'config> {
     type SharedMemory = shared_memory::process_local::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::process_local::Connection;
     type Event = event::process_local::Event<EventId>;
+    type Monitoring = monitoring::process_local::Monitoring;
+    type Reactor = reactor::posix_select::Reactor;
+
+    const PAYLOAD_TRANSFER: PayloadTransfer = PayloadTransfer::ZeroCopy;
 
     fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
         Self { state }
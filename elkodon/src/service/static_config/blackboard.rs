@@ -0,0 +1,91 @@
+//! # Examples
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+//! let blackboard = zero_copy::Service::new(&service_name)
+//!     .blackboard::<u64>()
+//!     .add::<i32>(0, -1)
+//!     .add::<u8>(1, 0)
+//!     .open_or_create()?;
+//!
+//! println!("max readers:                  {:?}", blackboard.static_config().max_supported_readers());
+//! println!("max writers:                  {:?}", blackboard.static_config().max_supported_writers());
+//! println!("number of entries:            {:?}", blackboard.static_config().number_of_entries());
+//! println!("key type name:                {:?}", blackboard.static_config().key_type_name());
+//!
+//! # Ok(())
+//! # }
+//! ```
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// Describes a single entry of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`] based service.
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EntryConfig {
+    pub(crate) type_name: String,
+    pub(crate) offset: usize,
+}
+
+impl EntryConfig {
+    /// Returns the type name of the value that is stored in the entry
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+}
+
+/// The static configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_readers: usize,
+    pub(crate) max_writers: usize,
+    pub(crate) key_type_name: String,
+    pub(crate) entries_size: usize,
+    pub(crate) entries_alignment: usize,
+    // must be the last member since it is serialized as an array of tables
+    pub(crate) entries: Vec<EntryConfig>,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_readers: config.defaults.blackboard.max_readers,
+            max_writers: config.defaults.blackboard.max_writers,
+            key_type_name: String::new(),
+            entries_size: 0,
+            entries_alignment: 1,
+            entries: vec![],
+        }
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::reader::Reader`] ports
+    pub fn max_supported_readers(&self) -> usize {
+        self.max_readers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::writer::Writer`] ports
+    pub fn max_supported_writers(&self) -> usize {
+        self.max_writers
+    }
+
+    /// Returns the type name of the key that identifies the entries
+    pub fn key_type_name(&self) -> &str {
+        &self.key_type_name
+    }
+
+    /// Returns the number of entries the blackboard contains
+    pub fn number_of_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the configuration of all entries of the blackboard
+    pub fn entries(&self) -> &[EntryConfig] {
+        &self.entries
+    }
+}
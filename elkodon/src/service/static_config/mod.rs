@@ -1,13 +1,29 @@
+/// The static service configuration of an
+/// [`MessagingPattern::Blackboard`]
+/// based service.
+pub mod blackboard;
+
 /// The static service configuration of an
 /// [`MessagingPattern::Event`]
 /// based service.
 pub mod event;
 
+/// The static service configuration of an
+/// [`MessagingPattern::Pipeline`]
+/// based service.
+pub mod pipeline;
+
 /// The static service configuration of an
 /// [`MessagingPattern::PublishSubscribe`]
 /// based service.
 pub mod publish_subscribe;
 
+/// The static service configuration of an
+/// [`MessagingPattern::RequestResponse`]
+/// based service.
+pub mod request_response;
+
+use crate::service::attribute::AttributeSet;
 use crate::service::messaging_pattern::MessagingPattern;
 use elkodon_bb_container::semantic_string::SemanticString;
 use elkodon_bb_log::fatal_panic;
@@ -23,10 +39,23 @@ use super::service_name::ServiceName;
 pub struct StaticConfig {
     uuid: String,
     service_name: ServiceName,
+    pub(crate) attributes: AttributeSet,
     pub(crate) messaging_pattern: MessagingPattern,
 }
 
 impl StaticConfig {
+    pub(crate) fn new_blackboard<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        Self {
+            uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
+            service_name: *service_name,
+            attributes: AttributeSet::new(),
+            messaging_pattern: MessagingPattern::Blackboard(blackboard::StaticConfig::new(config)),
+        }
+    }
+
     pub(crate) fn new_event<Hasher: Hash>(
         service_name: &ServiceName,
         config: &config::Config,
@@ -34,10 +63,23 @@ impl StaticConfig {
         Self {
             uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
             service_name: *service_name,
+            attributes: AttributeSet::new(),
             messaging_pattern: MessagingPattern::Event(event::StaticConfig::new(config)),
         }
     }
 
+    pub(crate) fn new_pipeline<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        Self {
+            uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
+            service_name: *service_name,
+            attributes: AttributeSet::new(),
+            messaging_pattern: MessagingPattern::Pipeline(pipeline::StaticConfig::new(config)),
+        }
+    }
+
     pub(crate) fn new_publish_subscribe<Hasher: Hash>(
         service_name: &ServiceName,
         config: &config::Config,
@@ -45,12 +87,27 @@ impl StaticConfig {
         Self {
             uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
             service_name: *service_name,
+            attributes: AttributeSet::new(),
             messaging_pattern: MessagingPattern::PublishSubscribe(
                 publish_subscribe::StaticConfig::new(config),
             ),
         }
     }
 
+    pub(crate) fn new_request_response<Hasher: Hash>(
+        service_name: &ServiceName,
+        config: &config::Config,
+    ) -> Self {
+        Self {
+            uuid: Hasher::new(service_name.as_bytes()).as_hex_string(),
+            service_name: *service_name,
+            attributes: AttributeSet::new(),
+            messaging_pattern: MessagingPattern::RequestResponse(
+                request_response::StaticConfig::new(config),
+            ),
+        }
+    }
+
     /// Returns the uuid of the [`crate::service::Service`]
     pub fn uuid(&self) -> &str {
         &self.uuid
@@ -61,6 +118,11 @@ impl StaticConfig {
         &self.service_name
     }
 
+    /// Returns the user-defined [`AttributeSet`] of the [`crate::service::Service`]
+    pub fn attributes(&self) -> &AttributeSet {
+        &self.attributes
+    }
+
     /// Returns the [`MessagingPattern`] of the [`crate::service::Service`]
     pub fn messaging_pattern(&self) -> &MessagingPattern {
         &self.messaging_pattern
@@ -71,6 +133,25 @@ impl StaticConfig {
             .is_same_pattern(&rhs.messaging_pattern)
     }
 
+    pub(crate) fn blackboard(&self) -> &blackboard::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn blackboard_mut(&mut self) -> &mut blackboard::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Blackboard(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access blackboard::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
     pub(crate) fn event(&self) -> &event::StaticConfig {
         match &self.messaging_pattern {
             MessagingPattern::Event(ref v) => v,
@@ -90,6 +171,25 @@ impl StaticConfig {
         }
     }
 
+    pub(crate) fn pipeline(&self) -> &pipeline::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn pipeline_mut(&mut self) -> &mut pipeline::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::Pipeline(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access pipeline::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
     pub(crate) fn publish_subscribe(&self) -> &publish_subscribe::StaticConfig {
         match &self.messaging_pattern {
             MessagingPattern::PublishSubscribe(ref v) => v,
@@ -108,4 +208,23 @@ impl StaticConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::StaticConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
+
+    pub(crate) fn request_response_mut(&mut self) -> &mut request_response::StaticConfig {
+        let origin = format!("{:?}", self);
+        match &mut self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref mut v) => v,
+            m => {
+                fatal_panic!(from origin, "This should never happen. Trying to access request_response::StaticConfig when the messaging pattern is actually {:?}!", m)
+            }
+        }
+    }
 }
@@ -0,0 +1,90 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! let pipeline = zero_copy::Service::new(&service_name)
+//!     .pipeline()
+//!     .open_or_create::<u64>()?;
+//!
+//! println!("type name:                    {:?}", pipeline.static_config().type_name());
+//! println!("max producers:                {:?}", pipeline.static_config().max_supported_producers());
+//! println!("max workers:                  {:?}", pipeline.static_config().max_supported_workers());
+//! println!("work queue capacity:          {:?}", pipeline.static_config().work_queue_capacity());
+//! println!("producer max loaned samples:  {:?}", pipeline.static_config().producer_max_loaned_samples());
+//! println!("worker max borrowed samples:  {:?}", pipeline.static_config().worker_max_borrowed_samples());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::Pipeline`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_producers: usize,
+    pub(crate) max_workers: usize,
+    pub(crate) work_queue_capacity: usize,
+    pub(crate) producer_max_loaned_samples: usize,
+    pub(crate) worker_max_borrowed_samples: usize,
+    pub(crate) type_name: String,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_producers: config.defaults.pipeline.max_producers,
+            max_workers: config.defaults.pipeline.max_workers,
+            work_queue_capacity: config.defaults.pipeline.work_queue_capacity,
+            producer_max_loaned_samples: config.defaults.pipeline.producer_max_loaned_samples,
+            worker_max_borrowed_samples: config.defaults.pipeline.worker_max_borrowed_samples,
+            type_name: String::new(),
+        }
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::producer::Producer`] ports
+    pub fn max_supported_producers(&self) -> usize {
+        self.max_producers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::worker::Worker`] ports
+    pub fn max_supported_workers(&self) -> usize {
+        self.max_workers
+    }
+
+    /// Returns how many samples can wait in the shared work queue until a
+    /// [`crate::port::worker::Worker`] takes them.
+    pub fn work_queue_capacity(&self) -> usize {
+        self.work_queue_capacity
+    }
+
+    /// Returns how many samples a [`crate::port::producer::Producer`] can loan in parallel.
+    pub fn producer_max_loaned_samples(&self) -> usize {
+        self.producer_max_loaned_samples
+    }
+
+    /// Returns how many samples a [`crate::port::worker::Worker`] can hold in parallel.
+    pub fn worker_max_borrowed_samples(&self) -> usize {
+        self.worker_max_borrowed_samples
+    }
+
+    /// Returns the type name of the [`crate::service::Service`].
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// Returns the number of samples the service has to provide so that every port can loan,
+    /// borrow and enqueue as many samples as it is allowed to.
+    pub(crate) fn number_of_samples(&self) -> usize {
+        self.work_queue_capacity
+            + self.max_producers * self.producer_max_loaned_samples
+            + self.max_workers * self.worker_max_borrowed_samples
+    }
+}
@@ -16,12 +16,24 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("notifications:                    {:?}", pubsub.static_config().has_notifications());
+//! println!("max slice length:                 {:?}", pubsub.static_config().max_slice_len());
+//! println!("payload alignment:                {:?}", pubsub.static_config().payload_alignment());
+//! println!("type fingerprint:                 {:?}", pubsub.static_config().type_fingerprint());
+//! println!("deadline:                         {:?}", pubsub.static_config().deadline());
+//! println!("liveliness lease duration:        {:?}", pubsub.static_config().liveliness_lease_duration());
+//! println!("lifespan:                         {:?}", pubsub.static_config().lifespan());
+//! println!("payload transfer:                 {:?}", pubsub.static_config().payload_transfer());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use std::time::Duration;
+
 use crate::config;
+use crate::payload_type::TypeFingerprint;
+use crate::service::PayloadTransfer;
 use serde::{Deserialize, Serialize};
 
 /// The static configuration of an
@@ -36,7 +48,15 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) enable_notifications: bool,
+    pub(crate) max_slice_len: usize,
+    pub(crate) payload_alignment: usize,
     pub(crate) type_name: String,
+    pub(crate) payload_transfer: PayloadTransfer,
+    pub(crate) type_fingerprint: TypeFingerprint,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) liveliness_lease_duration: Option<Duration>,
+    pub(crate) lifespan: Option<Duration>,
 }
 
 impl StaticConfig {
@@ -54,7 +74,15 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            enable_notifications: config.defaults.publish_subscribe.enable_notifications,
+            max_slice_len: 1,
+            payload_alignment: 1,
             type_name: String::new(),
+            payload_transfer: PayloadTransfer::ZeroCopy,
+            type_fingerprint: TypeFingerprint::new(),
+            deadline: None,
+            liveliness_lease_duration: None,
+            lifespan: None,
         }
     }
 
@@ -92,8 +120,61 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
-    /// Returns the type name of the [`crate::service::Service`].
+    /// Returns true if the [`crate::port::publisher::Publisher`] notifies every
+    /// [`crate::port::subscriber::Subscriber`] it delivered a [`crate::sample::Sample`] to,
+    /// otherwise false.
+    pub fn has_notifications(&self) -> bool {
+        self.enable_notifications
+    }
+
+    /// Returns the maximum number of elements a slice payload can contain. If the payload is not
+    /// a slice it is always 1.
+    pub fn max_slice_len(&self) -> usize {
+        self.max_slice_len
+    }
+
+    /// Returns the alignment of the payload inside every [`crate::sample::Sample`]. It is at
+    /// least the alignment of the payload type.
+    pub fn payload_alignment(&self) -> usize {
+        self.payload_alignment
+    }
+
+    /// Returns the type name of the [`crate::service::Service`]. It is only informational,
+    /// the compatibility of the payload type is verified with the
+    /// [`StaticConfig::type_fingerprint()`].
     pub fn type_name(&self) -> &str {
         &self.type_name
     }
+
+    /// Returns the [`TypeFingerprint`] of the payload type of the
+    /// [`crate::service::Service`].
+    pub fn type_fingerprint(&self) -> &TypeFingerprint {
+        &self.type_fingerprint
+    }
+
+    /// Returns the maximum time that is allowed to pass between two [`crate::sample::Sample`]s
+    /// of a [`crate::port::publisher::Publisher`]. If no deadline is defined it returns [`None`].
+    pub fn deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns the maximum time a [`crate::port::publisher::Publisher`] is considered alive after
+    /// it asserted its liveliness. If no liveliness is defined it returns [`None`].
+    pub fn liveliness_lease_duration(&self) -> Option<Duration> {
+        self.liveliness_lease_duration
+    }
+
+    /// Returns how long a [`crate::sample::Sample`] is valid after it was sent. Older samples
+    /// are discarded by the [`crate::port::subscriber::Subscriber`]. If no lifespan is defined
+    /// it returns [`None`].
+    pub fn lifespan(&self) -> Option<Duration> {
+        self.lifespan
+    }
+
+    /// Returns how the payload is transferred from the [`crate::port::publisher::Publisher`] to
+    /// the [`crate::port::subscriber::Subscriber`]. It is defined by the
+    /// [`crate::service::Service`] variant that created the service.
+    pub fn payload_transfer(&self) -> PayloadTransfer {
+        self.payload_transfer
+    }
 }
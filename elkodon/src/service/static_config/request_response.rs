@@ -0,0 +1,75 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let service_name = ServiceName::new(b"My/Funk/Calculator")?;
+//! let reqres = zero_copy::Service::new(&service_name)
+//!     .request_response()
+//!     .open_or_create::<u64, u64>()?;
+//!
+//! println!("request type name:                {:?}", reqres.static_config().request_type_name());
+//! println!("response type name:               {:?}", reqres.static_config().response_type_name());
+//! println!("max clients:                      {:?}", reqres.static_config().max_supported_clients());
+//! println!("max servers:                      {:?}", reqres.static_config().max_supported_servers());
+//! println!("max active requests:              {:?}", reqres.static_config().max_active_requests());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::RequestResponse`]
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`crate::service::Service`].
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_clients: usize,
+    pub(crate) max_servers: usize,
+    pub(crate) max_active_requests: usize,
+    pub(crate) request_type_name: String,
+    pub(crate) response_type_name: String,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_clients: config.defaults.request_response.max_clients,
+            max_servers: config.defaults.request_response.max_servers,
+            max_active_requests: config.defaults.request_response.max_active_requests,
+            request_type_name: String::new(),
+            response_type_name: String::new(),
+        }
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::client::Client`] ports
+    pub fn max_supported_clients(&self) -> usize {
+        self.max_clients
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::server::Server`] ports
+    pub fn max_supported_servers(&self) -> usize {
+        self.max_servers
+    }
+
+    /// Returns how many requests a [`crate::port::client::Client`] can have in flight at most.
+    /// A request is in flight until every [`crate::port::server::Server`] that received it
+    /// has released it.
+    pub fn max_active_requests(&self) -> usize {
+        self.max_active_requests
+    }
+
+    /// Returns the type name of the request of the [`crate::service::Service`].
+    pub fn request_type_name(&self) -> &str {
+        &self.request_type_name
+    }
+
+    /// Returns the type name of the response of the [`crate::service::Service`].
+    pub fn response_type_name(&self) -> &str {
+        &self.response_type_name
+    }
+}
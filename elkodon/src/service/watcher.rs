@@ -0,0 +1,340 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! use elkodon::service::watcher::{ServiceEvent, ServiceWatcher};
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut watcher = ServiceWatcher::<zero_copy::Service>::new()?;
+//!
+//! let service = zero_copy::Service::new(&ServiceName::new(b"My/Funk/WatchedService")?)
+//!     .publish_subscribe()
+//!     .open_or_create::<u64>()?;
+//!
+//! // the first call reports every existing service as added
+//! for event in watcher.timed_wait(Duration::from_millis(100))? {
+//!     match event {
+//!         ServiceEvent::ServiceAdded(config) => println!("added: {}", config.service_name()),
+//!         ServiceEvent::ServiceRemoved(config) => println!("removed: {}", config.service_name()),
+//!         ServiceEvent::PortsChanged(config) => println!("ports changed: {}", config.service_name()),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_log::{fail, fatal_panic};
+use elkodon_bb_posix::{directory::*, unique_system_id::UniqueSystemId};
+use elkodon_bb_system_types::{file_name::FileName, path::Path};
+use elkodon_cal::{
+    dynamic_storage::DynamicStorage,
+    event::{
+        unix_datagram_socket, Event, Listener, ListenerBuilder, ListenerCreateError, Notifier,
+        NotifierBuilder,
+    },
+    named_concept::{NamedConceptBuilder, NamedConceptConfiguration, NamedConceptMgmt},
+};
+
+use crate::config;
+
+use super::{
+    dynamic_config::PortId, open_dynamic_config_for_inspection, static_config::StaticConfig,
+    Details, ServiceListError,
+};
+
+// every watcher owns an event in the service directory that is notified whenever a service or
+// one of its ports is created or removed, it does not depend on the service type so that the
+// ports and services can notify the watchers without knowing how they were created
+type WatcherEvent = unix_datagram_socket::Event<u64>;
+
+/// Defines the failures that can occur when a [`ServiceWatcher`] is created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceWatcherCreateError {
+    InsufficientPermissions,
+    UnableToCreateServiceDirectory,
+    UnableToCreateEvent,
+}
+
+impl std::fmt::Display for ServiceWatcherCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceWatcherCreateError {}
+
+/// Defines the failures that can occur when a [`ServiceWatcher`] waits for changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceWatcherWaitError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ServiceWatcherWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ServiceWatcherWaitError {}
+
+/// A change of a [`crate::service::Service`] that was detected by the [`ServiceWatcher`].
+#[derive(Debug, Clone)]
+pub enum ServiceEvent {
+    /// The service was created.
+    ServiceAdded(StaticConfig),
+    /// The service was removed.
+    ServiceRemoved(StaticConfig),
+    /// A port of the service was created or removed.
+    PortsChanged(StaticConfig),
+}
+
+#[derive(Debug)]
+struct WatchedService {
+    static_config: StaticConfig,
+    port_ids: Vec<PortId>,
+}
+
+/// Watches all [`crate::service::Service`]s of a [`config::Config`] and reports when services
+/// are created or removed or when their ports change. It compares the current services and
+/// their ports with the state of the previous call, therefore changes that are reverted between
+/// two calls are not reported.
+///
+/// The waiting calls do not poll. The watcher owns an event in the service directory which is
+/// notified by every service and port of the [`config::Config`] when it is created or removed.
+#[derive(Debug)]
+pub struct ServiceWatcher<'config, Service: Details<'config>> {
+    config: &'config config::Config,
+    listener: <WatcherEvent as Event<u64>>::Listener,
+    services: HashMap<String, WatchedService>,
+    _phantom: PhantomData<Service>,
+}
+
+impl<'config, Service: Details<'config>> ServiceWatcher<'config, Service> {
+    /// Creates a new [`ServiceWatcher`] that watches the services of the global
+    /// [`config::Config`].
+    pub fn new() -> Result<Self, ServiceWatcherCreateError> {
+        Self::new_with_custom_config(config::Config::get_global_config())
+    }
+
+    /// Creates a new [`ServiceWatcher`] that watches the services of a given
+    /// [`config::Config`].
+    pub fn new_with_custom_config(
+        config: &'config config::Config,
+    ) -> Result<Self, ServiceWatcherCreateError> {
+        let msg = "Unable to create service watcher";
+        let origin = "ServiceWatcher::new()";
+        let event_config = service_watcher_config(config);
+
+        // the service directory is created with the first service, the watcher can be created
+        // before
+        let directory = event_config.get_path_hint();
+        if !fail!(from origin, when Directory::does_exist(directory),
+                with ServiceWatcherCreateError::UnableToCreateServiceDirectory,
+                "{} since the system is unable to determine if the service directory \"{}\" exists.", msg, directory)
+        {
+            fail!(from origin, when Directory::create(directory, Permission::OWNER_ALL | Permission::GROUP_ALL),
+                with ServiceWatcherCreateError::UnableToCreateServiceDirectory,
+                "{} since the service directory \"{}\" could not be created.", msg, directory);
+        }
+
+        let unique_id = fail!(from origin, when UniqueSystemId::new(),
+                with ServiceWatcherCreateError::UnableToCreateEvent,
+                "{} since no unique name for the event could be generated.", msg);
+        let name = fatal_panic!(from origin,
+                when FileName::new(unique_id.value().to_string().as_bytes()),
+                "This should never happen! The unique id is not a valid file name.");
+
+        let listener = fail!(from origin,
+                when <WatcherEvent as Event<u64>>::ListenerBuilder::new(&name)
+                    .config(&event_config)
+                    .create(),
+                map ListenerCreateError::InsufficientPermissions => ServiceWatcherCreateError::InsufficientPermissions,
+                unmatched ServiceWatcherCreateError::UnableToCreateEvent,
+                "{} since the event \"{}\" could not be created.", msg, name);
+
+        Ok(Self {
+            config,
+            listener,
+            services: HashMap::new(),
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the [`StaticConfig`]s of all services that were present at the last call of
+    /// [`ServiceWatcher::try_wait()`], [`ServiceWatcher::timed_wait()`] or
+    /// [`ServiceWatcher::blocking_wait()`].
+    pub fn services(&self) -> impl Iterator<Item = &StaticConfig> {
+        self.services.values().map(|s| &s.static_config)
+    }
+
+    /// Returns all changes since the last call without blocking. The first call reports every
+    /// existing service as [`ServiceEvent::ServiceAdded`].
+    pub fn try_wait(&mut self) -> Result<Vec<ServiceEvent>, ServiceWatcherWaitError> {
+        // the pending notifications are consumed before the services are compared, every change
+        // after the comparison notifies the watcher again
+        while fail!(from self, when self.listener.try_wait(),
+                with ServiceWatcherWaitError::InternalError,
+                "Unable to wait for service changes since the underlying event failed.")
+        .is_some()
+        {}
+
+        let mut current_services = HashMap::new();
+        let services = fail!(from self, when Service::list_with_custom_config(self.config),
+                map ServiceListError::InsufficientPermissions => ServiceWatcherWaitError::InsufficientPermissions,
+                unmatched ServiceWatcherWaitError::InternalError,
+                "Unable to wait for service changes since the services could not be listed.");
+        for static_config in services {
+            if let Some(port_ids) = self.port_ids_of(&static_config) {
+                current_services.insert(
+                    static_config.uuid().to_string(),
+                    WatchedService {
+                        static_config,
+                        port_ids,
+                    },
+                );
+            }
+        }
+
+        let mut events = vec![];
+        for (uuid, service) in &current_services {
+            match self.services.get(uuid) {
+                None => events.push(ServiceEvent::ServiceAdded(service.static_config.clone())),
+                Some(previous) if previous.port_ids != service.port_ids => {
+                    events.push(ServiceEvent::PortsChanged(service.static_config.clone()))
+                }
+                Some(_) => (),
+            }
+        }
+
+        for (uuid, service) in self.services.drain() {
+            if !current_services.contains_key(&uuid) {
+                events.push(ServiceEvent::ServiceRemoved(service.static_config));
+            }
+        }
+
+        self.services = current_services;
+        Ok(events)
+    }
+
+    /// Blocks until at least one change was detected or the timeout has passed and returns
+    /// all changes since the last call.
+    pub fn timed_wait(
+        &mut self,
+        timeout: Duration,
+    ) -> Result<Vec<ServiceEvent>, ServiceWatcherWaitError> {
+        let start = Instant::now();
+        loop {
+            let events = self.try_wait()?;
+            let elapsed = start.elapsed();
+            if !events.is_empty() || elapsed >= timeout {
+                return Ok(events);
+            }
+
+            // the watcher is also notified by services of other types and by changes that are
+            // reverted before the comparison, therefore it waits again until the timeout has
+            // passed
+            if fail!(from self, when self.listener.timed_wait(timeout - elapsed),
+                    with ServiceWatcherWaitError::InternalError,
+                    "Unable to wait for service changes since the underlying event failed.")
+            .is_none()
+            {
+                return self.try_wait();
+            }
+        }
+    }
+
+    /// Blocks until at least one change was detected and returns all changes since the last
+    /// call. Sporadic wakeups can occur, e.g. when a signal was received, and then the
+    /// returned changes are empty.
+    pub fn blocking_wait(&mut self) -> Result<Vec<ServiceEvent>, ServiceWatcherWaitError> {
+        loop {
+            let events = self.try_wait()?;
+            if !events.is_empty() {
+                return Ok(events);
+            }
+
+            if fail!(from self, when self.listener.blocking_wait(),
+                    with ServiceWatcherWaitError::InternalError,
+                    "Unable to wait for service changes since the underlying event failed.")
+            .is_none()
+            {
+                return self.try_wait();
+            }
+        }
+    }
+
+    // services whose dynamic config cannot be opened are not considered as present
+    fn port_ids_of(&self, static_config: &StaticConfig) -> Option<Vec<PortId>> {
+        let storage = open_dynamic_config_for_inspection::<Service>(self.config, static_config)?;
+        Some(storage.get().port_ids())
+    }
+}
+
+fn service_watcher_config(
+    global_config: &config::Config,
+) -> <WatcherEvent as NamedConceptMgmt>::Configuration {
+    let origin = "service_watcher_config()";
+
+    let f = match FileName::new(
+        global_config
+            .global
+            .service
+            .service_watcher_suffix
+            .as_bytes(),
+    ) {
+        Err(_) => {
+            fatal_panic!(from origin, "The service_watcher_suffix \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.service.service_watcher_suffix);
+        }
+        Ok(v) => v,
+    };
+
+    let mut path_hint = match Path::new(global_config.global.root_path.as_bytes()) {
+        Err(_) => {
+            fatal_panic!(from origin, "The root_path \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.root_path);
+        }
+        Ok(v) => v,
+    };
+
+    if path_hint
+        .push_bytes(global_config.global.service.directory.as_bytes())
+        .is_err()
+    {
+        fatal_panic!(from origin, "The service.directory \"{}\" provided by the config contains either invalid file name characters or is too long.",
+                                       global_config.global.service.directory);
+    }
+
+    <WatcherEvent as NamedConceptMgmt>::Configuration::default()
+        .suffix(f)
+        .path_hint(path_hint)
+}
+
+/// Wakes up every [`ServiceWatcher`] of the given [`config::Config`]. Must be called after a
+/// service or a port was created or removed. Watchers that cannot be notified, e.g. since their
+/// process died or since they have enough pending notifications, are skipped.
+pub(crate) fn notify_service_watchers(global_config: &config::Config) {
+    let config = service_watcher_config(global_config);
+    let watchers = match WatcherEvent::list_cfg(&config) {
+        Ok(watchers) => watchers,
+        Err(_) => return,
+    };
+
+    for name in watchers {
+        if let Ok(notifier) = <WatcherEvent as Event<u64>>::NotifierBuilder::new(&name)
+            .config(&config)
+            .open()
+        {
+            notifier.notify(0).ok();
+        }
+    }
+}
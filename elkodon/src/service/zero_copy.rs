@@ -23,7 +23,7 @@ use crate::service::dynamic_config::DynamicConfig;
 use elkodon_cal::shm_allocator::pool_allocator::PoolAllocator;
 use elkodon_cal::*;
 
-use super::ServiceState;
+use super::{PayloadTransfer, ServiceState};
 
 /// Defines a zero copy inter-process communication setup based on posix mechanisms.
 #[derive(Debug)]
@@ -47,6 +47,13 @@ impl<'config> crate::service::Details<'config> for Service<'config> {
     type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
     type Event = event::unix_datagram_socket::Event<EventId>;
+    type Monitoring = monitoring::file_lock::Monitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::linux_epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
+    type Reactor = reactor::posix_select::Reactor;
+
+    const PAYLOAD_TRANSFER: PayloadTransfer = PayloadTransfer::ZeroCopy;
 
     fn from_state(state: ServiceState<'config, Self::StaticStorage, Self::DynamicStorage>) -> Self {
         Self { state }
@@ -0,0 +1,453 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! use elkodon::waitset::WaitSetBuilder;
+//! use std::time::Duration;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let event = zero_copy::Service::new(&ServiceName::new(b"MyEventName")?)
+//!     .event()
+//!     .open_or_create()?;
+//! let mut listener = event.listener().create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<zero_copy::Service>()?;
+//! let listener_guard = waitset.attach_notification(&listener)?;
+//! let interval_guard = waitset.attach_interval(Duration::from_millis(10))?;
+//!
+//! let mut listener_has_events = false;
+//! waitset.timed_wait(
+//!     |attachment_id| {
+//!         if attachment_id.event_from(&listener_guard) {
+//!             listener_has_events = true;
+//!         } else if attachment_id.event_from(&interval_guard) {
+//!             println!("the interval has passed");
+//!         }
+//!     },
+//!     Duration::from_millis(100),
+//! )?;
+//!
+//! drop(listener_guard);
+//! if listener_has_events {
+//!     for event_id in listener.try_wait()? {
+//!         println!("event was triggered with id: {:?}", event_id);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use elkodon_bb_log::fail;
+use elkodon_bb_posix::{
+    file_descriptor::FileDescriptor, file_descriptor_set::SynchronousMultiplexing,
+};
+use elkodon_cal::reactor::{
+    Reactor, ReactorAttachError, ReactorBuilder, ReactorCreateError, ReactorWaitError,
+};
+
+use crate::service;
+
+/// Defines the failures that can occur when a [`WaitSet`] is created with the
+/// [`WaitSetBuilder`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetCreateError {
+    InsufficientResources,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetCreateError {}
+
+/// Defines the failures that can occur when an attachment is added to the [`WaitSet`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetAttachmentError {
+    InsufficientCapacity,
+    AlreadyAttached,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetAttachmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetAttachmentError {}
+
+/// Defines the failures that can occur while waiting on the [`WaitSet`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WaitSetWaitError {
+    Interrupt,
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for WaitSetWaitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for WaitSetWaitError {}
+
+type ServiceReactor<'config, Service> =
+    <<Service as service::Service>::Type<'config> as service::Details<'config>>::Reactor;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+enum AttachmentKind {
+    Event,
+    MissedDeadline,
+}
+
+/// Is provided in the callback of [`WaitSet::try_wait()`], [`WaitSet::timed_wait()`] and
+/// [`WaitSet::blocking_wait()`] and identifies the attachment that fired.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct AttachmentId {
+    key: u64,
+    kind: AttachmentKind,
+}
+
+impl AttachmentId {
+    /// Returns true if the notification of the attachment was signaled or, in the case of an
+    /// interval, when the interval has passed.
+    pub fn event_from<Service: service::Service>(
+        &self,
+        guard: &Guard<'_, '_, '_, Service>,
+    ) -> bool {
+        self.key == guard.key && self.kind == AttachmentKind::Event
+    }
+
+    /// Returns true if the attachment was not signaled within its deadline.
+    pub fn deadline_from<Service: service::Service>(
+        &self,
+        guard: &Guard<'_, '_, '_, Service>,
+    ) -> bool {
+        self.key == guard.key && self.kind == AttachmentKind::MissedDeadline
+    }
+}
+
+#[derive(Debug)]
+struct Timer {
+    period: Duration,
+    next_expiration: Instant,
+}
+
+#[derive(Debug)]
+struct Attachment {
+    key: u64,
+    file_descriptor: Option<i32>,
+    timer: Option<Timer>,
+}
+
+/// Represents an attachment of the [`WaitSet`]. As soon as it goes out of scope the attachment
+/// is detached.
+pub struct Guard<'waitset, 'attachment, 'config, Service: service::Service> {
+    waitset: &'waitset WaitSet<'config, Service>,
+    key: u64,
+    _reactor_guard:
+        Option<<ServiceReactor<'config, Service> as Reactor>::Guard<'waitset, 'attachment>>,
+}
+
+impl<Service: service::Service> Debug for Guard<'_, '_, '_, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Guard {{ key: {} }}", self.key)
+    }
+}
+
+impl<Service: service::Service> Drop for Guard<'_, '_, '_, Service> {
+    fn drop(&mut self) {
+        self.waitset
+            .attachments
+            .borrow_mut()
+            .retain(|attachment| attachment.key != self.key);
+    }
+}
+
+/// Creates a new [`WaitSet`].
+#[derive(Debug, Default)]
+pub struct WaitSetBuilder {}
+
+impl WaitSetBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the [`WaitSet`] with the [`Reactor`] of the provided [`service::Service`].
+    pub fn create<'config, Service: service::Service>(
+        self,
+    ) -> Result<WaitSet<'config, Service>, WaitSetCreateError> {
+        let msg = "Unable to create WaitSet";
+        let reactor = match <ServiceReactor<'config, Service> as Reactor>::Builder::new().create() {
+            Ok(reactor) => reactor,
+            Err(ReactorCreateError::InsufficientResources) => {
+                fail!(from self, with WaitSetCreateError::InsufficientResources,
+                    "{} due to insufficient resources for the underlying reactor.", msg);
+            }
+            Err(ReactorCreateError::UnknownError(e)) => {
+                fail!(from self, with WaitSetCreateError::InternalError,
+                    "{} since the underlying reactor could not be created ({}).", msg, e);
+            }
+        };
+
+        Ok(WaitSet {
+            reactor,
+            attachments: RefCell::new(vec![]),
+            key_counter: Cell::new(0),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+/// Waits until at least one of its attachments fired. An attachment can be a notification, for
+/// instance a [`crate::port::listener::Listener`], a notification with a deadline or an
+/// interval.
+pub struct WaitSet<'config, Service: service::Service> {
+    reactor: ServiceReactor<'config, Service>,
+    attachments: RefCell<Vec<Attachment>>,
+    key_counter: Cell<u64>,
+    _phantom: PhantomData<Service>,
+}
+
+impl<Service: service::Service> Debug for WaitSet<'_, Service> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WaitSet {{ attachments: {:?} }}",
+            self.attachments.borrow()
+        )
+    }
+}
+
+impl<'config, Service: service::Service> WaitSet<'config, Service> {
+    /// Returns the number of attachments.
+    pub fn len(&self) -> usize {
+        self.attachments.borrow().len()
+    }
+
+    /// Returns true if the [`WaitSet`] has no attachments, otherwise false.
+    pub fn is_empty(&self) -> bool {
+        self.attachments.borrow().is_empty()
+    }
+
+    /// Returns the maximum number of notifications that can be attached.
+    pub fn capacity(&self) -> usize {
+        <ServiceReactor<'config, Service> as Reactor>::capacity()
+    }
+
+    /// Attaches a notification, for instance a [`crate::port::listener::Listener`]. The
+    /// [`WaitSet`] wakes up as soon as it was signaled.
+    pub fn attach_notification<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+    ) -> Result<Guard<'waitset, 'attachment, 'config, Service>, WaitSetAttachmentError> {
+        self.attach(attachment, None)
+    }
+
+    /// Attaches a notification with a deadline. The [`WaitSet`] wakes up as soon as it was
+    /// signaled or when it was not signaled within the deadline, see
+    /// [`AttachmentId::deadline_from()`].
+    pub fn attach_deadline<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Duration,
+    ) -> Result<Guard<'waitset, 'attachment, 'config, Service>, WaitSetAttachmentError> {
+        self.attach(attachment, Some(deadline))
+    }
+
+    /// Attaches an interval. The [`WaitSet`] wakes up every time the interval has passed.
+    pub fn attach_interval<'waitset, 'attachment>(
+        &'waitset self,
+        interval: Duration,
+    ) -> Result<Guard<'waitset, 'attachment, 'config, Service>, WaitSetAttachmentError> {
+        let key = self.add_attachment(None, Some(interval));
+
+        Ok(Guard {
+            waitset: self,
+            key,
+            _reactor_guard: None,
+        })
+    }
+
+    /// Checks all attachments without blocking and calls the provided callback for every
+    /// attachment that fired.
+    pub fn try_wait<F: FnMut(AttachmentId)>(&self, fn_call: F) -> Result<(), WaitSetWaitError> {
+        self.wait(fn_call, Some(Duration::ZERO))
+    }
+
+    /// Blocks until at least one attachment fired or the timeout has passed. The provided
+    /// callback is called for every attachment that fired.
+    pub fn timed_wait<F: FnMut(AttachmentId)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<(), WaitSetWaitError> {
+        self.wait(fn_call, Some(timeout))
+    }
+
+    /// Blocks until at least one attachment fired. The provided callback is called for every
+    /// attachment that fired.
+    pub fn blocking_wait<F: FnMut(AttachmentId)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), WaitSetWaitError> {
+        self.wait(fn_call, None)
+    }
+
+    fn attach<'waitset, 'attachment, T: SynchronousMultiplexing + Debug>(
+        &'waitset self,
+        attachment: &'attachment T,
+        deadline: Option<Duration>,
+    ) -> Result<Guard<'waitset, 'attachment, 'config, Service>, WaitSetAttachmentError> {
+        let msg = "Unable to attach";
+        let fd = unsafe { attachment.file_descriptor().native_handle() };
+
+        if self
+            .attachments
+            .borrow()
+            .iter()
+            .any(|a| a.file_descriptor == Some(fd))
+        {
+            fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
+                "{} {:?} since it is already attached.", msg, attachment);
+        }
+
+        let reactor_guard = match self.reactor.attach(attachment) {
+            Ok(guard) => guard,
+            Err(ReactorAttachError::AlreadyAttached) => {
+                fail!(from self, with WaitSetAttachmentError::AlreadyAttached,
+                    "{} {:?} since it is already attached to the underlying reactor.", msg, attachment);
+            }
+            Err(ReactorAttachError::CapacityExceeded) => {
+                fail!(from self, with WaitSetAttachmentError::InsufficientCapacity,
+                    "{} {:?} since it would exceed the capacity of {}.",
+                    msg, attachment, self.capacity());
+            }
+            Err(ReactorAttachError::UnknownError(e)) => {
+                fail!(from self, with WaitSetAttachmentError::InternalError,
+                    "{} {:?} due to an internal failure in the underlying reactor ({}).",
+                    msg, attachment, e);
+            }
+        };
+
+        let key = self.add_attachment(Some(fd), deadline);
+
+        Ok(Guard {
+            waitset: self,
+            key,
+            _reactor_guard: Some(reactor_guard),
+        })
+    }
+
+    fn add_attachment(&self, file_descriptor: Option<i32>, period: Option<Duration>) -> u64 {
+        let key = self.key_counter.get();
+        self.key_counter.set(key + 1);
+
+        self.attachments.borrow_mut().push(Attachment {
+            key,
+            file_descriptor,
+            timer: period.map(|period| Timer {
+                period,
+                next_expiration: Instant::now() + period,
+            }),
+        });
+
+        key
+    }
+
+    fn time_until_next_expiration(&self) -> Option<Duration> {
+        let now = Instant::now();
+        self.attachments
+            .borrow()
+            .iter()
+            .filter_map(|a| a.timer.as_ref())
+            .map(|timer| timer.next_expiration.saturating_duration_since(now))
+            .min()
+    }
+
+    fn wait<F: FnMut(AttachmentId)>(
+        &self,
+        mut fn_call: F,
+        timeout: Option<Duration>,
+    ) -> Result<(), WaitSetWaitError> {
+        let msg = "Unable to wait on WaitSet";
+
+        let wait_time = match (timeout, self.time_until_next_expiration()) {
+            (Some(timeout), Some(expiration)) => Some(timeout.min(expiration)),
+            (Some(timeout), None) => Some(timeout),
+            (None, expiration) => expiration,
+        };
+
+        let mut fired_fds = vec![];
+        let collect_fds = |fd: &FileDescriptor| fired_fds.push(unsafe { fd.native_handle() });
+        let result = match wait_time {
+            Some(wait_time) => self.reactor.timed_wait(collect_fds, wait_time),
+            None => self.reactor.blocking_wait(collect_fds),
+        };
+
+        match result {
+            Ok(()) => (),
+            Err(ReactorWaitError::Interrupt) => {
+                fail!(from self, with WaitSetWaitError::Interrupt,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(ReactorWaitError::InsufficientPermissions) => {
+                fail!(from self, with WaitSetWaitError::InsufficientPermissions,
+                    "{} due to insufficient permissions.", msg);
+            }
+            Err(ReactorWaitError::UnknownError) => {
+                fail!(from self, with WaitSetWaitError::InternalError,
+                    "{} due to an internal failure in the underlying reactor.", msg);
+            }
+        }
+
+        let now = Instant::now();
+        let mut fired_attachments = vec![];
+        for attachment in self.attachments.borrow_mut().iter_mut() {
+            let has_event = attachment
+                .file_descriptor
+                .is_some_and(|fd| fired_fds.contains(&fd));
+
+            if has_event {
+                fired_attachments.push(AttachmentId {
+                    key: attachment.key,
+                    kind: AttachmentKind::Event,
+                });
+            }
+
+            if let Some(ref mut timer) = attachment.timer {
+                if has_event {
+                    timer.next_expiration = now + timer.period;
+                } else if timer.next_expiration <= now {
+                    fired_attachments.push(AttachmentId {
+                        key: attachment.key,
+                        kind: match attachment.file_descriptor {
+                            Some(_) => AttachmentKind::MissedDeadline,
+                            None => AttachmentKind::Event,
+                        },
+                    });
+                    timer.next_expiration = now + timer.period;
+                }
+            }
+        }
+
+        // the attachments must not be borrowed while the user callback is called, it could
+        // attach or detach other attachments
+        for id in fired_attachments {
+            fn_call(id);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/ImageProcessing")?;
+//! # let service = zero_copy::Service::new(&service_name)
+//! #   .pipeline()
+//! #   .open_or_create::<u64>()?;
+//! # let worker = service.worker().create()?;
+//!
+//! while let Some(work_item) = worker.receive()? {
+//!     println!("received: {:?}", *work_item);
+//!     println!("producer id {:?}", work_item.header().producer_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref, ptr::NonNull};
+
+use crate::{message::Message, port::worker::Worker, service, service::header::pipeline::Header};
+
+/// It stores the payload and is acquired by the [`Worker`] whenever it takes a sample from the
+/// work queue via [`Worker::receive()`]. When it goes out of scope the sample is considered
+/// as processed and its memory is released.
+#[derive(Debug)]
+pub struct WorkItem<'a, 'worker, 'config, Service: service::Details<'config>, MessageType: Debug> {
+    worker: &'worker Worker<'a, 'config, Service, MessageType>,
+    ptr: NonNull<Message<Header, MessageType>>,
+    sample_index: usize,
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug> Deref
+    for WorkItem<'_, '_, 'config, Service, MessageType>
+{
+    type Target = MessageType;
+    fn deref(&self) -> &Self::Target {
+        unsafe { &self.ptr.as_ref().data }
+    }
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug> Drop
+    for WorkItem<'_, '_, 'config, Service, MessageType>
+{
+    fn drop(&mut self) {
+        self.worker.release_sample(self.sample_index);
+    }
+}
+
+impl<'a, 'worker, 'config, Service: service::Details<'config>, MessageType: Debug>
+    WorkItem<'a, 'worker, 'config, Service, MessageType>
+{
+    pub(crate) fn new(
+        worker: &'worker Worker<'a, 'config, Service, MessageType>,
+        ptr: NonNull<Message<Header, MessageType>>,
+        sample_index: usize,
+    ) -> Self {
+        Self {
+            worker,
+            ptr,
+            sample_index,
+        }
+    }
+
+    /// Returns a reference to the payload of the work item
+    pub fn payload(&self) -> &MessageType {
+        &unsafe { self.ptr.as_ref() }.data
+    }
+
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        &unsafe { self.ptr.as_ref() }.header
+    }
+}
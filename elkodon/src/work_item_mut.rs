@@ -0,0 +1,101 @@
+//! # Example
+//!
+//! ```
+//! use elkodon::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let service_name = ServiceName::new(b"My/Funk/ImageProcessing").unwrap();
+//! #
+//! # let service = zero_copy::Service::new(&service_name)
+//! #     .pipeline()
+//! #     .open_or_create::<u64>()?;
+//! #
+//! # let producer = service.producer().create()?;
+//!
+//! let mut work_item = producer.loan()?;
+//!
+//! unsafe { work_item.as_mut_ptr().write(1234) };
+//! println!("producer port id: {:?}", work_item.header().producer_id());
+//! producer.send(work_item)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    message::Message, port::producer::Producer, service, service::header::pipeline::Header,
+};
+use std::{fmt::Debug, mem::MaybeUninit, ptr::NonNull, sync::atomic::Ordering};
+
+/// Acquired by a [`Producer`] via [`Producer::loan()`]. It stores the payload that will be put
+/// into the work queue of the pipeline. If the [`WorkItemMut`] is not sent it will release the
+/// loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent samples in the [`Producer`] and the
+/// [`Producer`] is not thread-safe!
+#[derive(Debug)]
+pub struct WorkItemMut<
+    'a,
+    'producer,
+    'config,
+    Service: service::Details<'config>,
+    MessageType: Debug,
+> {
+    producer: &'producer Producer<'a, 'config, Service, MessageType>,
+    ptr: NonNull<MaybeUninit<Message<Header, MessageType>>>,
+    sample_index: usize,
+    is_sent: bool,
+}
+
+impl<'config, Service: service::Details<'config>, MessageType: Debug> Drop
+    for WorkItemMut<'_, '_, 'config, Service, MessageType>
+{
+    fn drop(&mut self) {
+        if !self.is_sent {
+            self.producer.release_sample(self.sample_index);
+        }
+        self.producer.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl<'a, 'producer, 'config, Service: service::Details<'config>, MessageType: Debug>
+    WorkItemMut<'a, 'producer, 'config, Service, MessageType>
+{
+    pub(crate) fn new(
+        producer: &'producer Producer<'a, 'config, Service, MessageType>,
+        ptr: NonNull<MaybeUninit<Message<Header, MessageType>>>,
+        sample_index: usize,
+    ) -> Self {
+        producer.loan_counter.fetch_add(1, Ordering::Relaxed);
+        Self {
+            producer,
+            ptr,
+            sample_index,
+            is_sent: false,
+        }
+    }
+
+    pub(crate) fn sample_index(&self) -> usize {
+        self.sample_index
+    }
+
+    pub(crate) fn mark_as_sent(&mut self) {
+        self.is_sent = true;
+    }
+
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        &unsafe { &*self.ptr.as_ref().as_ptr() }.header
+    }
+
+    /// Returns a pointer to the underlying memory.
+    pub fn as_ptr(&self) -> *const MessageType {
+        &unsafe { &*self.ptr.as_ref().as_ptr() }.data
+    }
+
+    /// Returns a mutable pointer to the underlying memory.
+    pub fn as_mut_ptr(&mut self) -> *mut MessageType {
+        &mut unsafe { &mut *self.ptr.as_mut().as_mut_ptr() }.data
+    }
+}
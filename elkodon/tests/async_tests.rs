@@ -0,0 +1,280 @@
+#![cfg(feature = "async")]
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Barrier};
+use std::task::{Context, Poll, Wake};
+use std::thread::Thread;
+use std::time::{Duration, Instant};
+
+use elkodon::port::event_id::EventId;
+use elkodon::port::subscriber::ReceiveError;
+use elkodon::service::{service_name::ServiceName, zero_copy, Service};
+use elkodon_bb_container::semantic_string::*;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_testing::assert_that;
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+fn generate_name() -> ServiceName {
+    let mut service = ServiceName::new(b"async_tests_").unwrap();
+    service
+        .push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+    service
+}
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// A minimal executor which is not based on any runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
+#[test]
+fn listener_wait_async_returns_pending_events_immediately() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let mut sut = service.listener().create().unwrap();
+    let notifier = service.notifier().create().unwrap();
+
+    assert_that!(
+        notifier.notify_with_custom_event_id(EventId::new(12)),
+        is_ok
+    );
+
+    let result = block_on(sut.wait_async());
+    assert_that!(result, is_ok);
+    assert_that!(result.unwrap(), eq[EventId::new(12)]);
+}
+
+#[test]
+fn listener_wait_async_waits_until_notified() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let mut sut = service.listener().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let notifier = service.notifier().create().unwrap();
+            barrier.wait();
+            std::thread::sleep(TIMEOUT);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), is_ok);
+        });
+
+        barrier.wait();
+        let start = Instant::now();
+        let result = block_on(sut.wait_async());
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), eq[EventId::new(5)]);
+    });
+}
+
+#[test]
+fn multiple_listeners_wait_async_concurrently() {
+    const NUMBER_OF_LISTENERS: usize = 4;
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .max_listeners(NUMBER_OF_LISTENERS)
+        .create()
+        .unwrap();
+    let barrier = Barrier::new(NUMBER_OF_LISTENERS + 1);
+
+    std::thread::scope(|s| {
+        for _ in 0..NUMBER_OF_LISTENERS {
+            s.spawn(|| {
+                let mut sut = service.listener().create().unwrap();
+                barrier.wait();
+                let result = block_on(sut.wait_async());
+                assert_that!(result, is_ok);
+                assert_that!(result.unwrap(), eq[EventId::new(7)]);
+            });
+        }
+
+        let notifier = service.notifier().create().unwrap();
+        barrier.wait();
+        std::thread::sleep(TIMEOUT);
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(7)), eq Ok(NUMBER_OF_LISTENERS));
+    });
+}
+
+#[test]
+fn subscriber_receive_async_without_notifications_fails() {
+    let service = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .create::<u64>()
+        .unwrap();
+    let sut = service.subscriber().create().unwrap();
+
+    let result = block_on(sut.receive_async());
+    assert_that!(result, is_err);
+    assert_that!(result.err().unwrap(), eq ReceiveError::NotificationsNotEnabled);
+}
+
+#[test]
+fn subscriber_receive_async_returns_available_sample_immediately() {
+    let service = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+    let sut = service.subscriber().create().unwrap();
+    let publisher = service.publisher().create().unwrap();
+
+    assert_that!(publisher.send_copy(1234), is_ok);
+
+    let sample = block_on(sut.receive_async());
+    assert_that!(sample, is_ok);
+    assert_that!(*sample.unwrap(), eq 1234);
+}
+
+#[test]
+fn subscriber_receive_async_waits_until_sample_was_delivered() {
+    let service = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+    let publisher = service.publisher().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let sut = service.subscriber().create().unwrap();
+            barrier.wait();
+
+            let start = Instant::now();
+            let sample = block_on(sut.receive_async());
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+            assert_that!(sample, is_ok);
+            assert_that!(*sample.unwrap(), eq 5678);
+        });
+
+        barrier.wait();
+        std::thread::sleep(TIMEOUT);
+        assert_that!(publisher.send_copy(5678), is_ok);
+    });
+}
+
+#[cfg(feature = "tokio")]
+fn tokio_runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap()
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn listener_wait_async_in_tokio_runtime_waits_until_notified() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let mut sut = service.listener().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let notifier = service.notifier().create().unwrap();
+            barrier.wait();
+            std::thread::sleep(TIMEOUT);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(9)), is_ok);
+        });
+
+        barrier.wait();
+        let start = Instant::now();
+        let result = tokio_runtime().block_on(sut.wait_async());
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), eq[EventId::new(9)]);
+    });
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn subscriber_receive_async_in_tokio_runtime_waits_until_sample_was_delivered() {
+    let service = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+    let publisher = service.publisher().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let sut = service.subscriber().create().unwrap();
+            barrier.wait();
+
+            let start = Instant::now();
+            let sample = tokio_runtime().block_on(sut.receive_async());
+            assert_that!(start.elapsed(), time_at_least TIMEOUT);
+            assert_that!(sample, is_ok);
+            assert_that!(*sample.unwrap(), eq 91);
+        });
+
+        barrier.wait();
+        std::thread::sleep(TIMEOUT);
+        assert_that!(publisher.send_copy(91), is_ok);
+    });
+}
+
+#[cfg(feature = "tokio")]
+#[test]
+fn listener_wait_async_in_tokio_runtime_without_io_waits_until_notified() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let mut sut = service.listener().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let notifier = service.notifier().create().unwrap();
+            barrier.wait();
+            std::thread::sleep(TIMEOUT);
+            assert_that!(notifier.notify_with_custom_event_id(EventId::new(5)), is_ok);
+        });
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .build()
+            .unwrap();
+
+        barrier.wait();
+        let start = Instant::now();
+        let result = runtime.block_on(sut.wait_async());
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), eq[EventId::new(5)]);
+    });
+}
@@ -0,0 +1,216 @@
+#[generic_tests::define]
+mod introspection {
+    use std::time::{Duration, Instant};
+
+    use elkodon::config::Config;
+    use elkodon::introspection::{self, IntrospectionThread, Introspector, PortKind, PortState};
+    use elkodon::service::{service_name::ServiceName, Details, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_secs(2);
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"introspection_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    // every introspector publishes the state of all services, the tests are isolated in their
+    // own service directory so that they do not compete for the introspection publishers
+    fn generate_config() -> Config {
+        let mut config = Config::default();
+        config.global.service.directory = format!(
+            "introspection_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        );
+        config
+    }
+
+    // a new subscriber receives the port states with the next publish of the introspector
+    fn receive_port_states<Sut: Service>(
+        introspector: &mut Introspector<Sut>,
+        config: &Config,
+        service_uuid: &str,
+    ) -> Vec<PortState> {
+        introspector.publish().unwrap();
+        let service = Sut::new(&introspection::service_name(service_uuid))
+            .publish_subscribe_with_custom_config(config)
+            .open::<[PortState]>()
+            .unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+        introspector.publish().unwrap();
+
+        let mut states = None;
+        while let Some(sample) = subscriber.receive().unwrap() {
+            states = Some(sample.payload().to_vec());
+        }
+        states.unwrap()
+    }
+
+    fn state_of(states: &[PortState], kind: PortKind) -> PortState {
+        let states: Vec<&PortState> = states.iter().filter(|s| s.kind == kind).collect();
+        assert_that!(states, len 1);
+        *states[0]
+    }
+
+    #[test]
+    fn publishes_port_states_of_publisher_and_subscriber<Sut: Service>() {
+        let config = generate_config();
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe_with_custom_config(&config)
+            .subscriber_max_buffer_size(5)
+            .create::<u64>()
+            .unwrap();
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        publisher.send_copy(1).unwrap();
+        publisher.send_copy(2).unwrap();
+        let _loaned_sample = publisher.loan().unwrap();
+        let _received_sample = subscriber.receive().unwrap().unwrap();
+
+        let mut introspector = Introspector::<Sut>::new_with_custom_config(&config);
+        let states = receive_port_states(&mut introspector, &config, sut.uuid());
+        assert_that!(states, len 2);
+
+        let publisher_state = state_of(&states, PortKind::Publisher);
+        assert_that!(publisher_state.pid, eq std::process::id() as i32);
+        assert_that!(publisher_state.number_of_connections, eq 1);
+        assert_that!(publisher_state.samples_in_buffer, eq 1);
+        assert_that!(publisher_state.loaned_samples, eq 1);
+        assert_that!(publisher_state.dropped_samples, eq 0);
+
+        let subscriber_state = state_of(&states, PortKind::Subscriber);
+        assert_that!(subscriber_state.number_of_connections, eq 1);
+        assert_that!(subscriber_state.samples_in_buffer, eq 1);
+        assert_that!(subscriber_state.borrowed_samples, eq 1);
+        assert_that!(subscriber_state.dropped_samples, eq 0);
+    }
+
+    #[test]
+    fn publishes_ports_of_every_messaging_pattern<Sut: Service>() {
+        let config = generate_config();
+        let sut = Sut::new(&generate_name())
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+        let _notifier = sut.notifier().create().unwrap();
+        let _listener = sut.listener().create().unwrap();
+
+        let mut introspector = Introspector::<Sut>::new_with_custom_config(&config);
+        let states = receive_port_states(&mut introspector, &config, sut.uuid());
+        assert_that!(states, len 2);
+        assert_that!(state_of(&states, PortKind::Notifier).number_of_connections, eq 0);
+        assert_that!(state_of(&states, PortKind::Listener).number_of_connections, eq 0);
+    }
+
+    #[test]
+    fn published_port_states_are_updated<Sut: Service>() {
+        let config = generate_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+
+        let mut introspector = Introspector::<Sut>::new_with_custom_config(&config);
+        assert_that!(receive_port_states(&mut introspector, &config, sut.uuid()), len 0);
+
+        let _publisher = sut.publisher().create().unwrap();
+        assert_that!(receive_port_states(&mut introspector, &config, sut.uuid()), len 1);
+    }
+
+    #[test]
+    fn port_states_are_provided_without_publishing<Sut: Service>() {
+        let config = generate_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let introspector = Introspector::<Sut>::new_with_custom_config(&config);
+        let services = <Sut::Type<'_> as Details>::list_with_custom_config(&config).unwrap();
+        assert_that!(services, len 1);
+
+        let states = introspector.port_states(&services[0]).unwrap();
+        assert_that!(states, len 1);
+        assert_that!(states[0].kind, eq PortKind::Subscriber);
+
+        drop(subscriber);
+        drop(sut);
+        assert_that!(introspector.port_states(&services[0]), eq None);
+    }
+
+    #[test]
+    fn introspection_service_is_removed_with_the_introspected_service<Sut: Service>() {
+        let config = generate_config();
+        let sut = Sut::new(&generate_name())
+            .publish_subscribe_with_custom_config(&config)
+            .create::<u64>()
+            .unwrap();
+        let introspection_service_name = introspection::service_name(sut.uuid());
+
+        let mut introspector = Introspector::<Sut>::new_with_custom_config(&config);
+        introspector.publish().unwrap();
+        assert_that!(<Sut::Type<'_> as Details>::does_exist_with_custom_config(&introspection_service_name, &config), eq Ok(true));
+
+        drop(sut);
+        introspector.publish().unwrap();
+        assert_that!(<Sut::Type<'_> as Details>::does_exist_with_custom_config(&introspection_service_name, &config), eq Ok(false));
+    }
+
+    #[test]
+    fn introspection_services_are_not_introspected<Sut: Service>() {
+        let config = generate_config();
+        let _sut = Sut::new(&generate_name())
+            .event_with_custom_config(&config)
+            .create()
+            .unwrap();
+
+        let mut introspector = Introspector::<Sut>::new_with_custom_config(&config);
+        introspector.publish().unwrap();
+        introspector.publish().unwrap();
+
+        let services = <Sut::Type<'_> as Details>::list_with_custom_config(&config).unwrap();
+        assert_that!(services, len 2);
+        for service in &services {
+            if introspection::is_introspection_service(service) {
+                assert_that!(<Sut::Type<'_> as Details>::does_exist_with_custom_config(&introspection::service_name(service.uuid()), &config), eq Ok(false));
+            }
+        }
+    }
+
+    #[test]
+    fn introspection_thread_publishes_port_states_periodically<Sut: Service + 'static>() {
+        let sut = Sut::new(&generate_name()).event().create().unwrap();
+        let introspection_service_name = introspection::service_name(sut.uuid());
+
+        let _thread = IntrospectionThread::spawn::<Sut>(Duration::from_millis(10)).unwrap();
+
+        let start = Instant::now();
+        while !<Sut::Type<'static> as Details>::does_exist(&introspection_service_name).unwrap() {
+            assert_that!(start.elapsed() < TIMEOUT, eq true);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
@@ -0,0 +1,127 @@
+#![cfg(all(feature = "log", not(feature = "tracing")))]
+
+use std::sync::Mutex;
+
+use elkodon::service::{service_name::ServiceName, zero_copy, Service};
+use elkodon_bb_container::semantic_string::*;
+use elkodon_bb_log::{debug, fatal_panic, info, set_log_level, warn, LogLevel};
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_testing::assert_that;
+
+fn generate_name() -> ServiceName {
+    let mut service = ServiceName::new(b"log_tests_").unwrap();
+    service
+        .push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+    service
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    level: log::Level,
+    target: String,
+    message: String,
+}
+
+/// Stores every record of the `log` facade.
+struct Recorder {
+    entries: Mutex<Vec<Entry>>,
+}
+
+impl log::Log for Recorder {
+    fn enabled(&self, _: &log::Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &log::Record) {
+        self.entries.lock().unwrap().push(Entry {
+            level: record.level(),
+            target: record.target().to_string(),
+            message: record.args().to_string(),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+static RECORDER: Recorder = Recorder {
+    entries: Mutex::new(vec![]),
+};
+
+// the log facade and the log level are global, therefore everything is verified in one test
+#[test]
+fn log_messages_are_forwarded_to_the_log_facade() {
+    log::set_logger(&RECORDER).unwrap();
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let find = |message: &str| {
+        RECORDER
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|e| e.message.contains(message))
+            .cloned()
+    };
+
+    // without log level of elkodon only the max level of the log facade is applied
+    debug!("debug message without log level");
+    assert_that!(find("debug message without log level").unwrap().level, eq log::Level::Debug);
+    elkodon_bb_log::trace!("trace message without log level");
+    assert_that!(find("trace message without log level"), is_none);
+
+    set_log_level(LogLevel::Trace);
+
+    info!(from "Custom::Origin", "hello world");
+    let entry = find("hello world");
+    assert_that!(entry, is_some);
+    assert_that!(entry.unwrap(), eq Entry {
+        level: log::Level::Info,
+        target: "Custom::Origin".to_string(),
+        message: "hello world".to_string(),
+    });
+
+    warn!("no origin");
+    let entry = find("no origin").unwrap();
+    assert_that!(entry.level, eq log::Level::Warn);
+    assert_that!(entry.target, eq "elkodon");
+
+    debug!("debug message");
+    assert_that!(find("debug message").unwrap().level, eq log::Level::Debug);
+
+    // filtered by the max level of the log facade
+    elkodon_bb_log::trace!("trace message");
+    assert_that!(find("trace message"), is_none);
+
+    // filtered by the log level of elkodon
+    set_log_level(LogLevel::Warn);
+    info!("filtered info message");
+    assert_that!(find("filtered info message"), is_none);
+
+    let result = std::panic::catch_unwind(|| {
+        fatal_panic!(from "Fatal::Origin", "fatal message");
+    });
+    assert_that!(result, is_err);
+    assert_that!(find("fatal message").unwrap().level, eq log::Level::Error);
+
+    set_log_level(LogLevel::Debug);
+    let sut = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .open::<u64>();
+    assert_that!(sut, is_err);
+    assert_that!(
+        RECORDER
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|e| e.message.contains("does not exist")),
+        eq true
+    );
+}
@@ -172,4 +172,10 @@ mod publisher {
 
     #[instantiate_tests(<elkodon::service::process_local::Service>)]
     mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::copy::Service>)]
+    mod copy {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
 }
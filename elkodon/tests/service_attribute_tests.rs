@@ -0,0 +1,170 @@
+#[generic_tests::define]
+mod service_attribute {
+    use elkodon::service::builder::event::EventOpenError;
+    use elkodon::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use elkodon::service::{service_name::ServiceName, Details, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"service_attribute_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[test]
+    fn service_without_attributes_has_empty_attribute_set<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.attributes().is_empty(), eq true);
+    }
+
+    #[test]
+    fn attributes_are_stored_in_the_static_config<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .attribute("owner", "hypnotoad")
+            .attribute("frequency", "10Hz")
+            .attribute("frequency", "100Hz")
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let attributes = sut.attributes();
+        assert_that!(attributes.len(), eq 2);
+        assert_that!(attributes.get("owner"), eq Some("hypnotoad"));
+        assert_that!(attributes.get("frequency"), eq Some("100Hz"));
+        assert_that!(attributes.get("unit"), eq None);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+        assert_that!(*sut2.attributes(), eq * attributes);
+    }
+
+    #[test]
+    fn open_with_required_attributes_works<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .attribute("owner", "hypnotoad")
+            .attribute("unit", "m/s")
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .require_attribute("unit", "m/s")
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_required_attribute_differs_or_is_missing<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .attribute("owner", "hypnotoad")
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .require_attribute("owner", "nibbler")
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleAttributes);
+
+        let sut2 = Sut::new(&service_name)
+            .require_attribute("unit", "m/s")
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn open_event_fails_when_required_attribute_is_missing<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name).event().create().unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .require_attribute("owner", "hypnotoad")
+            .event()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq EventOpenError::IncompatibleAttributes);
+    }
+
+    #[test]
+    fn required_attributes_are_defined_when_the_service_is_created<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .require_attribute("owner", "hypnotoad")
+            .publish_subscribe()
+            .open_or_create::<u64>()
+            .unwrap();
+        assert_that!(sut.attributes().get("owner"), eq Some("hypnotoad"));
+
+        let sut2 = Sut::new(&service_name)
+            .require_attribute("owner", "hypnotoad")
+            .publish_subscribe()
+            .open_or_create::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn list_filtered_returns_only_services_with_matching_attributes<
+        Sut: Service + Details<'static>,
+    >() {
+        let service_name_1 = generate_name();
+        let service_name_2 = generate_name();
+        let tag = service_name_1.to_string();
+
+        let _sut_1 = Sut::new(&service_name_1)
+            .attribute("tag", &tag)
+            .attribute("unit", "m/s")
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let _sut_2 = Sut::new(&service_name_2)
+            .attribute("tag", &tag)
+            .attribute("unit", "km/h")
+            .event()
+            .create()
+            .unwrap();
+
+        let services = Sut::list_filtered(|attributes| attributes.contains("tag", &tag)).unwrap();
+        assert_that!(services, len 2);
+
+        let services = Sut::list_filtered(|attributes| {
+            attributes.contains("tag", &tag) && attributes.contains("unit", "m/s")
+        })
+        .unwrap();
+        assert_that!(services, len 1);
+        assert_that!(*services[0].service_name(), eq service_name_1);
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
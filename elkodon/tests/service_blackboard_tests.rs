@@ -0,0 +1,402 @@
+#[generic_tests::define]
+mod service_blackboard {
+    use elkodon::config::Config;
+    use elkodon::port::reader::{EntryHandleError, ReaderCreateError};
+    use elkodon::port::writer::{EntryHandleMutError, WriterCreateError};
+    use elkodon::service::builder::blackboard::BlackboardCreateError;
+    use elkodon::service::builder::blackboard::BlackboardOpenError;
+    use elkodon::service::{service_name::ServiceName, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"service_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Pose {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+        assert_that!(sut.static_config().number_of_entries(), eq 1);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn create_without_entries_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).blackboard::<u64>().create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardCreateError::NoEntriesProvided);
+    }
+
+    #[test]
+    fn create_with_duplicate_keys_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .add::<u8>(1, 0)
+            .add::<u64>(0, 0)
+            .create();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardCreateError::DuplicateEntryKey);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).blackboard::<u64>().open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_key_type<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).blackboard::<u32>().open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::IncompatibleKeyType);
+    }
+
+    #[test]
+    fn open_fails_when_service_is_event<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).event().create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).blackboard::<u64>().open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::IncompatibleMessagingPattern);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(2)
+            .add::<u32>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_support_requested_amount_of_writers<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_writers(2)
+            .add::<u32>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_writers(3)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfWriters);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_writers(2)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .open_or_create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .open_or_create();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().number_of_entries(), eq 1);
+    }
+
+    #[test]
+    fn max_readers_is_set_to_config_default<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(
+            sut.static_config().max_supported_readers(), eq
+            Config::get_global_config().defaults.blackboard.max_readers
+        );
+        assert_that!(
+            sut.static_config().max_supported_writers(), eq
+            Config::get_global_config().defaults.blackboard.max_writers
+        );
+    }
+
+    #[test]
+    fn set_max_readers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(0)
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_readers(), eq 1);
+    }
+
+    #[test]
+    fn set_max_writers_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_writers(0)
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_writers(), eq 1);
+    }
+
+    #[test]
+    fn number_of_readers_and_writers_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_READERS: usize = 4;
+
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_readers(MAX_READERS)
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for i in 0..MAX_READERS {
+            readers.push(sut.reader().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_readers(), eq i + 1);
+        }
+
+        let reader = sut.reader().create();
+        assert_that!(reader, is_err);
+        assert_that!(reader.err().unwrap(), eq ReaderCreateError::ExceedsMaxSupportedReaders);
+
+        let writer = sut.writer().create().unwrap();
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+        let writer2 = sut.writer().create();
+        assert_that!(writer2, is_err);
+        assert_that!(writer2.err().unwrap(), eq WriterCreateError::ExceedsMaxSupportedWriters);
+
+        drop(writer);
+        readers.clear();
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+    }
+
+    #[test]
+    fn entries_are_initialized_with_default_values<Sut: Service>() {
+        let service_name = generate_name();
+        let pose = Pose {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        };
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u8>(0, 12)
+            .add::<Pose>(1, pose)
+            .add::<i64>(2, -1234)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader().create().unwrap();
+        assert_that!(reader.entry::<u8>(&0).unwrap().get(), eq 12);
+        assert_that!(reader.entry::<Pose>(&1).unwrap().get(), eq pose);
+        assert_that!(reader.entry::<i64>(&2).unwrap().get(), eq - 1234);
+    }
+
+    #[test]
+    fn written_values_are_read_by_all_readers<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .add::<Pose>(
+                1,
+                Pose {
+                    x: 0.0,
+                    y: 0.0,
+                    z: 0.0,
+                },
+            )
+            .create()
+            .unwrap();
+        let sut2 = Sut::new(&service_name).blackboard::<u64>().open().unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader_1 = sut.reader().create().unwrap();
+        let reader_2 = sut2.reader().create().unwrap();
+
+        let entry_mut = writer.entry::<u32>(&0).unwrap();
+        let pose_mut = writer.entry::<Pose>(&1).unwrap();
+        let entry_1 = reader_1.entry::<u32>(&0).unwrap();
+        let entry_2 = reader_2.entry::<u32>(&0).unwrap();
+        let pose = reader_2.entry::<Pose>(&1).unwrap();
+
+        for i in 1..10 {
+            entry_mut.update(i);
+            assert_that!(entry_mut.get(), eq i);
+            assert_that!(entry_1.get(), eq i);
+            assert_that!(entry_2.get(), eq i);
+        }
+
+        let new_pose = Pose {
+            x: 4.5,
+            y: -1.5,
+            z: 0.25,
+        };
+        pose_mut.update(new_pose);
+        assert_that!(pose.get(), eq new_pose);
+    }
+
+    #[test]
+    fn acquiring_entry_with_wrong_key_or_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+        let reader = sut.reader().create().unwrap();
+
+        assert_that!(reader.entry::<u32>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u64>(&0).err(), eq Some(EntryHandleError::IncompatibleType));
+        assert_that!(writer.entry::<u32>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.entry::<i32>(&0).err(), eq Some(EntryHandleMutError::IncompatibleType));
+    }
+
+    #[test]
+    fn only_one_mutable_entry_handle_can_exist_at_a_time<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .add::<u32>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer().create().unwrap();
+
+        let entry = writer.entry::<u32>(&0);
+        assert_that!(entry, is_ok);
+        assert_that!(writer.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+
+        drop(entry);
+        assert_that!(writer.entry::<u32>(&0), is_ok);
+    }
+
+    #[test]
+    fn mutable_entry_handle_is_exclusive_across_writers<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .blackboard::<u64>()
+            .max_writers(2)
+            .add::<u32>(0, 0)
+            .add::<u32>(1, 0)
+            .create()
+            .unwrap();
+
+        let writer_1 = sut.writer().create().unwrap();
+        let writer_2 = sut.writer().create().unwrap();
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 2);
+
+        let entry = writer_1.entry::<u32>(&0);
+        assert_that!(entry, is_ok);
+        assert_that!(writer_2.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+        assert_that!(writer_2.entry::<u32>(&1), is_ok);
+
+        drop(entry);
+        let entry = writer_2.entry::<u32>(&0);
+        assert_that!(entry, is_ok);
+        entry.unwrap().update(1234);
+        assert_that!(sut.reader().create().unwrap().entry::<u32>(&0).unwrap().get(), eq 1234);
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
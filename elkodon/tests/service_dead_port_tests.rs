@@ -0,0 +1,424 @@
+use std::process::Command;
+use std::time::Duration;
+
+use elkodon::config::Config;
+use elkodon::service::{copy, service_name::ServiceName, zero_copy, Details, Service};
+use elkodon_bb_container::semantic_string::*;
+use elkodon_bb_posix::process::Process;
+use elkodon_bb_posix::signal::Signal;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_bb_testing::assert_that;
+use elkodon_cal::named_concept::*;
+
+// when set, the test binary acts as the process that dies while owning ports
+const DEAD_PROCESS_SERVICE_NAME: &str = "ELKODON_DEAD_PORT_TESTS_SERVICE_NAME";
+
+fn generate_name() -> ServiceName {
+    let mut service = ServiceName::new(b"service_tests_").unwrap();
+    service
+        .push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+    service
+}
+
+fn spawn_process_that_dies_while_owning_ports(test_name: &str, service_name: &ServiceName) {
+    let status = Command::new(std::env::current_exe().unwrap())
+        .args([test_name, "--exact", "--nocapture"])
+        .env(DEAD_PROCESS_SERVICE_NAME, service_name.to_string())
+        .status()
+        .unwrap();
+
+    assert_that!(status.success(), eq false);
+}
+
+fn number_of_connections_of_service(service_uuid: &str) -> usize {
+    type Connection = <zero_copy::Service<'static> as Details<'static>>::Connection;
+    let suffix = &Config::get_global_config().global.service.connection_suffix;
+    let config = <Connection as NamedConceptMgmt>::Configuration::default()
+        .suffix(FileName::new(suffix.as_bytes()).unwrap());
+
+    <Connection as NamedConceptMgmt>::list_cfg(&config)
+        .unwrap()
+        .iter()
+        .filter(|name| name.to_string().starts_with(service_uuid))
+        .count()
+}
+
+fn die_without_cleanup() -> ! {
+    Process::from_self().send_signal(Signal::Kill).unwrap();
+    unreachable!()
+}
+
+#[test]
+fn publish_subscribe_ports_of_dead_process_are_removed() {
+    if let Ok(name) = std::env::var(DEAD_PROCESS_SERVICE_NAME) {
+        let service_name = ServiceName::new(name.as_bytes()).unwrap();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        // the ports are leaked like in a crash but the service is released so that the test
+        // process remains its only owner and removes it
+        std::mem::forget(publisher);
+        std::mem::forget(subscriber);
+        drop(service);
+        die_without_cleanup();
+    }
+
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .max_publishers(1)
+        .max_subscribers(1)
+        .create::<u64>()
+        .unwrap();
+
+    spawn_process_that_dies_while_owning_ports(
+        "publish_subscribe_ports_of_dead_process_are_removed",
+        &service_name,
+    );
+
+    assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+    assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+    assert_that!(number_of_connections_of_service(sut.uuid()), eq 1);
+
+    // the ports of the dead process occupy the only slots, creating new ports removes them
+    let publisher = sut.publisher().create();
+    assert_that!(publisher, is_ok);
+    assert_that!(sut.dynamic_config().number_of_publishers(), eq 1);
+    assert_that!(sut.dynamic_config().number_of_subscribers(), eq 0);
+    assert_that!(number_of_connections_of_service(sut.uuid()), eq 0);
+
+    let subscriber = sut.subscriber().create();
+    assert_that!(subscriber, is_ok);
+    assert_that!(sut.dynamic_config().number_of_subscribers(), eq 1);
+
+    let publisher = publisher.unwrap();
+    let subscriber = subscriber.unwrap();
+    assert_that!(publisher.update_connections(), is_ok);
+    assert_that!(publisher.send_copy(5678), is_ok);
+    let sample = subscriber.receive().unwrap();
+    assert_that!(sample, is_some);
+    assert_that!(*sample.unwrap(), eq 5678);
+}
+
+#[test]
+fn event_ports_of_dead_process_are_removed() {
+    if let Ok(name) = std::env::var(DEAD_PROCESS_SERVICE_NAME) {
+        let service_name = ServiceName::new(name.as_bytes()).unwrap();
+        let service = zero_copy::Service::new(&service_name)
+            .event()
+            .open()
+            .unwrap();
+
+        // the ports are leaked like in a crash but the service is released so that the test
+        // process remains its only owner and removes it
+        std::mem::forget(service.notifier().create().unwrap());
+        std::mem::forget(service.listener().create().unwrap());
+        drop(service);
+        die_without_cleanup();
+    }
+
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .event()
+        .max_notifiers(1)
+        .max_listeners(1)
+        .create()
+        .unwrap();
+
+    spawn_process_that_dies_while_owning_ports(
+        "event_ports_of_dead_process_are_removed",
+        &service_name,
+    );
+
+    assert_that!(sut.dynamic_config().number_of_notifiers(), eq 1);
+    assert_that!(sut.dynamic_config().number_of_listeners(), eq 1);
+
+    let listener = sut.listener().create();
+    assert_that!(listener, is_ok);
+    assert_that!(sut.dynamic_config().number_of_notifiers(), eq 0);
+    assert_that!(sut.dynamic_config().number_of_listeners(), eq 1);
+
+    let notifier = sut.notifier().create();
+    assert_that!(notifier, is_ok);
+    assert_that!(sut.dynamic_config().number_of_notifiers(), eq 1);
+}
+
+#[test]
+fn publisher_reclaims_samples_of_subscriber_in_dead_process() {
+    const ITERATIONS: usize = 4;
+
+    if let Ok(name) = std::env::var(DEAD_PROCESS_SERVICE_NAME) {
+        let service_name = ServiceName::new(name.as_bytes()).unwrap();
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+
+        let subscriber = service.subscriber().create().unwrap();
+        // bounded so that the process does not outlive a failed test
+        let mut sample = None;
+        for _ in 0..10000 {
+            sample = subscriber.receive().unwrap();
+            if sample.is_some() {
+                break;
+            }
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        // the subscriber dies while it borrows one sample and has another in its buffer
+        std::mem::forget(sample);
+        std::mem::forget(subscriber);
+        drop(service);
+        die_without_cleanup();
+    }
+
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .publish_subscribe()
+        .max_subscribers(1)
+        .subscriber_max_buffer_size(1)
+        .subscriber_max_borrowed_samples(1)
+        .history_size(0)
+        .create::<u64>()
+        .unwrap();
+
+    let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+    // without reclaiming the samples of the dead subscribers the data segment of the publisher
+    // runs out of memory after the first few iterations
+    for i in 0..ITERATIONS {
+        let mut child = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "publisher_reclaims_samples_of_subscriber_in_dead_process",
+                "--exact",
+                "--nocapture",
+            ])
+            .env(DEAD_PROCESS_SERVICE_NAME, service_name.to_string())
+            .spawn()
+            .unwrap();
+
+        while child.try_wait().unwrap().is_none() {
+            assert_that!(publisher.update_connections(), is_ok);
+            assert_that!(publisher.send_copy(i as u64), is_ok);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        assert_that!(child.wait().unwrap().success(), eq false);
+    }
+
+    // the last dead subscriber is removed when a new one takes its slot
+    let subscriber = sut.subscriber().create().unwrap();
+    assert_that!(publisher.update_connections(), is_ok);
+    for i in 0..ITERATIONS {
+        assert_that!(publisher.send_copy(1234 + i as u64), is_ok);
+        let sample = subscriber.receive().unwrap();
+        assert_that!(sample, is_some);
+        assert_that!(*sample.unwrap(), eq 1234 + i as u64);
+    }
+}
+
+#[test]
+fn subscriber_releases_copies_of_publisher_in_dead_process() {
+    const BUFFER_SIZE: usize = 2;
+    const MAX_BORROWED_SAMPLES: usize = 2;
+
+    if let Ok(name) = std::env::var(DEAD_PROCESS_SERVICE_NAME) {
+        let service_name = ServiceName::new(name.as_bytes()).unwrap();
+        let service = copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        for i in 0..BUFFER_SIZE {
+            assert_that!(publisher.send_copy(i as u64), eq Ok(1));
+        }
+
+        // the publisher never reclaims the copies the subscriber releases until the test
+        // process kills it, it only sleeps bounded so that it does not outlive a failed test
+        std::mem::forget(publisher);
+        drop(service);
+        std::thread::sleep(Duration::from_secs(10));
+        die_without_cleanup();
+    }
+
+    let service_name = generate_name();
+    let sut = copy::Service::new(&service_name)
+        .publish_subscribe()
+        .max_publishers(1)
+        .subscriber_max_buffer_size(BUFFER_SIZE)
+        .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+        .history_size(0)
+        .create::<u64>()
+        .unwrap();
+
+    let subscriber = sut.subscriber().create().unwrap();
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "subscriber_releases_copies_of_publisher_in_dead_process",
+            "--exact",
+            "--nocapture",
+        ])
+        .env(DEAD_PROCESS_SERVICE_NAME, service_name.to_string())
+        .spawn()
+        .unwrap();
+
+    let mut samples = vec![];
+    for _ in 0..10000 {
+        if let Some(sample) = subscriber.receive().unwrap() {
+            samples.push(sample);
+        }
+        if samples.len() == BUFFER_SIZE {
+            break;
+        }
+        std::thread::sleep(Duration::from_millis(1));
+    }
+    assert_that!(samples, len BUFFER_SIZE);
+
+    // the copies are released into the retrieve channel but never reclaimed
+    drop(samples);
+    child.kill().unwrap();
+    assert_that!(child.wait().unwrap().success(), eq false);
+
+    // the dead publisher occupies the only slot, creating a new publisher removes it and the
+    // subscriber releases the copies of the dead publisher when it removes the connection
+    let publisher = sut.publisher().create().unwrap();
+    assert_that!(subscriber.receive().unwrap(), is_none);
+
+    // every sample of the data segment of the subscriber is available again
+    let mut samples = vec![];
+    for i in 0..MAX_BORROWED_SAMPLES {
+        assert_that!(publisher.send_copy(i as u64), eq Ok(1));
+        samples.push(subscriber.receive().unwrap().unwrap());
+    }
+    for i in 0..BUFFER_SIZE {
+        assert_that!(publisher.send_copy(1234 + i as u64), eq Ok(1));
+    }
+
+    drop(samples);
+    for i in 0..BUFFER_SIZE {
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234 + i as u64);
+    }
+}
+
+#[test]
+fn blackboard_entries_of_dead_writer_are_released() {
+    if let Ok(name) = std::env::var(DEAD_PROCESS_SERVICE_NAME) {
+        let service_name = ServiceName::new(name.as_bytes()).unwrap();
+        let service = zero_copy::Service::new(&service_name)
+            .blackboard::<u64>()
+            .open()
+            .unwrap();
+
+        let writer = service.writer().create().unwrap();
+        let entry = writer.entry::<u32>(&0).unwrap();
+        entry.update(1234);
+
+        // the ports are leaked like in a crash but the service is released so that the test
+        // process remains its only owner and removes it
+        std::mem::forget(entry);
+        std::mem::forget(writer);
+        drop(service);
+        die_without_cleanup();
+    }
+
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .blackboard::<u64>()
+        .max_writers(1)
+        .add::<u32>(0, 0)
+        .create()
+        .unwrap();
+
+    spawn_process_that_dies_while_owning_ports(
+        "blackboard_entries_of_dead_writer_are_released",
+        &service_name,
+    );
+
+    assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+    // the dead writer occupies the only slot, creating a new writer removes it together with
+    // the ownership of its entries
+    let writer = sut.writer().create();
+    assert_that!(writer, is_ok);
+    assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+    let writer = writer.unwrap();
+    let entry = writer.entry::<u32>(&0);
+    assert_that!(entry, is_ok);
+    let entry = entry.unwrap();
+    assert_that!(entry.get(), eq 1234);
+    entry.update(5678);
+    assert_that!(sut.reader().create().unwrap().entry::<u32>(&0).unwrap().get(), eq 5678);
+}
+
+#[test]
+fn work_items_of_dead_worker_are_put_back_into_the_work_queue() {
+    if let Ok(name) = std::env::var(DEAD_PROCESS_SERVICE_NAME) {
+        let service_name = ServiceName::new(name.as_bytes()).unwrap();
+        let service = zero_copy::Service::new(&service_name)
+            .pipeline()
+            .open::<u64>()
+            .unwrap();
+
+        let worker = service.worker().create().unwrap();
+        let work_item = worker.receive().unwrap().unwrap();
+        assert_that!(*work_item, eq 1234);
+
+        // the ports are leaked like in a crash but the service is released so that the test
+        // process remains its only owner and removes it
+        std::mem::forget(work_item);
+        std::mem::forget(worker);
+        drop(service);
+        die_without_cleanup();
+    }
+
+    let service_name = generate_name();
+    let sut = zero_copy::Service::new(&service_name)
+        .pipeline()
+        .max_workers(1)
+        .create::<u64>()
+        .unwrap();
+
+    let producer = sut.producer().create().unwrap();
+    assert_that!(producer.send_copy(1234), is_ok);
+    assert_that!(producer.send_copy(5678), is_ok);
+
+    spawn_process_that_dies_while_owning_ports(
+        "work_items_of_dead_worker_are_put_back_into_the_work_queue",
+        &service_name,
+    );
+
+    assert_that!(sut.dynamic_config().number_of_workers(), eq 1);
+    assert_that!(sut.dynamic_config().number_of_samples_in_process(), eq 1);
+
+    // the dead worker occupies the only slot, creating a new worker removes it and puts its
+    // work item back into the work queue
+    let worker = sut.worker().create();
+    assert_that!(worker, is_ok);
+    assert_that!(sut.dynamic_config().number_of_workers(), eq 1);
+    assert_that!(sut.dynamic_config().number_of_reclaimed_samples(), eq 1);
+
+    let worker = worker.unwrap();
+    let mut received = vec![];
+    while let Some(work_item) = worker.receive().unwrap() {
+        received.push(*work_item);
+    }
+    received.sort();
+    assert_that!(received, eq vec![1234, 5678]);
+}
@@ -369,4 +369,7 @@ mod service_event {
 
     #[instantiate_tests(<elkodon::service::process_local::Service>)]
     mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
 }
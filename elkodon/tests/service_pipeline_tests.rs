@@ -0,0 +1,476 @@
+#[generic_tests::define]
+mod service_pipeline {
+    use std::collections::HashSet;
+    use std::sync::{Barrier, Mutex};
+
+    use elkodon::config::Config;
+    use elkodon::port::producer::{ProducerCreateError, WorkItemSendError};
+    use elkodon::port::publisher::LoanError;
+    use elkodon::port::worker::{WorkItemReceiveError, WorkerCreateError};
+    use elkodon::service::builder::pipeline::PipelineCreateError;
+    use elkodon::service::builder::pipeline::PipelineOpenError;
+    use elkodon::service::{service_name::ServiceName, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"service_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().create::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().open::<u64>();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PipelineOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_type<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().open::<u32>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_service_is_event<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).event().create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::IncompatibleMessagingPattern);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(2)
+            .max_workers(3)
+            .work_queue_capacity(4)
+            .producer_max_loaned_samples(5)
+            .worker_max_borrowed_samples(6)
+            .create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(3)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfProducers);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .max_workers(4)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfWorkers);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .work_queue_capacity(5)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedWorkQueueCapacity);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .producer_max_loaned_samples(6)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfLoanedSamples);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .worker_max_borrowed_samples(7)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfBorrowedSamples);
+
+        let sut2 = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(1)
+            .max_workers(2)
+            .work_queue_capacity(3)
+            .producer_max_loaned_samples(4)
+            .worker_max_borrowed_samples(5)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_workers(3)
+            .open_or_create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name).pipeline().open_or_create::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().max_supported_workers(), eq 3);
+    }
+
+    #[test]
+    fn settings_are_set_to_config_defaults<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+
+        let defaults = &Config::get_global_config().defaults.pipeline;
+        assert_that!(sut.static_config().max_supported_producers(), eq defaults.max_producers);
+        assert_that!(sut.static_config().max_supported_workers(), eq defaults.max_workers);
+        assert_that!(sut.static_config().work_queue_capacity(), eq defaults.work_queue_capacity);
+        assert_that!(sut.static_config().producer_max_loaned_samples(), eq defaults.producer_max_loaned_samples);
+        assert_that!(sut.static_config().worker_max_borrowed_samples(), eq defaults.worker_max_borrowed_samples);
+        assert_that!(sut.static_config().type_name(), eq std::any::type_name::<u64>());
+    }
+
+    #[test]
+    fn settings_of_zero_are_adjusted_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(0)
+            .max_workers(0)
+            .work_queue_capacity(0)
+            .producer_max_loaned_samples(0)
+            .worker_max_borrowed_samples(0)
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_producers(), eq 1);
+        assert_that!(sut.static_config().max_supported_workers(), eq 1);
+        assert_that!(sut.static_config().work_queue_capacity(), eq 1);
+        assert_that!(sut.static_config().producer_max_loaned_samples(), eq 1);
+        assert_that!(sut.static_config().worker_max_borrowed_samples(), eq 1);
+    }
+
+    #[test]
+    fn number_of_producers_and_workers_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_PRODUCERS: usize = 3;
+        const MAX_WORKERS: usize = 4;
+
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_producers(MAX_PRODUCERS)
+            .max_workers(MAX_WORKERS)
+            .create::<u64>()
+            .unwrap();
+
+        let mut producers = vec![];
+        for i in 0..MAX_PRODUCERS {
+            producers.push(sut.producer().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_producers(), eq i + 1);
+        }
+
+        let producer = sut.producer().create();
+        assert_that!(producer, is_err);
+        assert_that!(producer.err().unwrap(), eq ProducerCreateError::ExceedsMaxSupportedProducers);
+
+        let mut workers = vec![];
+        for i in 0..MAX_WORKERS {
+            workers.push(sut.worker().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_workers(), eq i + 1);
+        }
+
+        let worker = sut.worker().create();
+        assert_that!(worker, is_err);
+        assert_that!(worker.err().unwrap(), eq WorkerCreateError::ExceedsMaxSupportedWorkers);
+
+        producers.clear();
+        workers.clear();
+        assert_that!(sut.dynamic_config().number_of_producers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_workers(), eq 0);
+    }
+
+    #[test]
+    fn work_items_are_received_in_fifo_order<Sut: Service>() {
+        let service_name = generate_name();
+        const CAPACITY: usize = 8;
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .work_queue_capacity(CAPACITY)
+            .create::<u64>()
+            .unwrap();
+        let sut2 = Sut::new(&service_name).pipeline().open::<u64>().unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let worker = sut2.worker().create().unwrap();
+
+        for n in 0..3 {
+            for i in 0..CAPACITY as u64 {
+                assert_that!(producer.send_copy(n * 100 + i), is_ok);
+            }
+
+            for i in 0..CAPACITY as u64 {
+                let work_item = worker.receive().unwrap().unwrap();
+                assert_that!(*work_item, eq n * 100 + i);
+                assert_that!(work_item.header().producer_id(), eq producer.id());
+            }
+            assert_that!(worker.receive().unwrap(), is_none);
+        }
+    }
+
+    #[test]
+    fn every_work_item_is_received_by_exactly_one_worker<Sut: Service>() {
+        let service_name = generate_name();
+        const NUMBER_OF_WORKERS: usize = 3;
+        const NUMBER_OF_ITEMS: u64 = 12;
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_workers(NUMBER_OF_WORKERS)
+            .work_queue_capacity(NUMBER_OF_ITEMS as usize)
+            .create::<u64>()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let mut workers = vec![];
+        for _ in 0..NUMBER_OF_WORKERS {
+            workers.push(sut.worker().create().unwrap());
+        }
+
+        for i in 0..NUMBER_OF_ITEMS {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+
+        let mut received = HashSet::new();
+        let mut n = 0;
+        while let Some(work_item) = workers[n % NUMBER_OF_WORKERS].receive().unwrap() {
+            assert_that!(received.insert(*work_item), eq true);
+            n += 1;
+        }
+
+        assert_that!(received, len NUMBER_OF_ITEMS as usize);
+        for worker in workers.iter() {
+            assert_that!(worker.receive().unwrap(), is_none);
+        }
+    }
+
+    #[test]
+    fn workers_in_parallel_process_every_work_item_once<Sut: Service>() {
+        let service_name = generate_name();
+        const NUMBER_OF_WORKERS: usize = 4;
+        const NUMBER_OF_ITEMS: u64 = 1000;
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .max_workers(NUMBER_OF_WORKERS)
+            .create::<u64>()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let received = Mutex::new(vec![]);
+        let barrier = Barrier::new(NUMBER_OF_WORKERS + 1);
+
+        std::thread::scope(|s| {
+            for _ in 0..NUMBER_OF_WORKERS {
+                s.spawn(|| {
+                    let sut = Sut::new(&service_name).pipeline().open::<u64>().unwrap();
+                    let worker = sut.worker().create().unwrap();
+                    barrier.wait();
+
+                    let mut local_received = vec![];
+                    loop {
+                        if let Some(work_item) = worker.receive().unwrap() {
+                            if *work_item == NUMBER_OF_ITEMS {
+                                break;
+                            }
+                            local_received.push(*work_item);
+                        }
+                    }
+                    received.lock().unwrap().append(&mut local_received);
+                });
+            }
+
+            barrier.wait();
+            for i in 0..NUMBER_OF_ITEMS {
+                while producer.send_copy(i).is_err() {}
+            }
+
+            for _ in 0..NUMBER_OF_WORKERS {
+                while producer.send_copy(NUMBER_OF_ITEMS).is_err() {}
+            }
+        });
+
+        let mut received = received.lock().unwrap();
+        received.sort();
+        assert_that!(*received, len NUMBER_OF_ITEMS as usize);
+        for (i, value) in received.iter().enumerate() {
+            assert_that!(*value, eq i as u64);
+        }
+    }
+
+    #[test]
+    fn send_fails_when_work_queue_is_full<Sut: Service>() {
+        let service_name = generate_name();
+        const CAPACITY: usize = 3;
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .work_queue_capacity(CAPACITY)
+            .create::<u64>()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        for i in 0..CAPACITY as u64 {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+
+        let work_item = producer.loan().unwrap();
+        assert_that!(producer.send(work_item).err(), eq Some(WorkItemSendError::WorkQueueFull));
+
+        let worker = sut.worker().create().unwrap();
+        drop(worker.receive().unwrap());
+        assert_that!(producer.send_copy(1234), is_ok);
+    }
+
+    #[test]
+    fn loan_fails_when_max_loaned_samples_are_exceeded<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_LOANED: usize = 2;
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .producer_max_loaned_samples(MAX_LOANED)
+            .create::<u64>()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let mut work_items = vec![];
+        for _ in 0..MAX_LOANED {
+            work_items.push(producer.loan().unwrap());
+        }
+
+        let work_item = producer.loan();
+        assert_that!(work_item, is_err);
+        assert_that!(work_item.err().unwrap(), eq LoanError::ExceedsMaxLoanedChunks);
+
+        work_items.pop();
+        assert_that!(producer.loan(), is_ok);
+    }
+
+    #[test]
+    fn receive_fails_when_max_borrowed_samples_are_exceeded<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_BORROWED: usize = 2;
+        let sut = Sut::new(&service_name)
+            .pipeline()
+            .worker_max_borrowed_samples(MAX_BORROWED)
+            .create::<u64>()
+            .unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let worker = sut.worker().create().unwrap();
+        for i in 0..MAX_BORROWED as u64 + 1 {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+
+        let mut work_items = vec![];
+        for _ in 0..MAX_BORROWED {
+            work_items.push(worker.receive().unwrap().unwrap());
+        }
+
+        let work_item = worker.receive();
+        assert_that!(work_item, is_err);
+        assert_that!(work_item.err().unwrap(), eq WorkItemReceiveError::ExceedsMaxBorrowedSamples);
+
+        work_items.pop();
+        assert_that!(worker.receive().unwrap(), is_some);
+    }
+
+    #[test]
+    fn work_items_held_by_a_removed_worker_are_put_back_into_the_work_queue<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let worker_1 = sut.worker().create().unwrap();
+        let worker_2 = sut.worker().create().unwrap();
+
+        assert_that!(producer.send_copy(42), is_ok);
+        let work_item = worker_1.receive().unwrap().unwrap();
+        std::mem::forget(work_item);
+        assert_that!(worker_2.receive().unwrap(), is_none);
+
+        drop(worker_1);
+        assert_that!(sut.dynamic_config().number_of_reclaimed_samples(), eq 1);
+
+        let work_item = worker_2.receive().unwrap();
+        assert_that!(work_item, is_some);
+        assert_that!(*work_item.unwrap(), eq 42);
+    }
+
+    #[test]
+    fn statistics_of_every_stage_are_tracked<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).pipeline().create::<u64>().unwrap();
+
+        let producer = sut.producer().create().unwrap();
+        let worker = sut.worker().create().unwrap();
+
+        for i in 0..4 {
+            assert_that!(producer.send_copy(i), is_ok);
+        }
+        assert_that!(sut.dynamic_config().number_of_sent_samples(), eq 4);
+        assert_that!(sut.dynamic_config().number_of_queued_samples(), eq 4);
+        assert_that!(sut.dynamic_config().number_of_samples_in_process(), eq 0);
+
+        let work_item = worker.receive().unwrap().unwrap();
+        assert_that!(sut.dynamic_config().number_of_queued_samples(), eq 3);
+        assert_that!(sut.dynamic_config().number_of_samples_in_process(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_processed_samples(), eq 0);
+
+        drop(work_item);
+        assert_that!(sut.dynamic_config().number_of_samples_in_process(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_processed_samples(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_reclaimed_samples(), eq 0);
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
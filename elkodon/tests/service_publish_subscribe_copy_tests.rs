@@ -0,0 +1,146 @@
+mod service_publish_subscribe_copy {
+    use elkodon::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use elkodon::service::port_factory::publisher::UnableToDeliverStrategy;
+    use elkodon::service::{copy, service_name::ServiceName, zero_copy, PayloadTransfer, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"service_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[test]
+    fn copy_service_has_copy_payload_transfer() {
+        let sut = copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().payload_transfer(), eq PayloadTransfer::Copy);
+    }
+
+    #[test]
+    fn copy_service_cannot_be_opened_as_zero_copy_service() {
+        let service_name = generate_name();
+        let _sut = copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let result = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq PublishSubscribeOpenError::IncompatiblePayloadTransfer);
+    }
+
+    #[test]
+    fn every_subscriber_receives_its_own_copy() {
+        let sut = copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber_1 = sut.subscriber().create().unwrap();
+        let subscriber_2 = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1234).unwrap(), eq 2);
+
+        let sample_1 = subscriber_1.receive().unwrap().unwrap();
+        let sample_2 = subscriber_2.receive().unwrap().unwrap();
+        assert_that!(*sample_1, eq 1234);
+        assert_that!(*sample_2, eq 1234);
+        assert_that!(&*sample_1 as *const u64, ne & *sample_2 as *const u64);
+    }
+
+    #[test]
+    fn publisher_does_not_block_when_subscriber_buffer_is_full() {
+        let sut = copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .subscriber_max_buffer_size(1)
+            .history_size(0)
+            .enable_safe_overflow(false)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut
+            .publisher()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1).unwrap(), eq 1);
+        assert_that!(publisher.send_copy(2).unwrap(), eq 0);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 1);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publisher_recycles_overflowing_copies_while_subscriber_holds_samples() {
+        const NUMBER_OF_SAMPLES: u64 = 64;
+        let sut = copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(2)
+            .history_size(0)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(0).unwrap(), eq 1);
+        assert_that!(publisher.send_copy(1).unwrap(), eq 1);
+        let _sample_1 = subscriber.receive().unwrap().unwrap();
+        let _sample_2 = subscriber.receive().unwrap().unwrap();
+
+        for i in 2..NUMBER_OF_SAMPLES {
+            assert_that!(publisher.send_copy(i).unwrap(), eq 1);
+        }
+    }
+
+    #[test]
+    fn subscriber_releases_copies_of_removed_publishers() {
+        const NUMBER_OF_PUBLISHERS: u64 = 16;
+        let sut = copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .max_publishers(1)
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(1)
+            .history_size(0)
+            .create::<u64>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+
+        for i in 0..NUMBER_OF_PUBLISHERS {
+            let publisher = sut.publisher().create().unwrap();
+            assert_that!(publisher.send_copy(i).unwrap(), eq 1);
+            assert_that!(publisher.send_copy(i + 1).unwrap(), eq 1);
+
+            let sample = subscriber.receive().unwrap().unwrap();
+            assert_that!(*sample, eq i);
+
+            drop(publisher);
+            // released after the publisher is gone, the unreceived sample is released when
+            // the subscriber removes the connection
+            drop(sample);
+        }
+    }
+}
@@ -3,14 +3,42 @@ mod service_publish_subscribe {
     use elkodon::config::Config;
     use elkodon::port::publisher::{LoanError, PublisherCreateError};
     use elkodon::port::subscriber::SubscriberCreateError;
+    use elkodon::prelude::ZeroCopySend;
     use elkodon::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use elkodon::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use elkodon::service::builder::publish_subscribe::PublishSubscribeOpenOrCreateError;
     use elkodon::service::port_factory::publisher::UnableToDeliverStrategy;
     use elkodon::service::static_config::StaticConfig;
     use elkodon::service::{service_name::ServiceName, Details, Service};
+    use elkodon_bb_container::byte_string::FixedSizeByteString;
     use elkodon_bb_container::semantic_string::*;
     use elkodon_bb_posix::unique_system_id::UniqueSystemId;
     use elkodon_bb_testing::assert_that;
+    use std::time::Duration;
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct ComplexType {
+        id: u64,
+        position: [f32; 3],
+        name: FixedSizeByteString<32>,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct RenamedComplexType {
+        identifier: u64,
+        coordinates: [f32; 3],
+        label: FixedSizeByteString<32>,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct ChangedComplexType {
+        id: u64,
+        position: [f32; 3],
+        name: FixedSizeByteString<64>,
+    }
 
     fn generate_name() -> ServiceName {
         let mut service = ServiceName::new(b"service_tests_").unwrap();
@@ -80,6 +108,46 @@ mod service_publish_subscribe {
         assert_that!(sut2, is_ok);
     }
 
+    #[test]
+    fn open_succeeds_when_type_with_same_layout_has_different_name<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<ComplexType>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<RenamedComplexType>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_type_layout_has_changed<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<ComplexType>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open::<ChangedComplexType>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeLayout);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_or_create::<ChangedComplexType>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(),
+            eq PublishSubscribeOpenOrCreateError::PublishSubscribeOpenError(
+                PublishSubscribeOpenError::IncompatibleTypeLayout
+            )
+        );
+    }
+
     #[test]
     fn open_fails_when_service_has_wrong_type<Sut: Service>() {
         let service_name = generate_name();
@@ -88,7 +156,7 @@ mod service_publish_subscribe {
 
         let sut2 = Sut::new(&service_name).publish_subscribe().open::<i64>();
         assert_that!(sut2, is_err);
-        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeLayout);
     }
 
     #[test]
@@ -473,6 +541,33 @@ mod service_publish_subscribe {
         assert_that!(*result.unwrap(), eq 4567);
     }
 
+    #[test]
+    fn communication_with_derived_zero_copy_send_type_works<Sut: Service>() {
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<ComplexType>()
+            .unwrap();
+
+        let subscriber = sut.subscriber().create().unwrap();
+        let publisher = sut.publisher().create().unwrap();
+
+        assert_that!(
+            publisher.send_copy(ComplexType {
+                id: 42,
+                position: [1.0, 2.0, 3.0],
+                name: FixedSizeByteString::from_bytes(b"funky").unwrap(),
+            }),
+            is_ok
+        );
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.id, eq 42);
+        assert_that!(sample.position, eq[1.0, 2.0, 3.0]);
+        assert_that!(sample.name, eq b"funky");
+    }
+
     #[test]
     fn simple_communication_works_publisher_created_first<Sut: Service>() {
         let service_name = generate_name();
@@ -663,6 +758,74 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn notification_handle_is_only_available_when_notifications_are_enabled<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_notifications(), eq true);
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.notification_handle(), is_some);
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().has_notifications(), eq false);
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(subscriber.notification_handle(), is_none);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_incompatible_notification_behavior<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleNotificationBehavior);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn communication_with_notifications_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .history_size(1)
+            .subscriber_max_buffer_size(2)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(12), is_ok);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(publisher.send_copy(34), is_ok);
+
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 12);
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 34);
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
     #[test]
     fn publish_non_overflow_with_greater_history_than_buffer_fails<Sut: Service>() {
         let service_name = generate_name();
@@ -939,6 +1102,43 @@ mod service_publish_subscribe {
         );
     }
 
+    #[test]
+    fn publisher_reclaims_samples_of_disappeared_subscriber<Sut: Service>() {
+        const ITERATIONS: usize = 16;
+        const BUFFER_SIZE: usize = 2;
+        let service_name = generate_name();
+
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(1)
+            .max_subscribers(1)
+            .history_size(0)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(BUFFER_SIZE)
+            .create::<usize>()
+            .unwrap();
+
+        let sut_publisher = sut.publisher().max_loaned_samples(1).create().unwrap();
+
+        for _ in 0..ITERATIONS {
+            let sut_subscriber = sut.subscriber().create().unwrap();
+            assert_that!(sut_publisher.update_connections(), is_ok);
+
+            for _ in 0..BUFFER_SIZE {
+                assert_that!(sut_publisher.send_copy(8912), is_ok);
+            }
+
+            // the subscriber disappears while owning one borrowed and one buffered sample
+            let sample = sut_subscriber.receive().unwrap();
+            assert_that!(sample, is_some);
+            std::mem::forget(sample);
+            drop(sut_subscriber);
+
+            assert_that!(sut_publisher.update_connections(), is_ok);
+            assert_that!(sut_publisher.loan(), is_ok);
+        }
+    }
+
     #[test]
     fn creating_max_supported_amount_of_ports_work<Sut: Service>() {
         const MAX_PUBLISHERS: usize = 4;
@@ -1043,6 +1243,332 @@ mod service_publish_subscribe {
         assert_that!(sut.static_config().subscriber_max_buffer_size(), eq 1);
     }
 
+    #[test]
+    fn set_max_slice_len_to_zero_adjusts_it_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(0)
+            .create::<[u64]>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_slice_len(), eq 1);
+    }
+
+    #[test]
+    fn payload_alignment_is_at_least_the_type_alignment<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        assert_that!(sut.static_config().payload_alignment(), eq std::mem::align_of::<u64>());
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(2)
+            .create::<[u64]>()
+            .unwrap();
+        assert_that!(sut.static_config().payload_alignment(), eq std::mem::align_of::<u64>());
+
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(100)
+            .create::<[u8]>()
+            .unwrap();
+        assert_that!(sut.static_config().payload_alignment(), eq 128);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_slice_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(10)
+            .payload_alignment(16)
+            .create::<[u64]>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(11)
+            .open::<[u64]>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotSupportRequestedMaxSliceLength);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .payload_alignment(32)
+            .open::<[u64]>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotSupportRequestedPayloadAlignment);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypeLayout);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(10)
+            .payload_alignment(16)
+            .open::<[u64]>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn slice_communication_works<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 8;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .subscriber_max_buffer_size(3)
+            .subscriber_max_borrowed_samples(3)
+            .create::<[u64]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let mut sample = publisher.loan_slice(3).unwrap();
+        assert_that!(sample.header().number_of_elements(), eq 3);
+        unsafe { (*sample.as_mut_ptr())[1] = 42 };
+        assert_that!(publisher.send(sample), is_ok);
+
+        let mut sample = publisher.loan_uninit_slice(MAX_SLICE_LEN).unwrap();
+        let elements = sample.as_mut_ptr() as *mut u64;
+        for i in 0..MAX_SLICE_LEN {
+            unsafe { elements.add(i).write(i as u64 * 10) };
+        }
+        assert_that!(publisher.send(sample), is_ok);
+
+        assert_that!(publisher.send_slice_copy(&[]), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().number_of_elements(), eq 3);
+        assert_that!(*sample, eq [0, 42, 0]);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), len MAX_SLICE_LEN);
+        for (i, value) in sample.iter().enumerate() {
+            assert_that!(*value, eq i as u64 * 10);
+        }
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), is_empty);
+    }
+
+    #[test]
+    fn loan_slice_fails_when_it_exceeds_max_slice_len<Sut: Service>() {
+        const MAX_SLICE_LEN: usize = 4;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(MAX_SLICE_LEN)
+            .create::<[u8]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+
+        let sample = publisher.loan_slice(MAX_SLICE_LEN + 1);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq LoanError::ExceedsMaxSliceLength);
+
+        let sample = publisher.loan_uninit_slice(MAX_SLICE_LEN + 1);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq LoanError::ExceedsMaxSliceLength);
+
+        let result = publisher.send_slice_copy(&[0; MAX_SLICE_LEN + 1]);
+        assert_that!(result, is_err);
+
+        assert_that!(publisher.loan_slice(MAX_SLICE_LEN), is_ok);
+    }
+
+    #[test]
+    fn loan_uninit_slice_with_alignment_works<Sut: Service>() {
+        const ALIGNMENT: usize = 64;
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(16)
+            .payload_alignment(ALIGNMENT)
+            .create::<[u8]>()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut.subscriber().create().unwrap();
+
+        let sample = publisher.loan_uninit_slice_with_alignment(16, ALIGNMENT * 2);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq LoanError::UnsupportedAlignment);
+
+        let sample = publisher.loan_uninit_slice_with_alignment(16, 3);
+        assert_that!(sample, is_err);
+        assert_that!(sample.err().unwrap(), eq LoanError::UnsupportedAlignment);
+
+        let mut sample = publisher
+            .loan_uninit_slice_with_alignment(16, ALIGNMENT)
+            .unwrap();
+        assert_that!(sample.as_ptr() as *const u8 as usize % ALIGNMENT, eq 0);
+        unsafe { (sample.as_mut_ptr() as *mut u8).write_bytes(7, 16) };
+        assert_that!(publisher.send(sample), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.as_ptr() as usize % ALIGNMENT, eq 0);
+        assert_that!(*sample, eq [7; 16]);
+    }
+
+    #[test]
+    fn open_untyped_receives_payload_as_bytes<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_untyped()
+            .unwrap();
+        assert_that!(sut2.static_config().type_fingerprint(), eq sut.static_config().type_fingerprint());
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut2.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(0x0102030405060708), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 0x0102030405060708u64.to_ne_bytes());
+    }
+
+    #[test]
+    fn open_untyped_receives_slice_payload_as_bytes<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_slice_len(4)
+            .create::<[u16]>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_untyped()
+            .unwrap();
+
+        let publisher = sut.publisher().create().unwrap();
+        let subscriber = sut2.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_slice_copy(&[1, 2, 3]), is_ok);
+        assert_that!(publisher.send_slice_copy(&[1, 2, 3, 4]), is_ok);
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().number_of_elements(), eq 3);
+        assert_that!(sample.payload(), len 6);
+        assert_that!(sample[4..], eq 3u16.to_ne_bytes());
+
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.payload(), len 8);
+    }
+
+    #[test]
+    fn open_untyped_service_cannot_create_publisher<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .open_untyped()
+            .unwrap();
+
+        let publisher = sut2.publisher().create();
+        assert_that!(publisher, is_err);
+        assert_that!(publisher.err().unwrap(), eq PublisherCreateError::UntypedService);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_deadline_and_liveliness<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(Duration::from_millis(100))
+            .liveliness_lease_duration(Duration::from_millis(200))
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(Duration::from_millis(50))
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotSupportRequestedDeadline);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .liveliness_lease_duration(Duration::from_millis(150))
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotSupportRequestedLivelinessLeaseDuration);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(Duration::from_millis(100))
+            .liveliness_lease_duration(Duration::from_millis(250))
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.static_config().deadline(), eq Some(Duration::from_millis(100)));
+        assert_that!(sut2.static_config().liveliness_lease_duration(), eq Some(Duration::from_millis(200)));
+    }
+
+    #[test]
+    fn open_fails_when_service_has_no_deadline_but_one_is_requested<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(Duration::from_secs(1))
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotSupportRequestedDeadline);
+
+        let sut2 = Sut::new(&service_name).publish_subscribe().open::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().deadline(), is_none);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_lifespan<Sut: Service>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .lifespan(Duration::from_millis(100))
+            .create::<u64>()
+            .unwrap();
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .lifespan(Duration::from_millis(50))
+            .open::<u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::DoesNotSupportRequestedLifespan);
+
+        let sut2 = Sut::new(&service_name)
+            .publish_subscribe()
+            .lifespan(Duration::from_millis(100))
+            .open::<u64>();
+        assert_that!(sut2, is_ok);
+        assert_that!(sut2.unwrap().static_config().lifespan(), eq Some(Duration::from_millis(100)));
+    }
+
     #[test]
     fn does_exist_works_single<Sut: Service + Details<'static>>() {
         let service_name = generate_name();
@@ -1159,4 +1685,10 @@ mod service_publish_subscribe {
 
     #[instantiate_tests(<elkodon::service::process_local::Service>)]
     mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::copy::Service>)]
+    mod copy {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
 }
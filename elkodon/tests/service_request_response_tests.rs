@@ -0,0 +1,464 @@
+#[generic_tests::define]
+mod service_request_response {
+    use elkodon::config::Config;
+    use elkodon::port::client::{ClientCreateError, RequestSendError};
+    use elkodon::port::server::ServerCreateError;
+    use elkodon::service::builder::request_response::RequestResponseCreateError;
+    use elkodon::service::builder::request_response::RequestResponseOpenError;
+    use elkodon::service::{service_name::ServiceName, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"service_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(
+            sut2.err().unwrap(), eq
+            RequestResponseCreateError::AlreadyExists
+        );
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RequestResponseOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_succeeds_when_service_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_has_wrong_request_or_response_type<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u32, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleTypes);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u32>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn open_fails_when_service_is_publish_subscribe<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).publish_subscribe().create::<u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleMessagingPattern);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_fulfill_opener_requirements<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(2)
+            .max_servers(2)
+            .max_active_requests(2)
+            .create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_clients(3)
+            .open::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_servers(3)
+            .open::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_active_requests(3)
+            .open::<u64, u64>();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .max_clients(1)
+            .max_servers(1)
+            .max_active_requests(1)
+            .open::<u64, u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_or_create_opens_service_if_it_does_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .open_or_create::<u64, u64>();
+        assert_that!(sut, is_ok);
+
+        let sut2 = Sut::new(&service_name)
+            .request_response()
+            .open_or_create::<u64, u64>();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn settings_are_set_to_config_default<Sut: Service>() {
+        let service_name = generate_name();
+        let config = Config::get_global_config();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        assert_that!(
+            sut.static_config().max_supported_clients(), eq
+            config.defaults.request_response.max_clients
+        );
+        assert_that!(
+            sut.static_config().max_supported_servers(), eq
+            config.defaults.request_response.max_servers
+        );
+        assert_that!(
+            sut.static_config().max_active_requests(), eq
+            config.defaults.request_response.max_active_requests
+        );
+    }
+
+    #[test]
+    fn set_settings_to_zero_adjusts_them_to_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(0)
+            .max_servers(0)
+            .max_active_requests(0)
+            .create::<u64, u64>()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_supported_clients(), eq 1);
+        assert_that!(sut.static_config().max_supported_servers(), eq 1);
+        assert_that!(sut.static_config().max_active_requests(), eq 1);
+    }
+
+    #[test]
+    fn number_of_clients_and_servers_works<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_CLIENTS: usize = 4;
+        const MAX_SERVERS: usize = 3;
+
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(MAX_CLIENTS)
+            .max_servers(MAX_SERVERS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let mut clients = vec![];
+        for i in 0..MAX_CLIENTS {
+            clients.push(sut.client().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_clients(), eq i + 1);
+        }
+
+        let client = sut.client().create();
+        assert_that!(client, is_err);
+        assert_that!(client.err().unwrap(), eq ClientCreateError::ExceedsMaxSupportedClients);
+
+        let mut servers = vec![];
+        for i in 0..MAX_SERVERS {
+            servers.push(sut.server().create().unwrap());
+            assert_that!(sut.dynamic_config().number_of_servers(), eq i + 1);
+        }
+
+        let server = sut.server().create();
+        assert_that!(server, is_err);
+        assert_that!(server.err().unwrap(), eq ServerCreateError::ExceedsMaxSupportedServers);
+
+        clients.clear();
+        servers.clear();
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 0);
+    }
+
+    #[test]
+    fn simple_request_response_works<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+
+        let mut request = client.loan().unwrap();
+        let request_id = request.header().request_id();
+        unsafe { request.as_mut_ptr().write(1234) };
+        assert_that!(client.send(request), eq Ok(1));
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 1234);
+        assert_that!(active_request.header().client_id(), eq client.id());
+        assert_that!(active_request.header().request_id(), eq request_id);
+
+        let mut response = active_request.loan().unwrap();
+        unsafe { response.as_mut_ptr().write(5678) };
+        assert_that!(response.send(), is_ok);
+        drop(active_request);
+
+        let response = client.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+        assert_that!(response.header().server_id(), eq server.id());
+        assert_that!(response.header().request_id(), eq request_id);
+        drop(response);
+
+        assert_that!(client.receive().unwrap(), is_none);
+        assert_that!(server.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn response_is_delivered_only_to_requesting_client<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client_1 = sut.client().create().unwrap();
+        let client_2 = sut.client().create().unwrap();
+        assert_that!(server.update_connections(), is_ok);
+
+        assert_that!(client_1.send_copy(11), eq Ok(1));
+        assert_that!(client_2.send_copy(22), eq Ok(1));
+
+        while let Some(request) = server.receive().unwrap() {
+            assert_that!(request.send_copy(*request + 1), is_ok);
+        }
+
+        let response = client_1.receive().unwrap().unwrap();
+        assert_that!(*response, eq 12);
+        drop(response);
+        assert_that!(client_1.receive().unwrap(), is_none);
+
+        let response = client_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 23);
+        drop(response);
+        assert_that!(client_2.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_is_delivered_to_all_servers<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_SERVERS: usize = 3;
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_servers(MAX_SERVERS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+        let mut servers = vec![];
+        for _ in 0..MAX_SERVERS {
+            servers.push(sut.server().create().unwrap());
+        }
+
+        assert_that!(client.send_copy(42), eq Ok(MAX_SERVERS));
+
+        for server in &servers {
+            let request = server.receive().unwrap().unwrap();
+            assert_that!(*request, eq 42);
+            assert_that!(request.send_copy(*request), is_ok);
+        }
+
+        let mut number_of_responses = 0;
+        while let Some(response) = client.receive().unwrap() {
+            assert_that!(*response, eq 42);
+            number_of_responses += 1;
+        }
+        assert_that!(number_of_responses, eq MAX_SERVERS);
+    }
+
+    #[test]
+    fn send_fails_when_max_active_requests_are_in_flight<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_ACTIVE_REQUESTS: usize = 3;
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+        let client = sut.client().create().unwrap();
+
+        for i in 0..MAX_ACTIVE_REQUESTS {
+            assert_that!(client.send_copy(i as u64), eq Ok(1));
+        }
+
+        let request = client.loan().unwrap();
+        assert_that!(client.send(request).err().unwrap(), eq RequestSendError::ExceedsMaxActiveRequests);
+
+        let active_request = server.receive().unwrap().unwrap();
+        drop(active_request);
+
+        assert_that!(client.send_copy(1234), eq Ok(1));
+    }
+
+    #[test]
+    fn send_without_servers_delivers_to_no_one<Sut: Service>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_active_requests(1)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+
+        assert_that!(client.send_copy(1), eq Ok(0));
+        assert_that!(client.send_copy(2), eq Ok(0));
+    }
+
+    #[test]
+    fn client_reclaims_requests_of_disconnected_servers<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_ACTIVE_REQUESTS: usize = 2;
+        const ITERATIONS: usize = 4 * MAX_ACTIVE_REQUESTS;
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_servers(1)
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let client = sut.client().create().unwrap();
+
+        for i in 0..ITERATIONS {
+            let server = sut.server().create().unwrap();
+            assert_that!(client.send_copy(i as u64), eq Ok(1));
+
+            // the server disappears without ever returning the request
+            drop(server);
+        }
+
+        let server = sut.server().create().unwrap();
+        assert_that!(client.send_copy(1234), eq Ok(1));
+        let request = server.receive().unwrap().unwrap();
+        assert_that!(*request, eq 1234);
+    }
+
+    #[test]
+    fn server_reclaims_responses_of_disconnected_clients<Sut: Service>() {
+        let service_name = generate_name();
+        const MAX_ACTIVE_REQUESTS: usize = 2;
+        const ITERATIONS: usize = 4 * MAX_ACTIVE_REQUESTS;
+        let sut = Sut::new(&service_name)
+            .request_response()
+            .max_clients(1)
+            .max_active_requests(MAX_ACTIVE_REQUESTS)
+            .create::<u64, u64>()
+            .unwrap();
+
+        let server = sut.server().create().unwrap();
+
+        for i in 0..ITERATIONS {
+            let client = sut.client().create().unwrap();
+            assert_that!(server.update_connections(), is_ok);
+            for n in 0..MAX_ACTIVE_REQUESTS {
+                assert_that!(client.send_copy((i + n) as u64), eq Ok(1));
+            }
+
+            while let Some(request) = server.receive().unwrap() {
+                assert_that!(request.send_copy(*request), is_ok);
+            }
+
+            // the client disappears without ever receiving the responses
+            drop(client);
+        }
+
+        let client = sut.client().create().unwrap();
+        assert_that!(server.update_connections(), is_ok);
+        assert_that!(client.send_copy(1234), eq Ok(1));
+        let request = server.receive().unwrap().unwrap();
+        assert_that!(request.send_copy(5678), is_ok);
+        drop(request);
+
+        let response = client.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
@@ -0,0 +1,182 @@
+#[generic_tests::define]
+mod service_watcher {
+    use std::time::{Duration, Instant};
+
+    use elkodon::service::watcher::{ServiceEvent, ServiceWatcher};
+    use elkodon::service::{service_name::ServiceName, Details, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    const CREATION_DELAY: Duration = Duration::from_millis(100);
+    const WAKEUP_TIMEOUT: Duration = Duration::from_secs(10);
+
+    fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"service_watcher_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[derive(Debug, PartialEq, Eq)]
+    enum Change {
+        Added,
+        Removed,
+        PortsChanged,
+    }
+
+    // other tests create services in parallel, therefore only the changes of the service
+    // under test are considered
+    fn changes_of<'config, S: Details<'config>>(
+        watcher: &mut ServiceWatcher<'config, S>,
+        service_name: &ServiceName,
+    ) -> Vec<Change> {
+        let mut changes = vec![];
+        for event in watcher.timed_wait(TIMEOUT).unwrap() {
+            match event {
+                ServiceEvent::ServiceAdded(c) if c.service_name() == service_name => {
+                    changes.push(Change::Added)
+                }
+                ServiceEvent::ServiceRemoved(c) if c.service_name() == service_name => {
+                    changes.push(Change::Removed)
+                }
+                ServiceEvent::PortsChanged(c) if c.service_name() == service_name => {
+                    changes.push(Change::PortsChanged)
+                }
+                _ => (),
+            }
+        }
+        changes
+    }
+
+    #[test]
+    fn existing_services_are_reported_as_added_on_first_call<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let _sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let mut watcher = ServiceWatcher::<Sut>::new().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::Added]);
+        assert_that!(watcher.services().any(|s| s.service_name() == &service_name), eq true);
+    }
+
+    #[test]
+    fn created_and_removed_services_are_reported<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let mut watcher = ServiceWatcher::<Sut>::new().unwrap();
+        watcher.try_wait().unwrap();
+
+        let sut = Sut::new(&service_name).event().create().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::Added]);
+        assert_that!(changes_of(&mut watcher, &service_name), len 0);
+
+        drop(sut);
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::Removed]);
+        assert_that!(watcher.services().any(|s| s.service_name() == &service_name), eq false);
+    }
+
+    #[test]
+    fn created_and_removed_ports_are_reported<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let mut watcher = ServiceWatcher::<Sut>::new().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::Added]);
+
+        let publisher = sut.publisher().create().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::PortsChanged]);
+
+        let subscriber = sut.subscriber().create().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::PortsChanged]);
+
+        drop(publisher);
+        drop(subscriber);
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::PortsChanged]);
+        assert_that!(changes_of(&mut watcher, &service_name), len 0);
+    }
+
+    #[test]
+    fn replaced_port_is_reported<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).event().create().unwrap();
+        let listener = sut.listener().create().unwrap();
+
+        let mut watcher = ServiceWatcher::<Sut>::new().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::Added]);
+
+        drop(listener);
+        let _listener = sut.listener().create().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::PortsChanged]);
+    }
+
+    #[test]
+    fn timed_wait_wakes_up_when_service_is_created<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let mut watcher = ServiceWatcher::<Sut>::new().unwrap();
+        watcher.try_wait().unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                std::thread::sleep(CREATION_DELAY);
+                let _sut = Sut::new(&service_name).event().create().unwrap();
+                std::thread::sleep(CREATION_DELAY);
+            });
+
+            let start = Instant::now();
+            let mut is_added = false;
+            while !is_added && start.elapsed() < WAKEUP_TIMEOUT {
+                is_added = watcher.timed_wait(WAKEUP_TIMEOUT).unwrap().iter().any(|e| {
+                    matches!(e, ServiceEvent::ServiceAdded(c) if c.service_name() == &service_name)
+                });
+            }
+
+            assert_that!(is_added, eq true);
+            assert_that!(start.elapsed(), lt WAKEUP_TIMEOUT);
+        });
+    }
+
+    #[test]
+    fn blocking_wait_wakes_up_when_port_is_created<Sut: Service + Details<'static>>() {
+        let service_name = generate_name();
+        let sut = Sut::new(&service_name).event().create().unwrap();
+        let mut watcher = ServiceWatcher::<Sut>::new().unwrap();
+        assert_that!(changes_of(&mut watcher, &service_name), eq vec![Change::Added]);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                std::thread::sleep(CREATION_DELAY);
+                let _listener = sut.listener().create().unwrap();
+                std::thread::sleep(CREATION_DELAY);
+            });
+
+            let mut is_changed = false;
+            while !is_changed {
+                is_changed = watcher.blocking_wait().unwrap().iter().any(|e| {
+                    matches!(e, ServiceEvent::PortsChanged(c) if c.service_name() == &service_name)
+                });
+            }
+        });
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
@@ -0,0 +1,434 @@
+#[generic_tests::define]
+mod subscriber {
+    use std::cell::Cell;
+    use std::time::{Duration, Instant};
+
+    use elkodon::port::subscriber::ReceiveError;
+    use elkodon::port::{DegrationAction, DegrationCause};
+    use elkodon::service::port_factory::subscriber::ReceivePolicy;
+    use elkodon::service::{service_name::ServiceName, Service};
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+    use elkodon_bb_posix::clock::{ClockType, Time};
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_testing::assert_that;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+    const LONG_WAKEUP_INTERVAL: Duration = Duration::from_secs(10);
+
+    pub(crate) fn generate_name() -> ServiceName {
+        let mut service = ServiceName::new(b"subscriber_tests_").unwrap();
+        service
+            .push_bytes(
+                UniqueSystemId::new()
+                    .unwrap()
+                    .value()
+                    .to_string()
+                    .as_bytes(),
+            )
+            .unwrap();
+        service
+    }
+
+    #[test]
+    fn subscriber_timed_receive_blocks_at_least_timeout<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut = service.subscriber().create().unwrap();
+
+        let start = Instant::now();
+        let result = sut.timed_receive(TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_timed_receive_returns_available_sample_immediately<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let sut = service
+            .subscriber()
+            .wakeup_interval(LONG_WAKEUP_INTERVAL)
+            .create()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        assert_that!(publisher.send_copy(1234), is_ok);
+
+        let start = Instant::now();
+        let sample = sut.timed_receive(LONG_WAKEUP_INTERVAL).unwrap();
+        assert_that!(start.elapsed(), lt LONG_WAKEUP_INTERVAL);
+        assert_that!(*sample.unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn subscriber_blocking_receive_is_woken_up_by_notification<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+
+        let handle = BarrierHandle::new();
+        let barrier = BarrierBuilder::new(2).create(&handle).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let service = Sut::new(&service_name)
+                    .publish_subscribe()
+                    .open::<u64>()
+                    .unwrap();
+
+                // without the notification the subscriber would sleep the whole wakeup interval
+                let sut = service
+                    .subscriber()
+                    .wakeup_interval(LONG_WAKEUP_INTERVAL)
+                    .create()
+                    .unwrap();
+                barrier.wait();
+
+                let start = Instant::now();
+                let sample = sut.blocking_receive();
+                assert_that!(start.elapsed(), time_at_least TIMEOUT);
+                assert_that!(start.elapsed(), lt LONG_WAKEUP_INTERVAL);
+                assert_that!(sample, is_ok);
+                assert_that!(*sample.unwrap(), eq 5678);
+            });
+
+            barrier.wait();
+            std::thread::sleep(TIMEOUT);
+            assert_that!(publisher.send_copy(5678), is_ok);
+        });
+    }
+
+    #[test]
+    fn subscriber_blocking_receive_without_notifications_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(false)
+            .create::<u64>()
+            .unwrap();
+
+        let sut = service.subscriber().create().unwrap();
+
+        let result = sut.blocking_receive();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ReceiveError::NotificationsNotEnabled);
+
+        let result = sut.timed_receive(TIMEOUT);
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ReceiveError::NotificationsNotEnabled);
+    }
+
+    #[test]
+    fn subscriber_with_zero_wakeup_interval_can_be_created<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .enable_notifications(true)
+            .create::<u64>()
+            .unwrap();
+
+        let factory = service.subscriber().wakeup_interval(Duration::ZERO);
+        let sut = factory.create().unwrap();
+        // the factory can be reused since create does not consume it
+        let sut2 = factory.create().unwrap();
+
+        let start = Instant::now();
+        let result = sut.timed_receive(TIMEOUT);
+        assert_that!(start.elapsed(), time_at_least TIMEOUT);
+        assert_that!(result, is_ok);
+        assert_that!(result.unwrap(), is_none);
+        assert_that!(sut2.timed_receive(Duration::ZERO).unwrap(), is_none);
+    }
+
+    #[test]
+    fn subscriber_reports_publisher_that_missed_its_deadline<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .deadline(TIMEOUT)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let reported_causes = Cell::new(0);
+        let mut sut = service.subscriber().create().unwrap();
+        sut.set_degration_callback(Some(|_, _, _, cause| {
+            assert_that!(cause, eq DegrationCause::DeadlineMissed);
+            reported_causes.set(reported_causes.get() + 1);
+            DegrationAction::Fail
+        }));
+
+        assert_that!(sut.publishers_with_missed_deadline(), is_empty);
+        assert_that!(sut.receive(), is_ok);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.publishers_with_missed_deadline(), eq vec![publisher.id()]);
+        let result = sut.receive();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ReceiveError::PublisherMissedDeadline);
+        assert_that!(reported_causes.get(), eq 1);
+
+        // the missed deadline is reported only once
+        assert_that!(sut.receive(), is_ok);
+        assert_that!(reported_causes.get(), eq 1);
+
+        assert_that!(publisher.send_copy(42), is_ok);
+        assert_that!(sut.publishers_with_missed_deadline(), is_empty);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 42);
+
+        // reported again after the publisher recovered
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.receive(), is_err);
+        assert_that!(reported_causes.get(), eq 2);
+    }
+
+    #[test]
+    fn publisher_asserts_liveliness_explicitly_and_on_send<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .liveliness_lease_duration(TIMEOUT)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let lost_liveliness = Cell::new(0);
+        let mut sut = service.subscriber().create().unwrap();
+        sut.set_degration_callback(Some(|_, _, _, cause| {
+            assert_that!(cause, eq DegrationCause::LivelinessLost);
+            lost_liveliness.set(lost_liveliness.get() + 1);
+            DegrationAction::Ignore
+        }));
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.publishers_with_lost_liveliness(), eq vec![publisher.id()]);
+        assert_that!(sut.receive(), is_ok);
+        assert_that!(lost_liveliness.get(), eq 1);
+
+        publisher.assert_liveliness();
+        assert_that!(sut.publishers_with_lost_liveliness(), is_empty);
+        // the service has no deadline
+        assert_that!(sut.publishers_with_missed_deadline(), is_empty);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.publishers_with_lost_liveliness(), eq vec![publisher.id()]);
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(sut.publishers_with_lost_liveliness(), is_empty);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 1);
+        assert_that!(lost_liveliness.get(), eq 1);
+    }
+
+    #[test]
+    fn subscriber_discards_samples_that_exceed_their_lifespan<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(3)
+            .lifespan(TIMEOUT)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let sut = service.subscriber().create().unwrap();
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(publisher.send_copy(2), is_ok);
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(publisher.send_copy(3), is_ok);
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 3);
+        assert_that!(sample.header().age(), lt TIMEOUT);
+        assert_that!(sut.number_of_expired_samples(), eq 2);
+        assert_that!(sut.receive().unwrap(), is_none);
+        drop(sample);
+
+        // the expired samples were returned to the publisher
+        for n in 0..3 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+        for n in 0..3 {
+            assert_that!(*sut.receive().unwrap().unwrap(), eq n);
+        }
+        assert_that!(sut.number_of_expired_samples(), eq 2);
+    }
+
+    #[test]
+    fn sample_time_stamp_uses_monotonic_clock<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let sut = service.subscriber().create().unwrap();
+
+        let sample = publisher.loan().unwrap();
+        std::thread::sleep(TIMEOUT);
+        let before_send = Time::now_with_clock(ClockType::Monotonic).unwrap();
+        assert_that!(publisher.send(sample), is_ok);
+
+        let sample = sut.receive().unwrap().unwrap();
+        let time_stamp = sample.header().time_stamp();
+        assert_that!(time_stamp.clock_type(), eq ClockType::Monotonic);
+        // the time stamp is acquired on send and not on loan
+        assert_that!(time_stamp.as_duration(), ge before_send.as_duration());
+        assert_that!(sample.header().age(), lt TIMEOUT);
+    }
+
+    #[test]
+    fn subscriber_detects_lost_samples_of_overflowing_service<Sut: Service>() {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .subscriber_max_buffer_size(2)
+            .history_size(0)
+            .enable_safe_overflow(true)
+            .create::<u64>()
+            .unwrap();
+
+        let publisher = service.publisher().create().unwrap();
+        let reported_losses = Cell::new(vec![]);
+        let mut sut = service.subscriber().create().unwrap();
+        sut.set_sample_loss_callback(Some(|publisher_id, number_of_lost_samples| {
+            let mut losses = reported_losses.take();
+            losses.push((publisher_id, number_of_lost_samples));
+            reported_losses.set(losses);
+        }));
+
+        assert_that!(publisher.send_copy(0), is_ok);
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 0);
+        drop(sample);
+
+        // the samples 1 to 3 are overwritten by the samples 4 and 5
+        for n in 1..6 {
+            assert_that!(publisher.send_copy(n), is_ok);
+        }
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample, eq 4);
+        assert_that!(sample.header().sequence_number(), eq 4);
+        drop(sample);
+        assert_that!(sut.receive().unwrap().unwrap().header().sequence_number(), eq 5);
+
+        assert_that!(sut.lost_samples(), eq vec![(publisher.id(), 3)]);
+        assert_that!(reported_losses.take(), eq vec![(publisher.id(), 3)]);
+
+        // no loss without a gap
+        assert_that!(publisher.send_copy(6), is_ok);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 6);
+        assert_that!(sut.lost_samples(), eq vec![(publisher.id(), 3)]);
+        assert_that!(reported_losses.take(), is_empty);
+    }
+
+    fn receive_all<Sut: Service>(policy: ReceivePolicy) -> Vec<u64> {
+        let service_name = generate_name();
+        let service = Sut::new(&service_name)
+            .publish_subscribe()
+            .max_publishers(2)
+            .subscriber_max_buffer_size(3)
+            .subscriber_max_borrowed_samples(3)
+            .enable_safe_overflow(false)
+            .create::<u64>()
+            .unwrap();
+
+        let sut = service
+            .subscriber()
+            .receive_policy(policy)
+            .create()
+            .unwrap();
+        let first_publisher = service.publisher().create().unwrap();
+        let second_publisher = service.publisher().create().unwrap();
+
+        assert_that!(second_publisher.send_copy(20), is_ok);
+        assert_that!(first_publisher.send_copy(10), is_ok);
+        assert_that!(first_publisher.send_copy(11), is_ok);
+        assert_that!(second_publisher.send_copy(21), is_ok);
+        assert_that!(second_publisher.send_copy(22), is_ok);
+        assert_that!(first_publisher.send_copy(12), is_ok);
+
+        let mut received = vec![];
+        while let Some(sample) = sut.receive().unwrap() {
+            received.push(*sample);
+        }
+        received
+    }
+
+    #[test]
+    fn subscriber_receives_in_connection_order<Sut: Service>() {
+        assert_that!(receive_all::<Sut>(ReceivePolicy::ConnectionOrder), eq vec![10, 11, 12, 20, 21, 22]);
+    }
+
+    #[test]
+    fn subscriber_receives_round_robin<Sut: Service>() {
+        assert_that!(receive_all::<Sut>(ReceivePolicy::RoundRobin), eq vec![10, 20, 11, 21, 12, 22]);
+    }
+
+    #[test]
+    fn subscriber_receives_oldest_sample_first<Sut: Service>() {
+        assert_that!(receive_all::<Sut>(ReceivePolicy::OldestFirst), eq vec![20, 10, 11, 21, 22, 12]);
+    }
+
+    #[instantiate_tests(<elkodon::service::zero_copy::Service>)]
+    mod zero_copy {}
+
+    #[instantiate_tests(<elkodon::service::process_local::Service>)]
+    mod process_local {}
+
+    #[instantiate_tests(<elkodon::service::copy::Service>)]
+    mod copy {}
+
+    #[instantiate_tests(<elkodon::service::grouped::Service>)]
+    mod grouped {}
+}
+
+// raises SIGINT in the whole process, therefore it must not be instantiated for multiple
+// service types which would consume the termination request of each other
+#[test]
+fn subscriber_blocking_receive_returns_when_termination_is_requested() {
+    use elkodon::port::subscriber::ReceiveError;
+    use elkodon::service::{zero_copy, Service};
+    use elkodon_bb_posix::process::Process;
+    use elkodon_bb_posix::signal::{Signal, SignalHandler};
+    use elkodon_bb_testing::assert_that;
+    use std::time::Duration;
+
+    let service = zero_copy::Service::new(&subscriber::generate_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+    let sut = service.subscriber().create().unwrap();
+
+    // installs the signal handler so that the interrupt does not terminate the test
+    SignalHandler::termination_requested();
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            std::thread::sleep(Duration::from_millis(50));
+            Process::from_self().send_signal(Signal::Interrupt).ok();
+        });
+
+        let result = sut.blocking_receive();
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ReceiveError::TerminationRequested);
+    });
+}
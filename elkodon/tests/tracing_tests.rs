@@ -0,0 +1,221 @@
+#![cfg(feature = "tracing")]
+
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use elkodon::port::event_id::EventId;
+use elkodon::service::{service_name::ServiceName, zero_copy, Service};
+use elkodon_bb_container::semantic_string::*;
+use elkodon_bb_log::{debug, trace};
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_testing::assert_that;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata};
+
+fn generate_name() -> ServiceName {
+    let mut service = ServiceName::new(b"tracing_tests_").unwrap();
+    service
+        .push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+    service
+}
+
+#[derive(Debug, Default)]
+struct Recording {
+    spans: Vec<(String, String)>,
+    events: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct FieldCollector(String);
+
+impl Visit for FieldCollector {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0 += &format!("{}={:?} ", field.name(), value);
+    }
+}
+
+/// Records the names and fields of all spans and events of the current thread up to the max
+/// level, without max level everything is recorded.
+#[derive(Default)]
+struct Recorder {
+    recording: Arc<Mutex<Recording>>,
+    next_id: AtomicU64,
+    max_level: Option<Level>,
+}
+
+impl tracing::Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        self.max_level
+            .map_or(true, |max_level| *metadata.level() <= max_level)
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = FieldCollector::default();
+        span.record(&mut fields);
+        self.recording
+            .lock()
+            .unwrap()
+            .spans
+            .push((span.metadata().name().to_string(), fields.0));
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = FieldCollector::default();
+        event.record(&mut fields);
+        self.recording
+            .lock()
+            .unwrap()
+            .events
+            .push((event.metadata().target().to_string(), fields.0));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn record<F: FnOnce()>(f: F) -> Recording {
+    record_up_to(None, f)
+}
+
+fn record_up_to<F: FnOnce()>(max_level: Option<Level>, f: F) -> Recording {
+    let recorder = Recorder {
+        max_level,
+        ..Default::default()
+    };
+    let recording = recorder.recording.clone();
+    tracing::subscriber::with_default(recorder, f);
+
+    let mut recording = recording.lock().unwrap();
+    std::mem::take(&mut *recording)
+}
+
+fn span<'a>(recording: &'a Recording, name: &str) -> Option<&'a str> {
+    recording
+        .spans
+        .iter()
+        .find(|(span_name, _)| span_name == name)
+        .map(|(_, fields)| fields.as_str())
+}
+
+#[test]
+fn tracing_service_creation_and_opening_emit_spans() {
+    let service_name = generate_name();
+
+    let recording = record(|| {
+        let _sut = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let _opened = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .open::<u64>()
+            .unwrap();
+    });
+
+    let create = span(&recording, "publish_subscribe::create");
+    assert_that!(create, is_some);
+    assert_that!(create.unwrap().contains(&service_name.to_string()), eq true);
+    assert_that!(span(&recording, "publish_subscribe::open"), is_some);
+}
+
+#[test]
+fn tracing_publish_subscribe_emits_spans_for_every_operation() {
+    let service_name = generate_name();
+
+    let recording = record(|| {
+        let service = zero_copy::Service::new(&service_name)
+            .publish_subscribe()
+            .create::<u64>()
+            .unwrap();
+        let publisher = service.publisher().create().unwrap();
+        let subscriber = service.subscriber().create().unwrap();
+
+        let mut sample = publisher.loan().unwrap();
+        unsafe { sample.as_mut_ptr().write(42) };
+        assert_that!(publisher.send(sample), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 42);
+    });
+
+    let publisher = span(&recording, "Publisher::new");
+    assert_that!(publisher, is_some);
+    assert_that!(publisher.unwrap().contains(&service_name.to_string()), eq true);
+    assert_that!(span(&recording, "Subscriber::new"), is_some);
+    assert_that!(span(&recording, "Publisher::loan"), is_some);
+    assert_that!(span(&recording, "Publisher::send"), is_some);
+    assert_that!(span(&recording, "Subscriber::receive"), is_some);
+    assert_that!(recording.events.iter().any(|(_, fields)| fields.contains("return=Ok(1)")), eq true);
+}
+
+#[test]
+fn tracing_event_emits_spans_for_notify_and_wait() {
+    let service_name = generate_name();
+
+    let recording = record(|| {
+        let service = zero_copy::Service::new(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let mut listener = service.listener().create().unwrap();
+        let notifier = service.notifier().create().unwrap();
+
+        assert_that!(notifier.notify_with_custom_event_id(EventId::new(3)), eq Ok(1));
+        assert_that!(listener.try_wait().unwrap(), len 1);
+    });
+
+    assert_that!(span(&recording, "Listener::new"), is_some);
+    assert_that!(span(&recording, "Notifier::new"), is_some);
+    let notify = span(&recording, "Notifier::notify");
+    assert_that!(notify, is_some);
+    assert_that!(notify.unwrap().contains("event_id=3"), eq true);
+    assert_that!(span(&recording, "Listener::try_wait"), is_some);
+}
+
+#[test]
+fn tracing_log_messages_are_forwarded_as_events() {
+    let recording = record(|| {
+        let sut = zero_copy::Service::new(&generate_name())
+            .publish_subscribe()
+            .open::<u64>();
+        assert_that!(sut, is_err);
+    });
+
+    assert_that!(
+        recording
+            .events
+            .iter()
+            .any(|(target, fields)| target == "elkodon" && fields.contains("origin=")),
+        eq true
+    );
+}
+
+#[test]
+fn tracing_log_messages_are_filtered_by_the_subscriber() {
+    let recording = record_up_to(Some(Level::DEBUG), || {
+        debug!(from "Custom::Origin", "debug message");
+        trace!(from "Custom::Origin", "trace message");
+    });
+
+    let has_event = |message: &str| {
+        recording
+            .events
+            .iter()
+            .any(|(_, fields)| fields.contains(message))
+    };
+    assert_that!(has_event("debug message"), eq true);
+    assert_that!(has_event("trace message"), eq false);
+}
@@ -0,0 +1,277 @@
+use std::sync::Barrier;
+use std::time::{Duration, Instant};
+
+use elkodon::service::{service_name::ServiceName, zero_copy, Service};
+use elkodon::waitset::{WaitSetAttachmentError, WaitSetBuilder};
+use elkodon_bb_container::semantic_string::*;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_testing::assert_that;
+
+const TIMEOUT: Duration = Duration::from_millis(50);
+
+fn generate_name() -> ServiceName {
+    let mut service = ServiceName::new(b"waitset_tests_").unwrap();
+    service
+        .push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+    service
+}
+
+#[test]
+fn waitset_attach_and_detach_works() {
+    const NUMBER_OF_LISTENERS: usize = 8;
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .max_listeners(NUMBER_OF_LISTENERS)
+        .create()
+        .unwrap();
+    let listeners: Vec<_> = (0..NUMBER_OF_LISTENERS)
+        .map(|_| service.listener().create().unwrap())
+        .collect();
+
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    assert_that!(sut.is_empty(), eq true);
+
+    let mut guards = vec![];
+    for (i, listener) in listeners.iter().enumerate() {
+        assert_that!(sut.len(), eq i);
+        guards.push(sut.attach_notification(listener).unwrap());
+        assert_that!(sut.is_empty(), eq false);
+    }
+    guards.push(sut.attach_interval(TIMEOUT).unwrap());
+    assert_that!(sut.len(), eq NUMBER_OF_LISTENERS + 1);
+
+    for i in 0..NUMBER_OF_LISTENERS + 1 {
+        assert_that!(sut.len(), eq NUMBER_OF_LISTENERS + 1 - i);
+        guards.pop();
+    }
+    assert_that!(sut.is_empty(), eq true);
+}
+
+#[test]
+fn waitset_attaching_same_notification_twice_fails() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let listener = service.listener().create().unwrap();
+
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    let _guard = sut.attach_notification(&listener).unwrap();
+
+    let result = sut.attach_notification(&listener);
+    assert_that!(result, is_err);
+    assert_that!(result.err().unwrap(), eq WaitSetAttachmentError::AlreadyAttached);
+
+    let result = sut.attach_deadline(&listener, TIMEOUT);
+    assert_that!(result, is_err);
+    assert_that!(result.err().unwrap(), eq WaitSetAttachmentError::AlreadyAttached);
+}
+
+#[test]
+fn waitset_timed_wait_without_events_blocks_until_timeout() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let listener = service.listener().create().unwrap();
+
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    let _guard = sut.attach_notification(&listener).unwrap();
+
+    let mut callback_called = false;
+    let start = Instant::now();
+    assert_that!(sut.timed_wait(|_| callback_called = true, TIMEOUT), is_ok);
+
+    assert_that!(start.elapsed(), ge TIMEOUT);
+    assert_that!(callback_called, eq false);
+}
+
+#[test]
+fn waitset_reports_only_notified_listeners() {
+    const NUMBER_OF_LISTENERS: usize = 4;
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .max_listeners(NUMBER_OF_LISTENERS)
+        .create()
+        .unwrap();
+    let mut listeners: Vec<_> = (0..NUMBER_OF_LISTENERS)
+        .map(|_| service.listener().create().unwrap())
+        .collect();
+    let notifier = service.notifier().create().unwrap();
+
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    let guards: Vec<_> = listeners
+        .iter()
+        .map(|listener| sut.attach_notification(listener).unwrap())
+        .collect();
+
+    let mut fired = vec![];
+    assert_that!(sut.try_wait(|id| fired.push(id)), is_ok);
+    assert_that!(fired, len 0);
+
+    assert_that!(notifier.notify(), eq Ok(NUMBER_OF_LISTENERS));
+    assert_that!(sut.timed_wait(|id| fired.push(id), TIMEOUT), is_ok);
+    assert_that!(fired, len NUMBER_OF_LISTENERS);
+    for guard in &guards {
+        assert_that!(fired.iter().any(|id| id.event_from(guard)), eq true);
+        assert_that!(fired.iter().any(|id| id.deadline_from(guard)), eq false);
+    }
+
+    drop(guards);
+    for listener in &mut listeners {
+        assert_that!(listener.try_wait().unwrap(), len 1);
+    }
+
+    // only the listeners that still have events are reported
+    let guard_a = sut.attach_notification(&listeners[0]).unwrap();
+    let guard_b = sut.attach_notification(&listeners[1]).unwrap();
+    let mut fired = vec![];
+    assert_that!(sut.try_wait(|id| fired.push(id)), is_ok);
+    assert_that!(fired, len 0);
+
+    drop(guard_a);
+    drop(guard_b);
+    assert_that!(notifier.notify(), eq Ok(NUMBER_OF_LISTENERS));
+    assert_that!(listeners[0].try_wait().unwrap(), len 1);
+
+    let guard_a = sut.attach_notification(&listeners[0]).unwrap();
+    let guard_b = sut.attach_notification(&listeners[1]).unwrap();
+    assert_that!(sut.timed_wait(|id| fired.push(id), TIMEOUT), is_ok);
+    assert_that!(fired, len 1);
+    assert_that!(fired[0].event_from(&guard_b), eq true);
+    assert_that!(fired[0].event_from(&guard_a), eq false);
+}
+
+#[test]
+fn waitset_interval_fires_repeatedly() {
+    const ITERATIONS: u32 = 3;
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    let guard = sut.attach_interval(TIMEOUT).unwrap();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let mut fired = vec![];
+        assert_that!(sut.blocking_wait(|id| fired.push(id)), is_ok);
+        assert_that!(fired, len 1);
+        assert_that!(fired[0].event_from(&guard), eq true);
+    }
+    assert_that!(start.elapsed(), ge TIMEOUT * ITERATIONS);
+}
+
+#[test]
+fn waitset_reports_missed_deadline() {
+    let service = zero_copy::Service::new(&generate_name())
+        .event()
+        .create()
+        .unwrap();
+    let listener = service.listener().create().unwrap();
+    let notifier = service.notifier().create().unwrap();
+
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    let guard = sut.attach_deadline(&listener, TIMEOUT).unwrap();
+
+    let start = Instant::now();
+    let mut fired = vec![];
+    assert_that!(sut.blocking_wait(|id| fired.push(id)), is_ok);
+    assert_that!(start.elapsed(), ge TIMEOUT);
+    assert_that!(fired, len 1);
+    assert_that!(fired[0].deadline_from(&guard), eq true);
+    assert_that!(fired[0].event_from(&guard), eq false);
+
+    assert_that!(notifier.notify(), eq Ok(1));
+    let mut fired = vec![];
+    assert_that!(sut.blocking_wait(|id| fired.push(id)), is_ok);
+    assert_that!(fired, len 1);
+    assert_that!(fired[0].event_from(&guard), eq true);
+    assert_that!(fired[0].deadline_from(&guard), eq false);
+}
+
+#[test]
+fn waitset_reports_subscriber_with_delivered_samples() {
+    let service = zero_copy::Service::new(&generate_name())
+        .publish_subscribe()
+        .enable_notifications(true)
+        .create::<u64>()
+        .unwrap();
+    let publisher = service.publisher().create().unwrap();
+    let subscriber = service.subscriber().create().unwrap();
+
+    let sut = WaitSetBuilder::new()
+        .create::<zero_copy::Service>()
+        .unwrap();
+    let guard = sut
+        .attach_notification(subscriber.notification_handle().unwrap())
+        .unwrap();
+
+    let mut fired = vec![];
+    assert_that!(sut.try_wait(|id| fired.push(id)), is_ok);
+    assert_that!(fired, len 0);
+
+    assert_that!(publisher.send_copy(1234), eq Ok(1));
+    assert_that!(sut.timed_wait(|id| fired.push(id), TIMEOUT), is_ok);
+    assert_that!(fired, len 1);
+    assert_that!(fired[0].event_from(&guard), eq true);
+
+    // receiving all samples consumes the notifications
+    assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    assert_that!(subscriber.receive().unwrap(), is_none);
+    let mut fired = vec![];
+    assert_that!(sut.try_wait(|id| fired.push(id)), is_ok);
+    assert_that!(fired, len 0);
+}
+
+#[test]
+fn waitset_blocking_wait_wakes_up_on_notification() {
+    let service_name = generate_name();
+    let service = zero_copy::Service::new(&service_name)
+        .event()
+        .create()
+        .unwrap();
+    let listener = service.listener().create().unwrap();
+    let barrier = Barrier::new(2);
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let service = zero_copy::Service::new(&service_name)
+                .event()
+                .open()
+                .unwrap();
+            let notifier = service.notifier().create().unwrap();
+            barrier.wait();
+            std::thread::sleep(TIMEOUT);
+            assert_that!(notifier.notify(), eq Ok(1));
+        });
+
+        let sut = WaitSetBuilder::new()
+            .create::<zero_copy::Service>()
+            .unwrap();
+        let guard = sut.attach_notification(&listener).unwrap();
+
+        barrier.wait();
+        let start = Instant::now();
+        let mut fired = vec![];
+        assert_that!(sut.blocking_wait(|id| fired.push(id)), is_ok);
+        assert_that!(start.elapsed(), ge TIMEOUT);
+        assert_that!(fired, len 1);
+        assert_that!(fired[0].event_from(&guard), eq true);
+    });
+}
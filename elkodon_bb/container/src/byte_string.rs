@@ -24,6 +24,7 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
+use elkodon_bb_elementary::zero_copy_send::*;
 use elkodon_bb_log::{fail, fatal_panic};
 
 /// Returns the length of a string
@@ -60,6 +61,10 @@ pub struct FixedSizeByteString<const CAPACITY: usize> {
 
 unsafe impl<const CAPACITY: usize> Send for FixedSizeByteString<CAPACITY> {}
 unsafe impl<const CAPACITY: usize> Sync for FixedSizeByteString<CAPACITY> {}
+unsafe impl<const CAPACITY: usize> ZeroCopySend for FixedSizeByteString<CAPACITY> {
+    const LAYOUT_HASH: u64 =
+        combine_layout_hash(layout_hash_of(b"FixedSizeByteString"), CAPACITY as u64);
+}
 
 impl<const CAPACITY: usize> Hash for FixedSizeByteString<CAPACITY> {
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
@@ -55,6 +55,7 @@ use elkodon_bb_elementary::math::align_to;
 use elkodon_bb_elementary::owning_pointer::OwningPointer;
 use elkodon_bb_elementary::pointer_trait::PointerTrait;
 use elkodon_bb_elementary::relocatable_ptr::RelocatablePointer;
+use elkodon_bb_elementary::zero_copy_send::*;
 use elkodon_bb_log::{fail, fatal_panic};
 use std::sync::atomic::AtomicBool;
 use std::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
@@ -306,6 +307,12 @@ impl<T, const CAPACITY: usize> Default for FixedSizeQueue<T, CAPACITY> {
 
 unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeQueue<T, CAPACITY> {}
 unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeQueue<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeQueue<T, CAPACITY> {
+    const LAYOUT_HASH: u64 = combine_layout_hash(
+        combine_layout_hash(layout_hash_of(b"FixedSizeQueue"), T::LAYOUT_HASH),
+        CAPACITY as u64,
+    );
+}
 
 impl<T, const CAPACITY: usize> FixedSizeQueue<T, CAPACITY> {
     /// Creates a new queue.
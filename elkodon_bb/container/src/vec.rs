@@ -59,7 +59,7 @@ use std::{
 
 use elkodon_bb_elementary::{
     math::align_to, pointer_trait::PointerTrait, relocatable_container::RelocatableContainer,
-    relocatable_ptr::RelocatablePointer,
+    relocatable_ptr::RelocatablePointer, zero_copy_send::*,
 };
 use elkodon_bb_log::{fail, fatal_panic};
 
@@ -322,6 +322,12 @@ impl<T, const CAPACITY: usize> Default for FixedSizeVec<T, CAPACITY> {
 
 unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeVec<T, CAPACITY> {}
 unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeVec<T, CAPACITY> {}
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeVec<T, CAPACITY> {
+    const LAYOUT_HASH: u64 = combine_layout_hash(
+        combine_layout_hash(layout_hash_of(b"FixedSizeVec"), T::LAYOUT_HASH),
+        CAPACITY as u64,
+    );
+}
 
 impl<T, const CAPACITY: usize> FixedSizeVec<T, CAPACITY> {
     /// Creates a new vector.
@@ -0,0 +1,183 @@
+//! Contains the derive macros of the elkodon building blocks.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, punctuated::Punctuated, Attribute, Data, DeriveInput, Meta,
+    Token, Type,
+};
+
+/// Implements `ZeroCopySend` for a struct or enum. It verifies at compile time that the type
+/// is `#[repr(C)]` and that every field is `ZeroCopySend`. Generic types are only
+/// `ZeroCopySend` when all of their fields are. The `LAYOUT_HASH` is computed from the offset,
+/// size and layout hash of every field, the field and type names are not part of it, so that
+/// renamed types stay compatible. The generated implementation refers to
+/// `elkodon_bb_elementary::zero_copy_send::ZeroCopySend`, therefore `elkodon_bb_elementary`
+/// must be a dependency of the crate that uses the macro.
+///
+/// # Example
+///
+/// ```
+/// use elkodon_bb_derive_macros::ZeroCopySend;
+/// use elkodon_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(Debug, ZeroCopySend)]
+/// #[repr(C)]
+/// struct Position {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// fn send<T: ZeroCopySend>(_value: T) {}
+///
+/// send(Position { x: 1.0, y: 2.0 });
+/// ```
+///
+/// Types without `#[repr(C)]` are rejected
+///
+/// ```compile_fail
+/// use elkodon_bb_derive_macros::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// struct Position {
+///     x: f64,
+///     y: f64,
+/// }
+/// ```
+///
+/// as well as types with fields that cannot be transferred into another process.
+///
+/// ```compile_fail
+/// use elkodon_bb_derive_macros::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct Message {
+///     id: u64,
+///     text: String,
+/// }
+/// ```
+#[proc_macro_derive(ZeroCopySend)]
+pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    match zero_copy_send_impl(&input) {
+        Ok(output) => output.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn zero_copy_send_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !is_repr_c(&input.attrs)? {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "ZeroCopySend can only be derived for types with #[repr(C)].",
+        ));
+    }
+
+    let (field_types, layout_hash) = match &input.data {
+        Data::Struct(data) => {
+            let field_types: Vec<&Type> = data.fields.iter().map(|f| &f.ty).collect();
+            let layout_hash = struct_layout_hash(&field_types);
+            (field_types, layout_hash)
+        }
+        Data::Enum(data) => {
+            let variants: Vec<Vec<&Type>> = data
+                .variants
+                .iter()
+                .map(|v| v.fields.iter().map(|f| &f.ty).collect())
+                .collect();
+            let layout_hash = enum_layout_hash(&variants);
+            (variants.into_iter().flatten().collect(), layout_hash)
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "ZeroCopySend cannot be derived for unions.",
+            ))
+        }
+    };
+
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in field_types {
+        where_clause
+            .predicates
+            .push(parse_quote!(#ty: ::elkodon_bb_elementary::zero_copy_send::ZeroCopySend));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        unsafe impl #impl_generics ::elkodon_bb_elementary::zero_copy_send::ZeroCopySend
+            for #name #ty_generics #where_clause {
+            const LAYOUT_HASH: u64 = {
+                #layout_hash
+                let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(
+                    hash, ::core::mem::size_of::<Self>() as u64);
+                ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(
+                    hash, ::core::mem::align_of::<Self>() as u64)
+            };
+        }
+    })
+}
+
+// every field contributes its offset, size and layout hash, the offsets follow the
+// #[repr(C)] rules
+fn struct_layout_hash(field_types: &[&Type]) -> TokenStream2 {
+    let fields = field_types.iter().map(|ty| {
+        quote! {
+            let _offset = ::elkodon_bb_elementary::math::align(_end, ::core::mem::align_of::<#ty>());
+            let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(hash, _offset as u64);
+            let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(
+                hash, ::core::mem::size_of::<#ty>() as u64);
+            let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(
+                hash, <#ty as ::elkodon_bb_elementary::zero_copy_send::ZeroCopySend>::LAYOUT_HASH);
+            let _end = _offset + ::core::mem::size_of::<#ty>();
+        }
+    });
+
+    quote! {
+        let hash = ::elkodon_bb_elementary::zero_copy_send::layout_hash_of(b"struct");
+        let _end = 0usize;
+        #(#fields)*
+    }
+}
+
+// the offsets inside the variants depend on the discriminant, therefore only the sequence of
+// sizes and layout hashes is used, the size of the enum is added by the caller
+fn enum_layout_hash(variants: &[Vec<&Type>]) -> TokenStream2 {
+    let variants = variants.iter().enumerate().map(|(index, field_types)| {
+        let index = index as u64;
+        let fields = field_types.iter().map(|ty| {
+            quote! {
+                let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(
+                    hash, ::core::mem::size_of::<#ty>() as u64);
+                let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(
+                    hash, <#ty as ::elkodon_bb_elementary::zero_copy_send::ZeroCopySend>::LAYOUT_HASH);
+            }
+        });
+
+        quote! {
+            let hash = ::elkodon_bb_elementary::zero_copy_send::combine_layout_hash(hash, #index);
+            #(#fields)*
+        }
+    });
+
+    quote! {
+        let hash = ::elkodon_bb_elementary::zero_copy_send::layout_hash_of(b"enum");
+        #(#variants)*
+    }
+}
+
+fn is_repr_c(attrs: &[Attribute]) -> syn::Result<bool> {
+    for attr in attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let reprs = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        if reprs.iter().any(|r| r.path().is_ident("C")) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -0,0 +1,156 @@
+use elkodon_bb_container::byte_string::FixedSizeByteString;
+use elkodon_bb_container::queue::FixedSizeQueue;
+use elkodon_bb_container::vec::FixedSizeVec;
+use elkodon_bb_derive_macros::ZeroCopySend;
+use elkodon_bb_elementary::zero_copy_send::ZeroCopySend;
+use elkodon_bb_testing::assert_that;
+
+fn is_zero_copy_send<T: ZeroCopySend>(_value: &T) -> bool {
+    true
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct NamedFields {
+    a: u64,
+    b: [f32; 4],
+    c: bool,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct UnnamedFields(i32, char);
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct UnitStruct;
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct Generic<T> {
+    value: T,
+    values: [T; 2],
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C, align(64))]
+struct Containers {
+    name: FixedSizeByteString<16>,
+    values: FixedSizeVec<u64, 8>,
+    queue: FixedSizeQueue<NamedFields, 4>,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+#[allow(dead_code)]
+enum Enum {
+    Unit,
+    Unnamed(u8, u16),
+    Named { value: Generic<u32> },
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_structs_with_named_fields() {
+    let sut = NamedFields {
+        a: 1,
+        b: [2.0; 4],
+        c: true,
+    };
+    assert_that!(is_zero_copy_send(&sut), eq true);
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_tuple_and_unit_structs() {
+    assert_that!(is_zero_copy_send(&UnnamedFields(1, 'x')), eq true);
+    assert_that!(is_zero_copy_send(&UnitStruct), eq true);
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_generic_structs() {
+    let sut = Generic {
+        value: NamedFields {
+            a: 1,
+            b: [2.0; 4],
+            c: false,
+        },
+        values: [
+            NamedFields {
+                a: 3,
+                b: [4.0; 4],
+                c: true,
+            },
+            NamedFields {
+                a: 5,
+                b: [6.0; 4],
+                c: false,
+            },
+        ],
+    };
+    assert_that!(is_zero_copy_send(&sut), eq true);
+}
+
+#[test]
+fn zero_copy_send_derive_works_with_fixed_size_containers() {
+    let sut = Containers {
+        name: FixedSizeByteString::new(),
+        values: FixedSizeVec::default(),
+        queue: FixedSizeQueue::default(),
+    };
+    assert_that!(is_zero_copy_send(&sut), eq true);
+}
+
+#[test]
+fn zero_copy_send_derive_works_for_enums() {
+    assert_that!(is_zero_copy_send(&Enum::Unit), eq true);
+    assert_that!(is_zero_copy_send(&Enum::Unnamed(1, 2)), eq true);
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct RenamedNamedFields {
+    x: u64,
+    y: [f32; 4],
+    z: bool,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct ReorderedFields {
+    b: [f32; 4],
+    a: u64,
+    c: bool,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C)]
+struct ChangedFieldType {
+    a: i64,
+    b: [f32; 4],
+    c: bool,
+}
+
+#[derive(Debug, ZeroCopySend)]
+#[repr(C, align(64))]
+struct ChangedAlignment {
+    a: u64,
+    b: [f32; 4],
+    c: bool,
+}
+
+#[test]
+fn zero_copy_send_derive_layout_hash_is_independent_of_names() {
+    assert_that!(NamedFields::LAYOUT_HASH, eq RenamedNamedFields::LAYOUT_HASH);
+}
+
+#[test]
+fn zero_copy_send_derive_layout_hash_differs_for_different_layouts() {
+    assert_that!(NamedFields::LAYOUT_HASH, ne ReorderedFields::LAYOUT_HASH);
+    assert_that!(NamedFields::LAYOUT_HASH, ne ChangedFieldType::LAYOUT_HASH);
+    assert_that!(NamedFields::LAYOUT_HASH, ne ChangedAlignment::LAYOUT_HASH);
+    assert_that!(Generic::<u32>::LAYOUT_HASH, ne Generic::<u64>::LAYOUT_HASH);
+    assert_that!(<[u64; 4]>::LAYOUT_HASH, ne<[u64; 5]>::LAYOUT_HASH);
+    assert_that!(
+        FixedSizeVec::<u64, 8>::LAYOUT_HASH,
+        ne FixedSizeQueue::<u64, 8>::LAYOUT_HASH
+    );
+}
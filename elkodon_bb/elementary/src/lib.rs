@@ -11,3 +11,4 @@ pub mod relocatable_container;
 pub mod relocatable_ptr;
 pub mod scope_guard;
 pub mod unique_id;
+pub mod zero_copy_send;
@@ -0,0 +1,99 @@
+//! Marker trait for types that can be transferred via shared memory into another process.
+//!
+//! The trait is implemented for all primitives and arrays of [`ZeroCopySend`] types. User
+//! defined types can derive it with `#[derive(ZeroCopySend)]` from the
+//! `elkodon_bb_derive_macros` crate which verifies at compile time that the type is
+//! `#[repr(C)]` and that every field is [`ZeroCopySend`]. The derive macro also computes the
+//! [`ZeroCopySend::LAYOUT_HASH`] from the offset, size and layout hash of every field.
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_bb_elementary::zero_copy_send::*;
+//!
+//! #[derive(Debug)]
+//! #[repr(C)]
+//! struct Position {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! // a simplified version of the implementation the derive macro generates
+//! unsafe impl ZeroCopySend for Position {
+//!     const LAYOUT_HASH: u64 = combine_layout_hash(
+//!         combine_layout_hash(layout_hash_of(b"struct"), f64::LAYOUT_HASH),
+//!         f64::LAYOUT_HASH,
+//!     );
+//! }
+//!
+//! fn send<T: ZeroCopySend>(_value: T) {}
+//!
+//! send(Position { x: 1.0, y: 2.0 });
+//! send([1u64, 2, 3]);
+//! ```
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Marks a type that can be transferred via shared memory into another process.
+///
+/// # Safety
+///
+///  * the type must be self-contained, it is not allowed to contain pointers, references or
+///    handles to process local resources like heap memory or file descriptors
+///  * the type must have a well defined memory layout, i.e. `#[repr(C)]`, so that every
+///    process interprets the memory in the same way
+///  * every field of the type must be [`ZeroCopySend`]
+pub unsafe trait ZeroCopySend {
+    /// Hash of the memory layout of the type. Two types with the same hash are assumed to have
+    /// the same memory representation, independent of their names.
+    const LAYOUT_HASH: u64;
+}
+
+/// Returns the start value of a [`ZeroCopySend::LAYOUT_HASH`] that is derived from `name`.
+pub const fn layout_hash_of(name: &[u8]) -> u64 {
+    let mut hash = FNV_OFFSET_BASIS;
+    let mut i = 0;
+    while i < name.len() {
+        hash ^= name[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Adds `value` to the [`ZeroCopySend::LAYOUT_HASH`] `hash` and returns the new hash.
+pub const fn combine_layout_hash(hash: u64, value: u64) -> u64 {
+    let bytes = value.to_le_bytes();
+    let mut hash = hash;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+macro_rules! impl_zero_copy_send {
+    ($($t:ty),*) => {
+        $(unsafe impl ZeroCopySend for $t {
+            const LAYOUT_HASH: u64 = layout_hash_of(stringify!($t).as_bytes());
+        })*
+    };
+}
+
+impl_zero_copy_send! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, bool, char, ()
+}
+
+unsafe impl<T: ZeroCopySend, const N: usize> ZeroCopySend for [T; N] {
+    const LAYOUT_HASH: u64 = combine_layout_hash(
+        combine_layout_hash(layout_hash_of(b"array"), T::LAYOUT_HASH),
+        N as u64,
+    );
+}
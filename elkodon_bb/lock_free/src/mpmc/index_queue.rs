@@ -0,0 +1,424 @@
+//! A **threadsafe** **lock-free** bounded multi producer multi consumer FIFO queue which can
+//! store [`usize`] integers or indices. Every element is assigned a sequence number so that
+//! arbitrary many producers and consumers can operate concurrently on the queue.
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_bb_lock_free::mpmc::index_queue::*;
+//!
+//! const QUEUE_CAPACITY: usize = 128;
+//! let queue = FixedSizeIndexQueue::<QUEUE_CAPACITY>::new();
+//!
+//! if !queue.push(1234) {
+//!     println!("queue is full");
+//! }
+//!
+//! match queue.pop() {
+//!     None => println!("queue is empty"),
+//!     Some(v) => println!("got {}", v)
+//! }
+//! ```
+
+use std::{
+    alloc::Layout,
+    fmt::Debug,
+    sync::atomic::{fence, AtomicBool, AtomicUsize, Ordering},
+};
+
+use elkodon_bb_elementary::{
+    math::align_to, owning_pointer::OwningPointer, pointer_trait::PointerTrait,
+    relocatable_container::RelocatableContainer, relocatable_ptr::RelocatablePointer,
+};
+use elkodon_bb_log::{fail, fatal_panic};
+
+pub type IndexQueue = details::IndexQueue<OwningPointer<details::Element>>;
+pub type RelocatableIndexQueue = details::IndexQueue<RelocatablePointer<details::Element>>;
+
+pub mod details {
+    use std::fmt::Debug;
+
+    use super::*;
+
+    /// A single element of the [`IndexQueue`]. The sequence number defines if the element can be
+    /// written by the next producer or read by the next consumer.
+    #[derive(Debug)]
+    pub struct Element {
+        sequence: AtomicUsize,
+        value: AtomicUsize,
+    }
+
+    impl Element {
+        pub(super) fn new(sequence: usize) -> Self {
+            Self {
+                sequence: AtomicUsize::new(sequence),
+                value: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    /// A threadsafe lock-free index queue with a capacity which can be set up at runtime, when the
+    /// queue is created.
+    #[repr(C)]
+    #[derive(Debug)]
+    pub struct IndexQueue<PointerType: PointerTrait<Element>> {
+        data_ptr: PointerType,
+        capacity: usize,
+        write_position: AtomicUsize,
+        read_position: AtomicUsize,
+        is_memory_initialized: AtomicBool,
+    }
+
+    unsafe impl<PointerType: PointerTrait<Element>> Sync for IndexQueue<PointerType> {}
+    unsafe impl<PointerType: PointerTrait<Element>> Send for IndexQueue<PointerType> {}
+
+    impl IndexQueue<OwningPointer<Element>> {
+        pub fn new(capacity: usize) -> Self {
+            let mut data_ptr = OwningPointer::<Element>::new_with_alloc(capacity);
+
+            for i in 0..capacity {
+                unsafe { data_ptr.as_mut_ptr().add(i).write(Element::new(i)) };
+            }
+
+            Self {
+                data_ptr,
+                capacity,
+                write_position: AtomicUsize::new(0),
+                read_position: AtomicUsize::new(0),
+                is_memory_initialized: AtomicBool::new(true),
+            }
+        }
+    }
+
+    impl RelocatableContainer for IndexQueue<RelocatablePointer<Element>> {
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                capacity,
+                write_position: AtomicUsize::new(0),
+                read_position: AtomicUsize::new(0),
+                is_memory_initialized: AtomicBool::new(false),
+            }
+        }
+
+        unsafe fn init<T: elkodon_bb_elementary::allocator::BaseAllocator>(
+            &self,
+            allocator: &T,
+        ) -> Result<(), elkodon_bb_elementary::allocator::AllocationError> {
+            if self.is_memory_initialized.load(Ordering::Relaxed) {
+                fatal_panic!(from self, "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+            }
+
+            self.data_ptr.init(fail!(from self, when allocator
+            .allocate(Layout::from_size_align_unchecked(
+                    std::mem::size_of::<Element>() * self.capacity,
+                    std::mem::align_of::<Element>())),
+            "Failed to initialize since the allocation of the data memory failed."));
+
+            for i in 0..self.capacity {
+                (self.data_ptr.as_ptr() as *mut Element)
+                    .add(i)
+                    .write(Element::new(i));
+            }
+
+            self.is_memory_initialized.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                capacity,
+                write_position: AtomicUsize::new(0),
+                read_position: AtomicUsize::new(0),
+                is_memory_initialized: AtomicBool::new(true),
+            }
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<PointerType: PointerTrait<Element> + Debug> IndexQueue<PointerType> {
+        fn verify_init(&self, source: &str) {
+            if !self.is_memory_initialized.load(Ordering::Relaxed) {
+                fatal_panic!(from self, "Undefined behavior when calling \"{}\" and the object is not initialized.", source);
+            }
+        }
+
+        /// Returns the amount of memory required to create a [`IndexQueue`] with the provided
+        /// capacity.
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            std::mem::size_of::<Element>() * capacity + std::mem::align_of::<Element>() - 1
+        }
+
+        unsafe fn at(&self, position: usize) -> &Element {
+            &*self.data_ptr.as_ptr().add(position % self.capacity)
+        }
+
+        /// Pushes a value into the queue. If the queue is full it returns false, otherwise true.
+        /// Can be called concurrently from arbitrary many threads.
+        pub fn push(&self, value: usize) -> bool {
+            self.verify_init("push");
+            let mut write_position = self.write_position.load(Ordering::Relaxed);
+
+            loop {
+                let element = unsafe { self.at(write_position) };
+                ////////////////
+                // SYNC POINT
+                ////////////////
+                let sequence = element.sequence.load(Ordering::Acquire);
+
+                if sequence == write_position {
+                    match self.write_position.compare_exchange_weak(
+                        write_position,
+                        write_position + 1,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => {
+                            // release so that a consumer of [`IndexQueue::pop_with()`] that
+                            // reads the overwritten value observes the new sequence number
+                            element.value.store(value, Ordering::Release);
+                            ////////////////
+                            // SYNC POINT
+                            ////////////////
+                            element
+                                .sequence
+                                .store(write_position + 1, Ordering::Release);
+                            return true;
+                        }
+                        Err(v) => write_position = v,
+                    }
+                } else if sequence < write_position {
+                    return false;
+                } else {
+                    write_position = self.write_position.load(Ordering::Relaxed);
+                }
+            }
+        }
+
+        /// Acquires a value from the queue. If the queue is empty it returns [`None`] otherwise
+        /// the value. Can be called concurrently from arbitrary many threads.
+        pub fn pop(&self) -> Option<usize> {
+            self.verify_init("pop");
+            let mut read_position = self.read_position.load(Ordering::Relaxed);
+
+            loop {
+                let element = unsafe { self.at(read_position) };
+                ////////////////
+                // SYNC POINT
+                ////////////////
+                let sequence = element.sequence.load(Ordering::Acquire);
+
+                if sequence == read_position + 1 {
+                    match self.read_position.compare_exchange_weak(
+                        read_position,
+                        read_position + 1,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    ) {
+                        Ok(_) => {
+                            let value = element.value.load(Ordering::Relaxed);
+                            ////////////////
+                            // SYNC POINT
+                            ////////////////
+                            element
+                                .sequence
+                                .store(read_position + self.capacity, Ordering::Release);
+                            return Some(value);
+                        }
+                        Err(v) => read_position = v,
+                    }
+                } else if sequence < read_position + 1 {
+                    return None;
+                } else {
+                    read_position = self.read_position.load(Ordering::Relaxed);
+                }
+            }
+        }
+
+        /// Acquires the oldest value for which `acquire` returns true. Values for which
+        /// `acquire` returns false are removed from the queue without being returned. If the
+        /// queue is empty it returns [`None`]. Can be called concurrently from arbitrary many
+        /// threads.
+        ///
+        /// `acquire` is called before the value is removed from the queue. A consumer that is
+        /// interrupted after it has acquired a value, for instance since its process crashed,
+        /// leaves the value in the queue and the next consumer removes it when its `acquire`
+        /// returns false. Therefore, `acquire` must return true at most once for every value
+        /// that was pushed into the queue.
+        pub fn pop_with<F: FnMut(usize) -> bool>(&self, mut acquire: F) -> Option<usize> {
+            self.verify_init("pop_with");
+            let mut read_position = self.read_position.load(Ordering::Relaxed);
+
+            loop {
+                let element = unsafe { self.at(read_position) };
+                ////////////////
+                // SYNC POINT
+                ////////////////
+                let sequence = element.sequence.load(Ordering::Acquire);
+
+                if sequence == read_position + 1 {
+                    let value = element.value.load(Ordering::Relaxed);
+                    ////////////////
+                    // SYNC POINT
+                    ////////////////
+                    fence(Ordering::Acquire);
+                    // the element could have been removed and overwritten in the meantime
+                    if element.sequence.load(Ordering::Relaxed) != read_position + 1 {
+                        read_position = self.read_position.load(Ordering::Relaxed);
+                        continue;
+                    }
+
+                    let is_acquired = acquire(value);
+
+                    // the element is released before the read position is advanced, a consumer
+                    // that is interrupted in between leaves a released element behind and the
+                    // next consumer advances the read position for it
+                    ////////////////
+                    // SYNC POINT
+                    ////////////////
+                    let _ = element.sequence.compare_exchange(
+                        read_position + 1,
+                        read_position + self.capacity,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                    );
+                    let _ = self.read_position.compare_exchange(
+                        read_position,
+                        read_position + 1,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    );
+
+                    if is_acquired {
+                        return Some(value);
+                    }
+                    read_position = self.read_position.load(Ordering::Relaxed);
+                } else if sequence < read_position + 1 {
+                    return None;
+                } else {
+                    let _ = self.read_position.compare_exchange(
+                        read_position,
+                        read_position + 1,
+                        Ordering::Relaxed,
+                        Ordering::Relaxed,
+                    );
+                    read_position = self.read_position.load(Ordering::Relaxed);
+                }
+            }
+        }
+
+        fn acquire_read_and_write_position(&self) -> (usize, usize) {
+            loop {
+                let write_position = self.write_position.load(Ordering::Relaxed);
+                let read_position = self.read_position.load(Ordering::Relaxed);
+
+                if write_position == self.write_position.load(Ordering::Relaxed)
+                    && read_position == self.read_position.load(Ordering::Relaxed)
+                {
+                    return (write_position, read_position);
+                }
+            }
+        }
+
+        /// Returns true when the [`IndexQueue`] is empty, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_empty(&self) -> bool {
+            self.len() == 0
+        }
+
+        /// Returns the length of the [`IndexQueue`].
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn len(&self) -> usize {
+            let (write_position, read_position) = self.acquire_read_and_write_position();
+            write_position
+                .saturating_sub(read_position)
+                .min(self.capacity)
+        }
+
+        /// Returns the capacity of the [`IndexQueue`].
+        pub const fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns true when the [`IndexQueue`] is full, otherwise false.
+        /// Note: This method may make only sense in a non-concurrent setup since the information
+        ///       could be out-of-date as soon as it is acquired.
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity
+        }
+    }
+}
+
+/// The compile-time fixed size version of the [`IndexQueue`].
+#[derive(Debug)]
+#[repr(C)]
+pub struct FixedSizeIndexQueue<const CAPACITY: usize> {
+    state: RelocatableIndexQueue,
+    data: [details::Element; CAPACITY],
+}
+
+unsafe impl<const CAPACITY: usize> Sync for FixedSizeIndexQueue<CAPACITY> {}
+unsafe impl<const CAPACITY: usize> Send for FixedSizeIndexQueue<CAPACITY> {}
+
+impl<const CAPACITY: usize> Default for FixedSizeIndexQueue<CAPACITY> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const CAPACITY: usize> FixedSizeIndexQueue<CAPACITY> {
+    /// Creates a new empty [`FixedSizeIndexQueue`].
+    pub fn new() -> Self {
+        Self {
+            state: unsafe {
+                RelocatableIndexQueue::new(
+                    CAPACITY,
+                    align_to::<details::Element>(std::mem::size_of::<RelocatableIndexQueue>())
+                        as isize,
+                )
+            },
+            data: core::array::from_fn(details::Element::new),
+        }
+    }
+
+    /// See [`IndexQueue::push()`]
+    pub fn push(&self, value: usize) -> bool {
+        self.state.push(value)
+    }
+
+    /// See [`IndexQueue::pop()`]
+    pub fn pop(&self) -> Option<usize> {
+        self.state.pop()
+    }
+
+    /// See [`IndexQueue::pop_with()`]
+    pub fn pop_with<F: FnMut(usize) -> bool>(&self, acquire: F) -> Option<usize> {
+        self.state.pop_with(acquire)
+    }
+
+    /// See [`IndexQueue::is_empty()`]
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// See [`IndexQueue::len()`]
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// See [`IndexQueue::capacity()`]
+    pub const fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// See [`IndexQueue::is_full()`]
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+}
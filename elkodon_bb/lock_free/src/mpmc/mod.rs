@@ -1,4 +1,5 @@
 //! Multi producer multi consumer constructs
 
 pub mod container;
+pub mod index_queue;
 pub mod unique_index_set;
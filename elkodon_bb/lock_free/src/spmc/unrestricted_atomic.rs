@@ -100,6 +100,18 @@ impl<T: Copy> UnrestrictedAtomic<T> {
         }
     }
 
+    /// Releases the acquired [`Producer`] without dropping it so that a new one can be acquired.
+    /// Required when the owner of the [`Producer`] is unable to release it, for instance since
+    /// the owning process died while the atomic is stored in shared memory.
+    ///
+    /// # Safety
+    ///
+    ///  * the previously acquired [`Producer`] must not be used anymore
+    ///
+    pub unsafe fn release_producer(&self) {
+        self.has_producer.store(true, Ordering::Relaxed);
+    }
+
     fn store(&self, new_value: T) {
         let write_cell = self.write_cell.load(Ordering::Relaxed);
         unsafe {
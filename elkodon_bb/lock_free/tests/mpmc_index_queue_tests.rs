@@ -0,0 +1,255 @@
+use elkodon_bb_elementary::relocatable_container::RelocatableContainer;
+use elkodon_bb_lock_free::mpmc::index_queue::*;
+use elkodon_bb_memory::bump_allocator::BumpAllocator;
+use elkodon_bb_posix::barrier::{BarrierBuilder, BarrierHandle};
+use elkodon_bb_testing::assert_that;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+#[test]
+fn mpmc_index_queue_push_works_until_full() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len i);
+        assert_that!(sut.push(i), eq true);
+    }
+    assert_that!(sut.push(1234), eq false);
+
+    assert_that!(sut.capacity(), eq CAPACITY);
+    assert_that!(sut, len CAPACITY);
+    assert_that!(sut.is_full(), eq true);
+    assert_that!(sut, is_not_empty);
+}
+
+#[test]
+fn mpmc_index_queue_pop_works_until_empty() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i), eq true);
+    }
+
+    for i in 0..CAPACITY {
+        assert_that!(sut, len CAPACITY - i);
+        let result = sut.pop();
+        assert_that!(result, is_some);
+        assert_that!(result.unwrap(), eq i);
+    }
+    assert_that!(sut.pop(), is_none);
+
+    assert_that!(sut, len 0);
+    assert_that!(sut.is_full(), eq false);
+    assert_that!(sut, is_empty);
+}
+
+#[test]
+fn mpmc_index_queue_push_pop_alteration_works() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+
+    for i in 0..CAPACITY - 1 {
+        assert_that!(sut.push(i), eq true);
+        assert_that!(sut.push(i), eq true);
+
+        assert_that!(sut.pop(), eq Some(i / 2))
+    }
+}
+
+#[test]
+fn mpmc_index_queue_works_with_relocatable_memory() {
+    const CAPACITY: usize = 12;
+    const MEMORY_SIZE: usize = RelocatableIndexQueue::const_memory_size(CAPACITY);
+    let mut memory = [0u8; MEMORY_SIZE];
+    let allocator = BumpAllocator::new(NonNull::new(memory.as_mut_ptr()).unwrap(), MEMORY_SIZE);
+
+    let sut = unsafe { RelocatableIndexQueue::new_uninit(CAPACITY) };
+    assert_that!(unsafe { sut.init(&allocator) }, is_ok);
+
+    for n in 0..3 {
+        for i in 0..CAPACITY {
+            assert_that!(sut.push(i + n), eq true);
+        }
+        assert_that!(sut.push(0), eq false);
+
+        for i in 0..CAPACITY {
+            assert_that!(sut.pop(), eq Some(i + n));
+        }
+        assert_that!(sut.pop(), is_none);
+    }
+}
+
+#[test]
+fn mpmc_index_queue_push_pop_works_concurrently() {
+    const LIMIT: usize = 100000;
+    const CAPACITY: usize = 1024;
+    const NUMBER_OF_PRODUCERS: usize = 4;
+    const NUMBER_OF_CONSUMERS: usize = 4;
+
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    let consumed = AtomicUsize::new(0);
+    let storage = Mutex::new(vec![0usize; LIMIT * NUMBER_OF_PRODUCERS]);
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new((NUMBER_OF_PRODUCERS + NUMBER_OF_CONSUMERS) as u32)
+        .is_interprocess_capable(false)
+        .create(&handle)
+        .unwrap();
+
+    thread::scope(|s| {
+        for p in 0..NUMBER_OF_PRODUCERS {
+            let sut = &sut;
+            let barrier = &barrier;
+            s.spawn(move || {
+                barrier.wait();
+                let mut counter = 0;
+                while counter < LIMIT {
+                    if sut.push(p * LIMIT + counter) {
+                        counter += 1;
+                    }
+                }
+            });
+        }
+
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            s.spawn(|| {
+                barrier.wait();
+                let mut received = vec![];
+                while consumed.load(Ordering::Relaxed) < LIMIT * NUMBER_OF_PRODUCERS {
+                    if let Some(v) = sut.pop() {
+                        consumed.fetch_add(1, Ordering::Relaxed);
+                        received.push(v);
+                    }
+                }
+
+                let mut guard = storage.lock().unwrap();
+                for v in received {
+                    guard[v] += 1;
+                }
+            });
+        }
+    });
+
+    let guard = storage.lock().unwrap();
+    for v in guard.iter() {
+        assert_that!(*v, eq 1);
+    }
+}
+
+#[test]
+fn mpmc_index_queue_pop_with_removes_values_which_are_not_acquired() {
+    const CAPACITY: usize = 128;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i), eq true);
+    }
+
+    for i in 0..CAPACITY / 2 {
+        assert_that!(sut.pop_with(|v| v % 2 == 1), eq Some(2 * i + 1));
+    }
+    assert_that!(sut.pop_with(|_| true), is_none);
+    assert_that!(sut, is_empty);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i), eq true);
+    }
+    assert_that!(sut, len CAPACITY);
+}
+
+#[test]
+fn mpmc_index_queue_pop_with_removes_value_of_interrupted_consumer() {
+    const CAPACITY: usize = 4;
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    let acquired = AtomicUsize::new(usize::MAX);
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i), eq true);
+    }
+
+    // the consumer is interrupted after it has acquired the value and before it was removed
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        sut.pop_with(|v| {
+            acquired.store(v, Ordering::Relaxed);
+            panic!("interrupted");
+        })
+    }));
+    assert_that!(result, is_err);
+    assert_that!(acquired.load(Ordering::Relaxed), eq 0);
+
+    let acquire = |v| v != acquired.load(Ordering::Relaxed);
+    for i in 1..CAPACITY {
+        assert_that!(sut.pop_with(acquire), eq Some(i));
+    }
+    assert_that!(sut.pop_with(acquire), is_none);
+
+    for i in 0..CAPACITY {
+        assert_that!(sut.push(i), eq true);
+    }
+    assert_that!(sut.push(0), eq false);
+}
+
+#[test]
+fn mpmc_index_queue_push_pop_with_works_concurrently() {
+    const LIMIT: usize = 100000;
+    const CAPACITY: usize = 1024;
+    const NUMBER_OF_PRODUCERS: usize = 4;
+    const NUMBER_OF_CONSUMERS: usize = 4;
+
+    let sut = FixedSizeIndexQueue::<CAPACITY>::new();
+    let consumed = AtomicUsize::new(0);
+    let is_acquired: Vec<AtomicBool> = (0..LIMIT * NUMBER_OF_PRODUCERS)
+        .map(|_| AtomicBool::new(false))
+        .collect();
+    let storage = Mutex::new(vec![0usize; LIMIT * NUMBER_OF_PRODUCERS]);
+    let handle = BarrierHandle::new();
+    let barrier = BarrierBuilder::new((NUMBER_OF_PRODUCERS + NUMBER_OF_CONSUMERS) as u32)
+        .is_interprocess_capable(false)
+        .create(&handle)
+        .unwrap();
+
+    thread::scope(|s| {
+        for p in 0..NUMBER_OF_PRODUCERS {
+            let sut = &sut;
+            let barrier = &barrier;
+            s.spawn(move || {
+                barrier.wait();
+                let mut counter = 0;
+                while counter < LIMIT {
+                    if sut.push(p * LIMIT + counter) {
+                        counter += 1;
+                    }
+                }
+            });
+        }
+
+        for _ in 0..NUMBER_OF_CONSUMERS {
+            s.spawn(|| {
+                barrier.wait();
+                let mut received = vec![];
+                while consumed.load(Ordering::Relaxed) < LIMIT * NUMBER_OF_PRODUCERS {
+                    if let Some(v) = sut.pop_with(|v| !is_acquired[v].swap(true, Ordering::Relaxed))
+                    {
+                        consumed.fetch_add(1, Ordering::Relaxed);
+                        received.push(v);
+                    }
+                }
+
+                let mut guard = storage.lock().unwrap();
+                for v in received {
+                    guard[v] += 1;
+                }
+            });
+        }
+    });
+
+    let guard = storage.lock().unwrap();
+    for v in guard.iter() {
+        assert_that!(*v, eq 1);
+    }
+}
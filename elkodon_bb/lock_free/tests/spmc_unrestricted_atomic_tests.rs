@@ -55,6 +55,24 @@ fn spmc_unrestricted_atomic_acquire_multiple_producer_fails() {
     assert_that!(p3, is_some);
 }
 
+#[test]
+fn spmc_unrestricted_atomic_release_producer_allows_to_acquire_new_producer() {
+    let _test_lock = TEST_LOCK.lock().unwrap();
+    let sut = UnrestrictedAtomic::<[u8; DATA_SIZE]>::new([0xff; DATA_SIZE]);
+
+    let p1 = sut.acquire_producer();
+    assert_that!(p1, is_some);
+    std::mem::forget(p1);
+    assert_that!(sut.acquire_producer(), is_none);
+
+    unsafe { sut.release_producer() };
+
+    let p2 = sut.acquire_producer();
+    assert_that!(p2, is_some);
+    p2.unwrap().store([0xaa; DATA_SIZE]);
+    assert_that!(verify(0xaa, &sut.load()), eq true);
+}
+
 #[test]
 fn spmc_unrestricted_atomic_load_store_works() {
     let _test_lock = TEST_LOCK.lock().unwrap();
@@ -125,6 +125,20 @@
 //!     println!("{:?} {} {}", entry.log_level, entry.origin, entry.message);
 //! }
 //! ```
+//!
+//! ## Default logger
+//!
+//! The default logger is chosen by feature flags:
+//!
+//!  * `tracing` - the `logger::tracing::Logger` forwards every log message to the `tracing`
+//!    subscriber of the application
+//!  * `log` - the `logger::log::Logger` forwards every log message to the `log` facade, unless
+//!    the `tracing` feature is enabled as well
+//!  * otherwise the [`crate::logger::console::Logger`] is used
+//!
+//! The loggers that forward to a facade leave the filtering to the facade, e.g. to
+//! `log::max_level()` or to the subscriber of `tracing`, until a log level is set with
+//! [`set_log_level()`].
 
 #[macro_use]
 pub mod log;
@@ -135,16 +149,22 @@ pub mod logger;
 use std::{
     fmt::Arguments,
     sync::{
-        atomic::{AtomicU8, Ordering},
+        atomic::{AtomicBool, AtomicU8, Ordering},
         Once,
     },
 };
 
 use logger::Logger;
 
+#[cfg(not(any(feature = "log", feature = "tracing")))]
 static DEFAULT_LOGGER: logger::console::Logger = logger::console::Logger::new();
+#[cfg(all(feature = "log", not(feature = "tracing")))]
+static DEFAULT_LOGGER: logger::log::Logger = logger::log::Logger::new();
+#[cfg(feature = "tracing")]
+static DEFAULT_LOGGER: logger::tracing::Logger = logger::tracing::Logger::new();
 static mut LOGGER: Option<&'static dyn logger::Logger> = None;
 static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Info as u8);
+static IS_LOG_LEVEL_SET: AtomicBool = AtomicBool::new(false);
 static INIT: Once = Once::new();
 
 /// Describes the log level.
@@ -162,6 +182,7 @@ pub enum LogLevel {
 /// Sets the current log level
 pub fn set_log_level(v: LogLevel) {
     LOG_LEVEL.store(v as u8, Ordering::Relaxed);
+    IS_LOG_LEVEL_SET.store(true, Ordering::Relaxed);
 }
 
 /// Returns the current log level
@@ -169,6 +190,12 @@ pub fn get_log_level() -> u8 {
     LOG_LEVEL.load(Ordering::Relaxed)
 }
 
+// the loggers that forward to a facade only filter by the log level when it was set explicitly
+#[cfg(any(feature = "log", feature = "tracing"))]
+pub(crate) fn is_filtered_by_log_level(log_level: LogLevel) -> bool {
+    IS_LOG_LEVEL_SET.load(Ordering::Relaxed) && get_log_level() > log_level as u8
+}
+
 /// Sets the [`Logger`]. Can be only called once at the beginning of the program. If the
 /// [`Logger`] is already set it returns false and does not update it.
 pub fn set_logger<T: logger::Logger + 'static>(value: &'static T) -> bool {
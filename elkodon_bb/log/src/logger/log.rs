@@ -0,0 +1,79 @@
+//! Forwards every log message to the [`log`] facade, so that it ends up in the logging pipeline
+//! of the application. The origin of the message becomes the target of the [`log::Record`],
+//! without the quotes of string origins. Messages without origin have the target `elkodon`.
+//!
+//! The [`LogLevel`] is mapped to the [`log::Level`] with the same name,
+//! [`LogLevel::Fatal`] is mapped to [`log::Level::Error`].
+//!
+//! The messages are filtered by [`log::max_level()`] and by the logger of the application.
+//! The log level of elkodon is only applied when it was set with [`crate::set_log_level()`].
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_bb_log::{set_logger, info};
+//!
+//! static LOGGER: elkodon_bb_log::logger::log::Logger =
+//!     elkodon_bb_log::logger::log::Logger::new();
+//!
+//! set_logger(&LOGGER);
+//! info!("hello world");
+//! ```
+
+use std::fmt::Arguments;
+
+use crate::{is_filtered_by_log_level, LogLevel};
+
+pub struct Logger {
+    _private: (),
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger {
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+
+    fn level(log_level: LogLevel) -> ::log::Level {
+        match log_level {
+            LogLevel::Trace => ::log::Level::Trace,
+            LogLevel::Debug => ::log::Level::Debug,
+            LogLevel::Info => ::log::Level::Info,
+            LogLevel::Warn => ::log::Level::Warn,
+            LogLevel::Error | LogLevel::Fatal => ::log::Level::Error,
+        }
+    }
+}
+
+impl crate::logger::Logger for Logger {
+    fn log(&self, log_level: LogLevel, origin: Arguments, formatted_message: Arguments) {
+        let level = Self::level(log_level);
+        if is_filtered_by_log_level(log_level) || level > ::log::max_level() {
+            return;
+        }
+
+        // origins are debug formatted, string origins like "Publisher::new()" are quoted
+        let origin = origin.to_string();
+        let target = match origin.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            Some(v) => v,
+            None => &origin,
+        };
+        let target = match target.is_empty() {
+            true => "elkodon",
+            false => target,
+        };
+
+        ::log::logger().log(
+            &::log::Record::builder()
+                .args(formatted_message)
+                .level(level)
+                .target(target)
+                .build(),
+        );
+    }
+}
@@ -3,6 +3,10 @@
 
 pub mod buffer;
 pub mod console;
+#[cfg(feature = "log")]
+pub mod log;
+#[cfg(feature = "tracing")]
+pub mod tracing;
 
 use std::fmt::Arguments;
 
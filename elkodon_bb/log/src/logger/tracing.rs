@@ -0,0 +1,67 @@
+//! Forwards every log message as event to the [`tracing`] subscriber of the application. The
+//! events have the target `elkodon` and carry the origin as `origin` field. A message that is
+//! logged inside of a span, e.g. the spans of the core operations of elkodon, is attached to it.
+//!
+//! The [`LogLevel`] is mapped to the [`tracing::Level`] with the same name,
+//! [`LogLevel::Fatal`] is mapped to [`tracing::Level::ERROR`].
+//!
+//! The events are filtered by the subscriber of the application. The log level of elkodon is
+//! only applied when it was set with [`crate::set_log_level()`].
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_bb_log::{set_logger, info};
+//!
+//! static LOGGER: elkodon_bb_log::logger::tracing::Logger =
+//!     elkodon_bb_log::logger::tracing::Logger::new();
+//!
+//! set_logger(&LOGGER);
+//! info!("hello world");
+//! ```
+
+use std::fmt::Arguments;
+
+use crate::{is_filtered_by_log_level, LogLevel};
+
+pub struct Logger {
+    _private: (),
+}
+
+impl Default for Logger {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Logger {
+    pub const fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl crate::logger::Logger for Logger {
+    fn log(&self, log_level: LogLevel, origin: Arguments, formatted_message: Arguments) {
+        if is_filtered_by_log_level(log_level) {
+            return;
+        }
+
+        match log_level {
+            LogLevel::Trace => {
+                ::tracing::trace!(target: "elkodon", origin = %origin, "{}", formatted_message)
+            }
+            LogLevel::Debug => {
+                ::tracing::debug!(target: "elkodon", origin = %origin, "{}", formatted_message)
+            }
+            LogLevel::Info => {
+                ::tracing::info!(target: "elkodon", origin = %origin, "{}", formatted_message)
+            }
+            LogLevel::Warn => {
+                ::tracing::warn!(target: "elkodon", origin = %origin, "{}", formatted_message)
+            }
+            LogLevel::Error | LogLevel::Fatal => {
+                ::tracing::error!(target: "elkodon", origin = %origin, "{}", formatted_message)
+            }
+        }
+    }
+}
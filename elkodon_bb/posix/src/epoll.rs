@@ -0,0 +1,285 @@
+//! Abstracts the Linux epoll facility. Like the [`crate::file_descriptor_set::FileDescriptorSet`]
+//! it can be used to wait on multiple objects which implement the [`SynchronousMultiplexing`]
+//! trait but it is not limited by `FD_SETSIZE` and reports only the triggered file descriptors,
+//! so that a wait costs O(number of triggered file descriptors) instead of O(number of attached
+//! file descriptors).
+//!
+//! # Example
+//!
+//! ```ignore
+//! use elkodon_bb_posix::epoll::*;
+//! use elkodon_bb_posix::file_descriptor_set::FileEvent;
+//! use elkodon_bb_posix::unix_datagram_socket::*;
+//! use std::time::Duration;
+//! use elkodon_bb_system_types::file_path::FilePath;
+//! use elkodon_bb_container::semantic_string::SemanticString;
+//!
+//! let socket_name = FilePath::new(b"some_socket").unwrap();
+//!
+//! let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+//!     .creation_mode(CreationMode::PurgeAndCreate)
+//!     .create()
+//!     .unwrap();
+//!
+//! let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+//!     .create()
+//!     .unwrap();
+//!
+//! let epoll = Epoll::new().unwrap();
+//! let _guard = epoll.add(&sut_receiver, FileEvent::Read).unwrap();
+//! let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+//! sut_sender.try_send(send_data.as_slice()).unwrap();
+//!
+//! // in some other process
+//! epoll.timed_wait(Duration::from_secs(1),
+//!     |fd| println!("Fd was triggered {}", unsafe { fd.native_handle() })).unwrap();
+//! ```
+
+use std::{cell::Cell, fmt::Debug, time::Duration};
+
+use crate::{
+    file_descriptor::FileDescriptor,
+    file_descriptor_set::{FileEvent, SynchronousMultiplexing},
+    system_configuration::ProcessResourceLimit,
+};
+use elkodon_bb_log::warn;
+use elkodon_pal_posix::posix::errno::Errno;
+use elkodon_pal_posix::posix::Struct;
+use elkodon_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollCreateError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollAddError {
+    AlreadyAttached,
+    InsufficientMemory,
+    CapacityExceeded,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+pub struct EpollGuard<'epoll, 'fd> {
+    epoll: &'epoll Epoll,
+    fd: &'fd FileDescriptor,
+}
+
+impl<'epoll, 'fd> EpollGuard<'epoll, 'fd> {
+    pub fn file_descriptor(&self) -> &'fd FileDescriptor {
+        self.fd
+    }
+}
+
+impl Drop for EpollGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.epoll.remove(unsafe { self.fd.native_handle() })
+    }
+}
+
+/// The Linux epoll abstraction to wait on multiple objects which implement the
+/// [`SynchronousMultiplexing`] trait.
+pub struct Epoll {
+    epoll_fd: FileDescriptor,
+    len: Cell<usize>,
+    // the buffer is taken out during a wait so that a callback can wait on the same epoll again
+    events: Cell<Vec<posix::epoll_event>>,
+}
+
+impl Debug for Epoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Epoll {{ epoll_fd: {}, len: {} }}",
+            unsafe { self.epoll_fd.native_handle() },
+            self.len.get()
+        )
+    }
+}
+
+impl Epoll {
+    /// Creates a new empty [`Epoll`].
+    pub fn new() -> Result<Epoll, EpollCreateError> {
+        let msg = "Unable to create epoll";
+        let raw_fd = unsafe { posix::epoll_create1(posix::EPOLL_CLOEXEC) };
+
+        if raw_fd == -1 {
+            handle_errno!(EpollCreateError, from "Epoll::new",
+                Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of file descriptors was reached.", msg),
+                Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system-wide limit of file descriptors was reached.", msg),
+                Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        Ok(Epoll {
+            epoll_fd: unsafe { FileDescriptor::new_unchecked(raw_fd) },
+            len: Cell::new(0),
+            events: Cell::new(vec![]),
+        })
+    }
+
+    /// Adds an object and waits on the provided [`FileEvent`] of it as long as the returned
+    /// [`EpollGuard`] lives.
+    pub fn add<'epoll, 'fd, F: SynchronousMultiplexing>(
+        &'epoll self,
+        fd: &'fd F,
+        event: FileEvent,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        self.add_impl(fd.file_descriptor(), event)
+    }
+
+    fn add_impl<'epoll, 'fd>(
+        &'epoll self,
+        fd: &'fd FileDescriptor,
+        event: FileEvent,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        let msg = "Unable to add file descriptor";
+        let raw_fd = unsafe { fd.native_handle() };
+
+        let mut epoll_event = posix::epoll_event::new();
+        epoll_event.events = match event {
+            FileEvent::Read => posix::EPOLLIN,
+            FileEvent::Write => posix::EPOLLOUT,
+            FileEvent::Exceptional => posix::EPOLLPRI,
+            FileEvent::ReadWrite => posix::EPOLLIN | posix::EPOLLOUT,
+            FileEvent::ReadExceptional => posix::EPOLLIN | posix::EPOLLPRI,
+            FileEvent::WriteExceptional => posix::EPOLLOUT | posix::EPOLLPRI,
+            FileEvent::ReadWriteExceptional => posix::EPOLLIN | posix::EPOLLOUT | posix::EPOLLPRI,
+        };
+        epoll_event.data.u64 = raw_fd as u64;
+
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_ADD,
+                raw_fd,
+                &mut epoll_event,
+            )
+        } == -1
+        {
+            handle_errno!(EpollAddError, from self,
+                Errno::EEXIST => (AlreadyAttached, "{} {} since it is already attached.", msg, raw_fd),
+                Errno::ENOMEM => (InsufficientMemory, "{} {} due to insufficient memory.", msg, raw_fd),
+                Errno::ENOSPC => (CapacityExceeded, "{} {} since the system limit of epoll attachments was reached.", msg, raw_fd),
+                v => (UnknownError(v as i32), "{} {} since an unknown error occurred ({}).", msg, raw_fd, v)
+            );
+        }
+
+        self.len.set(self.len.get() + 1);
+        Ok(EpollGuard { epoll: self, fd })
+    }
+
+    fn remove(&self, value: i32) {
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_DEL,
+                value,
+                std::ptr::null_mut(),
+            )
+        } == -1
+        {
+            // the kernel removes a file descriptor on its own when it was closed before its
+            // guard went out of scope
+            warn!(from self,
+                "Unable to remove the attached file descriptor {} ({}).", value, Errno::get());
+        }
+
+        self.len.set(self.len.get() - 1);
+    }
+
+    /// Returns the maximum capacity of the [`Epoll`] which is only limited by the number of
+    /// file descriptors a process can open.
+    pub fn capacity() -> usize {
+        ProcessResourceLimit::MaxNumberOfOpenFileDescriptors.soft_limit() as usize
+    }
+
+    /// Returns the number of attached [`FileDescriptor`]s
+    pub fn len(&self) -> usize {
+        self.len.get()
+    }
+
+    /// Returns true if the [`Epoll`] is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len.get() == 0
+    }
+
+    /// Returns immediately and calls the provided callback for every triggered
+    /// [`FileDescriptor`].
+    pub fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        self.wait(0, fd_callback)
+    }
+
+    /// Waits until either the timeout has passed or at least one attached [`FileDescriptor`]
+    /// was triggered and calls the provided callback for every triggered one.
+    pub fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: Duration,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        // rounded up so that the call blocks at least for the provided timeout
+        let timeout_in_ms = (timeout.as_nanos() + 999_999) / 1_000_000;
+        self.wait(
+            timeout_in_ms.min(posix::int::MAX as u128) as posix::int,
+            fd_callback,
+        )
+    }
+
+    /// Waits until at least one attached [`FileDescriptor`] was triggered and calls the provided
+    /// callback for every triggered one.
+    pub fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        self.wait(-1, fd_callback)
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout_in_ms: posix::int,
+        mut fd_callback: F,
+    ) -> Result<(), EpollWaitError> {
+        let mut events = self.events.take();
+        events.resize(self.len().max(1), posix::epoll_event::new());
+
+        let number_of_events = unsafe {
+            posix::epoll_wait(
+                self.epoll_fd.native_handle(),
+                events.as_mut_ptr(),
+                events.len() as posix::int,
+                timeout_in_ms,
+            )
+        };
+
+        if number_of_events == -1 {
+            self.events.set(events);
+            let msg = "Failure while waiting for file descriptor events";
+            handle_errno!(EpollWaitError, from self,
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        for event in &events[..number_of_events as usize] {
+            let raw_fd = unsafe { event.data.u64 } as i32;
+            let fd = FileDescriptor::non_owning_new(raw_fd).unwrap();
+            fd_callback(&fd);
+        }
+
+        self.events.set(events);
+        Ok(())
+    }
+}
@@ -0,0 +1,168 @@
+#![cfg(target_os = "linux")]
+
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_posix::config::*;
+use elkodon_bb_posix::epoll::*;
+use elkodon_bb_posix::file::File;
+use elkodon_bb_posix::file_descriptor::FileDescriptorBased;
+use elkodon_bb_posix::file_descriptor_set::FileEvent;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_posix::unix_datagram_socket::*;
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_bb_system_types::file_path::FilePath;
+use elkodon_bb_testing::assert_that;
+use elkodon_pal_posix::posix;
+use std::time::Duration;
+use std::time::Instant;
+
+static TIMEOUT: Duration = Duration::from_millis(10);
+
+fn generate_socket_name() -> FilePath {
+    let mut file = FileName::new(b"epoll_tests").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+
+    FilePath::from_path_and_file(&TEST_DIRECTORY, &file).unwrap()
+}
+
+fn create_receiver(socket_name: &FilePath) -> UnixDatagramReceiver {
+    UnixDatagramReceiverBuilder::new(socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap()
+}
+
+#[test]
+fn epoll_timed_wait_blocks_at_least_timeout() {
+    let sut_receiver = create_receiver(&generate_socket_name());
+
+    let sut = Epoll::new().unwrap();
+    let _guard = sut.add(&sut_receiver, FileEvent::Read).unwrap();
+
+    let start = Instant::now();
+
+    let mut result = vec![];
+    sut.timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    assert_that!(result, len 0);
+}
+
+#[test]
+fn epoll_add_and_remove_works() {
+    const NUMBER_OF_FDS: usize = 128;
+
+    let sut = Epoll::new().unwrap();
+    let sockets: Vec<_> = (0..NUMBER_OF_FDS)
+        .map(|_| create_receiver(&generate_socket_name()))
+        .collect();
+
+    let mut guards = vec![];
+    assert_that!(sut.is_empty(), eq true);
+    for (i, fd) in sockets.iter().enumerate() {
+        let guard = sut.add(fd, FileEvent::Read);
+        assert_that!(guard, is_ok);
+        guards.push(guard);
+        assert_that!(sut.len(), eq i + 1);
+    }
+
+    for i in 0..NUMBER_OF_FDS {
+        assert_that!(sut.is_empty(), eq false);
+        guards.pop();
+        assert_that!(sut.len(), eq NUMBER_OF_FDS - i - 1);
+    }
+    assert_that!(sut.is_empty(), eq true);
+}
+
+#[test]
+fn epoll_guard_of_closed_file_descriptor_can_be_dropped() {
+    let socket_name = generate_socket_name();
+    let sut_receiver = create_receiver(&socket_name);
+
+    let sut = Epoll::new().unwrap();
+    let guard = sut.add(&sut_receiver, FileEvent::Read).unwrap();
+    assert_that!(sut.len(), eq 1);
+
+    // the kernel removes the closed file descriptor, the removal in the guard must fail
+    // gracefully
+    assert_that!(unsafe { posix::close(sut_receiver.file_descriptor().native_handle()) }, eq 0);
+    drop(guard);
+    assert_that!(sut.is_empty(), eq true);
+
+    // the file descriptor of the receiver is already closed
+    std::mem::forget(sut_receiver);
+    assert_that!(File::remove(&socket_name), is_ok);
+}
+
+#[test]
+fn epoll_adding_the_same_file_descriptor_twice_fails() {
+    let sut_receiver = create_receiver(&generate_socket_name());
+
+    let sut = Epoll::new().unwrap();
+    let _guard = sut.add(&sut_receiver, FileEvent::Read).unwrap();
+
+    let result = sut.add(&sut_receiver, FileEvent::Read);
+    assert_that!(result, is_err);
+    assert_that!(result.err().unwrap(), eq EpollAddError::AlreadyAttached);
+    assert_that!(sut.len(), eq 1);
+}
+
+#[test]
+fn epoll_timed_wait_works() {
+    let socket_name = generate_socket_name();
+    let sut_receiver = create_receiver(&socket_name);
+    let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    let sut = Epoll::new().unwrap();
+    let _guard = sut.add(&sut_receiver, FileEvent::Read).unwrap();
+    let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+    sut_sender.blocking_send(send_data.as_slice()).unwrap();
+
+    let mut result = vec![];
+    sut.timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe{sut_receiver.file_descriptor().native_handle()});
+}
+
+#[test]
+fn epoll_reports_only_triggered_file_descriptors() {
+    const NUMBER_OF_FDS: usize = 16;
+
+    let socket_names: Vec<_> = (0..NUMBER_OF_FDS).map(|_| generate_socket_name()).collect();
+    let receivers: Vec<_> = socket_names.iter().map(create_receiver).collect();
+
+    let sut = Epoll::new().unwrap();
+    let _guards: Vec<_> = receivers
+        .iter()
+        .map(|receiver| sut.add(receiver, FileEvent::Read).unwrap())
+        .collect();
+
+    let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8];
+    for socket_name in socket_names.iter().step_by(2) {
+        UnixDatagramSenderBuilder::new(socket_name)
+            .create()
+            .unwrap()
+            .blocking_send(send_data.as_slice())
+            .unwrap();
+    }
+
+    let mut result = vec![];
+    sut.try_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(result, len NUMBER_OF_FDS / 2);
+    for receiver in receivers.iter().step_by(2) {
+        assert_that!(result, contains unsafe { receiver.file_descriptor().native_handle() });
+    }
+}
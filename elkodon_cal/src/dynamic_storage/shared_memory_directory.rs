@@ -0,0 +1,452 @@
+//! [`DynamicStorage`] which places all storages as files into one
+//! [`crate::shared_memory_directory::SharedMemoryDirectory`] instead of creating a POSIX shared
+//! memory object for every storage.
+//!
+//! The directory is created on first use with the size defined in the [`Configuration`] and
+//! remains until it is removed explicitly. Every storage, including its supplementary memory,
+//! must fit into [`Configuration::max_storage_size()`]. When a process dies while holding a
+//! storage, the memory of the storage is not released.
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_bb_system_types::file_name::FileName;
+//! use elkodon_bb_container::semantic_string::SemanticString;
+//! use elkodon_cal::dynamic_storage::shared_memory_directory::*;
+//! use elkodon_cal::named_concept::*;
+//! use std::sync::atomic::{AtomicI64, Ordering};
+//!
+//! let storage_name = FileName::new(b"myDirectoryStorageName").unwrap();
+//! let owner = Builder::new(&storage_name)
+//!                 .supplementary_size(1024)
+//!                 .create(AtomicI64::new(0)).unwrap();
+//! owner.get().store(123, Ordering::Relaxed);
+//!
+//! // usually a different process
+//! let storage = Builder::<AtomicI64>::new(&storage_name)
+//!                 .open().unwrap();
+//!
+//! println!("Initial value: {}", storage.get().load(Ordering::Relaxed));
+//! ```
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use elkodon_bb_log::fail;
+
+pub use crate::dynamic_storage::*;
+use crate::shared_memory_directory::registry::{self, DirectoryConfig, DirectoryFile};
+use crate::shared_memory_directory::SharedMemoryDirectoryCreateFileError;
+use crate::static_storage::file::{
+    NamedConceptConfiguration, NamedConceptDoesExistError, NamedConceptListError,
+    NamedConceptRemoveError,
+};
+
+const IS_INITIALIZED_STATE_VALUE: u64 = 0xbeefaffedeadbeef;
+
+/// The alignment of every storage in the directory
+const STORAGE_ALIGNMENT: usize = 64;
+
+/// The default name of the directory that contains all storages
+pub const DEFAULT_DIRECTORY_NAME: FileName =
+    unsafe { FileName::new_unchecked(b"elkodon_dynamic_storages") };
+
+/// The default size of the directory that contains all storages
+pub const DEFAULT_DIRECTORY_SIZE: usize = 64 * 1024 * 1024;
+
+/// The default of the maximum memory a single storage can occupy in the directory
+pub const DEFAULT_MAX_STORAGE_SIZE: usize = 64 * 1024;
+
+/// The builder of [`Storage`].
+#[derive(Debug)]
+pub struct Builder<T: Debug> {
+    storage_name: FileName,
+    supplementary_size: usize,
+    has_ownership: bool,
+    config: Configuration,
+    _phantom_data: PhantomData<T>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    suffix: FileName,
+    path: Path,
+    directory_name: FileName,
+    directory_size: usize,
+    max_storage_size: usize,
+}
+
+#[repr(C)]
+struct Data<T: Send + Sync + Debug> {
+    state: AtomicU64,
+    data: T,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: DEFAULT_PATH_HINT,
+            suffix: DEFAULT_SUFFIX,
+            directory_name: DEFAULT_DIRECTORY_NAME,
+            directory_size: DEFAULT_DIRECTORY_SIZE,
+            max_storage_size: DEFAULT_MAX_STORAGE_SIZE,
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Configuration {
+    /// Defines the name of the directory that contains the storages.
+    pub fn directory_name(mut self, value: FileName) -> Self {
+        self.directory_name = value;
+        self
+    }
+
+    /// Defines the size of the directory. It is only considered when the directory does not
+    /// yet exist.
+    pub fn directory_size(mut self, value: usize) -> Self {
+        self.directory_size = value;
+        self
+    }
+
+    /// Defines the maximum memory a single storage can occupy in the directory. It is only
+    /// considered when the directory does not yet exist.
+    pub fn max_storage_size(mut self, value: usize) -> Self {
+        self.max_storage_size = value;
+        self
+    }
+
+    pub fn get_directory_name(&self) -> &FileName {
+        &self.directory_name
+    }
+
+    pub fn get_directory_size(&self) -> usize {
+        self.directory_size
+    }
+
+    pub fn get_max_storage_size(&self) -> usize {
+        self.max_storage_size
+    }
+
+    fn directory_config(&self) -> DirectoryConfig {
+        DirectoryConfig {
+            name: self.directory_name,
+            size: self.directory_size,
+            bucket_layout: unsafe {
+                Layout::from_size_align_unchecked(self.max_storage_size, STORAGE_ALIGNMENT)
+            },
+        }
+    }
+
+    fn file_name_for(&self, name: &FileName) -> FileName {
+        unsafe { FileName::new_unchecked(self.path_for(name).file_name()) }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptBuilder<Storage<T>> for Builder<T> {
+    fn new(storage_name: &FileName) -> Self {
+        Self {
+            has_ownership: true,
+            storage_name: *storage_name,
+            supplementary_size: 0,
+            config: Configuration::default(),
+            _phantom_data: PhantomData,
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl<T: Send + Sync + Debug> Builder<T> {
+    fn size(&self) -> usize {
+        std::mem::size_of::<Data<T>>() + self.supplementary_size
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorageBuilder<T, Storage<T>> for Builder<T> {
+    fn has_ownership(mut self, value: bool) -> Self {
+        self.has_ownership = value;
+        self
+    }
+
+    fn supplementary_size(mut self, value: usize) -> Self {
+        self.supplementary_size = value;
+        self
+    }
+
+    fn create_and_initialize<F: FnOnce(&mut T, &mut BumpAllocator) -> bool>(
+        self,
+        initial_value: T,
+        initializer: F,
+    ) -> Result<Storage<T>, DynamicStorageCreateError> {
+        let msg = "Failed to create dynamic_storage::SharedMemoryDirectory";
+
+        if std::mem::align_of::<Data<T>>() > STORAGE_ALIGNMENT {
+            fail!(from self, with DynamicStorageCreateError::Creation,
+                "{} since the required alignment of {} exceeds the supported alignment of {}.",
+                msg, std::mem::align_of::<Data<T>>(), STORAGE_ALIGNMENT);
+        }
+
+        let directory = fail!(from self, when registry::open_or_create(&self.config.directory_config()),
+                            with DynamicStorageCreateError::Creation,
+                            "{} since the directory \"{}\" could not be opened or created.", msg, self.config.directory_name);
+        let file_name = self.config.file_name_for(&self.storage_name);
+
+        let mut initial_value = Some(initial_value);
+        let mut initializer = Some(initializer);
+        let mut is_initialized = false;
+
+        let file = DirectoryFile::acquire(directory, |directory| {
+            let creator = fail!(from self, when directory.new_file(unsafe {
+                                    Layout::from_size_align_unchecked(self.size(), STORAGE_ALIGNMENT) }),
+                                with DynamicStorageCreateError::Creation,
+                                "{} since the memory for the storage could not be allocated. Does the storage with the size {} exceed the max storage size or is the directory full?",
+                                msg, self.size());
+
+            match creator.is_persistent(true).create(&file_name, |content| {
+                let value = content.as_mut_ptr() as *mut Data<T>;
+                unsafe {
+                    core::ptr::addr_of_mut!((*value).state).write(AtomicU64::new(0));
+                    core::ptr::addr_of_mut!((*value).data).write(initial_value.take().unwrap())
+                };
+
+                let mut allocator = BumpAllocator::new(
+                    unsafe {
+                        NonNull::new_unchecked(
+                            content.as_mut_ptr().add(std::mem::size_of::<Data<T>>()),
+                        )
+                    },
+                    content.len() - std::mem::size_of::<Data<T>>(),
+                );
+
+                if (initializer.take().unwrap())(unsafe { &mut (*value).data }, &mut allocator) {
+                    unsafe {
+                        (*value)
+                            .state
+                            .store(IS_INITIALIZED_STATE_VALUE, Ordering::Relaxed)
+                    };
+                    is_initialized = true;
+                }
+            }) {
+                Ok(file) => Ok(file),
+                Err(SharedMemoryDirectoryCreateFileError::DoesExist)
+                | Err(SharedMemoryDirectoryCreateFileError::BeingCreated) => {
+                    fail!(from self, with DynamicStorageCreateError::AlreadyExists,
+                        "{} since a storage with the name already exists.", msg);
+                }
+                Err(SharedMemoryDirectoryCreateFileError::FileLimitExceeded) => {
+                    fail!(from self, with DynamicStorageCreateError::Creation,
+                        "{} since the directory already contains the maximum number of files.", msg);
+                }
+            }
+        })?;
+
+        if !is_initialized {
+            file.borrow_directory().remove_file(&file_name);
+            fail!(from self, with DynamicStorageCreateError::InitializationFailed,
+                "{} since the initialization of the underlying construct failed.", msg);
+        }
+
+        Ok(Storage {
+            file,
+            name: self.storage_name,
+            file_name,
+            has_ownership: self.has_ownership,
+            _phantom_data: PhantomData,
+        })
+    }
+
+    fn open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open ";
+        let origin = format!("{:?}", self);
+        match self.try_open() {
+            Err(DynamicStorageOpenError::DoesNotExist) => {
+                fail!(from origin, with DynamicStorageOpenError::DoesNotExist, "{} since a storage with that name does not exists.", msg);
+            }
+            Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                fail!(from origin, with DynamicStorageOpenError::InitializationNotYetFinalized, "{} since it is not yet readable - most likely since it is not finalized.", msg);
+            }
+            Err(e) => Err(e),
+            Ok(s) => Ok(s),
+        }
+    }
+
+    fn try_open(self) -> Result<Storage<T>, DynamicStorageOpenError> {
+        let msg = "Failed to open ";
+        let origin = format!("{:?}", self);
+
+        let directory = match registry::open_for_mgmt(
+            &self.config.directory_name,
+            DynamicStorageOpenError::Open,
+            &origin,
+            msg,
+        )? {
+            Some(directory) => directory,
+            None => return Err(DynamicStorageOpenError::DoesNotExist),
+        };
+
+        let file_name = self.config.file_name_for(&self.storage_name);
+        let file = DirectoryFile::acquire(directory, |directory| {
+            directory
+                .open_file(&file_name)
+                .ok_or(DynamicStorageOpenError::DoesNotExist)
+        })?;
+
+        if file.borrow_file().content().len() < self.size() {
+            fail!(from self, with DynamicStorageOpenError::InternalError,
+                "{} since the actual size {} does not match the required size of {}.",
+                msg, file.borrow_file().content().len(), self.size());
+        }
+
+        let value = file.content_address() as *const Data<T>;
+        if unsafe { &(*value) }.state.load(Ordering::Relaxed) != IS_INITIALIZED_STATE_VALUE {
+            return Err(DynamicStorageOpenError::InitializationNotYetFinalized);
+        }
+
+        Ok(Storage {
+            file,
+            name: self.storage_name,
+            file_name,
+            has_ownership: false,
+            _phantom_data: PhantomData,
+        })
+    }
+}
+
+/// Implements [`DynamicStorage`] for a
+/// [`crate::shared_memory_directory::SharedMemoryDirectory`]. It is built by [`Builder`].
+#[derive(Debug)]
+pub struct Storage<T> {
+    file: DirectoryFile,
+    name: FileName,
+    file_name: FileName,
+    has_ownership: bool,
+    _phantom_data: PhantomData<T>,
+}
+
+impl<T> Drop for Storage<T> {
+    fn drop(&mut self) {
+        if self.has_ownership {
+            self.file.borrow_directory().remove_file(&self.file_name);
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConcept for Storage<T> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<T: Send + Sync + Debug> NamedConceptMgmt for Storage<T> {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = "Unable to check if dynamic_storage::shared_memory_directory exists";
+        let origin = "dynamic_storage::shared_memory_directory::Storage::does_exist_cfg()";
+
+        match registry::open_for_mgmt(
+            &cfg.directory_name,
+            NamedConceptDoesExistError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => Ok(directory.does_file_exist(&cfg.file_name_for(name))),
+            None => Ok(false),
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all dynamic_storage::shared_memory_directory";
+        let origin = "dynamic_storage::shared_memory_directory::Storage::list_cfg()";
+
+        let directory = match registry::open_for_mgmt(
+            &config.directory_name,
+            NamedConceptListError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => directory,
+            None => return Ok(vec![]),
+        };
+
+        let mut result = vec![];
+        for file in directory.list_files() {
+            if let Some(entry_name) = config.extract_name_from_file(&file.name()) {
+                result.push(entry_name);
+            }
+        }
+
+        Ok(result)
+    }
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove dynamic_storage::shared_memory_directory";
+        let origin = "dynamic_storage::shared_memory_directory::Storage::remove_cfg()";
+
+        match registry::open_for_mgmt(
+            &cfg.directory_name,
+            NamedConceptRemoveError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => Ok(directory.remove_file(&cfg.file_name_for(name))),
+            None => Ok(false),
+        }
+    }
+}
+
+impl<T: Send + Sync + Debug> DynamicStorage<T> for Storage<T> {
+    type Builder = Builder<T>;
+
+    fn does_support_persistency() -> bool {
+        true
+    }
+
+    fn acquire_ownership(&mut self) {
+        self.has_ownership = true;
+    }
+
+    fn get(&self) -> &T {
+        unsafe { &(*(self.file.content_address() as *const Data<T>)).data }
+    }
+
+    fn has_ownership(&self) -> bool {
+        self.has_ownership
+    }
+
+    fn release_ownership(&mut self) {
+        self.has_ownership = false;
+    }
+}
@@ -0,0 +1,505 @@
+//! File lock based implementation of [`Monitoring`]. The [`Token`] is a file that is
+//! exclusively locked as long as the owning process is alive. When the process dies the
+//! operating system releases the lock and the file remains unlocked, this is detected as
+//! [`State::Dead`].
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_cal::monitoring::file_lock::*;
+//! use elkodon_bb_system_types::file_name::FileName;
+//! use elkodon_bb_container::semantic_string::SemanticString;
+//!
+//! let name = FileName::new(b"someProcessToken").unwrap();
+//! let token = Builder::new(&name).token().unwrap();
+//!
+//! // usually a different process
+//! let monitor = Builder::new(&name).monitor().unwrap();
+//! assert!(monitor.state().unwrap() == State::Alive);
+//!
+//! drop(token);
+//! assert!(monitor.state().unwrap() == State::DoesNotExist);
+//! ```
+
+pub use crate::monitoring::*;
+pub use crate::named_concept::*;
+
+use elkodon_bb_log::{fail, fatal_panic, warn};
+use elkodon_bb_posix::{
+    directory::*,
+    file::*,
+    file_lock::{FileLock, FileLockBuilder, FileLockWriteGuard, LockType, ReadWriteMutexHandle},
+    file_type::FileType,
+    mutex::*,
+};
+use once_cell::sync::Lazy;
+use ouroboros::self_referencing;
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+const TOKEN_PERMISSIONS: Permission = Permission::OWNER_ALL;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LockOwner {
+    Token,
+    Cleaner,
+}
+
+// fcntl locks are owned by the process. A process does not see its own locks and closing any
+// file descriptor of a locked file releases all locks the process holds on it. Therefore every
+// locked file of this process is tracked here and never touched by another monitor, token or
+// cleaner of the same process.
+static PROCESS_LOCAL_MTX_HANDLE: Lazy<MutexHandle<HashMap<FilePath, LockOwner>>> =
+    Lazy::new(MutexHandle::new);
+static PROCESS_LOCAL_LOCKS: Lazy<Mutex<HashMap<FilePath, LockOwner>>> = Lazy::new(|| {
+    let result = MutexBuilder::new()
+        .is_interprocess_capable(false)
+        .create(HashMap::new(), &PROCESS_LOCAL_MTX_HANDLE);
+
+    if result.is_err() {
+        fatal_panic!(from "PROCESS_LOCAL_LOCKS", "Failed to create global monitoring lock map");
+    }
+
+    result.unwrap()
+});
+
+fn unregister<T: Debug>(origin: &T, path: &FilePath) {
+    match PROCESS_LOCAL_LOCKS.lock() {
+        Ok(mut guard) => {
+            guard.remove(path);
+        }
+        Err(v) => {
+            fatal_panic!(from origin, "This should never happen! Failed to acquire the lock to unregister the file lock ({:?})", v);
+        }
+    }
+}
+
+#[self_referencing]
+#[derive(Debug)]
+struct LockedFile {
+    handle: ReadWriteMutexHandle<File>,
+    #[borrows(handle)]
+    #[covariant]
+    lock: FileLock<'this, File>,
+    #[borrows(lock)]
+    #[covariant]
+    guard: FileLockWriteGuard<'this, 'this, File>,
+}
+
+/// The custom configuration of the [`Monitoring`].
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    path: Path,
+    suffix: FileName,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: DEFAULT_PATH_HINT,
+            suffix: DEFAULT_SUFFIX,
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[derive(Debug)]
+pub struct Token {
+    name: FileName,
+    config: Configuration,
+    _file: LockedFile,
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        let path = self.config.path_for(&self.name);
+        match File::remove(&path) {
+            Ok(true) => (),
+            Ok(false) => {
+                warn!(from self, "The token was already removed. This could be caused by a corrupted system.");
+            }
+            Err(v) => {
+                warn!(from self, "Unable to remove token due to {:?}. This may cause a leak.", v);
+            }
+        }
+
+        // the file is already removed, therefore a monitor of this process can no longer open
+        // it and release the lock that is still held until the field is dropped
+        unregister(self, &path);
+    }
+}
+
+impl NamedConcept for Token {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringToken for Token {}
+
+#[derive(Debug)]
+pub struct Monitor {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConcept for Monitor {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringMonitor for Monitor {
+    fn state(&self) -> Result<State, MonitoringStateError> {
+        let msg = "Unable to acquire state";
+        let path = self.config.path_for(&self.name);
+        // hold the lock so that no token with this name can be created in this process while
+        // the file is inspected
+        let guard = fail!(from self, when PROCESS_LOCAL_LOCKS.lock(),
+                with MonitoringStateError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        match guard.get(&path) {
+            Some(LockOwner::Token) => return Ok(State::Alive),
+            Some(LockOwner::Cleaner) => return Ok(State::Dead),
+            None => (),
+        }
+
+        let file = match FileBuilder::new(&path).open_existing(AccessMode::Read) {
+            Ok(f) => f,
+            Err(FileOpenError::FileDoesNotExist) => return Ok(State::DoesNotExist),
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from self, with MonitoringStateError::InsufficientPermissions,
+                    "{} due to insufficient permissions to open the token.", msg);
+            }
+            Err(FileOpenError::Interrupt) => {
+                fail!(from self, with MonitoringStateError::Interrupt,
+                    "{} since an interrupt signal was received while opening the token.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringStateError::InternalError,
+                    "{} due to an internal failure while opening the token ({:?}).", msg, v);
+            }
+        };
+
+        let handle = ReadWriteMutexHandle::new();
+        let lock = fail!(from self, when FileLockBuilder::new().create(file, &handle),
+                with MonitoringStateError::InternalError,
+                "{} since the file lock could not be created.", msg);
+
+        let state = fail!(from self, when lock.get_lock_state(),
+                with MonitoringStateError::InternalError,
+                "{} since the lock state could not be acquired.", msg);
+
+        match state.lock_type() {
+            LockType::Unlock => Ok(State::Dead),
+            LockType::Read | LockType::Write => Ok(State::Alive),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Cleaner {
+    name: FileName,
+    config: Configuration,
+    _file: LockedFile,
+}
+
+impl Drop for Cleaner {
+    fn drop(&mut self) {
+        let path = self.config.path_for(&self.name);
+        match File::remove(&path) {
+            Ok(true) => (),
+            Ok(false) => {
+                warn!(from self, "The token of the dead instance was already removed. This could be caused by a corrupted system.");
+            }
+            Err(v) => {
+                warn!(from self, "Unable to remove the token of the dead instance due to {:?}. This may cause a leak.", v);
+            }
+        }
+
+        unregister(self, &path);
+    }
+}
+
+impl NamedConcept for Cleaner {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringCleaner for Cleaner {}
+
+#[derive(Debug)]
+pub struct Monitoring {}
+
+impl NamedConceptMgmt for Monitoring {
+    type Configuration = Configuration;
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = format!("Unable to remove monitoring token \"{}\"", name);
+        let origin = "monitoring::file_lock::Monitoring::remove_cfg()";
+
+        match File::remove(&config.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(FileRemoveError::InsufficientPermissions)
+            | Err(FileRemoveError::PartOfReadOnlyFileSystem) => {
+                fail!(from origin, with NamedConceptRemoveError::InsufficientPermissions,
+                        "{} due to insufficient permissions.", msg);
+            }
+            Err(v) => {
+                fail!(from origin, with NamedConceptRemoveError::InternalError,
+                        "{} due to unknown failure ({:?}).", msg, v);
+            }
+        }
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all monitoring tokens";
+        let origin = "monitoring::file_lock::Monitoring::list_cfg()";
+        let directory = fail!(from origin, when Directory::new(config.get_path_hint()),
+            map DirectoryOpenError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+            unmatched NamedConceptListError::InternalError,
+            "{} due to a failure while reading the token directory (\"{}\").", msg, config.get_path_hint());
+
+        let entries = fail!(from origin,
+                            when directory.contents(),
+                            map DirectoryReadError::InsufficientPermissions => NamedConceptListError::InsufficientPermissions,
+                            unmatched NamedConceptListError::InternalError,
+                            "{} due to a failure while reading the token directory (\"{}\") contents.", msg, config.get_path_hint());
+
+        let mut result = vec![];
+        for entry in &entries {
+            if entry.metadata().file_type() == FileType::File {
+                if let Some(entry_name) = config.extract_name_from_file(entry.name()) {
+                    result.push(entry_name);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn does_exist_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = format!("Unable to check if monitoring token \"{}\" exists", name);
+        let origin = "monitoring::file_lock::Monitoring::does_exist_cfg()";
+
+        match File::does_exist(&config.path_for(name)) {
+            Ok(v) => Ok(v),
+            Err(v) => {
+                fail!(from origin, with NamedConceptDoesExistError::InternalError,
+                    "{} due to an internal failure ({:?}).", msg, v);
+            }
+        }
+    }
+}
+
+impl crate::monitoring::Monitoring for Monitoring {
+    type Token = Token;
+    type Monitor = Monitor;
+    type Cleaner = Cleaner;
+    type Builder = Builder;
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<Monitoring> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl Builder {
+    fn lock_file<E: Copy>(
+        &self,
+        file: File,
+        lock_failure: E,
+        internal_failure: E,
+    ) -> Result<LockedFile, E> {
+        let msg = "Unable to lock file";
+        LockedFileTryBuilder {
+            handle: ReadWriteMutexHandle::new(),
+            lock_builder: |handle| {
+                Ok(
+                    fail!(from self, when FileLockBuilder::new().create(file, handle),
+                    with internal_failure,
+                    "{} since the file lock could not be created.", msg),
+                )
+            },
+            guard_builder: |lock| match fail!(from self, when lock.write_try_lock(),
+                    with internal_failure,
+                    "{} due to an internal failure while acquiring the lock.", msg)
+            {
+                Some(guard) => Ok(guard),
+                None => Err(lock_failure),
+            },
+        }
+        .try_build()
+    }
+}
+
+impl MonitoringBuilder<Monitoring> for Builder {
+    fn token(self) -> Result<Token, MonitoringCreateTokenError> {
+        let msg = "Failed to create monitoring token";
+        let path = self.config.path_for(&self.name);
+
+        let mut guard = fail!(from self, when PROCESS_LOCAL_LOCKS.lock(),
+                with MonitoringCreateTokenError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        if guard.contains_key(&path) {
+            fail!(from self, with MonitoringCreateTokenError::AlreadyExists,
+                "{} since a token with the name \"{}\" does already exist.", msg, self.name);
+        }
+
+        let file = match FileBuilder::new(&path)
+            .creation_mode(CreationMode::CreateExclusive)
+            .permission(TOKEN_PERMISSIONS)
+            .create()
+        {
+            Ok(f) => f,
+            Err(FileCreationError::FileAlreadyExists) => {
+                fail!(from self, with MonitoringCreateTokenError::AlreadyExists,
+                    "{} since a token with the name \"{}\" does already exist.", msg, self.name);
+            }
+            Err(FileCreationError::InsufficientPermissions) => {
+                fail!(from self, with MonitoringCreateTokenError::InsufficientPermissions,
+                    "{} due to insufficient permissions to create the token.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringCreateTokenError::InternalError,
+                    "{} due to an internal failure while creating the token ({:?}).", msg, v);
+            }
+        };
+
+        let locked_file = match self.lock_file(
+            file,
+            MonitoringCreateTokenError::AlreadyExists,
+            MonitoringCreateTokenError::InternalError,
+        ) {
+            Ok(f) => f,
+            Err(e) => {
+                if let Err(v) = File::remove(&path) {
+                    warn!(from self, "Unable to remove the token that could not be locked ({:?}). This may cause a leak.", v);
+                }
+                fail!(from self, with e, "{} since the token could not be locked.", msg);
+            }
+        };
+
+        guard.insert(path, LockOwner::Token);
+
+        Ok(Token {
+            name: self.name,
+            config: self.config,
+            _file: locked_file,
+        })
+    }
+
+    fn monitor(self) -> Result<Monitor, MonitoringCreateMonitorError> {
+        Ok(Monitor {
+            name: self.name,
+            config: self.config,
+        })
+    }
+
+    fn cleaner(self) -> Result<Cleaner, MonitoringCreateCleanerError> {
+        let msg = "Failed to acquire cleaner";
+        let path = self.config.path_for(&self.name);
+
+        let mut guard = fail!(from self, when PROCESS_LOCAL_LOCKS.lock(),
+                with MonitoringCreateCleanerError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        match guard.get(&path) {
+            Some(LockOwner::Token) => {
+                fail!(from self, with MonitoringCreateCleanerError::InstanceStillAlive,
+                    "{} since the owner of the token \"{}\" is still alive.", msg, self.name);
+            }
+            Some(LockOwner::Cleaner) => {
+                fail!(from self, with MonitoringCreateCleanerError::InstanceStillAlive,
+                    "{} since the token \"{}\" is already cleaned up by another instance.", msg, self.name);
+            }
+            None => (),
+        }
+
+        let file = match FileBuilder::new(&path).open_existing(AccessMode::ReadWrite) {
+            Ok(f) => f,
+            Err(FileOpenError::FileDoesNotExist) => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since the token \"{}\" does not exist.", msg, self.name);
+            }
+            Err(FileOpenError::InsufficientPermissions) => {
+                fail!(from self, with MonitoringCreateCleanerError::InsufficientPermissions,
+                    "{} due to insufficient permissions to open the token.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} due to an internal failure while opening the token ({:?}).", msg, v);
+            }
+        };
+
+        let locked_file = fail!(from self, when self.lock_file(
+                file,
+                MonitoringCreateCleanerError::InstanceStillAlive,
+                MonitoringCreateCleanerError::InternalError),
+            "{} since the token \"{}\" could not be locked.", msg, self.name);
+
+        // another cleaner may have removed the token right before the lock was acquired
+        match File::does_exist(&path) {
+            Ok(true) => (),
+            Ok(false) => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since the token \"{}\" was removed by another cleaner.", msg, self.name);
+            }
+            Err(v) => {
+                fail!(from self, with MonitoringCreateCleanerError::InternalError,
+                    "{} since the existance of the token \"{}\" could not be verified ({:?}).", msg, self.name, v);
+            }
+        }
+
+        guard.insert(path, LockOwner::Cleaner);
+
+        Ok(Cleaner {
+            name: self.name,
+            config: self.config,
+            _file: locked_file,
+        })
+    }
+}
@@ -0,0 +1,158 @@
+//! Traits that allow one process to detect if another process, that owns a
+//! [`MonitoringToken`], is still alive. When a process dies without releasing its resources
+//! another process can acquire a [`MonitoringCleaner`] and remove the stale resources exclusively.
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_bb_system_types::file_name::FileName;
+//! use elkodon_bb_container::semantic_string::SemanticString;
+//! use elkodon_cal::monitoring::*;
+//! use elkodon_cal::named_concept::*;
+//!
+//! fn example<Sut: Monitoring>() {
+//!     let name = FileName::new(b"my_process_token").unwrap();
+//!
+//!     // the monitored process holds the token as long as it is alive
+//!     let token = Sut::Builder::new(&name).token().unwrap();
+//!
+//!     // another process checks the state
+//!     let monitor = Sut::Builder::new(&name).monitor().unwrap();
+//!     match monitor.state().unwrap() {
+//!         State::Alive => println!("the process is alive"),
+//!         State::Dead => {
+//!             // cleanup the stale resources, only one process can hold the cleaner
+//!             if let Ok(cleaner) = Sut::Builder::new(&name).cleaner() {
+//!                 // remove the resources of the dead process
+//!             }
+//!         }
+//!         State::DoesNotExist => println!("the token does not exist"),
+//!     }
+//! }
+//! ```
+
+pub mod file_lock;
+pub mod process_local;
+
+use std::fmt::Debug;
+
+use elkodon_bb_posix::config::TEMP_DIRECTORY;
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_bb_system_types::path::Path;
+
+use crate::named_concept::{NamedConcept, NamedConceptBuilder, NamedConceptMgmt};
+
+/// The default suffix of every monitoring token
+pub const DEFAULT_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".monitor") };
+
+/// The default path hint for every monitoring token
+pub const DEFAULT_PATH_HINT: Path = TEMP_DIRECTORY;
+
+/// The state of a [`MonitoringToken`] observed by a [`MonitoringMonitor`].
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum State {
+    /// The owner of the [`MonitoringToken`] is alive.
+    Alive,
+    /// The owner of the [`MonitoringToken`] died without removing it.
+    Dead,
+    /// There exists no [`MonitoringToken`] with the given name.
+    DoesNotExist,
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MonitoringCreateTokenError {
+    InsufficientPermissions,
+    AlreadyExists,
+    InternalError,
+}
+
+impl std::fmt::Display for MonitoringCreateTokenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for MonitoringCreateTokenError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MonitoringCreateMonitorError {
+    InternalError,
+}
+
+impl std::fmt::Display for MonitoringCreateMonitorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for MonitoringCreateMonitorError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MonitoringCreateCleanerError {
+    InsufficientPermissions,
+    DoesNotExist,
+    /// Either the owner of the [`MonitoringToken`] is alive or another instance holds the
+    /// [`MonitoringCleaner`] already.
+    InstanceStillAlive,
+    InternalError,
+}
+
+impl std::fmt::Display for MonitoringCreateCleanerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for MonitoringCreateCleanerError {}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum MonitoringStateError {
+    InsufficientPermissions,
+    Interrupt,
+    InternalError,
+}
+
+impl std::fmt::Display for MonitoringStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for MonitoringStateError {}
+
+/// Held by the monitored instance as long as it is alive. When it goes out of scope the
+/// underlying resources are removed and the state becomes [`State::DoesNotExist`]. When the
+/// owning process dies the state becomes [`State::Dead`].
+pub trait MonitoringToken: NamedConcept + Debug {}
+
+/// Observes the [`State`] of a [`MonitoringToken`].
+pub trait MonitoringMonitor: NamedConcept + Debug {
+    /// Returns the current [`State`] of the corresponding [`MonitoringToken`].
+    fn state(&self) -> Result<State, MonitoringStateError>;
+}
+
+/// Can only be acquired when the owner of the [`MonitoringToken`] is dead. Only one instance
+/// can hold the [`MonitoringCleaner`] so that stale resources are removed exactly once. When it
+/// goes out of scope the underlying resources of the dead [`MonitoringToken`] are removed.
+pub trait MonitoringCleaner: NamedConcept + Debug {}
+
+/// Creates either a [`MonitoringToken`], a [`MonitoringMonitor`] or a [`MonitoringCleaner`].
+pub trait MonitoringBuilder<T: Monitoring>: NamedConceptBuilder<T> {
+    /// Creates a new [`MonitoringToken`]. Fails when a token with the same name already exists.
+    fn token(self) -> Result<T::Token, MonitoringCreateTokenError>;
+
+    /// Creates a [`MonitoringMonitor`] that observes the [`MonitoringToken`] with the same name.
+    fn monitor(self) -> Result<T::Monitor, MonitoringCreateMonitorError>;
+
+    /// Acquires the [`MonitoringCleaner`] of a [`MonitoringToken`] whose owner is dead.
+    fn cleaner(self) -> Result<T::Cleaner, MonitoringCreateCleanerError>;
+}
+
+/// The concept that ties [`MonitoringToken`], [`MonitoringMonitor`] and [`MonitoringCleaner`]
+/// together.
+pub trait Monitoring: NamedConceptMgmt + Sized + Debug {
+    type Token: MonitoringToken;
+    type Monitor: MonitoringMonitor;
+    type Cleaner: MonitoringCleaner;
+    type Builder: MonitoringBuilder<Self>;
+}
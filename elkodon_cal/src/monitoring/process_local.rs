@@ -0,0 +1,254 @@
+//! Process local implementation of [`Monitoring`]. Cannot be used in an inter-process context.
+//! Since the monitored instance lives in the same process it can never be observed as
+//! [`State::Dead`].
+//!
+//! # Example
+//!
+//! ```
+//! use elkodon_cal::monitoring::process_local::*;
+//! use elkodon_bb_system_types::file_name::FileName;
+//! use elkodon_bb_container::semantic_string::SemanticString;
+//!
+//! let name = FileName::new(b"someInternalToken").unwrap();
+//! let token = Builder::new(&name).token().unwrap();
+//!
+//! // at some other place in the local process, can be another thread
+//! let monitor = Builder::new(&name).monitor().unwrap();
+//! assert!(monitor.state().unwrap() == State::Alive);
+//! ```
+
+pub use crate::monitoring::*;
+pub use crate::named_concept::*;
+
+use elkodon_bb_log::{fail, fatal_panic};
+use elkodon_bb_posix::mutex::*;
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+static PROCESS_LOCAL_MTX_HANDLE: Lazy<MutexHandle<HashSet<FilePath>>> = Lazy::new(MutexHandle::new);
+static PROCESS_LOCAL_TOKENS: Lazy<Mutex<HashSet<FilePath>>> = Lazy::new(|| {
+    let result = MutexBuilder::new()
+        .is_interprocess_capable(false)
+        .create(HashSet::new(), &PROCESS_LOCAL_MTX_HANDLE);
+
+    if result.is_err() {
+        fatal_panic!(from "PROCESS_LOCAL_TOKENS", "Failed to create global monitoring token set");
+    }
+
+    result.unwrap()
+});
+
+/// The custom configuration of the [`Monitoring`].
+#[derive(Clone, Debug)]
+pub struct Configuration {
+    path: Path,
+    suffix: FileName,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            path: DEFAULT_PATH_HINT,
+            suffix: DEFAULT_SUFFIX,
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[derive(Debug)]
+pub struct Token {
+    name: FileName,
+    config: Configuration,
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        if let Err(v) = unsafe { Monitoring::remove_cfg(&self.name, &self.config) } {
+            fatal_panic!(from self, "This should never happen! Failed to remove underlying token ({:?})", v);
+        }
+    }
+}
+
+impl NamedConcept for Token {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringToken for Token {}
+
+#[derive(Debug)]
+pub struct Monitor {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConcept for Monitor {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringMonitor for Monitor {
+    fn state(&self) -> Result<State, MonitoringStateError> {
+        let msg = "Unable to acquire state";
+        let guard = fail!(from self, when PROCESS_LOCAL_TOKENS.lock(),
+                with MonitoringStateError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        match guard.contains(&self.config.path_for(&self.name)) {
+            true => Ok(State::Alive),
+            false => Ok(State::DoesNotExist),
+        }
+    }
+}
+
+/// Can never be acquired since a process local [`Token`] cannot be owned by a dead process.
+#[derive(Debug)]
+pub struct Cleaner {
+    name: FileName,
+}
+
+impl NamedConcept for Cleaner {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl MonitoringCleaner for Cleaner {}
+
+#[derive(Debug)]
+pub struct Monitoring {}
+
+impl NamedConceptMgmt for Monitoring {
+    type Configuration = Configuration;
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        let msg = "Unable to remove monitoring token";
+        let mut guard = fatal_panic!(from "monitoring::process_local::Monitoring::remove_cfg",
+                                 when PROCESS_LOCAL_TOKENS.lock(),
+                                "{} \"{}\" since the lock could not be acquired.", msg, name);
+
+        Ok(guard.remove(&config.path_for(name)))
+    }
+
+    fn list_cfg(config: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        let msg = "Unable to list all monitoring tokens";
+        let guard = fatal_panic!(from "monitoring::process_local::Monitoring::list_cfg",
+                                 when PROCESS_LOCAL_TOKENS.lock(),
+                                "{} since the lock could not be acquired.", msg);
+
+        let mut result = vec![];
+        for token in guard.iter() {
+            if let Some(v) = config.extract_name_from_path(token) {
+                result.push(v);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn does_exist_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        let msg = "Unable to check if monitoring token exists";
+        let guard = fatal_panic!(from "monitoring::process_local::Monitoring::does_exist_cfg",
+                        when PROCESS_LOCAL_TOKENS.lock(), "{} since the lock could not be acquired.", msg);
+
+        Ok(guard.contains(&config.path_for(name)))
+    }
+}
+
+impl crate::monitoring::Monitoring for Monitoring {
+    type Token = Token;
+    type Monitor = Monitor;
+    type Cleaner = Cleaner;
+    type Builder = Builder;
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    config: Configuration,
+}
+
+impl NamedConceptBuilder<Monitoring> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = config.clone();
+        self
+    }
+}
+
+impl MonitoringBuilder<Monitoring> for Builder {
+    fn token(self) -> Result<Token, MonitoringCreateTokenError> {
+        let msg = "Failed to create monitoring token";
+        let mut guard = fail!(from self, when PROCESS_LOCAL_TOKENS.lock(),
+                with MonitoringCreateTokenError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        if !guard.insert(self.config.path_for(&self.name)) {
+            fail!(from self, with MonitoringCreateTokenError::AlreadyExists,
+                "{} since a token with the name \"{}\" does already exist.", msg, self.name);
+        }
+
+        Ok(Token {
+            name: self.name,
+            config: self.config,
+        })
+    }
+
+    fn monitor(self) -> Result<Monitor, MonitoringCreateMonitorError> {
+        Ok(Monitor {
+            name: self.name,
+            config: self.config,
+        })
+    }
+
+    fn cleaner(self) -> Result<Cleaner, MonitoringCreateCleanerError> {
+        let msg = "Failed to acquire cleaner";
+        let guard = fail!(from self, when PROCESS_LOCAL_TOKENS.lock(),
+                with MonitoringCreateCleanerError::InternalError,
+                "{} due to a failure while acquiring the lock.", msg);
+
+        match guard.contains(&self.config.path_for(&self.name)) {
+            true => {
+                fail!(from self, with MonitoringCreateCleanerError::InstanceStillAlive,
+                    "{} since the owner of the token \"{}\" is still alive.", msg, self.name);
+            }
+            false => {
+                fail!(from self, with MonitoringCreateCleanerError::DoesNotExist,
+                    "{} since the token \"{}\" does not exist.", msg, self.name);
+            }
+        }
+    }
+}
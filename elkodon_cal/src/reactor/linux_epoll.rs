@@ -0,0 +1,125 @@
+use std::{fmt::Debug, time::Duration};
+
+use elkodon_bb_log::fail;
+use elkodon_bb_posix::{
+    epoll::{Epoll, EpollAddError, EpollCreateError, EpollGuard, EpollWaitError},
+    file_descriptor::FileDescriptor,
+    file_descriptor_set::{FileEvent, SynchronousMultiplexing},
+};
+
+use crate::reactor::{ReactorAttachError, ReactorCreateError, ReactorWaitError};
+
+impl crate::reactor::ReactorGuard<'_, '_> for EpollGuard<'_, '_> {}
+
+#[derive(Debug)]
+pub struct Reactor {
+    epoll: Epoll,
+}
+
+impl Reactor {
+    fn handle_wait_result(
+        &self,
+        result: Result<(), EpollWaitError>,
+    ) -> Result<(), super::ReactorWaitError> {
+        let msg = "Unable to wait on Reactor";
+        match result {
+            Ok(()) => Ok(()),
+            Err(EpollWaitError::Interrupt) => {
+                fail!(from self, with ReactorWaitError::Interrupt,
+                        "{} since an interrupt signal was received while waiting.",
+                        msg);
+            }
+            Err(v) => {
+                fail!(from self, with ReactorWaitError::UnknownError,
+                        "{} since an unknown failure occurred in the underlying Epoll ({:?}).",
+                        msg, v);
+            }
+        }
+    }
+}
+
+impl crate::reactor::Reactor for Reactor {
+    type Guard<'reactor, 'attachment> = EpollGuard<'reactor, 'attachment>;
+    type Builder = ReactorBuilder;
+
+    fn capacity() -> usize {
+        Epoll::capacity()
+    }
+
+    fn len(&self) -> usize {
+        self.epoll.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.epoll.is_empty()
+    }
+
+    fn attach<'reactor, 'attachment, F: SynchronousMultiplexing + Debug>(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, super::ReactorAttachError> {
+        let msg = "Unable to attach";
+        match self.epoll.add(value, FileEvent::Read) {
+            Ok(guard) => Ok(guard),
+            Err(EpollAddError::AlreadyAttached) => {
+                fail!(from self, with ReactorAttachError::AlreadyAttached,
+                    "{} {:?} to reactor since it is already attached.", msg, value);
+            }
+            Err(EpollAddError::CapacityExceeded) | Err(EpollAddError::InsufficientMemory) => {
+                fail!(from self, with ReactorAttachError::CapacityExceeded,
+                    "{} {:?} to reactor since the capacity of the underlying epoll was exceeded.",
+                    msg, value);
+            }
+            Err(EpollAddError::UnknownError(e)) => {
+                fail!(from self, with ReactorAttachError::UnknownError(e),
+                    "{} {:?} to reactor since an unknown failure occurred in the underlying epoll ({}).",
+                    msg, value, e);
+            }
+        }
+    }
+
+    fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), super::ReactorWaitError> {
+        self.handle_wait_result(self.epoll.try_wait(fn_call))
+    }
+
+    fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+        timeout: Duration,
+    ) -> Result<(), super::ReactorWaitError> {
+        self.handle_wait_result(self.epoll.timed_wait(timeout, fn_call))
+    }
+
+    fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fn_call: F,
+    ) -> Result<(), super::ReactorWaitError> {
+        self.handle_wait_result(self.epoll.blocking_wait(fn_call))
+    }
+}
+
+pub struct ReactorBuilder {}
+
+impl crate::reactor::ReactorBuilder<Reactor> for ReactorBuilder {
+    fn new() -> Self {
+        Self {}
+    }
+
+    fn create(self) -> Result<Reactor, super::ReactorCreateError> {
+        let msg = "Unable to create Reactor";
+        match Epoll::new() {
+            Ok(epoll) => Ok(Reactor { epoll }),
+            Err(EpollCreateError::UnknownError(e)) => {
+                fail!(from "ReactorBuilder::create()", with ReactorCreateError::UnknownError(e),
+                    "{} since an unknown failure occurred in the underlying epoll ({}).", msg, e);
+            }
+            Err(e) => {
+                fail!(from "ReactorBuilder::create()", with ReactorCreateError::InsufficientResources,
+                    "{} due to insufficient resources ({:?}).", msg, e);
+            }
+        }
+    }
+}
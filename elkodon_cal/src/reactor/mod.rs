@@ -1,3 +1,5 @@
+#[cfg(target_os = "linux")]
+pub mod linux_epoll;
 pub mod posix_select;
 
 use std::{fmt::Debug, time::Duration};
@@ -8,11 +10,13 @@ use elkodon_bb_posix::{
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReactorCreateError {
+    InsufficientResources,
     UnknownError(i32),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ReactorAttachError {
+    AlreadyAttached,
     CapacityExceeded,
     UnknownError(i32),
 }
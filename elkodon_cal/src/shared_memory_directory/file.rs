@@ -5,12 +5,12 @@ use std::{alloc::Layout, fmt::Debug};
 use crate::shared_memory::ShmPointer;
 use crate::shared_memory_directory::SharedMemoryDirectoryCreateFileError;
 
-use super::file_reference_set::{FileReferenceSet, FileReferenceSetId};
+use super::file_reference_set::{FileMemory, FileReferenceSet, FileReferenceSetId};
 
 pub struct File<'a> {
     pub(crate) set: &'a FileReferenceSet,
     pub(crate) id: FileReferenceSetId,
-    pub(crate) base_address: usize,
+    pub(crate) memory: &'a dyn FileMemory,
 }
 
 impl Debug for File<'_> {
@@ -20,7 +20,7 @@ impl Debug for File<'_> {
             "File {{ name: {}, id: {:?}, base_address: {} }}",
             self.name(),
             self.id,
-            self.base_address
+            self.memory.base_address()
         )
     }
 }
@@ -31,46 +31,66 @@ impl<'a> File<'a> {
     }
 
     pub fn content(&self) -> &[u8] {
-        self.set.get_payload(self.id, self.base_address)
+        self.set.get_payload(self.id, self.memory.base_address())
     }
 
     pub fn content_mut(&mut self) -> &mut [u8] {
-        self.set.get_payload_mut(self.id, self.base_address)
+        self.set
+            .get_payload_mut(self.id, self.memory.base_address())
     }
 
     pub fn is_persistent(&self) -> bool {
         self.set.is_persistent(self.id)
     }
+
+    /// Releases the reference of another owner of the file, for instance one whose process
+    /// died while it was holding the file. When no reference remains, the file is removed.
+    ///
+    /// # Safety
+    ///
+    ///  * the other owner must have acquired the reference and must never release it
+    pub unsafe fn release_stale_reference(&self) {
+        self.set.release(self.id, self.memory)
+    }
 }
 
 impl<'a> Drop for File<'a> {
     fn drop(&mut self) {
-        self.set.release(self.id)
+        self.set.release(self.id, self.memory)
     }
 }
 
-#[derive(Debug)]
 pub struct FileCreator<'a> {
     set: &'a FileReferenceSet,
     layout: Layout,
     is_persistent: bool,
-    memory: ShmPointer,
-    base_address: usize,
+    shm_pointer: ShmPointer,
+    memory: &'a dyn FileMemory,
+}
+
+impl Debug for FileCreator<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FileCreator {{ layout: {:?}, is_persistent: {}, shm_pointer: {:?} }}",
+            self.layout, self.is_persistent, self.shm_pointer
+        )
+    }
 }
 
 impl<'a> FileCreator<'a> {
     pub(crate) fn new(
         set: &'a FileReferenceSet,
-        memory: ShmPointer,
+        shm_pointer: ShmPointer,
         layout: Layout,
-        base_address: usize,
+        memory: &'a dyn FileMemory,
     ) -> Self {
         Self {
             set,
             layout,
             is_persistent: false,
+            shm_pointer,
             memory,
-            base_address,
         }
     }
 
@@ -84,16 +104,28 @@ impl<'a> FileCreator<'a> {
         name: &FileName,
         mut initializer: F,
     ) -> Result<File<'a>, SharedMemoryDirectoryCreateFileError> {
-        let id = fail!(from self, when self.set.insert(
-                                        name,
-                                        self.memory.offset.value(),
-                                        self.layout.size(),
-                                        self.is_persistent,
-                                    ),
-                            "Failed to create new file {}.", *name);
+        let id = match self.set.insert(
+            name,
+            self.shm_pointer.offset.value(),
+            self.layout,
+            self.is_persistent,
+            self.memory,
+        ) {
+            Ok(id) => id,
+            Err(e) => {
+                // the set releases the payload of an inserted entry on failure
+                if e == SharedMemoryDirectoryCreateFileError::FileLimitExceeded {
+                    unsafe {
+                        self.memory
+                            .release(self.shm_pointer.offset.value(), self.layout)
+                    };
+                }
+                fail!(from self, with e, "Failed to create new file {}.", *name);
+            }
+        };
 
         initializer(unsafe {
-            core::slice::from_raw_parts_mut(self.memory.data_ptr, self.layout.size())
+            core::slice::from_raw_parts_mut(self.shm_pointer.data_ptr, self.layout.size())
         });
 
         self.set.finalize_initialization(id);
@@ -101,7 +133,7 @@ impl<'a> FileCreator<'a> {
         Ok(File {
             set: self.set,
             id,
-            base_address: self.base_address,
+            memory: self.memory,
         })
     }
 }
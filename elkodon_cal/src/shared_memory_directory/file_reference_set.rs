@@ -6,9 +6,23 @@ use crate::shared_memory_directory::MAX_NUMBER_OF_ENTRIES;
 use elkodon_bb_lock_free::mpmc::unique_index_set::FixedSizeUniqueIndexSet;
 use elkodon_bb_log::fail;
 use elkodon_bb_system_types::file_name::FileName;
+use std::alloc::Layout;
 use std::cell::UnsafeCell;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+/// The memory in which the payload of the files is stored.
+pub(crate) trait FileMemory {
+    /// The address to which the offsets of the files are relative to.
+    fn base_address(&self) -> usize;
+
+    /// Releases the payload of a file that was removed.
+    ///
+    /// # Safety
+    ///
+    ///  * the offset and layout must belong to a file that is no longer accessed by anyone
+    unsafe fn release(&self, offset: usize, layout: Layout);
+}
+
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct FileReferenceSetId(usize);
 
@@ -18,6 +32,7 @@ struct Entry {
     name: FileName,
     offset: usize,
     len: usize,
+    align: usize,
 }
 
 impl Entry {
@@ -26,8 +41,13 @@ impl Entry {
             name: unsafe { FileName::new_unchecked(b"empty") },
             offset: 0,
             len: 0,
+            align: 1,
         }
     }
+
+    fn layout(&self) -> Layout {
+        unsafe { Layout::from_size_align_unchecked(self.len, self.align) }
+    }
 }
 
 #[derive(Debug)]
@@ -43,32 +63,31 @@ pub(crate) struct FileReferenceSet {
 unsafe impl Send for FileReferenceSet {}
 unsafe impl Sync for FileReferenceSet {}
 
-impl Default for FileReferenceSet {
-    fn default() -> Self {
-        #[allow(clippy::declare_interior_mutable_const)]
-        const COUNTER: ReferenceCounter = ReferenceCounter::new(0);
-        #[allow(clippy::declare_interior_mutable_const)]
-        const DEFAULT_ENTRY: UnsafeCell<Entry> = UnsafeCell::new(Entry::default());
-        #[allow(clippy::declare_interior_mutable_const)]
-        const DECISION: DecisionCounter = DecisionCounter::new();
-
-        Self {
-            entries: [DEFAULT_ENTRY; MAX_NUMBER_OF_ENTRIES],
-            counter: [COUNTER; MAX_NUMBER_OF_ENTRIES],
-            decision_counter: [DECISION; MAX_NUMBER_OF_ENTRIES],
-            ids: FixedSizeUniqueIndexSet::new(),
-            global_decision_counter: AtomicU64::new(0),
+impl FileReferenceSet {
+    /// Initializes the [`FileReferenceSet`] in place. The set is too large to be constructed
+    /// on the stack and moved into the shared memory afterwards.
+    ///
+    /// # Safety
+    ///
+    ///  * `set` must point to memory that is large enough and correctly aligned for the set
+    pub(crate) unsafe fn init(set: *mut FileReferenceSet) {
+        for i in 0..MAX_NUMBER_OF_ENTRIES {
+            core::ptr::addr_of_mut!((*set).entries[i]).write(UnsafeCell::new(Entry::default()));
+            core::ptr::addr_of_mut!((*set).counter[i]).write(ReferenceCounter::new(0));
+            core::ptr::addr_of_mut!((*set).decision_counter[i]).write(DecisionCounter::new());
         }
+
+        core::ptr::addr_of_mut!((*set).ids).write(FixedSizeUniqueIndexSet::new());
+        core::ptr::addr_of_mut!((*set).global_decision_counter).write(AtomicU64::new(0));
     }
-}
 
-impl FileReferenceSet {
     pub(crate) fn insert(
         &self,
         name: &FileName,
         offset: usize,
-        len: usize,
+        layout: Layout,
         is_persistent: bool,
+        memory: &dyn FileMemory,
     ) -> Result<FileReferenceSetId, SharedMemoryDirectoryCreateFileError> {
         let msg = "Unable to insert file";
         let id = match unsafe { self.ids.acquire_raw_index() } {
@@ -84,7 +103,8 @@ impl FileReferenceSet {
             self.entries[id].get().write(Entry {
                 name: *name,
                 offset,
-                len,
+                len: layout.size(),
+                align: layout.align(),
             })
         };
 
@@ -92,6 +112,7 @@ impl FileReferenceSet {
         self.counter[id].increment_ref_counter();
         let current_decision_count = self.global_decision_counter.fetch_add(1, Ordering::Relaxed);
         if !self.decision_counter[id].set(current_decision_count) {
+            self.discard_entry(FileReferenceSetId(id), memory);
             fail!(from self, with SharedMemoryDirectoryCreateFileError::DoesExist,
                     "{} \"{}\" since the file already exists.", msg, *name);
         }
@@ -106,7 +127,11 @@ impl FileReferenceSet {
                 if unsafe { &*self.entries[i].get() }.name == *name
                     && !self.decision_counter[i].does_value_win(current_decision_count)
                 {
-                    if self.counter[i].is_initialized() {
+                    let is_initialized = self.counter[i].is_initialized();
+                    self.decrement_ref_counter(FileReferenceSetId(i), memory);
+                    self.discard_entry(FileReferenceSetId(id), memory);
+
+                    if is_initialized {
                         fail!(from self, with SharedMemoryDirectoryCreateFileError::DoesExist,
                         "{} \"{}\" since the file already exists.", msg, *name);
                     } else {
@@ -115,7 +140,7 @@ impl FileReferenceSet {
                     }
                 }
 
-                self.decrement_ref_counter(FileReferenceSetId(i));
+                self.decrement_ref_counter(FileReferenceSetId(i), memory);
             }
         }
 
@@ -123,11 +148,11 @@ impl FileReferenceSet {
     }
 
     // can only be called when the ownership is acquired
-    pub(crate) fn to_be_removed(&self, name: &FileName) -> bool {
-        if let Some(id) = self.find_entry(name) {
+    pub(crate) fn to_be_removed(&self, name: &FileName, memory: &dyn FileMemory) -> bool {
+        if let Some(id) = self.find_entry(name, memory) {
             self.counter[id.0].set_persistency_bit(false);
             self.counter[id.0].to_be_removed();
-            self.decrement_ref_counter(id);
+            self.decrement_ref_counter(id, memory);
             return true;
         }
 
@@ -139,26 +164,30 @@ impl FileReferenceSet {
         self.counter[id.0].set_initialized_bit(true);
     }
 
-    pub(crate) fn does_exist(&self, name: &FileName) -> bool {
-        if let Some(id) = self.find_entry(name) {
-            self.decrement_ref_counter(id);
+    pub(crate) fn does_exist(&self, name: &FileName, memory: &dyn FileMemory) -> bool {
+        if let Some(id) = self.find_entry(name, memory) {
+            self.decrement_ref_counter(id, memory);
             return true;
         }
 
         false
     }
 
-    pub(crate) fn borrow(&self, name: &FileName, base_address: usize) -> Option<File> {
-        self.find_entry(name).map(|id| File {
+    pub(crate) fn borrow<'a>(
+        &'a self,
+        name: &FileName,
+        memory: &'a dyn FileMemory,
+    ) -> Option<File<'a>> {
+        self.find_entry(name, memory).map(|id| File {
             set: self,
             id,
-            base_address,
+            memory,
         })
     }
 
     // can only be called when the ownership is acquired
-    pub(crate) fn release(&self, id: FileReferenceSetId) {
-        self.decrement_ref_counter(id)
+    pub(crate) fn release(&self, id: FileReferenceSetId, memory: &dyn FileMemory) {
+        self.decrement_ref_counter(id, memory)
     }
 
     // can only be called when the ownership is acquired
@@ -166,14 +195,14 @@ impl FileReferenceSet {
         self.counter[id.0].is_persistent()
     }
 
-    pub(crate) fn list(&self, base_address: usize) -> Vec<File> {
+    pub(crate) fn list<'a>(&'a self, memory: &'a dyn FileMemory) -> Vec<File<'a>> {
         let mut ret_val = vec![];
         for id in 0..self.ids.capacity() as usize {
             if self.counter[id].increment_ref_counter_when_initialized() {
                 ret_val.push(File {
                     set: self,
                     id: FileReferenceSetId(id),
-                    base_address,
+                    memory,
                 });
             }
         }
@@ -208,23 +237,31 @@ impl FileReferenceSet {
     #[deny(clippy::mut_from_ref)]
 
     // if entry exists it acquires read-only ownership and returns the id
-    fn find_entry(&self, name: &FileName) -> Option<FileReferenceSetId> {
+    fn find_entry(&self, name: &FileName, memory: &dyn FileMemory) -> Option<FileReferenceSetId> {
         for id in 0..self.ids.capacity() as usize {
             if self.counter[id].increment_ref_counter_when_initialized() {
                 if unsafe { *self.entries[id].get() }.name == *name {
                     return Some(FileReferenceSetId(id));
                 }
 
-                self.counter[id].decrement_ref_counter();
+                self.decrement_ref_counter(FileReferenceSetId(id), memory);
             }
         }
 
         None
     }
 
-    fn decrement_ref_counter(&self, id: FileReferenceSetId) {
+    // removes an entry that lost the race against another entry with the same name
+    fn discard_entry(&self, id: FileReferenceSetId, memory: &dyn FileMemory) {
+        self.counter[id.0].set_persistency_bit(false);
+        self.decrement_ref_counter(id, memory);
+    }
+
+    fn decrement_ref_counter(&self, id: FileReferenceSetId, memory: &dyn FileMemory) {
         if self.counter[id.0].decrement_ref_counter() {
-            // remove entry
+            // remove entry and release its payload
+            let entry = unsafe { *self.entries[id.0].get() };
+            unsafe { memory.release(entry.offset, entry.layout()) };
             self.counter[id.0].reset();
             self.decision_counter[id.0].set_to_undecided();
             unsafe { self.ids.release_raw_index(id.0 as u32) };
@@ -2,8 +2,9 @@ mod decision_counter;
 pub mod file;
 mod file_reference_set;
 mod reference_counter;
+pub(crate) mod registry;
 
-use crate::shared_memory_directory::file_reference_set::FileReferenceSet;
+use crate::shared_memory_directory::file_reference_set::{FileMemory, FileReferenceSet};
 use crate::shm_allocator::bump_allocator::BumpAllocator;
 use crate::{named_concept::*, shared_memory::*, shm_allocator::ShmAllocator};
 use elkodon_bb_elementary::math::align_to;
@@ -13,7 +14,7 @@ use std::{alloc::Layout, fmt::Debug, marker::PhantomData};
 
 use crate::shared_memory_directory::file::{File, FileCreator};
 
-const MAX_NUMBER_OF_ENTRIES: usize = 512;
+const MAX_NUMBER_OF_ENTRIES: usize = 4096;
 const MGMT_SHM_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".dm") };
 const DATA_SHM_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".dd") };
 
@@ -75,7 +76,7 @@ impl SharedMemoryDirectoryCreator {
                                 msg);
 
         let files = shm_ptr.data_ptr as *mut FileReferenceSet;
-        unsafe { files.write(FileReferenceSet::default()) };
+        unsafe { FileReferenceSet::init(files) };
 
         let mut data_shm = fail!(from self,
             when DataShm::Builder::new(&self.name).config(
@@ -155,6 +156,40 @@ impl<
     }
 }
 
+// the file reference set is thread-safe and resides like the shared memories in memory that
+// is shared between processes
+unsafe impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > Send for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+}
+
+unsafe impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > Sync for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+}
+
+impl<
+        MgmtShm: SharedMemory<BumpAllocator>,
+        Allocator: ShmAllocator,
+        DataShm: SharedMemory<Allocator>,
+    > FileMemory for SharedMemoryDirectory<MgmtShm, Allocator, DataShm>
+{
+    fn base_address(&self) -> usize {
+        self.data_shm.allocator_data_start_address()
+    }
+
+    unsafe fn release(&self, offset: usize, layout: Layout) {
+        fatal_panic!(from self, when self.data_shm.deallocate(PointerOffset::new(offset), layout),
+            "This should never happen! Unable to release the memory of a removed file.");
+    }
+}
+
 impl<
         MgmtShm: SharedMemory<BumpAllocator>,
         Allocator: ShmAllocator,
@@ -165,30 +200,25 @@ impl<
         let memory = fail!(from self, when self.data_shm.allocate(layout),
             "Unable to create file since the allocation of {:?} failed.", layout);
 
-        Ok(FileCreator::new(
-            self.files(),
-            memory,
-            layout,
-            self.data_shm.allocator_data_start_address(),
-        ))
+        Ok(FileCreator::new(self.files(), memory, layout, self))
     }
 
     pub fn open_file(&self, name: &FileName) -> Option<File> {
-        self.files()
-            .borrow(name, self.data_shm.allocator_data_start_address())
+        self.files().borrow(name, self)
     }
 
     pub fn list_files(&self) -> Vec<File> {
-        self.files()
-            .list(self.data_shm.allocator_data_start_address())
+        self.files().list(self)
     }
 
     pub fn does_file_exist(&self, name: &FileName) -> bool {
-        self.files().does_exist(name)
+        self.files().does_exist(name, self)
     }
 
+    /// Removes the file. It can no longer be opened but its payload is released when the last
+    /// [`File`] that refers to it goes out of scope.
     pub fn remove_file(&self, name: &FileName) -> bool {
-        self.files().to_be_removed(name)
+        self.files().to_be_removed(name, self)
     }
 
     pub fn file_capacity(&self) -> usize {
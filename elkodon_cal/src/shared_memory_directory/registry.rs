@@ -0,0 +1,180 @@
+//! Process wide registry of the [`SharedMemoryDirectory`]s which are used by concepts that place
+//! many small objects into one directory instead of creating one shared memory object per
+//! object. Every directory is mapped only once per process and shared by all users.
+
+use std::alloc::Layout;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use elkodon_bb_log::{fail, fatal_panic};
+use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use elkodon_bb_posix::mutex::{Mutex, MutexBuilder, MutexHandle};
+use elkodon_bb_system_types::file_name::FileName;
+use once_cell::sync::Lazy;
+use ouroboros::self_referencing;
+
+use crate::shared_memory::{posix, SharedMemoryCreateError, SharedMemoryOpenError};
+use crate::shared_memory_directory::file::File;
+use crate::shared_memory_directory::{SharedMemoryDirectory, SharedMemoryDirectoryCreator};
+use crate::shm_allocator::bump_allocator::BumpAllocator;
+use crate::shm_allocator::pool_allocator::{self, PoolAllocator};
+
+const MAX_CREATION_DURATION: Duration = Duration::from_millis(100);
+
+pub(crate) type Directory = SharedMemoryDirectory<
+    posix::Memory<BumpAllocator>,
+    PoolAllocator,
+    posix::Memory<PoolAllocator>,
+>;
+
+static REGISTRY_MTX_HANDLE: Lazy<MutexHandle<HashMap<FileName, Weak<Directory>>>> =
+    Lazy::new(MutexHandle::new);
+static REGISTRY: Lazy<Mutex<HashMap<FileName, Weak<Directory>>>> = Lazy::new(|| {
+    let result = MutexBuilder::new()
+        .is_interprocess_capable(false)
+        .create(HashMap::new(), &REGISTRY_MTX_HANDLE);
+
+    if result.is_err() {
+        fatal_panic!(from "SHARED_MEMORY_DIRECTORY_REGISTRY", "Failed to create the shared memory directory registry");
+    }
+
+    result.unwrap()
+});
+
+/// Describes the [`SharedMemoryDirectory`] a concept uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DirectoryConfig {
+    pub(crate) name: FileName,
+    pub(crate) size: usize,
+    pub(crate) bucket_layout: Layout,
+}
+
+/// Opens the [`SharedMemoryDirectory`] or creates it when it does not exist. A newly created
+/// directory is persistent so that it outlives the process which created it.
+pub(crate) fn open_or_create(
+    config: &DirectoryConfig,
+) -> Result<Arc<Directory>, SharedMemoryCreateError> {
+    let msg = "Unable to open or create shared memory directory";
+    let origin = "shared_memory_directory::registry::open_or_create()";
+    let mut guard = fail!(from origin, when REGISTRY.lock(), with SharedMemoryCreateError::InternalError,
+                        "{} \"{}\" since the registry lock could not be acquired.", msg, config.name);
+
+    if let Some(directory) = guard.get(&config.name).and_then(|d| d.upgrade()) {
+        return Ok(directory);
+    }
+
+    let mut adaptive_wait = fail!(from origin, when AdaptiveWaitBuilder::new().create(),
+                                with SharedMemoryCreateError::InternalError,
+                                "{} \"{}\" since the adaptive wait could not be created.", msg, config.name);
+
+    loop {
+        match SharedMemoryDirectoryCreator::new(&config.name).open() {
+            Ok(directory) => return Ok(register(&mut guard, &config.name, directory)),
+            Err(SharedMemoryOpenError::DoesNotExist) => {
+                match SharedMemoryDirectoryCreator::new(&config.name)
+                    .size(config.size)
+                    .is_persistent(true)
+                    .create(&pool_allocator::Config {
+                        bucket_layout: config.bucket_layout,
+                    }) {
+                    Ok(directory) => return Ok(register(&mut guard, &config.name, directory)),
+                    Err(SharedMemoryCreateError::AlreadyExists) => (),
+                    Err(e) => {
+                        fail!(from origin, with e,
+                            "{} \"{}\" since the directory could not be created ({:?}).", msg, config.name, e);
+                    }
+                }
+            }
+            // the directory exists but is still being created by another process
+            Err(SharedMemoryOpenError::InternalError) => (),
+            Err(e) => {
+                fail!(from origin, with SharedMemoryCreateError::InternalError,
+                    "{} \"{}\" since the existing directory could not be opened ({:?}).", msg, config.name, e);
+            }
+        }
+
+        if fail!(from origin, when adaptive_wait.wait(), with SharedMemoryCreateError::InternalError,
+                "{} \"{}\" since a failure while waiting for the creation of the directory occurred.", msg, config.name)
+            >= MAX_CREATION_DURATION
+        {
+            fail!(from origin, with SharedMemoryCreateError::InternalError,
+                "{} \"{}\" since the directory is still being created by another instance after {:?}.",
+                msg, config.name, MAX_CREATION_DURATION);
+        }
+    }
+}
+
+/// Opens an existing [`SharedMemoryDirectory`]. In contrast to [`open_or_create()`] it never
+/// creates a directory and is therefore used by all management functions.
+pub(crate) fn open(name: &FileName) -> Result<Arc<Directory>, SharedMemoryOpenError> {
+    let msg = "Unable to open shared memory directory";
+    let origin = "shared_memory_directory::registry::open()";
+    let mut guard = fail!(from origin, when REGISTRY.lock(), with SharedMemoryOpenError::InternalError,
+                        "{} \"{}\" since the registry lock could not be acquired.", msg, name);
+
+    if let Some(directory) = guard.get(name).and_then(|d| d.upgrade()) {
+        return Ok(directory);
+    }
+
+    Ok(register(
+        &mut guard,
+        name,
+        SharedMemoryDirectoryCreator::new(name).open()?,
+    ))
+}
+
+/// Opens an existing [`SharedMemoryDirectory`] for a management function of a concept. Returns
+/// [`None`] when it does not exist and `error` when it could not be opened.
+pub(crate) fn open_for_mgmt<E: Debug>(
+    name: &FileName,
+    error: E,
+    origin: &str,
+    msg: &str,
+) -> Result<Option<Arc<Directory>>, E> {
+    match open(name) {
+        Ok(directory) => Ok(Some(directory)),
+        Err(SharedMemoryOpenError::DoesNotExist) => Ok(None),
+        Err(e) => {
+            fail!(from origin, with error,
+                "{} since the directory \"{}\" could not be opened ({:?}).", msg, name, e);
+        }
+    }
+}
+
+fn register(
+    registry: &mut HashMap<FileName, Weak<Directory>>,
+    name: &FileName,
+    directory: Directory,
+) -> Arc<Directory> {
+    let directory = Arc::new(directory);
+    registry.retain(|_, d| d.strong_count() != 0);
+    registry.insert(*name, Arc::downgrade(&directory));
+    directory
+}
+
+/// A [`File`] that keeps the [`SharedMemoryDirectory`] it belongs to alive.
+#[self_referencing]
+#[derive(Debug)]
+pub(crate) struct DirectoryFile {
+    pub(crate) directory: Arc<Directory>,
+    #[borrows(directory)]
+    #[covariant]
+    pub(crate) file: File<'this>,
+}
+
+impl DirectoryFile {
+    /// Creates a [`DirectoryFile`] from the [`File`] the `file_builder` acquires from the
+    /// `directory`.
+    pub(crate) fn acquire<E>(
+        directory: Arc<Directory>,
+        file_builder: impl for<'this> FnOnce(&'this Arc<Directory>) -> Result<File<'this>, E>,
+    ) -> Result<Self, E> {
+        Self::try_new(directory, file_builder)
+    }
+
+    pub(crate) fn content_address(&self) -> usize {
+        self.borrow_file().content().as_ptr() as usize
+    }
+}
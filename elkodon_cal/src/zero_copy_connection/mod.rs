@@ -1,5 +1,7 @@
 pub mod posix_shared_memory;
 pub mod process_local;
+pub mod shared_memory_directory;
+mod used_offset_list;
 
 use std::fmt::Debug;
 
@@ -31,6 +33,7 @@ impl std::error::Error for ZeroCopyCreationError {}
 pub enum ZeroCopySendError {
     ReceiveBufferFull,
     ClearRetrieveChannelBeforeSend,
+    OffsetOutOfRange,
 }
 
 impl std::fmt::Display for ZeroCopySendError {
@@ -55,7 +58,9 @@ impl std::fmt::Display for ZeroCopyReceiveError {
 impl std::error::Error for ZeroCopyReceiveError {}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum ZeroCopyReclaimError {}
+pub enum ZeroCopyReclaimError {
+    ReceiverReturnedCorruptedOffset,
+}
 
 impl std::fmt::Display for ZeroCopyReclaimError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -78,9 +83,42 @@ impl std::fmt::Display for ZeroCopyReleaseError {
 
 impl std::error::Error for ZeroCopyReleaseError {}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyStateError {
+    DoesNotExist,
+    InsufficientPermissions,
+    InternalError,
+}
+
+impl std::fmt::Display for ZeroCopyStateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ZeroCopyStateError {}
+
+/// The state of a connection that can be acquired with [`ZeroCopyConnection::state_cfg()`]
+/// without connecting to it. It can be out of date as soon as it is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroCopyConnectionState {
+    /// The capacity of the receive buffer
+    pub buffer_size: usize,
+    /// The number of samples that were sent but not yet received
+    pub number_of_samples_in_buffer: usize,
+    /// The maximum number of samples the receiver can borrow in parallel
+    pub max_borrowed_samples: usize,
+    /// The number of samples the receiver has received but not yet released
+    pub number_of_borrowed_samples: usize,
+    /// The number of samples that were never received, either since they were discarded
+    /// because the receive buffer was full or since they were overridden in safe overflow mode
+    pub number_of_dropped_samples: u64,
+}
+
 pub const DEFAULT_BUFFER_SIZE: usize = 4;
 pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
 pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
+pub const DEFAULT_SAMPLE_SIZE: usize = 1;
 
 /// The default suffix of every zero copy connection
 pub const DEFAULT_SUFFIX: FileName = unsafe { FileName::new_unchecked(b".rx") };
@@ -92,6 +130,14 @@ pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<
     fn buffer_size(self, value: usize) -> Self;
     fn enable_safe_overflow(self, value: bool) -> Self;
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
+    /// Defines the number of samples in the data segment of the sender. When it is set, every
+    /// [`PointerOffset`] that is sent must point to one of them, otherwise the send call fails
+    /// with [`ZeroCopySendError::OffsetOutOfRange`]. When it is not set, any offset can be
+    /// sent.
+    fn number_of_samples(self, value: usize) -> Self;
+    /// Defines the size of a sample in the data segment of the sender. A [`PointerOffset`]
+    /// divided by it is the index of the sample it points to.
+    fn sample_size(self, value: usize) -> Self;
 
     fn create_sender(self) -> Result<C::Sender, ZeroCopyCreationError>;
     fn create_receiver(self) -> Result<C::Receiver, ZeroCopyCreationError>;
@@ -111,11 +157,31 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
         -> Result<Option<PointerOffset>, ZeroCopySendError>;
 
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
+
+    /// Calls `callback` for every [`PointerOffset`] that was sent and not yet reclaimed and
+    /// forgets about it. It is used to recover the samples of a receiver that disappeared
+    /// without returning them.
+    ///
+    /// # Safety
+    ///
+    ///  * the receiver must not release any [`PointerOffset`] anymore, otherwise it is handed
+    ///    out twice
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F);
 }
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
+
+    /// Calls `callback` for every [`PointerOffset`] that was released and not yet reclaimed
+    /// and forgets about it. It is used to recover the samples the receiver owns when the
+    /// sender disappeared without reclaiming them.
+    ///
+    /// # Safety
+    ///
+    ///  * the sender must not reclaim any [`PointerOffset`] anymore, otherwise it is handed
+    ///    out twice
+    unsafe fn acquire_released_offsets<F: FnMut(PointerOffset)>(&self, callback: F);
 }
 
 pub trait ZeroCopyConnection: Sized + NamedConceptMgmt {
@@ -132,4 +198,16 @@ pub trait ZeroCopyConnection: Sized + NamedConceptMgmt {
     fn has_configurable_buffer_size() -> bool {
         false
     }
+
+    /// Returns the [`ZeroCopyConnectionState`] of the connection with the default
+    /// configuration without connecting to it.
+    fn state(name: &FileName) -> Result<ZeroCopyConnectionState, ZeroCopyStateError> {
+        Self::state_cfg(name, &Self::Configuration::default())
+    }
+
+    /// Returns the [`ZeroCopyConnectionState`] of the connection without connecting to it.
+    fn state_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<ZeroCopyConnectionState, ZeroCopyStateError>;
 }
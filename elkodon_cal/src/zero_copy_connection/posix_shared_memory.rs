@@ -1,25 +1,28 @@
-use std::cell::UnsafeCell;
 use std::fmt::Debug;
 use std::ptr::NonNull;
-use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering};
 use std::time::Duration;
 
 use crate::named_concept::*;
+use crate::zero_copy_connection::used_offset_list::UsedOffsetList;
 pub use crate::zero_copy_connection::*;
 use elkodon_bb_elementary::relocatable_container::RelocatableContainer;
 use elkodon_bb_lock_free::spsc::{
     index_queue::RelocatableIndexQueue,
     safely_overflowing_index_queue::RelocatableSafelyOverflowingIndexQueue,
 };
-use elkodon_bb_log::{error, fail, fatal_panic};
+use elkodon_bb_log::{error, fail, fatal_panic, warn};
 use elkodon_bb_memory::bump_allocator::BumpAllocator;
+use elkodon_bb_posix::access_mode::AccessMode;
 use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
 use elkodon_bb_posix::creation_mode::CreationMode;
 use elkodon_bb_posix::permission::Permission;
-use elkodon_bb_posix::shared_memory::{SharedMemory, SharedMemoryBuilder};
+use elkodon_bb_posix::shared_memory::{
+    SharedMemory, SharedMemoryBuilder, SharedMemoryCreationError,
+};
 
 const MAX_CREATION_DURATION: Duration = Duration::from_millis(10);
-const IS_INITIALIZED_STATE_VALUE: u64 = 0xbeefaffedeadbeef;
+pub(crate) const IS_INITIALIZED_STATE_VALUE: u64 = 0xbeefaffedeadbeef;
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct Configuration {
@@ -58,7 +61,7 @@ impl NamedConceptConfiguration for Configuration {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
-enum State {
+pub(crate) enum State {
     None = 0b00000000,
     Sender = 0b00000001,
     Receiver = 0b00000010,
@@ -66,7 +69,7 @@ enum State {
 }
 
 impl State {
-    fn value(&self) -> u8 {
+    pub(crate) fn value(&self) -> u8 {
         *self as u8
     }
 }
@@ -79,29 +82,7 @@ fn cleanup_shared_memory<T: Debug>(
     let mgmt_ref =
         unsafe { &*(shared_memory.base_address().as_ptr() as *const SharedManagementData) };
 
-    let mut current_state = mgmt_ref.state.load(Ordering::Relaxed);
-    loop {
-        let new_state = if current_state == state_to_remove.value() {
-            State::MarkedForDestruction.value()
-        } else {
-            current_state & !state_to_remove.value()
-        };
-
-        match mgmt_ref.state.compare_exchange(
-            current_state,
-            new_state,
-            Ordering::Relaxed,
-            Ordering::Relaxed,
-        ) {
-            Ok(_) => {
-                current_state = new_state;
-                break;
-            }
-            Err(s) => {
-                current_state = s;
-            }
-        }
-    }
+    let current_state = mgmt_ref.release_port(state_to_remove, true);
 
     if current_state == State::MarkedForDestruction.value()
         && SharedMemory::remove(shared_memory.name()).is_err()
@@ -110,11 +91,15 @@ fn cleanup_shared_memory<T: Debug>(
     }
 }
 
+/// The management data of a connection that resides in memory which is shared between the
+/// sender and the receiver. The channels are placed in the memory directly behind it.
 #[repr(C)]
-struct SharedManagementData {
+pub(crate) struct SharedManagementData {
     receive_channel: RelocatableSafelyOverflowingIndexQueue,
     retrieve_channel: RelocatableIndexQueue,
     max_borrowed_samples: usize,
+    borrowed_samples: AtomicUsize,
+    dropped_samples: AtomicU64,
     state: AtomicU8,
     init_state: AtomicU64,
     enable_safe_overflow: bool,
@@ -138,10 +123,12 @@ impl SharedManagementData {
             init_state: AtomicU64::new(0),
             enable_safe_overflow,
             max_borrowed_samples,
+            borrowed_samples: AtomicUsize::new(0),
+            dropped_samples: AtomicU64::new(0),
         }
     }
 
-    const fn const_memory_size(
+    pub(crate) const fn const_memory_size(
         receive_channel_buffer_size: usize,
         retrieve_channel_buffer_size: usize,
     ) -> usize {
@@ -151,6 +138,295 @@ impl SharedManagementData {
             + RelocatableIndexQueue::const_memory_size(retrieve_channel_buffer_size)
             + RelocatableSafelyOverflowingIndexQueue::const_memory_size(receive_channel_buffer_size)
     }
+
+    /// Constructs the management data and its channels in place and marks it as initialized.
+    ///
+    /// # Safety
+    ///
+    ///  * `mgmt_ptr` must point to memory of at least [`SharedManagementData::const_memory_size()`]
+    ///    bytes
+    pub(crate) unsafe fn init<T: Debug>(
+        origin: &T,
+        mgmt_ptr: *mut SharedManagementData,
+        receive_channel_buffer_size: usize,
+        retrieve_channel_buffer_size: usize,
+        enable_safe_overflow: bool,
+        max_borrowed_samples: usize,
+    ) {
+        let msg = "Failed to set up newly created connection";
+        mgmt_ptr.write(SharedManagementData::new(
+            receive_channel_buffer_size,
+            retrieve_channel_buffer_size,
+            enable_safe_overflow,
+            max_borrowed_samples,
+        ));
+
+        let supplementary_ptr =
+            (mgmt_ptr as usize + std::mem::size_of::<SharedManagementData>()) as *mut u8;
+        let supplementary_len =
+            Self::const_memory_size(receive_channel_buffer_size, retrieve_channel_buffer_size)
+                - std::mem::size_of::<SharedManagementData>();
+
+        let allocator =
+            BumpAllocator::new(NonNull::new_unchecked(supplementary_ptr), supplementary_len);
+
+        fatal_panic!(from origin, when unsafe { (*mgmt_ptr).receive_channel.init(&allocator) },
+                    "{} since the receive channel allocation failed. - This is an implementation bug!", msg);
+        fatal_panic!(from origin, when unsafe { (*mgmt_ptr).retrieve_channel.init(&allocator) },
+                    "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
+
+        (*mgmt_ptr)
+            .init_state
+            .store(IS_INITIALIZED_STATE_VALUE, Ordering::Relaxed);
+    }
+
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.init_state.load(Ordering::Relaxed) == IS_INITIALIZED_STATE_VALUE
+    }
+
+    /// Verifies that the settings of an existing connection are compatible with the requested
+    /// ones.
+    pub(crate) fn verify_compatibility<T: Debug>(
+        &self,
+        origin: &T,
+        receive_channel_buffer_size: usize,
+        retrieve_channel_buffer_size: usize,
+        enable_safe_overflow: bool,
+        max_borrowed_samples: usize,
+    ) -> Result<(), ZeroCopyCreationError> {
+        let msg = "Failed to open existing connection";
+
+        if self.receive_channel.capacity() != receive_channel_buffer_size {
+            fail!(from origin, with ZeroCopyCreationError::IncompatibleBufferSize,
+                "{} since the connection has a buffer size of {} but a buffer size of {} is required.",
+                msg, self.receive_channel.capacity(), receive_channel_buffer_size);
+        }
+
+        if self.retrieve_channel.capacity() != retrieve_channel_buffer_size {
+            fail!(from origin, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
+                "{} since the max borrowed sample setting is set to {} but a value of {} is required.",
+                msg, self.retrieve_channel.capacity() - self.receive_channel.capacity(), max_borrowed_samples);
+        }
+
+        if self.enable_safe_overflow != enable_safe_overflow {
+            fail!(from origin, with ZeroCopyCreationError::IncompatibleOverflowSetting,
+                "{} since the safe overflow is set to {} but should be set to {}.",
+                msg, self.enable_safe_overflow, enable_safe_overflow);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn reserve_port<T: Debug>(
+        &self,
+        origin: &T,
+        new_state: u8,
+        msg: &str,
+    ) -> Result<(), ZeroCopyCreationError> {
+        let mut current_state = State::None.value();
+
+        loop {
+            match self.state.compare_exchange(
+                current_state,
+                current_state | new_state,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break,
+                Err(v) => {
+                    current_state = v;
+                    if current_state & new_state != 0 {
+                        fail!(from origin, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
+                            "{} since an instance is already connected.", msg);
+                    } else if current_state & State::MarkedForDestruction.value() != 0 {
+                        fail!(from origin, with ZeroCopyCreationError::InternalError,
+                            "{} since the connection is currently being cleaned up.", msg);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes the port from the connection and returns the remaining state.
+    pub(crate) fn release_port(&self, state_to_remove: State, mark_for_destruction: bool) -> u8 {
+        let mut current_state = self.state.load(Ordering::Relaxed);
+        loop {
+            let new_state = if current_state == state_to_remove.value() && mark_for_destruction {
+                State::MarkedForDestruction.value()
+            } else {
+                current_state & !state_to_remove.value()
+            };
+
+            match self.state.compare_exchange(
+                current_state,
+                new_state,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return new_state,
+                Err(s) => {
+                    current_state = s;
+                }
+            }
+        }
+    }
+
+    pub(crate) fn buffer_size(&self) -> usize {
+        self.receive_channel.capacity()
+    }
+
+    pub(crate) fn max_borrowed_samples(&self) -> usize {
+        self.max_borrowed_samples
+    }
+
+    pub(crate) fn has_enabled_safe_overflow(&self) -> bool {
+        self.enable_safe_overflow
+    }
+
+    pub(crate) fn is_connected(&self) -> bool {
+        self.state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
+    }
+
+    pub(crate) fn try_send<T: Debug>(
+        &self,
+        origin: &T,
+        used_offsets: &UsedOffsetList,
+        ptr: PointerOffset,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        let msg = "Unable to send sample";
+        let space_in_retrieve_channel =
+            self.retrieve_channel.capacity() - self.retrieve_channel.len();
+
+        if space_in_retrieve_channel <= self.max_borrowed_samples + self.receive_channel.len() {
+            fail!(from origin, with ZeroCopySendError::ClearRetrieveChannelBeforeSend,
+                "{} since sufficient space for every sample in the retrieve channel cannot be guaranteed. Samples have to be retrieved before a new sample can be send.", msg);
+        }
+
+        if !self.enable_safe_overflow && self.receive_channel.is_full() {
+            self.dropped_samples.fetch_add(1, Ordering::Relaxed);
+            fail!(from origin, with ZeroCopySendError::ReceiveBufferFull,
+                             "{} since the receive buffer is full.", msg);
+        }
+
+        if !used_offsets.insert(ptr) {
+            fail!(from origin, with ZeroCopySendError::OffsetOutOfRange,
+                "{} since the offset {:?} does not point to a sample of the data segment.", msg, ptr);
+        }
+
+        match unsafe { self.receive_channel.push(ptr.value()) } {
+            Some(v) => {
+                let overflow = PointerOffset::new(v);
+                self.dropped_samples.fetch_add(1, Ordering::Relaxed);
+                if !used_offsets.remove(overflow) {
+                    warn!(from origin, "The receive buffer contained the offset {:?} which was never sent. It is not handed out.", overflow);
+                    return Ok(None);
+                }
+                Ok(Some(overflow))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn blocking_send<T: Debug>(
+        &self,
+        origin: &T,
+        used_offsets: &UsedOffsetList,
+        ptr: PointerOffset,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        if !self.enable_safe_overflow {
+            AdaptiveWaitBuilder::new()
+                .create()
+                .unwrap()
+                .wait_while(|| self.receive_channel.is_full())
+                .unwrap();
+        }
+
+        self.try_send(origin, used_offsets, ptr)
+    }
+
+    pub(crate) fn reclaim<T: Debug>(
+        &self,
+        origin: &T,
+        used_offsets: &UsedOffsetList,
+    ) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+        match unsafe { self.retrieve_channel.pop() } {
+            None => Ok(None),
+            Some(v) => {
+                let offset = PointerOffset::new(v);
+                if !used_offsets.remove(offset) {
+                    fail!(from origin, with ZeroCopyReclaimError::ReceiverReturnedCorruptedOffset,
+                        "Unable to reclaim sample since the receiver returned the offset {:?} which was never sent or already reclaimed.", offset);
+                }
+                Ok(Some(offset))
+            }
+        }
+    }
+
+    pub(crate) unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(
+        &self,
+        used_offsets: &UsedOffsetList,
+        callback: F,
+    ) {
+        // the receiver is gone, everything in the retrieve channel is contained in the used
+        // offsets as well
+        while self.retrieve_channel.pop().is_some() {}
+        used_offsets.drain(callback);
+    }
+
+    pub(crate) unsafe fn acquire_released_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+        while let Some(v) = self.retrieve_channel.pop() {
+            callback(PointerOffset::new(v));
+        }
+    }
+
+    pub(crate) fn receive<T: Debug>(
+        &self,
+        origin: &T,
+    ) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+        let borrowed_samples = self.borrowed_samples.load(Ordering::Relaxed);
+        if borrowed_samples >= self.max_borrowed_samples {
+            fail!(from origin, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
+                "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
+                    borrowed_samples, self.max_borrowed_samples);
+        }
+
+        match unsafe { self.receive_channel.pop() } {
+            None => Ok(None),
+            Some(v) => {
+                self.borrowed_samples.fetch_add(1, Ordering::Relaxed);
+                Ok(Some(PointerOffset::new(v)))
+            }
+        }
+    }
+
+    pub(crate) fn release<T: Debug>(
+        &self,
+        origin: &T,
+        ptr: PointerOffset,
+    ) -> Result<(), ZeroCopyReleaseError> {
+        match unsafe { self.retrieve_channel.push(ptr.value()) } {
+            true => {
+                self.borrowed_samples.fetch_sub(1, Ordering::Relaxed);
+                Ok(())
+            }
+            false => {
+                fail!(from origin, with ZeroCopyReleaseError::RetrieveBufferFull,
+                    "Unable to release pointer since the retrieve buffer is full.");
+            }
+        }
+    }
+
+    pub(crate) fn connection_state(&self) -> ZeroCopyConnectionState {
+        ZeroCopyConnectionState {
+            buffer_size: self.receive_channel.capacity(),
+            number_of_samples_in_buffer: self.receive_channel.len(),
+            max_borrowed_samples: self.max_borrowed_samples,
+            number_of_borrowed_samples: self.borrowed_samples.load(Ordering::Relaxed),
+            number_of_dropped_samples: self.dropped_samples.load(Ordering::Relaxed),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -159,6 +435,8 @@ pub struct Builder {
     buffer_size: usize,
     enable_safe_overflow: bool,
     max_borrowed_samples: usize,
+    number_of_samples: Option<usize>,
+    sample_size: usize,
     config: Configuration,
 }
 
@@ -191,34 +469,15 @@ impl Builder {
         let mgmt_ptr = shm.base_address().as_ptr() as *mut SharedManagementData;
         match shm.has_ownership() {
             true => {
-                let msg = "Failed to set up newly created connection";
                 unsafe {
-                    mgmt_ptr.write(SharedManagementData::new(
+                    SharedManagementData::init(
+                        self,
+                        mgmt_ptr,
                         self.receive_channel_size(),
                         self.retrieve_channel_size(),
                         self.enable_safe_overflow,
                         self.max_borrowed_samples,
-                    ))
-                };
-
-                let supplementary_ptr =
-                    (mgmt_ptr as usize + std::mem::size_of::<SharedManagementData>()) as *mut u8;
-                let supplementary_len = shm_size - std::mem::size_of::<SharedManagementData>();
-
-                let allocator = BumpAllocator::new(
-                    unsafe { NonNull::new_unchecked(supplementary_ptr) },
-                    supplementary_len,
-                );
-
-                fatal_panic!(from self, when unsafe { (*mgmt_ptr).receive_channel.init(&allocator) },
-                            "{} since the receive channel allocation failed. - This is an implementation bug!", msg);
-                fatal_panic!(from self, when unsafe { (*mgmt_ptr).retrieve_channel.init(&allocator) },
-                            "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
-
-                unsafe {
-                    (*mgmt_ptr)
-                        .init_state
-                        .store(IS_INITIALIZED_STATE_VALUE, Ordering::Relaxed)
+                    )
                 };
                 shm.release_ownership();
             }
@@ -228,8 +487,8 @@ impl Builder {
                 let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
                                             with ZeroCopyCreationError::InternalError, "{} since the adaptive wait could not be created.", msg);
 
-                let mgmt_ref = unsafe { &mut *mgmt_ptr };
-                while mgmt_ref.init_state.load(Ordering::Relaxed) != IS_INITIALIZED_STATE_VALUE {
+                let mgmt_ref = unsafe { &*mgmt_ptr };
+                while !mgmt_ref.is_initialized() {
                     if fail!(from self, when adaptive_wait.wait(), with ZeroCopyCreationError::InternalError,
                             "{} since a failure while waiting for creation finalization occurred.", msg)
                         < MAX_CREATION_DURATION
@@ -238,60 +497,18 @@ impl Builder {
                     }
                 }
 
-                if mgmt_ref.receive_channel.capacity() != self.receive_channel_size() {
-                    fail!(from self, with ZeroCopyCreationError::IncompatibleBufferSize,
-                        "{} since the connection has a buffer size of {} but a buffer size of {} is required.",
-                        msg, mgmt_ref.receive_channel.capacity(), self.receive_channel_size());
-                }
-
-                if mgmt_ref.retrieve_channel.capacity() != self.retrieve_channel_size() {
-                    fail!(from self, with ZeroCopyCreationError::IncompatibleMaxBorrowedSampleSetting,
-                        "{} since the max borrowed sample setting is set to {} but a value of {} is required.",
-                        msg, mgmt_ref.retrieve_channel.capacity() - mgmt_ref.receive_channel.capacity(), self.max_borrowed_samples);
-                }
-
-                if mgmt_ref.enable_safe_overflow != self.enable_safe_overflow {
-                    fail!(from self, with ZeroCopyCreationError::IncompatibleOverflowSetting,
-                        "{} since the safe overflow is set to {} but should be set to {}.",
-                        msg, mgmt_ref.enable_safe_overflow, self.enable_safe_overflow);
-                }
+                mgmt_ref.verify_compatibility(
+                    self,
+                    self.receive_channel_size(),
+                    self.retrieve_channel_size(),
+                    self.enable_safe_overflow,
+                    self.max_borrowed_samples,
+                )?;
             }
         }
 
         Ok(shm)
     }
-
-    fn reserve_port(
-        &self,
-        mgmt_ref: &mut SharedManagementData,
-        new_state: u8,
-        msg: &str,
-    ) -> Result<(), ZeroCopyCreationError> {
-        let mut current_state = State::None.value();
-
-        loop {
-            match mgmt_ref.state.compare_exchange(
-                current_state,
-                current_state | new_state,
-                Ordering::Relaxed,
-                Ordering::Relaxed,
-            ) {
-                Ok(_) => break,
-                Err(v) => {
-                    current_state = v;
-                    if current_state & new_state != 0 {
-                        fail!(from self, with ZeroCopyCreationError::AnotherInstanceIsAlreadyConnected,
-                            "{} since an instance is already connected.", msg);
-                    } else if current_state & State::MarkedForDestruction.value() != 0 {
-                        fail!(from self, with ZeroCopyCreationError::InternalError,
-                            "{} since the connection is currently being cleaned up.", msg);
-                    }
-                }
-            }
-        }
-
-        Ok(())
-    }
 }
 
 impl NamedConceptBuilder<Connection> for Builder {
@@ -301,6 +518,8 @@ impl NamedConceptBuilder<Connection> for Builder {
             buffer_size: DEFAULT_BUFFER_SIZE,
             enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
             max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            number_of_samples: None,
+            sample_size: DEFAULT_SAMPLE_SIZE,
             config: Configuration::default(),
         }
     }
@@ -327,17 +546,32 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         self
     }
 
+    fn number_of_samples(mut self, value: usize) -> Self {
+        self.number_of_samples = Some(value);
+        self
+    }
+
+    fn sample_size(mut self, value: usize) -> Self {
+        self.sample_size = value;
+        self
+    }
+
     fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
         let msg = "Unable to create sender";
         let shm = fail!(from self, when self.create_or_open_shm(),
             "{} since the corresponding connection could not be created or opened", msg);
 
-        let mgmt_ref = unsafe { &mut *(shm.base_address().as_ptr() as *mut SharedManagementData) };
-        self.reserve_port(mgmt_ref, State::Sender.value(), msg)?;
+        let mgmt_ref = unsafe { &*(shm.base_address().as_ptr() as *const SharedManagementData) };
+        mgmt_ref.reserve_port(&self, State::Sender.value(), msg)?;
 
         Ok(Sender {
             shared_memory: shm,
             name: self.name,
+            used_offsets: UsedOffsetList::new(
+                self.number_of_samples,
+                self.sample_size,
+                self.retrieve_channel_size(),
+            ),
         })
     }
 
@@ -348,12 +582,11 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         let shm = fail!(from self, when self.create_or_open_shm(),
             "{} since the corresponding connection could not be created or opened", msg);
 
-        let mgmt_ref = unsafe { &mut *(shm.base_address().as_ptr() as *mut SharedManagementData) };
-        self.reserve_port(mgmt_ref, State::Receiver.value(), msg)?;
+        let mgmt_ref = unsafe { &*(shm.base_address().as_ptr() as *const SharedManagementData) };
+        mgmt_ref.reserve_port(&self, State::Receiver.value(), msg)?;
 
         Ok(Receiver {
             shared_memory: shm,
-            borrow_counter: UnsafeCell::new(0),
             name: self.name,
         })
     }
@@ -363,6 +596,7 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 pub struct Sender {
     shared_memory: SharedMemory,
     name: FileName,
+    used_offsets: UsedOffsetList,
 }
 
 impl Drop for Sender {
@@ -385,73 +619,47 @@ impl NamedConcept for Sender {
 
 impl ZeroCopyPortDetails for Sender {
     fn buffer_size(&self) -> usize {
-        self.mgmt().receive_channel.capacity()
+        self.mgmt().buffer_size()
     }
 
     fn max_borrowed_samples(&self) -> usize {
-        self.mgmt().max_borrowed_samples
+        self.mgmt().max_borrowed_samples()
     }
 
     fn has_enabled_safe_overflow(&self) -> bool {
-        self.mgmt().enable_safe_overflow
+        self.mgmt().has_enabled_safe_overflow()
     }
 
     fn is_connected(&self) -> bool {
-        self.mgmt().state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
+        self.mgmt().is_connected()
     }
 }
 
 impl ZeroCopySender for Sender {
     fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
-        let msg = "Unable to send sample";
-        let space_in_retrieve_channel =
-            self.mgmt().retrieve_channel.capacity() - self.mgmt().retrieve_channel.len();
-
-        if space_in_retrieve_channel
-            <= self.mgmt().max_borrowed_samples + self.mgmt().receive_channel.len()
-        {
-            fail!(from self, with ZeroCopySendError::ClearRetrieveChannelBeforeSend,
-                "{} since sufficient space for every sample in the retrieve channel cannot be guaranteed. Samples have to be retrieved before a new sample can be send.", msg);
-        }
-
-        if !self.mgmt().enable_safe_overflow && self.mgmt().receive_channel.is_full() {
-            fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
-                             "{} since the receive buffer is full.", msg);
-        }
-
-        match unsafe { self.mgmt().receive_channel.push(ptr.value()) } {
-            Some(v) => Ok(Some(PointerOffset::new(v))),
-            None => Ok(None),
-        }
+        self.mgmt().try_send(self, &self.used_offsets, ptr)
     }
 
     fn blocking_send(
         &self,
         ptr: PointerOffset,
     ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
-        if !self.mgmt().enable_safe_overflow {
-            AdaptiveWaitBuilder::new()
-                .create()
-                .unwrap()
-                .wait_while(|| self.mgmt().receive_channel.is_full())
-                .unwrap();
-        }
-
-        self.try_send(ptr)
+        self.mgmt().blocking_send(self, &self.used_offsets, ptr)
     }
 
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
-        match unsafe { self.mgmt().retrieve_channel.pop() } {
-            None => Ok(None),
-            Some(v) => Ok(Some(PointerOffset::new(v))),
-        }
+        self.mgmt().reclaim(self, &self.used_offsets)
+    }
+
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F) {
+        self.mgmt()
+            .acquire_used_offsets(&self.used_offsets, callback)
     }
 }
 
 #[derive(Debug)]
 pub struct Receiver {
     shared_memory: SharedMemory,
-    borrow_counter: UnsafeCell<usize>,
     name: FileName,
 }
 
@@ -465,15 +673,6 @@ impl Receiver {
     fn mgmt(&self) -> &SharedManagementData {
         unsafe { &*(self.shared_memory.base_address().as_ptr() as *const SharedManagementData) }
     }
-
-    #[allow(clippy::mut_from_ref)]
-    // convenience to access internal mutable object
-    fn borrow_counter(&self) -> &mut usize {
-        #[deny(clippy::mut_from_ref)]
-        unsafe {
-            &mut *self.borrow_counter.get()
-        }
-    }
 }
 
 impl NamedConcept for Receiver {
@@ -484,50 +683,33 @@ impl NamedConcept for Receiver {
 
 impl ZeroCopyPortDetails for Receiver {
     fn buffer_size(&self) -> usize {
-        self.mgmt().receive_channel.capacity()
+        self.mgmt().buffer_size()
     }
 
     fn max_borrowed_samples(&self) -> usize {
-        self.mgmt().max_borrowed_samples
+        self.mgmt().max_borrowed_samples()
     }
 
     fn has_enabled_safe_overflow(&self) -> bool {
-        self.mgmt().enable_safe_overflow
+        self.mgmt().has_enabled_safe_overflow()
     }
 
     fn is_connected(&self) -> bool {
-        self.mgmt().state.load(Ordering::Relaxed) == State::Sender.value() | State::Receiver.value()
+        self.mgmt().is_connected()
     }
 }
 
 impl ZeroCopyReceiver for Receiver {
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
-        if *self.borrow_counter() >= self.mgmt().max_borrowed_samples {
-            fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
-                "Unable to receive another sample since already {} samples were borrowed and this would exceed the max borrow value of {}.",
-                    self.borrow_counter(), self.max_borrowed_samples());
-        }
-
-        match unsafe { self.mgmt().receive_channel.pop() } {
-            None => Ok(None),
-            Some(v) => {
-                *self.borrow_counter() += 1;
-                Ok(Some(PointerOffset::new(v)))
-            }
-        }
+        self.mgmt().receive(self)
     }
 
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
-        match unsafe { self.mgmt().retrieve_channel.push(ptr.value()) } {
-            true => {
-                *self.borrow_counter() -= 1;
-                Ok(())
-            }
-            false => {
-                fail!(from self, with ZeroCopyReleaseError::RetrieveBufferFull,
-                    "Unable to release pointer since the retrieve buffer is full.");
-            }
-        }
+        self.mgmt().release(self, ptr)
+    }
+
+    unsafe fn acquire_released_offsets<F: FnMut(PointerOffset)>(&self, callback: F) {
+        self.mgmt().acquire_released_offsets(callback)
     }
 }
 
@@ -596,4 +778,42 @@ impl ZeroCopyConnection for Connection {
     fn has_configurable_buffer_size() -> bool {
         true
     }
+
+    fn state_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<ZeroCopyConnectionState, ZeroCopyStateError> {
+        let full_name = unsafe { FileName::new_unchecked(config.path_for(name).file_name()) };
+        let msg = "Unable to acquire the state of zero_copy_connection::posix_shared_memory";
+        let origin = "zero_copy_connection::posix_shared_memory::Connection::state_cfg()";
+
+        let shm = match SharedMemoryBuilder::new(&full_name).open_existing(AccessMode::Read) {
+            Ok(shm) => shm,
+            Err(SharedMemoryCreationError::DoesNotExist) => {
+                fail!(from origin, with ZeroCopyStateError::DoesNotExist,
+                    "{} \"{}\" since it does not exist.", msg, name);
+            }
+            Err(SharedMemoryCreationError::InsufficientPermissions) => {
+                fail!(from origin, with ZeroCopyStateError::InsufficientPermissions,
+                    "{} \"{}\" due to insufficient permissions.", msg, name);
+            }
+            Err(e) => {
+                fail!(from origin, with ZeroCopyStateError::InternalError,
+                    "{} \"{}\" due to an internal failure ({:?}).", msg, name, e);
+            }
+        };
+
+        if shm.size() < std::mem::size_of::<SharedManagementData>() {
+            fail!(from origin, with ZeroCopyStateError::InternalError,
+                "{} \"{}\" since the underlying shared memory has an unexpected size of {}.", msg, name, shm.size());
+        }
+
+        let mgmt_ref = unsafe { &*(shm.base_address().as_ptr() as *const SharedManagementData) };
+        if !mgmt_ref.is_initialized() {
+            fail!(from origin, with ZeroCopyStateError::DoesNotExist,
+                "{} \"{}\" since it is not yet initialized.", msg, name);
+        }
+
+        Ok(mgmt_ref.connection_state())
+    }
 }
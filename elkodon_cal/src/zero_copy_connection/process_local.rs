@@ -1,19 +1,19 @@
 use crate::named_concept::*;
+use crate::zero_copy_connection::used_offset_list::UsedOffsetList;
 pub use crate::zero_copy_connection::*;
 use elkodon_bb_lock_free::spsc::{
     index_queue::IndexQueue, safely_overflowing_index_queue::SafelyOverflowingIndexQueue,
 };
-use elkodon_bb_log::{error, fail, fatal_panic};
+use elkodon_bb_log::{error, fail, fatal_panic, warn};
 use elkodon_bb_posix::{
     adaptive_wait::AdaptiveWaitBuilder,
     mutex::{Mutex, MutexBuilder, MutexHandle},
 };
 use once_cell::sync::Lazy;
 use std::{
-    cell::UnsafeCell,
     collections::HashMap,
     sync::{
-        atomic::{AtomicU8, Ordering},
+        atomic::{AtomicU64, AtomicU8, AtomicUsize, Ordering},
         Arc,
     },
 };
@@ -32,6 +32,8 @@ struct Management {
     retrieve_channel: IndexQueue,
     enable_safe_overflow: bool,
     max_borrowed_samples: usize,
+    borrowed_samples: AtomicUsize,
+    dropped_samples: AtomicU64,
     state: AtomicU8,
 }
 
@@ -90,6 +92,8 @@ pub struct Builder {
     buffer_size: usize,
     enable_safe_overflow: bool,
     max_borrowed_samples: usize,
+    number_of_samples: Option<usize>,
+    sample_size: usize,
     config: Configuration,
 }
 
@@ -129,6 +133,8 @@ impl NamedConceptBuilder<Connection> for Builder {
             buffer_size: DEFAULT_BUFFER_SIZE,
             enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
             max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            number_of_samples: None,
+            sample_size: DEFAULT_SAMPLE_SIZE,
             config: Configuration::default(),
         }
     }
@@ -155,6 +161,16 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
         self
     }
 
+    fn number_of_samples(mut self, value: usize) -> Self {
+        self.number_of_samples = Some(value);
+        self
+    }
+
+    fn sample_size(mut self, value: usize) -> Self {
+        self.sample_size = value;
+        self
+    }
+
     fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
         let msg = "Unable to create sender";
         let mut guard = fail!(from self, when PROCESS_LOCAL_STORAGE.lock(),
@@ -178,6 +194,11 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 
                 Ok(Sender {
                     mgmt: entry.clone(),
+                    used_offsets: UsedOffsetList::new(
+                        self.number_of_samples,
+                        self.sample_size,
+                        self.retrieve_channel_size(),
+                    ),
                     config: self.config,
                 })
             }
@@ -188,12 +209,19 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
                     retrieve_channel: IndexQueue::new(self.retrieve_channel_size()),
                     enable_safe_overflow: self.enable_safe_overflow,
                     max_borrowed_samples: self.max_borrowed_samples,
+                    borrowed_samples: AtomicUsize::new(0),
+                    dropped_samples: AtomicU64::new(0),
                     state: AtomicU8::new(State::Sender as u8),
                 });
                 guard.insert(full_path, entry.clone());
 
                 Ok(Sender {
                     mgmt: entry,
+                    used_offsets: UsedOffsetList::new(
+                        self.number_of_samples,
+                        self.sample_size,
+                        self.retrieve_channel_size(),
+                    ),
                     config: self.config,
                 })
             }
@@ -223,7 +251,6 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 
                 Ok(Receiver {
                     mgmt: entry.clone(),
-                    borrow_counter: UnsafeCell::new(0),
                     config: self.config,
                 })
             }
@@ -234,13 +261,14 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
                     retrieve_channel: IndexQueue::new(self.retrieve_channel_size()),
                     enable_safe_overflow: self.enable_safe_overflow,
                     max_borrowed_samples: self.max_borrowed_samples,
+                    borrowed_samples: AtomicUsize::new(0),
+                    dropped_samples: AtomicU64::new(0),
                     state: AtomicU8::new(State::Receiver as u8),
                 });
                 guard.insert(full_path, entry.clone());
 
                 Ok(Receiver {
                     mgmt: entry,
-                    borrow_counter: UnsafeCell::new(0),
                     config: self.config,
                 })
             }
@@ -252,6 +280,7 @@ impl ZeroCopyConnectionBuilder<Connection> for Builder {
 pub struct Sender {
     mgmt: Arc<Management>,
     config: Configuration,
+    used_offsets: UsedOffsetList,
 }
 
 impl Drop for Sender {
@@ -299,12 +328,26 @@ impl ZeroCopySender for Sender {
         }
 
         if !self.mgmt.enable_safe_overflow && self.mgmt.receive_channel.is_full() {
+            self.mgmt.dropped_samples.fetch_add(1, Ordering::Relaxed);
             fail!(from self, with ZeroCopySendError::ReceiveBufferFull,
                         "{} since the receive buffer is full.", msg);
         }
 
+        if !self.used_offsets.insert(ptr) {
+            fail!(from self, with ZeroCopySendError::OffsetOutOfRange,
+                "{} since the offset {:?} does not point to a sample of the data segment.", msg, ptr);
+        }
+
         match unsafe { self.mgmt.receive_channel.push(ptr.value()) } {
-            Some(v) => Ok(Some(PointerOffset::new(v))),
+            Some(v) => {
+                let overflow = PointerOffset::new(v);
+                self.mgmt.dropped_samples.fetch_add(1, Ordering::Relaxed);
+                if !self.used_offsets.remove(overflow) {
+                    warn!(from self, "The receive buffer contained the offset {:?} which was never sent. It is not handed out.", overflow);
+                    return Ok(None);
+                }
+                Ok(Some(overflow))
+            }
             None => Ok(None),
         }
     }
@@ -329,15 +372,28 @@ impl ZeroCopySender for Sender {
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
         match unsafe { self.mgmt.retrieve_channel.pop() } {
             None => Ok(None),
-            Some(v) => Ok(Some(PointerOffset::new(v))),
+            Some(v) => {
+                let offset = PointerOffset::new(v);
+                if !self.used_offsets.remove(offset) {
+                    fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedOffset,
+                        "Unable to reclaim sample since the receiver returned the offset {:?} which was never sent or already reclaimed.", offset);
+                }
+                Ok(Some(offset))
+            }
         }
     }
+
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F) {
+        // the receiver is gone, everything in the retrieve channel is contained in the used
+        // offsets as well
+        while self.mgmt.retrieve_channel.pop().is_some() {}
+        self.used_offsets.drain(callback);
+    }
 }
 
 #[derive(Debug)]
 pub struct Receiver {
     mgmt: Arc<Management>,
-    borrow_counter: UnsafeCell<usize>,
     config: Configuration,
 }
 
@@ -375,17 +431,6 @@ impl Drop for Receiver {
     }
 }
 
-impl Receiver {
-    #[allow(clippy::mut_from_ref)]
-    // convenience to access internal mutable object
-    fn borrow_counter(&self) -> &mut usize {
-        #[deny(clippy::mut_from_ref)]
-        unsafe {
-            &mut *self.borrow_counter.get()
-        }
-    }
-}
-
 impl NamedConcept for Receiver {
     fn name(&self) -> &FileName {
         &self.mgmt.name
@@ -415,7 +460,7 @@ impl ZeroCopyReceiver for Receiver {
     fn receive(
         &self,
     ) -> Result<Option<crate::shared_memory::PointerOffset>, super::ZeroCopyReceiveError> {
-        if *self.borrow_counter() >= self.mgmt.max_borrowed_samples {
+        if self.mgmt.borrowed_samples.load(Ordering::Relaxed) >= self.mgmt.max_borrowed_samples {
             fail!(from self, with ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue,
                 "Unable to receive another sample since this would exceed the max borrow value.");
         }
@@ -423,7 +468,7 @@ impl ZeroCopyReceiver for Receiver {
         match unsafe { self.mgmt.receive_channel.pop() } {
             None => Ok(None),
             Some(v) => {
-                *self.borrow_counter() += 1;
+                self.mgmt.borrowed_samples.fetch_add(1, Ordering::Relaxed);
                 Ok(Some(PointerOffset::new(v)))
             }
         }
@@ -435,7 +480,7 @@ impl ZeroCopyReceiver for Receiver {
     ) -> Result<(), super::ZeroCopyReleaseError> {
         match unsafe { self.mgmt.retrieve_channel.push(ptr.value()) } {
             true => {
-                *self.borrow_counter() -= 1;
+                self.mgmt.borrowed_samples.fetch_sub(1, Ordering::Relaxed);
                 Ok(())
             }
             false => {
@@ -444,6 +489,12 @@ impl ZeroCopyReceiver for Receiver {
             }
         }
     }
+
+    unsafe fn acquire_released_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+        while let Some(v) = self.mgmt.retrieve_channel.pop() {
+            callback(PointerOffset::new(v));
+        }
+    }
 }
 
 pub struct Connection {}
@@ -515,4 +566,30 @@ impl ZeroCopyConnection for Connection {
     fn has_configurable_buffer_size() -> bool {
         true
     }
+
+    fn state_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<ZeroCopyConnectionState, ZeroCopyStateError> {
+        let msg = "Unable to acquire the state of zero_copy_connection::process_local";
+        let origin = "zero_copy_connection::process_local::Connection::state_cfg()";
+
+        let guard = fail!(from origin, when PROCESS_LOCAL_STORAGE.lock(),
+            with ZeroCopyStateError::InternalError,
+            "{} \"{}\" due to a failure while acquiring the lock to the global zero copy connections.", msg, name);
+
+        match guard.get(&config.path_for(name)) {
+            Some(mgmt) => Ok(ZeroCopyConnectionState {
+                buffer_size: mgmt.receive_channel.capacity(),
+                number_of_samples_in_buffer: mgmt.receive_channel.len(),
+                max_borrowed_samples: mgmt.max_borrowed_samples,
+                number_of_borrowed_samples: mgmt.borrowed_samples.load(Ordering::Relaxed),
+                number_of_dropped_samples: mgmt.dropped_samples.load(Ordering::Relaxed),
+            }),
+            None => {
+                fail!(from origin, with ZeroCopyStateError::DoesNotExist,
+                    "{} \"{}\" since it does not exist.", msg, name);
+            }
+        }
+    }
 }
@@ -0,0 +1,645 @@
+//! A [`ZeroCopyConnection`] which places all connections as files into one
+//! [`crate::shared_memory_directory::SharedMemoryDirectory`] instead of creating a POSIX shared
+//! memory object for every connection. Huge setups with thousands of connections therefore
+//! require only a single shared memory object.
+//!
+//! The directory is created on first use with the size defined in the [`Configuration`] and
+//! remains until it is removed explicitly. Every connection must fit into
+//! [`Configuration::max_connection_size()`].
+
+use std::alloc::Layout;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+use crate::named_concept::*;
+use crate::shared_memory_directory::file::File;
+use crate::shared_memory_directory::registry::{self, Directory, DirectoryConfig, DirectoryFile};
+use crate::shared_memory_directory::SharedMemoryDirectoryCreateFileError;
+use crate::zero_copy_connection::posix_shared_memory::{SharedManagementData, State};
+use crate::zero_copy_connection::used_offset_list::UsedOffsetList;
+pub use crate::zero_copy_connection::*;
+use elkodon_bb_log::fail;
+use elkodon_bb_posix::adaptive_wait::AdaptiveWaitBuilder;
+use elkodon_bb_posix::process::{Process, ProcessId};
+
+const MAX_CREATION_DURATION: Duration = Duration::from_millis(10);
+
+/// The default name of the directory that contains all connections
+pub const DEFAULT_DIRECTORY_NAME: FileName =
+    unsafe { FileName::new_unchecked(b"elkodon_zero_copy_connections") };
+
+/// The default size of the directory that contains all connections
+pub const DEFAULT_DIRECTORY_SIZE: usize = 16 * 1024 * 1024;
+
+/// The default of the maximum memory a single connection can occupy in the directory
+pub const DEFAULT_MAX_CONNECTION_SIZE: usize = 4096;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Configuration {
+    suffix: FileName,
+    path_hint: Path,
+    directory_name: FileName,
+    directory_size: usize,
+    max_connection_size: usize,
+}
+
+impl Default for Configuration {
+    fn default() -> Self {
+        Self {
+            suffix: DEFAULT_SUFFIX,
+            path_hint: DEFAULT_PATH_HINT,
+            directory_name: DEFAULT_DIRECTORY_NAME,
+            directory_size: DEFAULT_DIRECTORY_SIZE,
+            max_connection_size: DEFAULT_MAX_CONNECTION_SIZE,
+        }
+    }
+}
+
+impl NamedConceptConfiguration for Configuration {
+    fn suffix(mut self, value: FileName) -> Self {
+        self.suffix = value;
+        self
+    }
+
+    fn path_hint(mut self, value: Path) -> Self {
+        self.path_hint = value;
+        self
+    }
+
+    fn get_suffix(&self) -> &FileName {
+        &self.suffix
+    }
+
+    fn get_path_hint(&self) -> &Path {
+        &self.path_hint
+    }
+}
+
+impl Configuration {
+    /// Defines the name of the directory that contains the connections.
+    pub fn directory_name(mut self, value: FileName) -> Self {
+        self.directory_name = value;
+        self
+    }
+
+    /// Defines the size of the directory. It is only considered when the directory does not
+    /// yet exist.
+    pub fn directory_size(mut self, value: usize) -> Self {
+        self.directory_size = value;
+        self
+    }
+
+    /// Defines the maximum memory a single connection can occupy in the directory. It is only
+    /// considered when the directory does not yet exist.
+    pub fn max_connection_size(mut self, value: usize) -> Self {
+        self.max_connection_size = value;
+        self
+    }
+
+    pub fn get_directory_name(&self) -> &FileName {
+        &self.directory_name
+    }
+
+    pub fn get_directory_size(&self) -> usize {
+        self.directory_size
+    }
+
+    pub fn get_max_connection_size(&self) -> usize {
+        self.max_connection_size
+    }
+
+    fn directory_config(&self) -> DirectoryConfig {
+        DirectoryConfig {
+            name: self.directory_name,
+            size: self.directory_size,
+            bucket_layout: unsafe {
+                Layout::from_size_align_unchecked(
+                    self.max_connection_size,
+                    std::mem::align_of::<ConnectionData>(),
+                )
+            },
+        }
+    }
+
+    fn file_name_for(&self, name: &FileName) -> FileName {
+        unsafe { FileName::new_unchecked(self.path_for(name).file_name()) }
+    }
+}
+
+/// The content of a connection file. The process ids of the ports are stored to release the
+/// references of dead processes in [`NamedConceptMgmt::remove_cfg()`].
+#[repr(C)]
+struct ConnectionData {
+    sender_pid: AtomicU64,
+    receiver_pid: AtomicU64,
+    // must be the last member, the channels are placed directly behind it
+    mgmt: SharedManagementData,
+}
+
+impl ConnectionData {
+    fn from_file<'a>(file: &'a File<'_>) -> Option<&'a ConnectionData> {
+        match file.content().len() < std::mem::size_of::<ConnectionData>() {
+            true => None,
+            false => Some(unsafe { &*(file.content().as_ptr() as *const ConnectionData) }),
+        }
+    }
+
+    fn pid(&self, port: State) -> &AtomicU64 {
+        match port {
+            State::Sender => &self.sender_pid,
+            _ => &self.receiver_pid,
+        }
+    }
+
+    /// Releases the ports and file references of all processes that died while being
+    /// connected.
+    ///
+    /// # Safety
+    ///
+    ///  * `file` must be the file which contains the connection data
+    unsafe fn release_dead_ports(&self, file: &File<'_>) {
+        for port in [State::Sender, State::Receiver] {
+            let pid = self.pid(port).load(Ordering::Relaxed);
+            // a pid of 0 means that the port is either not connected or was just reserved
+            if pid == 0 || Process::from_pid(ProcessId::new(pid as _)).is_alive() {
+                continue;
+            }
+
+            if self
+                .pid(port)
+                .compare_exchange(pid, 0, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+            {
+                self.mgmt.release_port(port, false);
+                file.release_stale_reference();
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Builder {
+    name: FileName,
+    buffer_size: usize,
+    enable_safe_overflow: bool,
+    max_borrowed_samples: usize,
+    number_of_samples: Option<usize>,
+    sample_size: usize,
+    config: Configuration,
+}
+
+impl Builder {
+    fn receive_channel_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    fn retrieve_channel_size(&self) -> usize {
+        self.buffer_size + self.max_borrowed_samples + 1
+    }
+
+    fn layout(&self) -> Layout {
+        unsafe {
+            Layout::from_size_align_unchecked(
+                std::mem::size_of::<ConnectionData>() - std::mem::size_of::<SharedManagementData>()
+                    + SharedManagementData::const_memory_size(
+                        self.receive_channel_size(),
+                        self.retrieve_channel_size(),
+                    ),
+                std::mem::align_of::<ConnectionData>(),
+            )
+        }
+    }
+
+    fn try_open_or_create_file<'a>(
+        &self,
+        directory: &'a Directory,
+        file_name: &FileName,
+    ) -> Result<Option<File<'a>>, ZeroCopyCreationError> {
+        let msg = "Failed to acquire the connection file";
+        if let Some(file) = directory.open_file(file_name) {
+            return Ok(Some(file));
+        }
+
+        let layout = self.layout();
+        let creator = fail!(from self, when directory.new_file(layout),
+                            with ZeroCopyCreationError::InternalError,
+                            "{} since the memory for the connection could not be allocated. Does the connection with the size {} exceed the max connection size or is the directory full?",
+                            msg, layout.size());
+
+        match creator.create(file_name, |content| unsafe {
+            let data = content.as_mut_ptr() as *mut ConnectionData;
+            core::ptr::addr_of_mut!((*data).sender_pid).write(AtomicU64::new(0));
+            core::ptr::addr_of_mut!((*data).receiver_pid).write(AtomicU64::new(0));
+            SharedManagementData::init(
+                self,
+                core::ptr::addr_of_mut!((*data).mgmt),
+                self.receive_channel_size(),
+                self.retrieve_channel_size(),
+                self.enable_safe_overflow,
+                self.max_borrowed_samples,
+            );
+        }) {
+            Ok(file) => Ok(Some(file)),
+            Err(SharedMemoryDirectoryCreateFileError::DoesExist)
+            | Err(SharedMemoryDirectoryCreateFileError::BeingCreated) => Ok(None),
+            Err(SharedMemoryDirectoryCreateFileError::FileLimitExceeded) => {
+                fail!(from self, with ZeroCopyCreationError::InternalError,
+                    "{} since the directory already contains the maximum number of files.", msg);
+            }
+        }
+    }
+
+    fn open_or_create_file(&self) -> Result<DirectoryFile, ZeroCopyCreationError> {
+        let msg = "Failed to acquire the connection file";
+        let directory = fail!(from self, when registry::open_or_create(&self.config.directory_config()),
+                            with ZeroCopyCreationError::InternalError,
+                            "{} since the directory \"{}\" could not be opened or created.", msg, self.config.directory_name);
+        let file_name = self.config.file_name_for(&self.name);
+
+        let mut adaptive_wait = fail!(from self, when AdaptiveWaitBuilder::new().create(),
+                                    with ZeroCopyCreationError::InternalError, "{} since the adaptive wait could not be created.", msg);
+
+        loop {
+            let file = DirectoryFile::acquire(directory.clone(), |directory| {
+                match self.try_open_or_create_file(directory, &file_name) {
+                    Ok(Some(file)) => Ok(file),
+                    Ok(None) => Err(None),
+                    Err(e) => Err(Some(e)),
+                }
+            });
+
+            match file {
+                Ok(file) => {
+                    let data = match ConnectionData::from_file(file.borrow_file()) {
+                        Some(data) => data,
+                        None => {
+                            fail!(from self, with ZeroCopyCreationError::ConnectionMaybeCorrupted,
+                                "{} since the connection file has an unexpected size of {}.", msg, file.borrow_file().content().len());
+                        }
+                    };
+
+                    data.mgmt.verify_compatibility(
+                        self,
+                        self.receive_channel_size(),
+                        self.retrieve_channel_size(),
+                        self.enable_safe_overflow,
+                        self.max_borrowed_samples,
+                    )?;
+
+                    return Ok(file);
+                }
+                Err(Some(e)) => return Err(e),
+                // the connection is currently being created or removed by another instance
+                Err(None) => {
+                    if fail!(from self, when adaptive_wait.wait(), with ZeroCopyCreationError::InternalError,
+                            "{} since a failure while waiting for creation finalization occurred.", msg)
+                        >= MAX_CREATION_DURATION
+                    {
+                        fail!(from self, with ZeroCopyCreationError::InternalError,
+                            "{} since the connection is still being created or removed by another instance after {:?}.",
+                            msg, MAX_CREATION_DURATION);
+                    }
+                }
+            }
+        }
+    }
+
+    fn reserve_port(&self, port: State, msg: &str) -> Result<DirectoryFile, ZeroCopyCreationError> {
+        let file = fail!(from self, when self.open_or_create_file(),
+            "{} since the corresponding connection could not be created or opened", msg);
+
+        let data = unsafe { &*(file.content_address() as *const ConnectionData) };
+        data.mgmt.reserve_port(self, port.value(), msg)?;
+        data.pid(port)
+            .store(Process::from_self().id().value() as u64, Ordering::Relaxed);
+
+        Ok(file)
+    }
+}
+
+impl NamedConceptBuilder<Connection> for Builder {
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            buffer_size: DEFAULT_BUFFER_SIZE,
+            enable_safe_overflow: DEFAULT_ENABLE_SAFE_OVERFLOW,
+            max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
+            number_of_samples: None,
+            sample_size: DEFAULT_SAMPLE_SIZE,
+            config: Configuration::default(),
+        }
+    }
+
+    fn config(mut self, config: &Configuration) -> Self {
+        self.config = *config;
+        self
+    }
+}
+
+impl ZeroCopyConnectionBuilder<Connection> for Builder {
+    fn buffer_size(mut self, value: usize) -> Self {
+        self.buffer_size = value;
+        self
+    }
+
+    fn enable_safe_overflow(mut self, value: bool) -> Self {
+        self.enable_safe_overflow = value;
+        self
+    }
+
+    fn receiver_max_borrowed_samples(mut self, value: usize) -> Self {
+        self.max_borrowed_samples = value;
+        self
+    }
+
+    fn number_of_samples(mut self, value: usize) -> Self {
+        self.number_of_samples = Some(value);
+        self
+    }
+
+    fn sample_size(mut self, value: usize) -> Self {
+        self.sample_size = value;
+        self
+    }
+
+    fn create_sender(self) -> Result<Sender, ZeroCopyCreationError> {
+        let file = self.reserve_port(State::Sender, "Unable to create sender")?;
+
+        Ok(Sender {
+            file,
+            name: self.name,
+            used_offsets: UsedOffsetList::new(
+                self.number_of_samples,
+                self.sample_size,
+                self.retrieve_channel_size(),
+            ),
+        })
+    }
+
+    fn create_receiver(self) -> Result<Receiver, ZeroCopyCreationError> {
+        let file = self.reserve_port(State::Receiver, "Unable to create receiver")?;
+
+        Ok(Receiver {
+            file,
+            name: self.name,
+        })
+    }
+}
+
+fn release_port(file: &DirectoryFile, port: State) {
+    let data = unsafe { &*(file.content_address() as *const ConnectionData) };
+    data.pid(port).store(0, Ordering::Relaxed);
+    data.mgmt.release_port(port, false);
+}
+
+#[derive(Debug)]
+pub struct Sender {
+    file: DirectoryFile,
+    name: FileName,
+    used_offsets: UsedOffsetList,
+}
+
+impl Drop for Sender {
+    fn drop(&mut self) {
+        release_port(&self.file, State::Sender);
+    }
+}
+
+impl Sender {
+    fn mgmt(&self) -> &SharedManagementData {
+        unsafe { &(*(self.file.content_address() as *const ConnectionData)).mgmt }
+    }
+}
+
+impl NamedConcept for Sender {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl ZeroCopyPortDetails for Sender {
+    fn buffer_size(&self) -> usize {
+        self.mgmt().buffer_size()
+    }
+
+    fn max_borrowed_samples(&self) -> usize {
+        self.mgmt().max_borrowed_samples()
+    }
+
+    fn has_enabled_safe_overflow(&self) -> bool {
+        self.mgmt().has_enabled_safe_overflow()
+    }
+
+    fn is_connected(&self) -> bool {
+        self.mgmt().is_connected()
+    }
+}
+
+impl ZeroCopySender for Sender {
+    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        self.mgmt().try_send(self, &self.used_offsets, ptr)
+    }
+
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        self.mgmt().blocking_send(self, &self.used_offsets, ptr)
+    }
+
+    fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+        self.mgmt().reclaim(self, &self.used_offsets)
+    }
+
+    unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, callback: F) {
+        self.mgmt()
+            .acquire_used_offsets(&self.used_offsets, callback)
+    }
+}
+
+#[derive(Debug)]
+pub struct Receiver {
+    file: DirectoryFile,
+    name: FileName,
+}
+
+impl Drop for Receiver {
+    fn drop(&mut self) {
+        release_port(&self.file, State::Receiver);
+    }
+}
+
+impl Receiver {
+    fn mgmt(&self) -> &SharedManagementData {
+        unsafe { &(*(self.file.content_address() as *const ConnectionData)).mgmt }
+    }
+}
+
+impl NamedConcept for Receiver {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl ZeroCopyPortDetails for Receiver {
+    fn buffer_size(&self) -> usize {
+        self.mgmt().buffer_size()
+    }
+
+    fn max_borrowed_samples(&self) -> usize {
+        self.mgmt().max_borrowed_samples()
+    }
+
+    fn has_enabled_safe_overflow(&self) -> bool {
+        self.mgmt().has_enabled_safe_overflow()
+    }
+
+    fn is_connected(&self) -> bool {
+        self.mgmt().is_connected()
+    }
+}
+
+impl ZeroCopyReceiver for Receiver {
+    fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError> {
+        self.mgmt().receive(self)
+    }
+
+    fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
+        self.mgmt().release(self, ptr)
+    }
+
+    unsafe fn acquire_released_offsets<F: FnMut(PointerOffset)>(&self, callback: F) {
+        self.mgmt().acquire_released_offsets(callback)
+    }
+}
+
+pub struct Connection {}
+
+impl NamedConceptMgmt for Connection {
+    type Configuration = Configuration;
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptDoesExistError> {
+        let msg = "Unable to check if zero_copy_connection::shared_memory_directory exists";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::does_exist_cfg()";
+
+        match registry::open_for_mgmt(
+            &cfg.directory_name,
+            NamedConceptDoesExistError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => Ok(directory.does_file_exist(&cfg.file_name_for(name))),
+            None => Ok(false),
+        }
+    }
+
+    fn list_cfg(
+        cfg: &Self::Configuration,
+    ) -> Result<Vec<FileName>, crate::static_storage::file::NamedConceptListError> {
+        let msg = "Unable to list all zero_copy_connection::shared_memory_directory";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::list_cfg()";
+
+        let directory = match registry::open_for_mgmt(
+            &cfg.directory_name,
+            NamedConceptListError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => directory,
+            None => return Ok(vec![]),
+        };
+
+        let mut result = vec![];
+        for file in directory.list_files() {
+            if let Some(entry_name) = cfg.extract_name_from_file(&file.name()) {
+                result.push(entry_name);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Removes the connection from the directory. The ports of dead processes are released,
+    /// the memory of a connection with a living port is released when this port goes out of
+    /// scope.
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, crate::static_storage::file::NamedConceptRemoveError> {
+        let msg = "Unable to remove zero_copy_connection::shared_memory_directory";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::remove_cfg()";
+
+        let directory = match registry::open_for_mgmt(
+            &cfg.directory_name,
+            NamedConceptRemoveError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => directory,
+            None => return Ok(false),
+        };
+
+        let file_name = cfg.file_name_for(name);
+        if let Some(file) = directory.open_file(&file_name) {
+            if let Some(data) = ConnectionData::from_file(&file) {
+                data.release_dead_ports(&file);
+            }
+        }
+
+        Ok(directory.remove_file(&file_name))
+    }
+}
+
+impl ZeroCopyConnection for Connection {
+    type Sender = Sender;
+    type Builder = Builder;
+    type Receiver = Receiver;
+
+    fn does_support_safe_overflow() -> bool {
+        true
+    }
+
+    fn has_configurable_buffer_size() -> bool {
+        true
+    }
+
+    fn state_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<ZeroCopyConnectionState, ZeroCopyStateError> {
+        let msg = "Unable to acquire the state of zero_copy_connection::shared_memory_directory";
+        let origin = "zero_copy_connection::shared_memory_directory::Connection::state_cfg()";
+
+        let directory = match registry::open_for_mgmt(
+            &config.directory_name,
+            ZeroCopyStateError::InternalError,
+            origin,
+            msg,
+        )? {
+            Some(directory) => directory,
+            None => {
+                fail!(from origin, with ZeroCopyStateError::DoesNotExist,
+                    "{} \"{}\" since the directory \"{}\" does not exist.", msg, name, config.directory_name);
+            }
+        };
+
+        let file = match directory.open_file(&config.file_name_for(name)) {
+            Some(file) => file,
+            None => {
+                fail!(from origin, with ZeroCopyStateError::DoesNotExist,
+                    "{} \"{}\" since it does not exist.", msg, name);
+            }
+        };
+
+        match ConnectionData::from_file(&file) {
+            Some(data) => Ok(data.mgmt.connection_state()),
+            None => {
+                fail!(from origin, with ZeroCopyStateError::InternalError,
+                    "{} \"{}\" since the connection file has an unexpected size of {}.", msg, name, file.content().len());
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use std::cell::Cell;
+
+use super::PointerOffset;
+
+const UNUSED: usize = usize::MAX;
+
+/// Tracks every [`PointerOffset`] a sender has sent and not yet reclaimed. Those are the
+/// offsets the receiver currently owns, either in its receive buffer, borrowed by the user or
+/// released into the retrieve channel.
+///
+/// When the layout of the data segment of the sender is known, every offset is stored in the
+/// slot of its sample, so that insert and remove are O(1). Otherwise, the offsets are stored
+/// in the first free slot of a list that has room for every offset the receiver can own at
+/// the same time. In both cases the list never allocates after its creation.
+#[derive(Debug)]
+pub(crate) struct UsedOffsetList {
+    sample_size: Option<usize>,
+    used: Box<[Cell<usize>]>,
+}
+
+impl UsedOffsetList {
+    /// Creates a list with one slot per sample when `number_of_samples` is known, otherwise
+    /// with `capacity` slots.
+    pub(crate) fn new(
+        number_of_samples: Option<usize>,
+        sample_size: usize,
+        capacity: usize,
+    ) -> Self {
+        let (sample_size, number_of_slots) = match number_of_samples {
+            Some(n) => (Some(sample_size.max(1)), n),
+            None => (None, capacity),
+        };
+
+        Self {
+            sample_size,
+            used: (0..number_of_slots).map(|_| Cell::new(UNUSED)).collect(),
+        }
+    }
+
+    fn slot(&self, offset: PointerOffset) -> Option<&Cell<usize>> {
+        match self.sample_size {
+            Some(sample_size) => self.used.get(offset.value() / sample_size),
+            None => self.used.iter().find(|slot| slot.get() == offset.value()),
+        }
+    }
+
+    /// Returns false when the offset does not point to one of the samples or when there is
+    /// no free slot left.
+    pub(crate) fn insert(&self, offset: PointerOffset) -> bool {
+        let slot = match self.sample_size {
+            Some(sample_size) => self.used.get(offset.value() / sample_size),
+            None => self.used.iter().find(|slot| slot.get() == UNUSED),
+        };
+
+        match slot {
+            Some(slot) => {
+                slot.set(offset.value());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns false when the offset is not contained in the list.
+    pub(crate) fn remove(&self, offset: PointerOffset) -> bool {
+        match self.slot(offset) {
+            Some(slot) if slot.get() == offset.value() => {
+                slot.set(UNUSED);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn drain<F: FnMut(PointerOffset)>(&self, mut callback: F) {
+        for slot in self.used.iter() {
+            let value = slot.replace(UNUSED);
+            if value != UNUSED {
+                callback(PointerOffset::new(value));
+            }
+        }
+    }
+}
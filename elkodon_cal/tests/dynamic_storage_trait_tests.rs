@@ -435,4 +435,7 @@ mod dynamic_storage {
 
     #[instantiate_tests(<elkodon_cal::dynamic_storage::process_local::Storage<TestData>>)]
     mod process_local {}
+
+    #[instantiate_tests(<elkodon_cal::dynamic_storage::shared_memory_directory::Storage<TestData>>)]
+    mod shared_memory_directory {}
 }
@@ -0,0 +1,61 @@
+use elkodon_bb_container::semantic_string::*;
+use elkodon_bb_posix::file::*;
+use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+use elkodon_bb_system_types::file_name::FileName;
+use elkodon_bb_testing::assert_that;
+use elkodon_cal::monitoring::file_lock::*;
+
+fn generate_name() -> FileName {
+    let mut file = FileName::new(b"monitoring_file_lock_tests_").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+    file
+}
+
+// a token whose owner died is an unlocked file, the operating system released the lock
+fn create_token_of_dead_process(name: &FileName) {
+    let path = Configuration::default().path_for(name);
+    FileBuilder::new(&path)
+        .creation_mode(CreationMode::CreateExclusive)
+        .permission(Permission::OWNER_ALL)
+        .create()
+        .unwrap();
+}
+
+#[test]
+fn monitoring_file_lock_detects_dead_token() {
+    let name = generate_name();
+    create_token_of_dead_process(&name);
+
+    let monitor = Builder::new(&name).monitor().unwrap();
+    assert_that!(monitor.state(), eq Ok(State::Dead));
+
+    let cleaner = Builder::new(&name).cleaner();
+    assert_that!(cleaner, is_ok);
+    drop(cleaner);
+
+    assert_that!(monitor.state(), eq Ok(State::DoesNotExist));
+    assert_that!(Monitoring::does_exist(&name), eq Ok(false));
+}
+
+#[test]
+fn monitoring_file_lock_dead_token_can_be_cleaned_up_only_once() {
+    let name = generate_name();
+    create_token_of_dead_process(&name);
+
+    let cleaner = Builder::new(&name).cleaner().unwrap();
+    let cleaner2 = Builder::new(&name).cleaner();
+    assert_that!(cleaner2, is_err);
+    assert_that!(cleaner2.err().unwrap(), eq MonitoringCreateCleanerError::InstanceStillAlive);
+
+    drop(cleaner);
+    let cleaner2 = Builder::new(&name).cleaner();
+    assert_that!(cleaner2, is_err);
+    assert_that!(cleaner2.err().unwrap(), eq MonitoringCreateCleanerError::DoesNotExist);
+}
@@ -0,0 +1,125 @@
+#[generic_tests::define]
+mod monitoring {
+    use elkodon_bb_container::semantic_string::*;
+    use elkodon_bb_posix::unique_system_id::UniqueSystemId;
+    use elkodon_bb_system_types::file_name::FileName;
+    use elkodon_bb_testing::assert_that;
+    use elkodon_cal::monitoring::*;
+    use elkodon_cal::named_concept::*;
+
+    fn generate_name() -> FileName {
+        let mut file = FileName::new(b"monitoring_tests_").unwrap();
+        file.push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+        file
+    }
+
+    #[test]
+    fn create_token_works<Sut: Monitoring>() {
+        let name = generate_name();
+
+        let sut = Sut::Builder::new(&name).token();
+        assert_that!(sut, is_ok);
+        assert_that!(*sut.as_ref().unwrap().name(), eq name);
+        assert_that!(Sut::does_exist(&name), eq Ok(true));
+
+        drop(sut);
+        assert_that!(Sut::does_exist(&name), eq Ok(false));
+    }
+
+    #[test]
+    fn create_same_token_twice_fails<Sut: Monitoring>() {
+        let name = generate_name();
+
+        let _sut = Sut::Builder::new(&name).token().unwrap();
+        let sut2 = Sut::Builder::new(&name).token();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq MonitoringCreateTokenError::AlreadyExists);
+    }
+
+    #[test]
+    fn monitor_detects_alive_and_removed_token<Sut: Monitoring>() {
+        let name = generate_name();
+
+        let monitor = Sut::Builder::new(&name).monitor().unwrap();
+        assert_that!(monitor.state(), eq Ok(State::DoesNotExist));
+
+        let token = Sut::Builder::new(&name).token().unwrap();
+        assert_that!(monitor.state(), eq Ok(State::Alive));
+        // the monitor must not interfere with the token of the same process
+        assert_that!(monitor.state(), eq Ok(State::Alive));
+
+        drop(token);
+        assert_that!(monitor.state(), eq Ok(State::DoesNotExist));
+    }
+
+    #[test]
+    fn cleaner_cannot_be_acquired_when_token_is_alive<Sut: Monitoring>() {
+        let name = generate_name();
+
+        let _token = Sut::Builder::new(&name).token().unwrap();
+        let cleaner = Sut::Builder::new(&name).cleaner();
+        assert_that!(cleaner, is_err);
+        assert_that!(cleaner.err().unwrap(), eq MonitoringCreateCleanerError::InstanceStillAlive);
+
+        let monitor = Sut::Builder::new(&name).monitor().unwrap();
+        assert_that!(monitor.state(), eq Ok(State::Alive));
+    }
+
+    #[test]
+    fn cleaner_cannot_be_acquired_when_token_does_not_exist<Sut: Monitoring>() {
+        let name = generate_name();
+
+        let cleaner = Sut::Builder::new(&name).cleaner();
+        assert_that!(cleaner, is_err);
+        assert_that!(cleaner.err().unwrap(), eq MonitoringCreateCleanerError::DoesNotExist);
+    }
+
+    #[test]
+    fn list_tokens_works<Sut: Monitoring>() {
+        const LIMIT: usize = 8;
+        let config = <Sut as NamedConceptMgmt>::Configuration::default()
+            .suffix(unsafe { FileName::new_unchecked(b".list_test") });
+
+        let mut tokens = vec![];
+        let mut names = vec![];
+        for i in 0..LIMIT {
+            assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config).unwrap(), len i);
+            names.push(generate_name());
+            tokens.push(
+                Sut::Builder::new(&names[i])
+                    .config(&config)
+                    .token()
+                    .unwrap(),
+            );
+        }
+
+        let list = <Sut as NamedConceptMgmt>::list_cfg(&config).unwrap();
+        assert_that!(list, len LIMIT);
+        for name in &names {
+            assert_that!(list, contains * name);
+        }
+
+        tokens.clear();
+        assert_that!(<Sut as NamedConceptMgmt>::list_cfg(&config).unwrap(), len 0);
+    }
+
+    #[test]
+    fn defaults_for_configuration_are_set_correctly<Sut: Monitoring>() {
+        let config = <Sut as NamedConceptMgmt>::Configuration::default();
+        assert_that!(*config.get_suffix(), eq DEFAULT_SUFFIX);
+        assert_that!(*config.get_path_hint(), eq DEFAULT_PATH_HINT);
+    }
+
+    #[instantiate_tests(<elkodon_cal::monitoring::file_lock::Monitoring>)]
+    mod file_lock {}
+
+    #[instantiate_tests(<elkodon_cal::monitoring::process_local::Monitoring>)]
+    mod process_local {}
+}
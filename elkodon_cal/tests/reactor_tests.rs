@@ -546,4 +546,8 @@ mod reactor {
 
     #[instantiate_tests(<elkodon_cal::reactor::posix_select::Reactor>)]
     mod posix_select {}
+
+    #[cfg(target_os = "linux")]
+    #[instantiate_tests(<elkodon_cal::reactor::linux_epoll::Reactor>)]
+    mod linux_epoll {}
 }
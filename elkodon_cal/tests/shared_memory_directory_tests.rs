@@ -276,7 +276,7 @@ mod shared_memory_directory {
         let name = generate_name();
 
         let sut = SharedMemoryDirectoryCreator::new(&name)
-            .size(1024 * 1024)
+            .size(8 * 1024 * 1024)
             .create::<MgmtShm, Allocator, DataShm>(
                 &<Allocator as ShmAllocator>::Configuration::default(),
             )
@@ -313,7 +313,7 @@ mod shared_memory_directory {
         let name = generate_name();
 
         let sut_1 = SharedMemoryDirectoryCreator::new(&name)
-            .size(1024 * 1024)
+            .size(8 * 1024 * 1024)
             .create::<MgmtShm, Allocator, DataShm>(
                 &<Allocator as ShmAllocator>::Configuration::default(),
             )
@@ -467,4 +467,81 @@ mod shared_memory_directory {
             assert_that!(remove_result, eq false);
         });
     }
+
+    #[test]
+    fn memory_of_removed_files_is_released() {
+        let name = generate_name();
+
+        let sut = SharedMemoryDirectoryCreator::new(&name)
+            .size(16 * 1024)
+            .create::<MgmtShm, Allocator, DataShm>(
+                &<Allocator as ShmAllocator>::Configuration::default(),
+            )
+            .unwrap();
+
+        let mut files = vec![];
+        loop {
+            let file_creator = sut.new_file(Layout::new::<u64>());
+            if file_creator.is_err() {
+                break;
+            }
+
+            files.push(
+                file_creator
+                    .unwrap()
+                    .create(&generate_name(), |_| {})
+                    .unwrap(),
+            );
+        }
+
+        assert_that!(files, is_not_empty);
+
+        for _ in 0..files.len() * 4 {
+            files.pop();
+            let file = sut.new_file(Layout::new::<u64>());
+            assert_that!(file, is_ok);
+            files.push(file.unwrap().create(&generate_name(), |_| {}).unwrap());
+        }
+
+        let file = sut.open_file(&files[0].name()).unwrap();
+        let file_name = file.name();
+        assert_that!(sut.remove_file(&file_name), eq true);
+        drop(files.remove(0));
+        assert_that!(sut.new_file(Layout::new::<u64>()), is_err);
+
+        drop(file);
+        assert_that!(sut.new_file(Layout::new::<u64>()), is_ok);
+        assert_that!(sut.does_file_exist(&file_name), eq false);
+    }
+
+    #[test]
+    fn release_stale_reference_removes_file_of_dead_owner() {
+        let name = generate_name();
+
+        let sut = SharedMemoryDirectoryCreator::new(&name)
+            .size(1024 * 1024)
+            .create::<MgmtShm, Allocator, DataShm>(
+                &<Allocator as ShmAllocator>::Configuration::default(),
+            )
+            .unwrap();
+
+        let file_name = generate_name();
+        let file = sut
+            .new_file(Layout::new::<u8>())
+            .unwrap()
+            .create(&file_name, |_| {})
+            .unwrap();
+
+        // an owner that never releases its reference
+        std::mem::forget(sut.open_file(&file_name).unwrap());
+
+        drop(sut.open_file(&file_name).unwrap());
+        assert_that!(sut.does_file_exist(&file_name), eq true);
+
+        unsafe { file.release_stale_reference() };
+        assert_that!(sut.does_file_exist(&file_name), eq true);
+
+        drop(file);
+        assert_that!(sut.does_file_exist(&file_name), eq false);
+    }
 }
@@ -239,6 +239,188 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn acquire_used_offsets_returns_all_offsets_owned_by_receiver<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 10;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.try_send(PointerOffset::new(i)), is_ok);
+        }
+
+        // 0..3 are reclaimed, 3..6 are released but not reclaimed, 6..8 are borrowed and
+        // 8..10 are still in the receive buffer
+        for _ in 0..8 {
+            assert_that!(sut_receiver.receive().unwrap(), is_some);
+        }
+
+        for i in 0..6 {
+            assert_that!(sut_receiver.release(PointerOffset::new(i)), is_ok);
+        }
+
+        for i in 0..3 {
+            assert_that!(sut_sender.reclaim().unwrap().unwrap().value(), eq i);
+        }
+
+        drop(sut_receiver);
+
+        let mut offsets = vec![];
+        unsafe { sut_sender.acquire_used_offsets(|offset| offsets.push(offset.value())) };
+        offsets.sort();
+        assert_that!(offsets, eq(3..BUFFER_SIZE).collect::<Vec<_>>());
+
+        let mut offsets = vec![];
+        unsafe { sut_sender.acquire_used_offsets(|offset| offsets.push(offset.value())) };
+        assert_that!(offsets, is_empty);
+        assert_that!(sut_sender.reclaim().unwrap(), is_none);
+    }
+
+    #[test]
+    fn acquire_used_offsets_does_not_return_overflown_offsets<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 4;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create_sender()
+            .unwrap();
+
+        for i in 0..2 * BUFFER_SIZE {
+            assert_that!(sut_sender.try_send(PointerOffset::new(i)), is_ok);
+        }
+
+        let mut offsets = vec![];
+        unsafe { sut_sender.acquire_used_offsets(|offset| offsets.push(offset.value())) };
+        offsets.sort();
+        assert_that!(
+            offsets,
+            eq(BUFFER_SIZE..2 * BUFFER_SIZE).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn acquire_released_offsets_returns_offsets_that_were_not_reclaimed<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 4;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .create_receiver()
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(sut_sender.try_send(PointerOffset::new(i)), is_ok);
+        }
+
+        for _ in 0..BUFFER_SIZE {
+            let offset = sut_receiver.receive().unwrap().unwrap();
+            assert_that!(sut_receiver.release(offset), is_ok);
+        }
+        assert_that!(sut_sender.reclaim().unwrap().unwrap().value(), eq 0);
+        drop(sut_sender);
+
+        let mut offsets = vec![];
+        unsafe { sut_receiver.acquire_released_offsets(|offset| offsets.push(offset.value())) };
+        assert_that!(offsets, eq(1..BUFFER_SIZE).collect::<Vec<_>>());
+
+        let mut offsets = vec![];
+        unsafe { sut_receiver.acquire_released_offsets(|offset| offsets.push(offset.value())) };
+        assert_that!(offsets, is_empty);
+    }
+
+    #[test]
+    fn acquire_used_offsets_returns_offsets_of_samples_with_custom_size<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 8;
+        const SAMPLE_SIZE: usize = 64;
+        // the first sample of a data segment starts at the first aligned position
+        const ALIGNMENT_PADDING: usize = 10;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(2 * BUFFER_SIZE)
+            .sample_size(SAMPLE_SIZE)
+            .create_sender()
+            .unwrap();
+
+        for i in (0..2 * BUFFER_SIZE).step_by(2) {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(i * SAMPLE_SIZE + ALIGNMENT_PADDING)),
+                is_ok
+            );
+        }
+
+        let mut offsets = vec![];
+        unsafe { sut_sender.acquire_used_offsets(|offset| offsets.push(offset.value())) };
+        offsets.sort();
+        assert_that!(
+            offsets,
+            eq(0..2 * BUFFER_SIZE)
+                .step_by(2)
+                .map(|i| i * SAMPLE_SIZE + ALIGNMENT_PADDING)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn send_offset_outside_of_configured_samples_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const NUMBER_OF_SAMPLES: usize = 4;
+        const SAMPLE_SIZE: usize = 8;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .sample_size(SAMPLE_SIZE)
+            .create_sender()
+            .unwrap();
+
+        let result = sut_sender.try_send(PointerOffset::new(NUMBER_OF_SAMPLES * SAMPLE_SIZE));
+        assert_that!(result, is_err);
+        assert_that!(result.err().unwrap(), eq ZeroCopySendError::OffsetOutOfRange);
+
+        assert_that!(
+            sut_sender.try_send(PointerOffset::new((NUMBER_OF_SAMPLES - 1) * SAMPLE_SIZE)),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn reclaim_offset_that_was_never_sent_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name).create_sender().unwrap();
+        let sut_receiver = Sut::Builder::new(&name).create_receiver().unwrap();
+
+        assert_that!(sut_sender.try_send(PointerOffset::new(12)), is_ok);
+        let sample = sut_receiver.receive().unwrap().unwrap();
+        assert_that!(sut_receiver.release(PointerOffset::new(34)), is_ok);
+
+        let result = sut_sender.reclaim();
+        assert_that!(result, is_err);
+        assert_that!(
+            result.err().unwrap(), eq ZeroCopyReclaimError::ReceiverReturnedCorruptedOffset
+        );
+
+        assert_that!(sut_receiver.release(sample), is_ok);
+        assert_that!(sut_sender.reclaim().unwrap().unwrap().value(), eq 12);
+    }
+
     #[test]
     fn receive_can_acquire_data_with_late_connection<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -489,9 +671,91 @@ mod zero_copy_connection {
         assert_that!(*config.get_path_hint(), eq DEFAULT_PATH_HINT);
     }
 
+    #[test]
+    fn state_of_non_existing_connection_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        assert_that!(Sut::state(&name).err(), eq Some(ZeroCopyStateError::DoesNotExist));
+    }
+
+    #[test]
+    fn state_contains_buffer_fill_level_and_borrowed_samples<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 12;
+        const MAX_BORROW: usize = 3;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROW)
+            .create_sender()
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(MAX_BORROW)
+            .create_receiver()
+            .unwrap();
+
+        let state = Sut::state(&name).unwrap();
+        assert_that!(state.buffer_size, eq BUFFER_SIZE);
+        assert_that!(state.max_borrowed_samples, eq MAX_BORROW);
+        assert_that!(state.number_of_samples_in_buffer, eq 0);
+        assert_that!(state.number_of_borrowed_samples, eq 0);
+        assert_that!(state.number_of_dropped_samples, eq 0);
+
+        for i in 0..5 {
+            assert_that!(sut_sender.try_send(PointerOffset::new(i)), is_ok);
+        }
+        let sample = sut_receiver.receive().unwrap().unwrap();
+
+        let state = Sut::state(&name).unwrap();
+        assert_that!(state.number_of_samples_in_buffer, eq 4);
+        assert_that!(state.number_of_borrowed_samples, eq 1);
+
+        assert_that!(sut_receiver.release(sample), is_ok);
+        assert_that!(Sut::state(&name).unwrap().number_of_borrowed_samples, eq 0);
+    }
+
+    #[test]
+    fn state_counts_samples_that_did_not_fit_into_the_buffer<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 4;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .create_sender()
+            .unwrap();
+        for i in 0..BUFFER_SIZE + 3 {
+            let _ = sut_sender.try_send(PointerOffset::new(i));
+        }
+
+        assert_that!(Sut::state(&name).unwrap().number_of_dropped_samples, eq 3);
+    }
+
+    #[test]
+    fn state_counts_overflown_samples<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 4;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .enable_safe_overflow(true)
+            .create_sender()
+            .unwrap();
+        for i in 0..BUFFER_SIZE + 2 {
+            assert_that!(sut_sender.try_send(PointerOffset::new(i)), is_ok);
+        }
+
+        let state = Sut::state(&name).unwrap();
+        assert_that!(state.number_of_samples_in_buffer, eq BUFFER_SIZE);
+        assert_that!(state.number_of_dropped_samples, eq 2);
+    }
+
     #[instantiate_tests(<zero_copy_connection::posix_shared_memory::Connection>)]
     mod posix_shared_memory {}
 
     #[instantiate_tests(<zero_copy_connection::process_local::Connection>)]
     mod process_local {}
+
+    #[instantiate_tests(<zero_copy_connection::shared_memory_directory::Connection>)]
+    mod shared_memory_directory {}
 }
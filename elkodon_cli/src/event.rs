@@ -0,0 +1,77 @@
+use std::time::Duration;
+
+use clap::Subcommand;
+use elkodon::config::Config;
+use elkodon::port::event_id::EventId;
+use elkodon::service::Service;
+use elkodon_bb_posix::signal::SignalHandler;
+
+// the interval in which the listener checks whether the termination was requested
+const WAIT_INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Notifies all listeners of an event service with an event id
+    Notify {
+        /// The name of the service
+        name: String,
+        /// The event id that is sent with the notification
+        id: u64,
+    },
+    /// Waits for notifications of an event service and prints their event ids
+    Listen {
+        /// The name of the service
+        name: String,
+    },
+}
+
+pub fn run<S: Service>(
+    command: Command,
+    config: &Config,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::Notify { name, id } => notify::<S>(config, &name, id),
+        Command::Listen { name } => listen::<S>(config, &name),
+    }
+}
+
+fn notify<S: Service>(
+    config: &Config,
+    name: &str,
+    id: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let event = S::new(&crate::service_name(name)?)
+        .event_with_custom_config(config)
+        .open()?;
+    let notifier = event.notifier().create()?;
+
+    let number_of_listeners = notifier.notify_with_custom_event_id(EventId::new(id))?;
+    println!(
+        "notified {} listener(s) with event id {}",
+        number_of_listeners, id
+    );
+
+    Ok(())
+}
+
+fn listen<S: Service>(config: &Config, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let event = S::new(&crate::service_name(name)?)
+        .event_with_custom_config(config)
+        .open()?;
+    let mut listener = event.listener().create()?;
+
+    while !SignalHandler::termination_requested() {
+        let event_ids = match listener.timed_wait(WAIT_INTERVAL) {
+            Ok(event_ids) => event_ids,
+            // the wait is interrupted by the termination signal
+            Err(_) if SignalHandler::termination_requested() => break,
+            Err(e) => return Err(e.into()),
+        };
+
+        for event_id in event_ids {
+            println!("{}", event_id.as_u64());
+        }
+    }
+
+    Ok(())
+}
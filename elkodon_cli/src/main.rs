@@ -0,0 +1,182 @@
+//! # elk
+//!
+//! Lists and inspects the services of elkodon and interacts with them from the command line.
+//!
+//! ```console
+//! elk service list
+//! elk service show My/Funk/ServiceName
+//! elk event notify My/Funk/EventName 12
+//! elk event listen My/Funk/EventName
+//! elk pubsub echo My/Funk/ServiceName
+//! elk --service-type copy service list
+//! ```
+
+mod event;
+mod pubsub;
+mod service;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use elkodon::config::Config;
+use elkodon::service::service_name::ServiceName;
+use elkodon::service::{copy, grouped, zero_copy, Service};
+use elkodon_bb_container::semantic_string::SemanticString;
+use elkodon_bb_system_types::file_path::FilePath;
+
+#[derive(Debug, Parser)]
+#[clap(
+    name = "elk",
+    version,
+    about = "Lists, inspects and interacts with elkodon services"
+)]
+struct Cli {
+    /// Uses the configuration file instead of the default configuration
+    #[clap(long, short, global = true)]
+    config: Option<String>,
+
+    /// The type of the services, it must be the same type the applications use
+    #[clap(long, global = true, value_enum, default_value = "zero-copy")]
+    service_type: ServiceType,
+
+    #[clap(subcommand)]
+    command: Command,
+}
+
+// process local services are not listed since they are not visible outside of their process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ServiceType {
+    ZeroCopy,
+    Copy,
+    Grouped,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Lists and inspects services
+    #[clap(subcommand)]
+    Service(service::Command),
+    /// Notifies and listens on event services
+    #[clap(subcommand)]
+    Event(event::Command),
+    /// Interacts with publish-subscribe services
+    #[clap(subcommand)]
+    Pubsub(pubsub::Command),
+}
+
+fn load_config(config_file: &Option<String>) -> Result<Config, Box<dyn std::error::Error>> {
+    match config_file {
+        None => Ok(Config::get_global_config().clone()),
+        Some(file) => Ok(Config::from_file(&FilePath::new(file.as_bytes())?)?),
+    }
+}
+
+fn service_name(name: &str) -> Result<ServiceName, Box<dyn std::error::Error>> {
+    Ok(ServiceName::new(name.as_bytes())?)
+}
+
+fn run<S: Service>(command: Command, config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::Service(command) => service::run::<S>(command, config),
+        Command::Event(command) => event::run::<S>(command, config),
+        Command::Pubsub(command) => pubsub::run::<S>(command, config),
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+    let config = load_config(&cli.config)?;
+
+    match cli.service_type {
+        ServiceType::ZeroCopy => run::<zero_copy::Service>(cli.command, &config),
+        ServiceType::Copy => run::<copy::Service>(cli.command, &config),
+        ServiceType::Grouped => run::<grouped::Service>(cli.command, &config),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use elkodon_bb_testing::assert_that;
+
+    use crate::{event, pubsub, service, Cli, Command, ServiceType};
+
+    #[test]
+    fn service_type_is_zero_copy_by_default() {
+        let sut = Cli::try_parse_from(["elk", "service", "list"]).unwrap();
+
+        assert_that!(sut.service_type, eq ServiceType::ZeroCopy);
+        assert_that!(sut.config, is_none);
+        assert_that!(matches!(sut.command, Command::Service(service::Command::List { all: false })), eq true);
+    }
+
+    #[test]
+    fn global_options_are_accepted_after_the_subcommand() {
+        let sut = Cli::try_parse_from([
+            "elk",
+            "service",
+            "list",
+            "--all",
+            "--service-type",
+            "copy",
+            "--config",
+            "elkodon.toml",
+        ])
+        .unwrap();
+
+        assert_that!(sut.service_type, eq ServiceType::Copy);
+        assert_that!(sut.config, eq Some("elkodon.toml".to_string()));
+        assert_that!(matches!(sut.command, Command::Service(service::Command::List { all: true })), eq true);
+    }
+
+    #[test]
+    fn unknown_service_type_fails() {
+        let sut =
+            Cli::try_parse_from(["elk", "--service-type", "process-local", "service", "list"]);
+
+        assert_that!(sut, is_err);
+    }
+
+    #[test]
+    fn event_notify_requires_numeric_event_id() {
+        let sut = Cli::try_parse_from(["elk", "event", "notify", "My/Event", "12"]).unwrap();
+        match sut.command {
+            Command::Event(event::Command::Notify { name, id }) => {
+                assert_that!(name, eq "My/Event");
+                assert_that!(id, eq 12);
+            }
+            command => panic!("unexpected command {:?}", command),
+        }
+
+        let sut = Cli::try_parse_from(["elk", "event", "notify", "My/Event", "twelve"]);
+        assert_that!(sut, is_err);
+    }
+
+    #[test]
+    fn pubsub_echo_parses_hex_flag() {
+        let sut = Cli::try_parse_from([
+            "elk",
+            "--service-type",
+            "grouped",
+            "pubsub",
+            "echo",
+            "My/Service",
+            "--hex",
+        ])
+        .unwrap();
+
+        assert_that!(sut.service_type, eq ServiceType::Grouped);
+        match sut.command {
+            Command::Pubsub(pubsub::Command::Echo { name, hex }) => {
+                assert_that!(name, eq "My/Service");
+                assert_that!(hex, eq true);
+            }
+            command => panic!("unexpected command {:?}", command),
+        }
+    }
+
+    #[test]
+    fn missing_service_name_fails() {
+        assert_that!(Cli::try_parse_from(["elk", "service", "show"]), is_err);
+        assert_that!(Cli::try_parse_from(["elk", "pubsub", "echo"]), is_err);
+        assert_that!(Cli::try_parse_from(["elk", "event", "listen"]), is_err);
+    }
+}
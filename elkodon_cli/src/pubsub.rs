@@ -0,0 +1,199 @@
+use clap::Subcommand;
+use elkodon::config::Config;
+use elkodon::port::subscriber::ReceiveError;
+use elkodon::service::Service;
+
+const HEX_DUMP_BYTES_PER_LINE: usize = 16;
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Receives the samples of a publish-subscribe service and prints their payload, requires a
+    /// service with notifications enabled
+    Echo {
+        /// The name of the service
+        name: String,
+        /// Prints a hex dump of the payload even when its type is known
+        #[clap(long)]
+        hex: bool,
+    },
+}
+
+pub fn run<S: Service>(
+    command: Command,
+    config: &Config,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::Echo { name, hex } => echo::<S>(config, &name, hex),
+    }
+}
+
+// a primitive type that can be printed, every element of the payload has the size of the type
+struct Primitive {
+    size: usize,
+    format: fn(&[u8]) -> String,
+}
+
+macro_rules! primitive {
+    ($t:ty) => {
+        Primitive {
+            size: std::mem::size_of::<$t>(),
+            format: |bytes| <$t>::from_ne_bytes(bytes.try_into().unwrap()).to_string(),
+        }
+    };
+}
+
+fn primitive(type_name: &str) -> Option<Primitive> {
+    Some(match type_name {
+        "u8" => primitive!(u8),
+        "u16" => primitive!(u16),
+        "u32" => primitive!(u32),
+        "u64" => primitive!(u64),
+        "u128" => primitive!(u128),
+        "usize" => primitive!(usize),
+        "i8" => primitive!(i8),
+        "i16" => primitive!(i16),
+        "i32" => primitive!(i32),
+        "i64" => primitive!(i64),
+        "i128" => primitive!(i128),
+        "isize" => primitive!(isize),
+        "f32" => primitive!(f32),
+        "f64" => primitive!(f64),
+        "bool" => Primitive {
+            size: 1,
+            format: |bytes| (bytes[0] != 0).to_string(),
+        },
+        _ => return None,
+    })
+}
+
+// the type name is the one of std::any::type_name(), slices "[T]" and arrays "[T; N]" of
+// primitives are printed as a list of their elements
+fn pretty_print(type_name: &str, payload: &[u8]) -> Option<String> {
+    let (element_type, is_list) = match type_name
+        .strip_prefix('[')
+        .and_then(|t| t.strip_suffix(']'))
+    {
+        Some(inner) => (inner.split(';').next().unwrap_or(inner).trim(), true),
+        None => (type_name, false),
+    };
+
+    let primitive = primitive(element_type)?;
+    if payload.len() % primitive.size != 0 || (!is_list && payload.len() != primitive.size) {
+        return None;
+    }
+
+    let elements: Vec<String> = payload
+        .chunks_exact(primitive.size)
+        .map(primitive.format)
+        .collect();
+
+    match is_list {
+        true => Some(format!("[{}]", elements.join(", "))),
+        false => Some(elements.join("")),
+    }
+}
+
+fn hex_dump(payload: &[u8]) -> String {
+    payload
+        .chunks(HEX_DUMP_BYTES_PER_LINE)
+        .enumerate()
+        .map(|(line, bytes)| {
+            let bytes: Vec<String> = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            format!(
+                "{:08x}  {}",
+                line * HEX_DUMP_BYTES_PER_LINE,
+                bytes.join(" ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn echo<S: Service>(
+    config: &Config,
+    name: &str,
+    hex: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let service = S::new(&crate::service_name(name)?)
+        .publish_subscribe_with_custom_config(config)
+        .open_untyped()?;
+
+    if !service.static_config().has_notifications() {
+        return Err(format!(
+            "The service \"{}\" has no notifications enabled, therefore its samples cannot be awaited.",
+            name
+        )
+        .into());
+    }
+
+    let type_name = service.static_config().type_name().to_string();
+    let type_fingerprint = service.static_config().type_fingerprint();
+    println!(
+        "type: {} (size: {}, alignment: {})",
+        type_name,
+        type_fingerprint.size(),
+        type_fingerprint.alignment()
+    );
+
+    let subscriber = service.subscriber().create()?;
+
+    loop {
+        let sample = match subscriber.blocking_receive() {
+            Ok(sample) => sample,
+            Err(ReceiveError::TerminationRequested) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+
+        let pretty = match hex {
+            true => None,
+            false => pretty_print(&type_name, sample.payload()),
+        };
+
+        match pretty {
+            Some(value) => println!("{}", value),
+            None => println!("{}", hex_dump(sample.payload())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use elkodon_bb_testing::assert_that;
+
+    use crate::pubsub::{hex_dump, pretty_print};
+
+    #[test]
+    fn pretty_print_formats_primitives() {
+        assert_that!(pretty_print("u32", &1234u32.to_ne_bytes()), eq Some("1234".to_string()));
+        assert_that!(pretty_print("i16", &(-12i16).to_ne_bytes()), eq Some("-12".to_string()));
+        assert_that!(pretty_print("f64", &1.5f64.to_ne_bytes()), eq Some("1.5".to_string()));
+        assert_that!(pretty_print("bool", &[1]), eq Some("true".to_string()));
+        assert_that!(pretty_print("bool", &[0]), eq Some("false".to_string()));
+    }
+
+    #[test]
+    fn pretty_print_formats_slices_and_arrays_as_list() {
+        let payload: Vec<u8> = [1u16, 2, 3].iter().flat_map(|v| v.to_ne_bytes()).collect();
+
+        assert_that!(pretty_print("[u16]", &payload), eq Some("[1, 2, 3]".to_string()));
+        assert_that!(pretty_print("[u16; 3]", &payload), eq Some("[1, 2, 3]".to_string()));
+        assert_that!(pretty_print("[u8]", &[]), eq Some("[]".to_string()));
+    }
+
+    #[test]
+    fn pretty_print_fails_for_unknown_types_and_mismatching_sizes() {
+        assert_that!(pretty_print("my::Struct", &[0; 8]), is_none);
+        assert_that!(pretty_print("u64", &[0; 4]), is_none);
+        assert_that!(pretty_print("[u32]", &[0; 6]), is_none);
+    }
+
+    #[test]
+    fn hex_dump_prints_sixteen_bytes_per_line_with_offset() {
+        let payload: Vec<u8> = (0..20).collect();
+
+        assert_that!(hex_dump(&payload), eq
+            "00000000  00 01 02 03 04 05 06 07 08 09 0a 0b 0c 0d 0e 0f\n00000010  10 11 12 13");
+        assert_that!(hex_dump(&[0xab]), eq "00000000  ab");
+        assert_that!(hex_dump(&[]), eq "");
+    }
+}
@@ -0,0 +1,126 @@
+use clap::Subcommand;
+use elkodon::config::Config;
+use elkodon::introspection::{self, Introspector};
+use elkodon::service::messaging_pattern::MessagingPattern;
+use elkodon::service::static_config::StaticConfig;
+use elkodon::service::{Details, Service};
+use elkodon_bb_container::semantic_string::SemanticString;
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Lists all services
+    List {
+        /// Lists the introspection services as well
+        #[clap(long)]
+        all: bool,
+    },
+    /// Shows the static configuration and the ports of a service
+    Show {
+        /// The name of the service
+        name: String,
+    },
+}
+
+pub fn run<S: Service>(
+    command: Command,
+    config: &Config,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match command {
+        Command::List { all } => list::<S>(config, all),
+        Command::Show { name } => show::<S>(config, &name),
+    }
+}
+
+fn messaging_pattern_name(messaging_pattern: &MessagingPattern) -> &'static str {
+    match messaging_pattern {
+        MessagingPattern::PublishSubscribe(_) => "publish_subscribe",
+        MessagingPattern::Event(_) => "event",
+        MessagingPattern::RequestResponse(_) => "request_response",
+        MessagingPattern::Blackboard(_) => "blackboard",
+        MessagingPattern::Pipeline(_) => "pipeline",
+        _ => "unknown",
+    }
+}
+
+fn list<S: Service>(config: &Config, all: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut services = S::Type::list_with_custom_config(config)?;
+    services.retain(|s| all || !introspection::is_introspection_service(s));
+    services.sort_by(|lhs, rhs| {
+        lhs.service_name()
+            .as_bytes()
+            .cmp(rhs.service_name().as_bytes())
+    });
+
+    for service in services {
+        println!(
+            "{:<18} {}",
+            messaging_pattern_name(service.messaging_pattern()),
+            service.service_name()
+        );
+    }
+
+    Ok(())
+}
+
+fn show<S: Service>(config: &Config, name: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let name = crate::service_name(name)?;
+    let services: Vec<StaticConfig> = S::Type::list_with_custom_config(config)?
+        .into_iter()
+        .filter(|s| *s.service_name() == name)
+        .collect();
+
+    if services.is_empty() {
+        return Err(format!("The service \"{}\" does not exist.", name).into());
+    }
+
+    let introspector = Introspector::<S>::new_with_custom_config(config);
+    for service in services {
+        println!("name:              {}", service.service_name());
+        println!("uuid:              {}", service.uuid());
+        println!(
+            "messaging pattern: {}",
+            messaging_pattern_name(service.messaging_pattern())
+        );
+
+        println!("attributes:");
+        for attribute in service.attributes().iter() {
+            println!("  {} = {}", attribute.key(), attribute.value());
+        }
+
+        println!("static config:     {:#?}", service.messaging_pattern());
+
+        match introspector.port_states(&service) {
+            None => println!("ports:             the service was removed"),
+            Some(ports) => {
+                println!("ports:             {}", ports.len());
+                println!(
+                    "  {:<10} {:>8} {:>40} {:>11} {:>9} {:>8} {:>6} {:>7}",
+                    "kind",
+                    "pid",
+                    "port id",
+                    "connections",
+                    "in buffer",
+                    "borrowed",
+                    "loaned",
+                    "dropped"
+                );
+                for port in ports {
+                    println!(
+                        "  {:<10} {:>8} {:>40} {:>11} {:>9} {:>8} {:>6} {:>7}",
+                        format!("{:?}", port.kind),
+                        port.pid,
+                        port.port_id,
+                        port.number_of_connections,
+                        port.samples_in_buffer,
+                        port.borrowed_samples,
+                        port.loaned_samples,
+                        port.dropped_samples
+                    );
+                }
+            }
+        }
+        println!();
+    }
+
+    Ok(())
+}
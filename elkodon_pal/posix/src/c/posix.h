@@ -10,6 +10,7 @@
 #ifdef __linux__
 #include <acl/libacl.h>
 #include <mqueue.h>
+#include <sys/epoll.h>
 #endif
 
 #ifndef _WIN64
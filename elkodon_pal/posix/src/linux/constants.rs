@@ -193,6 +193,16 @@ pub const SCM_MAX_FD: u32 = 253;
 pub const SCM_RIGHTS: int = crate::internal::SCM_RIGHTS as _;
 pub const SOL_SOCKET: int = crate::internal::SOL_SOCKET as _;
 pub const SUN_PATH_LEN: usize = 108;
+
+pub const EPOLL_CLOEXEC: int = crate::internal::EPOLL_CLOEXEC as _;
+pub const EPOLL_CTL_ADD: int = crate::internal::EPOLL_CTL_ADD as _;
+pub const EPOLL_CTL_MOD: int = crate::internal::EPOLL_CTL_MOD as _;
+pub const EPOLL_CTL_DEL: int = crate::internal::EPOLL_CTL_DEL as _;
+pub const EPOLLIN: u32 = crate::internal::EPOLL_EVENTS_EPOLLIN as _;
+pub const EPOLLPRI: u32 = crate::internal::EPOLL_EVENTS_EPOLLPRI as _;
+pub const EPOLLOUT: u32 = crate::internal::EPOLL_EVENTS_EPOLLOUT as _;
+pub const EPOLLERR: u32 = crate::internal::EPOLL_EVENTS_EPOLLERR as _;
+pub const EPOLLHUP: u32 = crate::internal::EPOLL_EVENTS_EPOLLHUP as _;
 pub const SA_DATA_LEN: usize = 14;
 
 pub const S_IFMT: mode_t = crate::internal::S_IFMT as _;
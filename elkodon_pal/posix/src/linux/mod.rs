@@ -1,6 +1,7 @@
 pub mod acl;
 pub mod constants;
 pub mod dirent;
+pub mod epoll;
 pub mod errno;
 pub mod fcntl;
 pub mod inet;
@@ -26,6 +27,7 @@ pub mod unistd;
 pub use crate::linux::acl::*;
 pub use crate::linux::constants::*;
 pub use crate::linux::dirent::*;
+pub use crate::linux::epoll::*;
 pub use crate::linux::errno::*;
 pub use crate::linux::fcntl::*;
 pub use crate::linux::inet::*;
@@ -0,0 +1,33 @@
+use elkodon::prelude::*;
+use elkodon_bb_posix::signal::SignalHandler;
+
+const COUNTER_KEY: u64 = 0;
+const TEMPERATURE_KEY: u64 = 1;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .blackboard::<u64>()
+        .add::<u64>(COUNTER_KEY, 0)
+        .add::<f64>(TEMPERATURE_KEY, 20.0)
+        .open_or_create()?;
+
+    let reader = service.reader().create()?;
+    let counter_entry = reader.entry::<u64>(&COUNTER_KEY)?;
+    let temperature_entry = reader.entry::<f64>(&TEMPERATURE_KEY)?;
+
+    while !SignalHandler::termination_requested() {
+        println!(
+            "counter: {}, temperature: {}",
+            counter_entry.get(),
+            temperature_entry.get()
+        );
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
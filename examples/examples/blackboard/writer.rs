@@ -0,0 +1,35 @@
+use elkodon::prelude::*;
+use elkodon_bb_posix::signal::SignalHandler;
+
+const COUNTER_KEY: u64 = 0;
+const TEMPERATURE_KEY: u64 = 1;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(b"My/Funk/Blackboard")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .blackboard::<u64>()
+        .add::<u64>(COUNTER_KEY, 0)
+        .add::<f64>(TEMPERATURE_KEY, 20.0)
+        .open_or_create()?;
+
+    let writer = service.writer().create()?;
+    let counter_entry = writer.entry::<u64>(&COUNTER_KEY)?;
+    let temperature_entry = writer.entry::<f64>(&TEMPERATURE_KEY)?;
+
+    let mut counter: u64 = 0;
+
+    while !SignalHandler::termination_requested() {
+        counter += 1;
+        counter_entry.update(counter);
+        temperature_entry.update(20.0 + (counter % 10) as f64 * 0.5);
+
+        println!("Update entries to counter {} ...", counter);
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use elkodon::prelude::*;
+use elkodon_bb_posix::signal::SignalHandler;
+use transmission_data::TransmissionData;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(b"My/Funk/Pipeline")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .pipeline()
+        .open_or_create::<TransmissionData>()?;
+
+    let producer = service.producer().create()?;
+
+    let mut counter: u64 = 0;
+
+    while !SignalHandler::termination_requested() {
+        let mut work_item = producer.loan()?;
+        unsafe {
+            work_item.as_mut_ptr().write(TransmissionData {
+                x: counter as i32,
+                y: counter as i32 * 3,
+                funky: counter as f64 * 812.12,
+            });
+        }
+
+        counter += 1;
+        match producer.send(work_item) {
+            Ok(()) => println!("Send work item {} ...", counter),
+            Err(e) => println!("Unable to send work item {} ({:?})", counter, e),
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(250));
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use elkodon::prelude::*;
+use elkodon_bb_posix::signal::SignalHandler;
+use transmission_data::TransmissionData;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(b"My/Funk/Pipeline")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .pipeline()
+        .open_or_create::<TransmissionData>()?;
+
+    let worker = service.worker().create()?;
+
+    while !SignalHandler::termination_requested() {
+        while let Some(work_item) = worker.receive()? {
+            println!("processing work item: {:?}", *work_item);
+            std::thread::sleep(std::time::Duration::from_millis(500));
+        }
+
+        std::thread::sleep(std::time::Duration::from_millis(100));
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use elkodon::prelude::*;
+use elkodon_bb_posix::signal::SignalHandler;
+use transmission_data::TransmissionData;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(b"My/Funk/RequestResponse")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .request_response()
+        .open_or_create::<u64, TransmissionData>()?;
+
+    let client = service.client().create()?;
+
+    let mut counter: u64 = 0;
+
+    while !SignalHandler::termination_requested() {
+        let mut request = client.loan()?;
+        unsafe { request.as_mut_ptr().write(counter) };
+        let number_of_servers = client.send(request)?;
+
+        counter += 1;
+        println!(
+            "Send request {} to {} server(s) ...",
+            counter, number_of_servers
+        );
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+
+        while let Some(response) = client.receive()? {
+            println!(
+                "received response to request {}: {:?}",
+                response.header().request_id(),
+                *response
+            );
+        }
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
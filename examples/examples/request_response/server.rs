@@ -0,0 +1,35 @@
+use elkodon::prelude::*;
+use elkodon_bb_posix::signal::SignalHandler;
+use transmission_data::TransmissionData;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let service_name = ServiceName::new(b"My/Funk/RequestResponse")?;
+
+    let service = zero_copy::Service::new(&service_name)
+        .request_response()
+        .open_or_create::<u64, TransmissionData>()?;
+
+    let server = service.server().create()?;
+
+    while !SignalHandler::termination_requested() {
+        while let Some(request) = server.receive()? {
+            println!("received request: {:?}", *request);
+
+            let mut response = request.loan()?;
+            unsafe {
+                response.as_mut_ptr().write(TransmissionData {
+                    x: *request as i32,
+                    y: *request as i32 * 3,
+                    funky: *request as f64 * 812.12,
+                });
+            }
+            response.send()?;
+        }
+
+        std::thread::sleep(std::time::Duration::from_secs(1));
+    }
+
+    println!("exit ...");
+
+    Ok(())
+}
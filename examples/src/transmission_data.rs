@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use elkodon::prelude::*;
+
+#[derive(Debug, ZeroCopySend)]
 #[repr(C)]
 pub struct TransmissionData {
     pub x: i32,